@@ -1,5 +1,5 @@
 use crate::any::{Any, AnyTypeInfo};
-use crate::column::{Column, ColumnIndex};
+use crate::column::{Column, ColumnIndex, UStr};
 
 #[cfg(feature = "postgres")]
 use crate::postgres::{PgColumn, PgRow, PgStatement};
@@ -71,6 +71,22 @@ impl Column for AnyColumn {
         }
     }
 
+    fn name_shared(&self) -> UStr {
+        match &self.kind {
+            #[cfg(feature = "postgres")]
+            AnyColumnKind::Postgres(row) => row.name_shared(),
+
+            #[cfg(feature = "mysql")]
+            AnyColumnKind::MySql(row) => row.name_shared(),
+
+            #[cfg(feature = "sqlite")]
+            AnyColumnKind::Sqlite(row) => row.name_shared(),
+
+            #[cfg(feature = "mssql")]
+            AnyColumnKind::Mssql(row) => row.name_shared(),
+        }
+    }
+
     fn type_info(&self) -> &AnyTypeInfo {
         &self.type_info
     }
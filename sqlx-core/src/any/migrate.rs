@@ -2,7 +2,7 @@ use crate::any::connection::AnyConnectionKind;
 use crate::any::kind::AnyKind;
 use crate::any::{Any, AnyConnection};
 use crate::error::Error;
-use crate::migrate::{Migrate, MigrateDatabase, MigrateError, Migration};
+use crate::migrate::{Migrate, MigrateDatabase, MigrateError, Migration, MigrationProgress};
 use futures_core::future::BoxFuture;
 use std::str::FromStr;
 use std::time::Duration;
@@ -21,7 +21,7 @@ impl MigrateDatabase for Any {
                 AnyKind::MySql => crate::mysql::MySql::create_database(uri).await,
 
                 #[cfg(feature = "mssql")]
-                AnyKind::Mssql => unimplemented!(),
+                AnyKind::Mssql => crate::mssql::Mssql::create_database(uri).await,
             }
         })
     }
@@ -39,7 +39,7 @@ impl MigrateDatabase for Any {
                 AnyKind::MySql => crate::mysql::MySql::database_exists(uri).await,
 
                 #[cfg(feature = "mssql")]
-                AnyKind::Mssql => unimplemented!(),
+                AnyKind::Mssql => crate::mssql::Mssql::database_exists(uri).await,
             }
         })
     }
@@ -57,7 +57,7 @@ impl MigrateDatabase for Any {
                 AnyKind::MySql => crate::mysql::MySql::drop_database(uri).await,
 
                 #[cfg(feature = "mssql")]
-                AnyKind::Mssql => unimplemented!(),
+                AnyKind::Mssql => crate::mssql::Mssql::drop_database(uri).await,
             }
         })
     }
@@ -76,7 +76,7 @@ impl Migrate for AnyConnection {
             AnyConnectionKind::MySql(conn) => conn.ensure_migrations_table(),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+            AnyConnectionKind::Mssql(conn) => conn.ensure_migrations_table(),
         }
     }
 
@@ -92,7 +92,7 @@ impl Migrate for AnyConnection {
             AnyConnectionKind::MySql(conn) => conn.version(),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+            AnyConnectionKind::Mssql(conn) => conn.version(),
         }
     }
 
@@ -108,7 +108,7 @@ impl Migrate for AnyConnection {
             AnyConnectionKind::MySql(conn) => conn.lock(),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+            AnyConnectionKind::Mssql(conn) => conn.lock(),
         }
     }
 
@@ -124,7 +124,7 @@ impl Migrate for AnyConnection {
             AnyConnectionKind::MySql(conn) => conn.unlock(),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+            AnyConnectionKind::Mssql(conn) => conn.unlock(),
         }
     }
 
@@ -143,32 +143,48 @@ impl Migrate for AnyConnection {
             AnyConnectionKind::MySql(conn) => conn.validate(migration),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => {
-                let _ = migration;
-                unimplemented!()
-            }
+            AnyConnectionKind::Mssql(conn) => conn.validate(migration),
         }
     }
 
-    fn apply<'e: 'm, 'm>(
+    fn apply_with_progress<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
+        progress: &'m mut (dyn FnMut(MigrationProgress<'_>) + Send),
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
-            AnyConnectionKind::Postgres(conn) => conn.apply(migration),
+            AnyConnectionKind::Postgres(conn) => conn.apply_with_progress(migration, progress),
 
             #[cfg(feature = "sqlite")]
-            AnyConnectionKind::Sqlite(conn) => conn.apply(migration),
+            AnyConnectionKind::Sqlite(conn) => conn.apply_with_progress(migration, progress),
 
             #[cfg(feature = "mysql")]
-            AnyConnectionKind::MySql(conn) => conn.apply(migration),
+            AnyConnectionKind::MySql(conn) => conn.apply_with_progress(migration, progress),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => {
-                let _ = migration;
-                unimplemented!()
-            }
+            AnyConnectionKind::Mssql(conn) => conn.apply_with_progress(migration, progress),
+        }
+    }
+
+    fn record<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        success: bool,
+        elapsed: Duration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.record(migration, success, elapsed),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.record(migration, success, elapsed),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.record(migration, success, elapsed),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn.record(migration, success, elapsed),
         }
     }
 
@@ -187,7 +203,7 @@ impl Migrate for AnyConnection {
             AnyConnectionKind::MySql(conn) => conn.revert(migration),
 
             #[cfg(feature = "mssql")]
-            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+            AnyConnectionKind::Mssql(conn) => conn.revert(migration),
         }
     }
 }
@@ -4,7 +4,7 @@ use crate::any::connection::AnyConnectionKind;
 use crate::any::{Any, AnyConnection};
 use crate::database::Database;
 use crate::error::Error;
-use crate::transaction::TransactionManager;
+use crate::transaction::{SavepointCommand, TransactionManager};
 
 pub struct AnyTransactionManager;
 
@@ -106,4 +106,64 @@ impl TransactionManager for AnyTransactionManager {
             }
         }
     }
+
+    fn execute_savepoint<'c>(
+        conn: &'c mut AnyConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::execute_savepoint(
+                    conn, command, name,
+                )
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::execute_savepoint(
+                    conn, command, name,
+                )
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::execute_savepoint(
+                    conn, command, name,
+                )
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::execute_savepoint(
+                    conn, command, name,
+                )
+            }
+        }
+    }
+
+    fn start_rollback_to_savepoint(conn: &mut AnyConnection, name: &str) {
+        match &mut conn.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => {
+                <crate::postgres::Postgres as Database>::TransactionManager::start_rollback_to_savepoint(conn, name)
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => {
+                <crate::mysql::MySql as Database>::TransactionManager::start_rollback_to_savepoint(conn, name)
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => {
+                <crate::sqlite::Sqlite as Database>::TransactionManager::start_rollback_to_savepoint(conn, name)
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => {
+                <crate::mssql::Mssql as Database>::TransactionManager::start_rollback_to_savepoint(conn, name)
+            }
+        }
+    }
 }
@@ -5,6 +5,11 @@ use crate::encode::Encode;
 use crate::types::Type;
 
 /// A tuple of arguments to be sent to the database.
+///
+/// The arguments can be built separately from the SQL of the query they are sent with, and
+/// then passed to [`query_with`](crate::query::query_with). The arguments of the PostgreSQL,
+/// MySQL and SQLite drivers can also be cloned, to be sent with several queries, and appended
+/// to one another.
 pub trait Arguments<'q>: Send + Sized + Default {
     type Database: Database;
 
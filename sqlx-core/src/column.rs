@@ -2,6 +2,8 @@ use crate::database::Database;
 use crate::error::Error;
 use std::fmt::Debug;
 
+pub use crate::ext::ustr::UStr;
+
 pub trait Column: private_column::Sealed + 'static + Send + Sync + Debug {
     type Database: Database;
 
@@ -17,6 +19,10 @@ pub trait Column: private_column::Sealed + 'static + Send + Sync + Debug {
     /// column is an expression that has not been aliased.
     fn name(&self) -> &str;
 
+    /// Gets the column name or alias, as a [`UStr`] which is cheap to clone and can be stored
+    /// without copying the name.
+    fn name_shared(&self) -> UStr;
+
     /// Gets the type information for the column.
     fn type_info(&self) -> &<Self::Database as Database>::TypeInfo;
 }
@@ -30,7 +36,9 @@ pub(crate) mod private_column {
 ///
 /// The [`get`] and [`try_get`] methods of [`Row`] accept any type that implements `ColumnIndex`.
 /// This trait is implemented for strings which are used to look up a column by name, and for
-/// `usize` which is used as a positional index into the row.
+/// `usize` which is used as a positional index into the row. The rows of some databases can also
+/// be indexed by a `(table, name)` pair, to tell apart the columns of the same name of joined
+/// tables.
 ///
 /// This trait is sealed and cannot be implemented for types outside of SQLx.
 ///
@@ -87,11 +95,61 @@ macro_rules! impl_column_index_for_statement {
     };
 }
 
+// Looks up a column by name; the first column of that name is used, unless `strict` is set
+// and there are more than one.
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+pub(crate) fn index_by_name<C: Column>(
+    columns: &[C],
+    column_names: &crate::HashMap<UStr, usize>,
+    name: &str,
+    strict: bool,
+) -> Result<usize, Error> {
+    let index = *column_names
+        .get(name)
+        .ok_or_else(|| Error::ColumnNotFound(name.into()))?;
+
+    if strict
+        && columns
+            .iter()
+            .filter(|column| column.name() == name)
+            .nth(1)
+            .is_some()
+    {
+        return Err(Error::ColumnAmbiguous(name.into()));
+    }
+
+    Ok(index)
+}
+
+// Looks up a column by the name of its table and its own name.
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+pub(crate) fn index_by_table_and_name<C: Column>(
+    columns: &[C],
+    table_name: impl Fn(&C) -> Option<&str>,
+    (table, name): (&str, &str),
+    strict: bool,
+) -> Result<usize, Error> {
+    let mut matches = columns
+        .iter()
+        .filter(|column| column.name() == name && table_name(column) == Some(table));
+
+    let column = matches
+        .next()
+        .ok_or_else(|| Error::ColumnNotFound(format!("{}.{}", table, name)))?;
+
+    if strict && matches.next().is_some() {
+        return Err(Error::ColumnAmbiguous(format!("{}.{}", table, name)));
+    }
+
+    Ok(column.ordinal())
+}
+
 // Prevent users from implementing the `ColumnIndex` trait.
 mod private_column_index {
     pub trait Sealed {}
 
     impl Sealed for usize {}
     impl Sealed for str {}
+    impl Sealed for (&'_ str, &'_ str) {}
     impl<T> Sealed for &'_ T where T: Sealed + ?Sized {}
 }
@@ -1,10 +1,18 @@
 use hashlink::lru_cache::LruCache;
 
+use crate::HashMap;
+
 /// A cache for prepared statements. When full, the least recently used
 /// statement gets removed.
+///
+/// Pinned statements are not counted in the capacity, and are never removed as the least
+/// recently used.
 #[derive(Debug)]
 pub struct StatementCache<T> {
     inner: LruCache<String, T>,
+    pinned: HashMap<String, T>,
+    hits: u64,
+    misses: u64,
 }
 
 impl<T> StatementCache<T> {
@@ -12,22 +20,44 @@ impl<T> StatementCache<T> {
     pub fn new(capacity: usize) -> Self {
         Self {
             inner: LruCache::new(capacity),
+            pinned: HashMap::new(),
+            hits: 0,
+            misses: 0,
         }
     }
 
     /// Returns a mutable reference to the value corresponding to the given key
     /// in the cache, if any.
     pub fn get_mut(&mut self, k: &str) -> Option<&mut T> {
-        self.inner.get_mut(k)
+        match self.pinned.get_mut(k) {
+            Some(v) => Some(v),
+            None => self.inner.get_mut(k),
+        }
+    }
+
+    /// Like [`get_mut`](Self::get_mut), but counts the lookup as a hit or a miss of the cache.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn lookup(&mut self, k: &str) -> Option<&mut T> {
+        if self.contains_key(k) {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+
+        self.get_mut(k)
     }
 
     /// Inserts a new statement to the cache, returning the least recently used
     /// statement id if the cache is full, or if inserting with an existing key,
     /// the replaced existing statement.
     pub fn insert(&mut self, k: &str, v: T) -> Option<T> {
+        if let Some(pinned) = self.pinned.get_mut(k) {
+            return Some(std::mem::replace(pinned, v));
+        }
+
         let mut lru_item = None;
 
-        if self.capacity() == self.len() && !self.contains_key(k) {
+        if self.capacity() == self.len_unpinned() && !self.contains_key(k) {
             lru_item = self.remove_lru();
         } else if self.contains_key(k) {
             lru_item = self.inner.remove(k);
@@ -38,8 +68,53 @@ impl<T> StatementCache<T> {
         lru_item
     }
 
+    /// Pins a statement, which is then never removed as the least recently used, returning
+    /// the replaced existing statement, if any.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn pin(&mut self, k: &str, v: T) -> Option<T> {
+        let existing = self.inner.remove(k);
+
+        self.pinned.insert(k.into(), v).or(existing)
+    }
+
+    /// Unpins a statement, which is then the most recently used, returning the least
+    /// recently used statement if the cache is full.
+    ///
+    /// Returns `Err` with the statement if the cache is disabled.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn unpin(&mut self, k: &str) -> Result<Option<T>, T> {
+        match self.pinned.remove(k) {
+            Some(v) if !self.is_enabled() => Err(v),
+            Some(v) => Ok(self.insert(k, v)),
+            None => Ok(None),
+        }
+    }
+
+    /// True if the statement of the given key is pinned.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn is_pinned(&self, k: &str) -> bool {
+        self.pinned.contains_key(k)
+    }
+
+    /// Removes the statement of the given key, pinned or not.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn remove(&mut self, k: &str) -> Option<T> {
+        self.pinned.remove(k).or_else(|| self.inner.remove(k))
+    }
+
     /// The number of statements in the cache.
+    #[allow(dead_code)] // Only used for some `cfg`s
     pub fn len(&self) -> usize {
+        self.inner.len() + self.pinned.len()
+    }
+
+    /// The number of pinned statements in the cache.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn len_pinned(&self) -> usize {
+        self.pinned.len()
+    }
+
+    fn len_unpinned(&self) -> usize {
         self.inner.len()
     }
 
@@ -48,22 +123,59 @@ impl<T> StatementCache<T> {
         self.inner.remove_lru().map(|(_, v)| v)
     }
 
+    /// Removes all the statements from the cache, including the pinned ones.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn drain(&mut self) -> Vec<T> {
+        let mut drained: Vec<T> = self.inner.drain().map(|(_, v)| v).collect();
+        drained.extend(self.pinned.drain().map(|(_, v)| v));
+
+        drained
+    }
+
     /// Clear all cached statements from the cache.
     #[cfg(feature = "sqlite")]
     pub fn clear(&mut self) {
         self.inner.clear();
+        self.pinned.clear();
     }
 
     /// True if cache has a value for the given key.
     pub fn contains_key(&mut self, k: &str) -> bool {
-        self.inner.contains_key(k)
+        self.pinned.contains_key(k) || self.inner.contains_key(k)
     }
 
-    /// Returns the maximum number of statements the cache can hold.
+    /// Returns the maximum number of statements the cache can hold, besides the pinned ones.
     pub fn capacity(&self) -> usize {
         self.inner.capacity()
     }
 
+    /// Changes the capacity of the cache, returning the least recently used statements
+    /// which no longer fit.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn set_capacity(&mut self, capacity: usize) -> Vec<T> {
+        let mut evicted = Vec::new();
+
+        while self.len_unpinned() > capacity {
+            evicted.extend(self.remove_lru());
+        }
+
+        self.inner.set_capacity(capacity);
+
+        evicted
+    }
+
+    /// The number of lookups which found a statement in the cache.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// The number of lookups which did not find a statement in the cache.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
     /// Returns true if the cache capacity is more than 0.
     #[allow(dead_code)] // Only used for some `cfg`s
     pub fn is_enabled(&self) -> bool {
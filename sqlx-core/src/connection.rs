@@ -1,5 +1,7 @@
 use crate::database::{Database, HasStatementCache};
+use crate::describe::Describe;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
@@ -79,6 +81,46 @@ pub trait Connection: Send {
         })
     }
 
+    /// Describe a statement, returning its parameter and column metadata, without any of its
+    /// effects taking place.
+    ///
+    /// The statement is only prepared, never executed, and this happens inside of a
+    /// transaction that is always rolled back. This makes it safe to use on `INSERT`,
+    /// `UPDATE`, `DELETE` or DDL statements; for instance, when building interactive tooling.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx_core::connection::Connection;
+    /// use sqlx_core::error::Error;
+    /// use sqlx_core::postgres::PgConnection;
+    ///
+    /// # pub async fn _f(conn: &mut PgConnection) -> Result<(), Error> {
+    /// let describe = conn.dry_run("DELETE FROM users WHERE id = $1 RETURNING name").await?;
+    ///
+    /// assert_eq!(describe.columns().len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn dry_run<'e>(
+        &'e mut self,
+        sql: &'e str,
+    ) -> BoxFuture<'e, Result<Describe<Self::Database>, Error>>
+    where
+        Self: Sized,
+        for<'c> &'c mut <Self::Database as Database>::Connection:
+            Executor<'c, Database = Self::Database>,
+    {
+        Box::pin(async move {
+            let mut transaction = self.begin().await?;
+            let describe = (&mut *transaction).describe(sql).await;
+
+            transaction.rollback().await?;
+
+            describe
+        })
+    }
+
     /// The number of statements currently cached in the connection.
     fn cached_statements_size(&self) -> usize
     where
@@ -102,6 +144,13 @@ pub trait Connection: Send {
     #[doc(hidden)]
     fn should_flush(&self) -> bool;
 
+    /// Returns `true` if the server reported the connection to be in a transaction block, open
+    /// or failed, after the last query.
+    #[doc(hidden)]
+    fn in_transaction_block(&self) -> bool {
+        false
+    }
+
     /// Establish a new database connection.
     ///
     /// A value of [`Options`][Self::Options] is parsed from the provided connection string. This parsing
@@ -130,6 +179,7 @@ pub(crate) struct LogSettings {
     pub(crate) statements_level: LevelFilter,
     pub(crate) slow_statements_level: LevelFilter,
     pub(crate) slow_statements_duration: Duration,
+    pub(crate) stage_timings: bool,
 }
 
 impl Default for LogSettings {
@@ -138,6 +188,7 @@ impl Default for LogSettings {
             statements_level: LevelFilter::Info,
             slow_statements_level: LevelFilter::Warn,
             slow_statements_duration: Duration::from_secs(1),
+            stage_timings: false,
         }
     }
 }
@@ -26,6 +26,27 @@ pub type BoxDynError = Box<dyn StdError + 'static + Send + Sync>;
 #[error("unexpected null; try decoding as an `Option`")]
 pub struct UnexpectedNullError;
 
+/// A limit on the size of a query, see [`Error::LimitExceeded`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Limit {
+    /// The number of arguments bound to a query.
+    BindParameters,
+
+    /// The size of a message of the wire protocol, which holds the SQL or the arguments of a
+    /// query.
+    MessageSize,
+}
+
+impl Display for Limit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Limit::BindParameters => "bind parameters",
+            Limit::MessageSize => "bytes per message",
+        })
+    }
+}
+
 /// Represents all the ways a method can fail within SQLx.
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -69,6 +90,11 @@ pub enum Error {
     #[error("no column found for name: {0}")]
     ColumnNotFound(String),
 
+    /// More than one column was found for the given name, when the row is accessed in strict
+    /// mode.
+    #[error("more than one column found for name: {0}")]
+    ColumnAmbiguous(String),
+
     /// Error occurred while decoding a value from a specific column.
     #[error("error occurred while decoding column {index}: {source}")]
     ColumnDecode {
@@ -82,6 +108,10 @@ pub enum Error {
     #[error("error occurred while decoding: {0}")]
     Decode(#[source] BoxDynError),
 
+    /// Error occurred while encoding a value to be bound as an argument.
+    #[error("error occurred while encoding a value: {0}")]
+    Encode(#[source] BoxDynError),
+
     /// A [`Pool::acquire`] timed out due to connections not becoming available or
     /// because another task encountered too many errors while trying to open a new connection.
     ///
@@ -97,9 +127,32 @@ pub enum Error {
     PoolClosed,
 
     /// A background worker has crashed.
+    ///
+    /// For SQLite, this is returned when a user callback (such as a collation) panics while
+    /// the statement is being stepped on the dedicated worker thread. The panic is caught,
+    /// the statement is aborted, the panic message is logged and the connection can continue
+    /// to be used. If the worker thread itself has exited, the connection is no longer usable
+    /// and should be closed (or, in a pool, dropped so it is not returned).
     #[error("attempted to communicate with a crashed background worker")]
     WorkerCrashed,
 
+    /// A query exceeds a limit of the database or of its wire protocol.
+    ///
+    /// This is checked before anything is sent, so the connection is still usable. Bulk
+    /// operations which run into a limit have to be split into several statements.
+    #[error("query exceeds the limit of {max} {limit}: got {actual}")]
+    LimitExceeded {
+        limit: Limit,
+        max: usize,
+        actual: usize,
+    },
+
+    /// The number of arguments bound to a query is not the number of its bind parameters.
+    ///
+    /// This is checked once the query is prepared, before the arguments are sent.
+    #[error("query has {expected} bind parameters but {actual} arguments were bound")]
+    BindParameterCount { expected: usize, actual: usize },
+
     #[cfg(feature = "migrate")]
     #[error("{0}")]
     Migrate(#[source] Box<crate::migrate::MigrateError>),
@@ -120,6 +173,16 @@ impl Error {
         }
     }
 
+    #[allow(dead_code)]
+    #[inline]
+    pub(crate) fn check_limit(limit: Limit, max: usize, actual: usize) -> StdResult<(), Self> {
+        if actual > max {
+            Err(Error::LimitExceeded { limit, max, actual })
+        } else {
+            Ok(())
+        }
+    }
+
     #[allow(dead_code)]
     #[inline]
     pub(crate) fn protocol(err: impl Display) -> Self {
@@ -1,6 +1,9 @@
+use crate::arguments::IntoArguments;
 use crate::database::{Database, HasArguments, HasStatement};
 use crate::describe::Describe;
 use crate::error::Error;
+use crate::from_row::FromRow;
+use crate::query_macros::DeferredCheck;
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -129,6 +132,47 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e,
         E: Execute<'q, Self::Database>;
 
+    /// Execute the SQL query, with the given arguments, and map exactly one row to a concrete
+    /// type using [`FromRow`].
+    ///
+    /// This is a shorthand for [`query_as_with`](crate::query_as::query_as_with) followed by
+    /// `fetch_one`, for queries built at runtime.
+    fn fetch_one_as<'e, 'q: 'e, O, A>(
+        self,
+        sql: &'q str,
+        arguments: A,
+    ) -> BoxFuture<'e, Result<O, Error>>
+    where
+        'c: 'e,
+        A: IntoArguments<'q, Self::Database>,
+        O: for<'r> FromRow<'r, <Self::Database as Database>::Row> + Send + Unpin + 'e,
+    {
+        self.fetch_one((sql, Some(arguments.into_arguments())))
+            .and_then(|row| future::ready(O::from_row(&row)))
+            .boxed()
+    }
+
+    /// Execute the SQL query, with the given arguments, and map all the generated rows to
+    /// a concrete type using [`FromRow`], collected into a [`Vec`].
+    ///
+    /// This is a shorthand for [`query_as_with`](crate::query_as::query_as_with) followed by
+    /// `fetch_all`, for queries built at runtime.
+    fn fetch_all_as<'e, 'q: 'e, O, A>(
+        self,
+        sql: &'q str,
+        arguments: A,
+    ) -> BoxFuture<'e, Result<Vec<O>, Error>>
+    where
+        'c: 'e,
+        A: IntoArguments<'q, Self::Database>,
+        O: for<'r> FromRow<'r, <Self::Database as Database>::Row> + Send + Unpin + 'e,
+    {
+        self.fetch((sql, Some(arguments.into_arguments())))
+            .and_then(|row| future::ready(O::from_row(&row)))
+            .try_collect()
+            .boxed()
+    }
+
     /// Prepare the SQL query to inspect the type information of its parameters
     /// and results.
     ///
@@ -198,6 +242,14 @@ pub trait Execute<'q, DB: Database>: Send + Sized {
 
     /// Returns `true` if the statement should be cached.
     fn persistent(&self) -> bool;
+
+    /// Returns the check of a query macro to run before the query is first executed, if it could
+    /// not be checked at compile time.
+    #[doc(hidden)]
+    #[inline]
+    fn deferred_check(&self) -> Option<DeferredCheck<DB>> {
+        None
+    }
 }
 
 // NOTE: `Execute` is explicitly not implemented for String and &String to make it slightly more
@@ -7,16 +7,40 @@ use std::sync::Arc;
 // U meaning micro
 // a micro-string is either a reference-counted string or a static string
 // this guarantees these are cheap to clone everywhere
-#[derive(Clone, Eq)]
-pub enum UStr {
+
+/// An immutable string which is cheap to clone, used for the names of columns and types.
+///
+/// A `UStr` is either a `&'static str` or a reference-counted `Arc<str>`, so it can be
+/// stored, e.g. with [`Column::name_shared`], without copying the string for every row.
+///
+/// It dereferences to `str`, and with the `serde` feature of SQLx (enabled by `json` and
+/// `offline`), it is serialized as a string.
+///
+/// [`Column::name_shared`]: crate::column::Column::name_shared
+#[derive(Clone)]
+pub struct UStr(Repr);
+
+#[derive(Clone)]
+enum Repr {
     Static(&'static str),
     Shared(Arc<str>),
 }
 
 impl UStr {
-    #[allow(dead_code)]
-    pub(crate) fn new(s: &str) -> Self {
-        UStr::Shared(Arc::from(s.to_owned()))
+    /// Creates a `UStr` by copying the string.
+    pub fn new(s: &str) -> Self {
+        UStr(Repr::Shared(Arc::from(s)))
+    }
+
+    /// Creates a `UStr` from a static string, without allocating.
+    pub const fn from_static(s: &'static str) -> Self {
+        UStr(Repr::Static(s))
+    }
+
+    /// Returns the string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self
     }
 }
 
@@ -25,9 +49,9 @@ impl Deref for UStr {
 
     #[inline]
     fn deref(&self) -> &str {
-        match self {
-            UStr::Static(s) => s,
-            UStr::Shared(s) => s,
+        match &self.0 {
+            Repr::Static(s) => s,
+            Repr::Shared(s) => s,
         }
     }
 }
@@ -48,23 +72,60 @@ impl Borrow<str> for UStr {
     }
 }
 
+impl AsRef<str> for UStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &**self
+    }
+}
+
 impl PartialEq<UStr> for UStr {
     fn eq(&self, other: &UStr) -> bool {
         (**self).eq(&**other)
     }
 }
 
+impl Eq for UStr {}
+
+impl PartialEq<str> for UStr {
+    fn eq(&self, other: &str) -> bool {
+        (**self).eq(other)
+    }
+}
+
+impl PartialEq<&'_ str> for UStr {
+    fn eq(&self, other: &&str) -> bool {
+        (**self).eq(*other)
+    }
+}
+
 impl From<&'static str> for UStr {
     #[inline]
     fn from(s: &'static str) -> Self {
-        UStr::Static(s)
+        UStr(Repr::Static(s))
     }
 }
 
 impl From<String> for UStr {
     #[inline]
     fn from(s: String) -> Self {
-        UStr::Shared(s.into())
+        UStr(Repr::Shared(s.into()))
+    }
+}
+
+impl From<Arc<str>> for UStr {
+    #[inline]
+    fn from(s: Arc<str>) -> Self {
+        UStr(Repr::Shared(s))
+    }
+}
+
+impl From<UStr> for Arc<str> {
+    fn from(s: UStr) -> Self {
+        match s.0 {
+            Repr::Static(s) => Arc::from(s),
+            Repr::Shared(s) => s,
+        }
     }
 }
 
@@ -84,7 +145,7 @@ impl Display for UStr {
 
 // manual impls because otherwise things get a little screwy with lifetimes
 
-#[cfg(feature = "offline")]
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for UStr {
     fn deserialize<D>(deserializer: D) -> Result<Self, <D as serde::Deserializer<'de>>::Error>
     where
@@ -94,7 +155,7 @@ impl<'de> serde::Deserialize<'de> for UStr {
     }
 }
 
-#[cfg(feature = "offline")]
+#[cfg(feature = "serde")]
 impl serde::Serialize for UStr {
     fn serialize<S>(
         &self,
@@ -0,0 +1,319 @@
+//! Fault injection for testing how an application copes with failing database connections.
+//!
+//! A [`FaultInjector`] is attached to the connect options of a network-based driver
+//! (e.g., [`PgConnectOptions::fault_injector`]). Every connection opened with those options
+//! is then routed through the injector which can, at any time, be told to refuse new
+//! connections, delay reads, silently drop written data or kill connections once a given
+//! amount of data has gone through them.
+//!
+//! This makes it possible to exercise retry and timeout logic against the same failure modes
+//! SQLx surfaces in production, without setting up an external proxy.
+//!
+//! ```rust,ignore
+//! let faults = FaultInjector::new();
+//!
+//! let pool = PgPoolOptions::new()
+//!     .connect_with(PgConnectOptions::new().fault_injector(&faults))
+//!     .await?;
+//!
+//! // the next query loses its connection right after it was sent
+//! faults.kill_after(FaultPoint::Write, 0);
+//!
+//! assert!(sqlx::query("SELECT 1").execute(&pool).await.is_err());
+//! ```
+//!
+//! Injected faults are never visible to connections that were not opened with the injector.
+//! This module is only available with the `fault-injection` feature and is not intended for
+//! production use.
+//!
+//! [`PgConnectOptions::fault_injector`]: crate::postgres::PgConnectOptions::fault_injector
+
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::ready;
+use sqlx_rt::{AsyncRead, AsyncWrite};
+
+use crate::net::{PollReadBuf, PollReadOut, Socket};
+
+/// A point in the exchange with the database server at which a connection can be killed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FaultPoint {
+    /// While receiving data from the server.
+    Read,
+
+    /// While sending data to the server.
+    Write,
+}
+
+/// A handle for injecting faults into the connections opened with it.
+///
+/// The handle is cheap to clone; all clones control the same set of connections.
+#[derive(Debug, Clone, Default)]
+pub struct FaultInjector {
+    faults: Arc<Mutex<Faults>>,
+}
+
+#[derive(Debug, Default)]
+struct Faults {
+    refuse_connections: usize,
+    read_delay: Option<Duration>,
+    drop_writes: bool,
+    kill_after_read: Option<usize>,
+    kill_after_write: Option<usize>,
+
+    // incremented by [FaultInjector::kill_connections]; a connection opened in an earlier
+    // epoch is dead
+    epoch: u64,
+}
+
+impl Faults {
+    fn kill_after(&mut self, point: FaultPoint) -> &mut Option<usize> {
+        match point {
+            FaultPoint::Read => &mut self.kill_after_read,
+            FaultPoint::Write => &mut self.kill_after_write,
+        }
+    }
+}
+
+impl FaultInjector {
+    /// Creates a new injector which does not inject any fault until told to.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refuses the next `count` connection attempts with
+    /// [`ConnectionRefused`](io::ErrorKind::ConnectionRefused).
+    pub fn refuse_connections(&self, count: usize) {
+        self.lock().refuse_connections = count;
+    }
+
+    /// Delays every read from the server by `delay`, or stops doing so with `None`.
+    ///
+    /// Use this to trigger timeouts, such as the one of
+    /// [`PoolOptions::connect_timeout`](crate::pool::PoolOptions::connect_timeout).
+    pub fn delay_reads(&self, delay: Option<Duration>) {
+        self.lock().read_delay = delay;
+    }
+
+    /// Silently discards any data sent to the server, as if packets were lost.
+    ///
+    /// Writes still appear successful; as the server never receives the request, no response
+    /// will come back either.
+    pub fn drop_writes(&self, enabled: bool) {
+        self.lock().drop_writes = enabled;
+    }
+
+    /// Kills the connection that next transfers `bytes` bytes in the direction of `point`.
+    ///
+    /// With `0`, the next connection to read (or write) is killed immediately, before any data
+    /// is transferred. Otherwise, the transfer that reaches the threshold goes through and the
+    /// connection is killed afterwards. In both cases, the fault is then cleared.
+    ///
+    /// A killed connection fails every further operation with
+    /// [`ConnectionReset`](io::ErrorKind::ConnectionReset).
+    pub fn kill_after(&self, point: FaultPoint, bytes: usize) {
+        *self.lock().kill_after(point) = Some(bytes);
+    }
+
+    /// Kills every connection that is currently open.
+    ///
+    /// Connections opened afterwards are not affected.
+    pub fn kill_connections(&self) {
+        self.lock().epoch += 1;
+    }
+
+    /// Clears all pending faults.
+    ///
+    /// Connections that were already killed stay dead.
+    pub fn reset(&self) {
+        let mut faults = self.lock();
+        let epoch = faults.epoch;
+
+        *faults = Faults {
+            epoch,
+            ..Faults::default()
+        };
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Faults> {
+        // a panic while holding the lock can not leave the faults in an inconsistent state
+        self.faults.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn connect(&self, socket: Socket) -> io::Result<Socket> {
+        let mut faults = self.lock();
+
+        if faults.refuse_connections > 0 {
+            faults.refuse_connections -= 1;
+
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                "connection refused by fault injection",
+            ));
+        }
+
+        Ok(Socket::Faulty(Box::new(FaultySocket {
+            inner: socket,
+            injector: self.clone(),
+            epoch: faults.epoch,
+            killed: false,
+            delay: None,
+            delayed: false,
+        })))
+    }
+}
+
+pub(crate) struct FaultySocket {
+    pub(crate) inner: Socket,
+    injector: FaultInjector,
+    epoch: u64,
+    killed: bool,
+
+    // pending delay before the next read
+    delay: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+
+    // set once the delay for the current read has elapsed
+    delayed: bool,
+}
+
+impl FaultySocket {
+    // fails if this connection is dead or has to be killed before transferring data
+    fn check(&mut self, point: FaultPoint) -> io::Result<()> {
+        let mut faults = self.injector.lock();
+
+        if !self.killed && faults.epoch != self.epoch {
+            self.killed = true;
+        }
+
+        if !self.killed && *faults.kill_after(point) == Some(0) {
+            *faults.kill_after(point) = None;
+            self.killed = true;
+        }
+
+        if self.killed {
+            return Err(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "connection killed by fault injection",
+            ));
+        }
+
+        Ok(())
+    }
+
+    // records that `n` bytes were transferred, returning how many of them may go through
+    // before the connection has to be killed
+    fn transfer(&mut self, point: FaultPoint, n: usize) -> usize {
+        let mut faults = self.injector.lock();
+        let kill_after = faults.kill_after(point);
+
+        match *kill_after {
+            Some(remaining) if n >= remaining => {
+                *kill_after = None;
+                self.killed = true;
+
+                remaining
+            }
+
+            Some(ref mut remaining) => {
+                *remaining -= n;
+
+                n
+            }
+
+            None => n,
+        }
+    }
+}
+
+impl Debug for FaultySocket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FaultySocket")
+            .field("inner", &self.inner)
+            .field("killed", &self.killed)
+            .finish()
+    }
+}
+
+impl AsyncRead for FaultySocket {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut PollReadBuf<'_>,
+    ) -> Poll<io::Result<PollReadOut>> {
+        self.check(FaultPoint::Read)?;
+
+        if !self.delayed {
+            if self.delay.is_none() {
+                let delay = self.injector.lock().read_delay;
+
+                if let Some(delay) = delay {
+                    self.delay = Some(Box::pin(sqlx_rt::sleep(delay)));
+                }
+            }
+
+            if let Some(delay) = &mut self.delay {
+                ready!(delay.as_mut().poll(cx));
+                self.delay = None;
+            }
+
+            self.delayed = true;
+        }
+
+        #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+        let filled = buf.filled().len();
+
+        let out = ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        self.delayed = false;
+
+        #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+        let n = buf.filled().len() - filled;
+
+        #[cfg(feature = "_rt-async-std")]
+        let n = out;
+
+        // the data was already received, it is handed over even if this kills the connection
+        self.transfer(FaultPoint::Read, n);
+
+        Poll::Ready(Ok(out))
+    }
+}
+
+impl AsyncWrite for FaultySocket {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.check(FaultPoint::Write)?;
+
+        if self.injector.lock().drop_writes {
+            return Poll::Ready(Ok(self.transfer(FaultPoint::Write, buf.len())));
+        }
+
+        let n = ready!(Pin::new(&mut self.inner).poll_write(cx, buf))?;
+
+        // if the connection is to be killed part-way, any data past the threshold was still
+        // written; this is indistinguishable from the server never processing it
+        Poll::Ready(Ok(self.transfer(FaultPoint::Write, n)))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    #[cfg(feature = "_rt-async-std")]
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
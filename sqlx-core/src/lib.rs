@@ -19,6 +19,12 @@
 #[cfg(feature = "bigdecimal")]
 extern crate bigdecimal_ as bigdecimal;
 
+#[cfg(feature = "tracing")]
+extern crate tracing_ as tracing;
+
+#[cfg(feature = "metrics")]
+extern crate metrics_ as metrics;
+
 #[macro_use]
 mod ext;
 
@@ -66,6 +72,8 @@ mod io;
 mod logger;
 mod net;
 pub mod query_as;
+#[doc(hidden)]
+pub mod query_macros;
 pub mod query_scalar;
 pub mod row;
 pub mod type_info;
@@ -74,6 +82,14 @@ pub mod value;
 #[cfg(feature = "migrate")]
 pub mod migrate;
 
+#[cfg(feature = "fault-injection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fault-injection")))]
+pub mod fault;
+
+#[cfg(feature = "transcripts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "transcripts")))]
+pub mod transcript;
+
 #[cfg(all(
     any(
         feature = "postgres",
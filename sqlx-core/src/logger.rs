@@ -1,28 +1,75 @@
 use crate::connection::LogSettings;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+// The stages of a statement, when enabled with `stage_timings`, are reported as a `tracing`
+// event and as `metrics` histograms, in seconds, when the features are enabled: the time to
+// prepare it, or to find it in the cache, then from its execution to its first row, and to its
+// last one. A slow prepare points at the planner, while a slow first row with a fast last row
+// points at the query itself, and a slow drain at the network or at the decoding of the rows.
+
+const PREPARE_HISTOGRAM: &str = "sqlx_query_prepare_seconds";
+const FIRST_ROW_HISTOGRAM: &str = "sqlx_query_first_row_seconds";
+const DRAIN_HISTOGRAM: &str = "sqlx_query_drain_seconds";
 
 pub(crate) struct QueryLogger<'q> {
     sql: &'q str,
     rows: usize,
     start: Instant,
     settings: LogSettings,
+    stages: Option<StageTimings>,
+}
+
+struct StageTimings {
+    prepare: Option<Duration>,
+    execute_start: Instant,
+    first_row: Option<Duration>,
 }
 
 impl<'q> QueryLogger<'q> {
     pub(crate) fn new(sql: &'q str, settings: LogSettings) -> Self {
+        let start = Instant::now();
+
         Self {
             sql,
             rows: 0,
-            start: Instant::now(),
+            start,
+            stages: if settings.stage_timings {
+                Some(StageTimings {
+                    prepare: None,
+                    execute_start: start,
+                    first_row: None,
+                })
+            } else {
+                None
+            },
             settings,
         }
     }
 
+    /// Marks the end of the preparation of the statement, and the start of its execution.
+    #[allow(dead_code)] // Only used for some `cfg`s
+    pub(crate) fn prepared(&mut self) {
+        if let Some(stages) = &mut self.stages {
+            stages.execute_start = Instant::now();
+            stages.prepare = Some(stages.execute_start - self.start);
+        }
+    }
+
     pub(crate) fn increment_rows(&mut self) {
+        if let Some(stages) = &mut self.stages {
+            if self.rows == 0 {
+                stages.first_row = Some(stages.execute_start.elapsed());
+            }
+        }
+
         self.rows += 1;
     }
 
     pub(crate) fn finish(&self) {
+        if let Some(stages) = &self.stages {
+            stages.report(self.rows);
+        }
+
         let elapsed = self.start.elapsed();
 
         let lvl = if elapsed >= self.settings.slow_statements_duration {
@@ -74,6 +121,39 @@ impl<'q> Drop for QueryLogger<'q> {
     }
 }
 
+impl StageTimings {
+    #[allow(unused_variables)]
+    fn report(&self, rows: usize) {
+        let drain = self.execute_start.elapsed();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: "sqlx::query::stages",
+            prepare = ?self.prepare,
+            first_row = ?self.first_row,
+            drain = ?drain,
+            rows,
+            "statement stages"
+        );
+
+        if let Some(prepare) = self.prepare {
+            record_histogram(PREPARE_HISTOGRAM, prepare);
+        }
+
+        if let Some(first_row) = self.first_row {
+            record_histogram(FIRST_ROW_HISTOGRAM, first_row);
+        }
+
+        record_histogram(DRAIN_HISTOGRAM, drain);
+    }
+}
+
+#[allow(unused_variables)]
+fn record_histogram(name: &'static str, duration: Duration) {
+    #[cfg(feature = "metrics")]
+    metrics::histogram!(name, duration.as_secs_f64());
+}
+
 fn parse_query_summary(sql: &str) -> String {
     // For now, just take the first 4 words
     sql.split_whitespace()
@@ -81,3 +161,24 @@ fn parse_query_summary(sql: &str) -> String {
         .collect::<Vec<&str>>()
         .join(" ")
 }
+
+#[test]
+fn test_stage_timings() {
+    let mut settings = LogSettings::default();
+    settings.stage_timings = true;
+
+    let mut logger = QueryLogger::new("SELECT 1", settings);
+
+    std::thread::sleep(Duration::from_millis(10));
+    logger.prepared();
+    logger.increment_rows();
+    logger.increment_rows();
+
+    let stages = logger.stages.as_ref().unwrap();
+    assert!(stages.prepare.unwrap() >= Duration::from_millis(10));
+    assert!(stages.first_row.unwrap() < Duration::from_millis(10));
+
+    // disabled by default
+    let logger = QueryLogger::new("SELECT 1", LogSettings::default());
+    assert!(logger.stages.is_none());
+}
@@ -17,45 +17,129 @@ pub trait MigrateDatabase {
     fn drop_database(uri: &str) -> BoxFuture<'_, Result<(), Error>>;
 }
 
+/// The operations of the migrations of a connection, which [`Migrator`] runs.
+///
+/// This is implemented by the connection of each driver, and by [`AnyConnection`], so that the
+/// same [`Migrator`] can run against any database. A third-party driver implements it to be
+/// supported by [`Migrator`] too; the migrations are recorded in a `_sqlx_migrations` table.
+///
+/// [`Migrator`]: crate::migrate::Migrator
+/// [`AnyConnection`]: crate::any::AnyConnection
 // 'e = Executor
 pub trait Migrate {
-    // ensure migrations table exists
-    // will create or migrate it if needed
+    /// Ensures that the migrations table exists, creating or migrating it if needed.
     fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
-    // Return the current version and if the database is "dirty".
-    // "dirty" means there is a partially applied migration that failed.
+    /// Returns the current version and if the database is "dirty".
+    ///
+    /// "dirty" means there is a partially applied migration that failed.
     fn version(&mut self) -> BoxFuture<'_, Result<Option<(i64, bool)>, MigrateError>>;
 
-    // Should acquire a database lock so that only one migration process
-    // can run at a time. [`Migrate`] will call this function before applying
-    // any migrations.
+    /// Acquires a database lock so that only one migration process can run at a time.
+    ///
+    /// [`Migrator`](crate::migrate::Migrator) calls this function before applying any
+    /// migrations.
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
-    // Should release the lock. [`Migrate`] will call this function after all
-    // migrations have been run.
+    /// Releases the lock.
+    ///
+    /// [`Migrator`](crate::migrate::Migrator) calls this function after all migrations have
+    /// been run.
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
-    // validate the migration
-    // checks that it does exist on the database and that the checksum matches
+    /// Validates the migration: checks that it does exist on the database and that the
+    /// checksum matches.
     fn validate<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<(), MigrateError>>;
 
-    // run SQL from migration in a DDL transaction
-    // insert new row to [_migrations] table on completion (success or failure)
-    // returns the time taking to run the migration SQL
+    /// Runs the SQL of the migration in a DDL transaction, unless it is annotated with
+    /// `-- sqlx:no-transaction`, and [records](Self::record) it on completion (success or
+    /// failure).
+    ///
+    /// Returns the time taken to run the migration SQL.
     fn apply<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>>
+    where
+        Self: Send,
+    {
+        Box::pin(async move { self.apply_with_progress(migration, &mut |_| {}).await })
+    }
+
+    /// Like [`apply`](Self::apply), calling `progress` after each statement of the migration.
+    fn apply_with_progress<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        progress: &'m mut (dyn FnMut(MigrationProgress<'_>) + Send),
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
 
-    // run a revert SQL from migration in a DDL transaction
-    // deletes the row in [_migrations] table with specified migration version on completion (success or failure)
-    // returns the time taking to run the migration SQL
+    /// Inserts a row for the migration in the migrations table, with its checksum and the time
+    /// taken to run it.
+    ///
+    /// A migration recorded without `success` makes the database "dirty".
+    ///
+    /// The drivers of SQLx call this from [`apply`](Self::apply). A driver which records
+    /// migrations by other means does not need to implement it; by default, an error is returned.
+    fn record<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        _success: bool,
+        _elapsed: Duration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            Err(MigrateError::Execute(Error::Configuration(
+                format!(
+                    "cannot record migration {}: not supported by this driver",
+                    migration.version
+                )
+                .into(),
+            )))
+        })
+    }
+
+    /// Runs the revert SQL of the migration in a DDL transaction, and deletes the row of the
+    /// migration in the migrations table on completion (success or failure).
+    ///
+    /// Returns the time taken to run the migration SQL.
     fn revert<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
 }
+
+/// The progress of a migration, reported after each of its statements by
+/// [`Migrator::run_with_progress`](crate::migrate::Migrator::run_with_progress).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MigrationProgress<'a> {
+    /// The migration being applied.
+    pub migration: &'a Migration,
+
+    /// The index of the statement which was executed, in the migration.
+    pub statement: usize,
+
+    /// The SQL of the statement, without the comments before it.
+    ///
+    /// When a migration runs in a transaction, its SQL is sent to the database at once and
+    /// split by SQLx to find the statements, so this may be empty for statements it could not
+    /// find, e.g. in the body of a trigger without `BEGIN ... END`.
+    pub sql: &'a str,
+
+    /// The time taken by the statement.
+    pub elapsed: Duration,
+}
+
+impl MigrationProgress<'_> {
+    /// Returns the first line of the SQL of the statement, truncated to 80 characters.
+    pub fn sql_preview(&self) -> &str {
+        let line = self.sql.lines().next().unwrap_or_default();
+
+        match line.char_indices().nth(80) {
+            Some((end, _)) => &line[..end],
+            None => line,
+        }
+    }
+}
@@ -4,6 +4,8 @@ use sha2::{Digest, Sha384};
 
 use super::MigrationType;
 
+const NO_TRANSACTION: &str = "-- sqlx:no-transaction";
+
 #[derive(Debug, Clone)]
 pub struct Migration {
     pub version: i64,
@@ -30,4 +32,31 @@ impl Migration {
             checksum,
         }
     }
+
+    /// Returns `true` if the migration must not run in a transaction, e.g. because it uses
+    /// `CREATE INDEX CONCURRENTLY`, which is declared with a `-- sqlx:no-transaction` line
+    /// before its first statement.
+    ///
+    /// The statements of such a migration are executed one by one, and a failure leaves the
+    /// database partially migrated, which is reported as [`MigrateError::Dirty`] by the next run.
+    ///
+    /// [`MigrateError::Dirty`]: crate::migrate::MigrateError::Dirty
+    pub fn no_tx(&self) -> bool {
+        self.sql
+            .lines()
+            .map(str::trim)
+            .take_while(|line| line.is_empty() || line.starts_with("--"))
+            .any(|line| line == NO_TRANSACTION)
+    }
+}
+
+#[test]
+fn test_no_tx() {
+    let migration =
+        |sql: &'static str| Migration::new(1, "test".into(), MigrationType::Simple, sql.into());
+
+    assert!(migration("-- sqlx:no-transaction\nCREATE INDEX CONCURRENTLY i ON t (a);").no_tx());
+    assert!(migration("\n-- add an index\n  -- sqlx:no-transaction\nSELECT 1;").no_tx());
+    assert!(!migration("CREATE TABLE t (a TEXT);").no_tx());
+    assert!(!migration("SELECT 1;\n-- sqlx:no-transaction\n").no_tx());
 }
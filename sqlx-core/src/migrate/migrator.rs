@@ -1,5 +1,5 @@
 use crate::acquire::Acquire;
-use crate::migrate::{Migrate, MigrateError, Migration, MigrationSource};
+use crate::migrate::{Migrate, MigrateError, Migration, MigrationProgress, MigrationSource};
 use std::borrow::Cow;
 use std::ops::Deref;
 use std::slice;
@@ -63,6 +63,49 @@ impl Migrator {
     where
         A: Acquire<'a>,
         <A::Connection as Deref>::Target: Migrate,
+    {
+        self.run_with_progress(migrator, |_| {}).await
+    }
+
+    /// Like [`run`](Migrator::run), calling `progress` after each statement of the pending
+    /// migrations, e.g. to log the progress of long migrations.
+    ///
+    /// A migration with a `-- sqlx:no-transaction` line before its first statement is not run in
+    /// a transaction, for statements such as `CREATE INDEX CONCURRENTLY` in PostgreSQL. See
+    /// [`Migration::no_tx`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::migrate::MigrateError;
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn main() -> Result<(), MigrateError> {
+    /// #     sqlx_rt::block_on(async move {
+    /// # use sqlx_core::migrate::Migrator;
+    /// let m = Migrator::new(std::path::Path::new("./migrations")).await?;
+    /// let pool = sqlx_core::sqlite::SqlitePoolOptions::new().connect("sqlite::memory:").await?;
+    /// m.run_with_progress(&pool, |progress| {
+    ///     println!(
+    ///         "{}/{}: {} ({:?})",
+    ///         progress.migration.version,
+    ///         progress.statement,
+    ///         progress.sql_preview(),
+    ///         progress.elapsed,
+    ///     );
+    /// })
+    /// .await
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn run_with_progress<'a, A, F>(
+        &self,
+        migrator: A,
+        mut progress: F,
+    ) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+        F: FnMut(MigrationProgress<'_>) + Send,
     {
         let mut conn = migrator.acquire().await?;
 
@@ -81,7 +124,7 @@ impl Migrator {
 
         for migration in self.iter() {
             if migration.version > version {
-                conn.apply(migration).await?;
+                conn.apply_with_progress(migration, &mut progress).await?;
             } else {
                 conn.validate(migration).await?;
             }
@@ -5,10 +5,13 @@ mod migration;
 mod migration_type;
 mod migrator;
 mod source;
+mod statements;
 
 pub use error::MigrateError;
-pub use migrate::{Migrate, MigrateDatabase};
+pub use migrate::{Migrate, MigrateDatabase, MigrationProgress};
 pub use migration::Migration;
 pub use migration_type::MigrationType;
 pub use migrator::Migrator;
 pub use source::MigrationSource;
+
+pub(crate) use statements::{execute_statements, split_statements};
@@ -0,0 +1,294 @@
+use crate::error::Error;
+use crate::migrate::{MigrateError, Migration, MigrationProgress};
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+use std::time::Instant;
+
+// drives the results of the SQL of a migration, as returned by `execute_many`, and reports the
+// progress after each statement; the statements are only used to report the progress
+pub(crate) async fn execute_statements<R>(
+    mut results: BoxStream<'_, Result<R, Error>>,
+    migration: &Migration,
+    statements: &[&str],
+    progress: &mut (dyn FnMut(MigrationProgress<'_>) + Send),
+) -> Result<(), MigrateError> {
+    let mut statement = 0;
+    let mut start = Instant::now();
+
+    while results.try_next().await?.is_some() {
+        progress(MigrationProgress {
+            migration,
+            statement,
+            sql: statements.get(statement).copied().unwrap_or_default(),
+            elapsed: start.elapsed(),
+        });
+
+        statement += 1;
+        start = Instant::now();
+    }
+
+    Ok(())
+}
+
+// splits SQL into statements, on the semicolons which are not in a string, a quoted identifier,
+// a comment, a dollar-quoted string or the `BEGIN ... END` body of a trigger or routine
+
+// statements are trimmed and those without any code are skipped
+pub(crate) fn split_statements(sql: &str, backslash_escapes: bool) -> Vec<&str> {
+    let bytes = sql.as_bytes();
+    let mut statements = Vec::new();
+
+    let mut statement = Statement::default();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = find(bytes, i + 2, b"\n").map_or(bytes.len(), |end| end + 1);
+                continue;
+            }
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = find(bytes, i + 2, b"*/").map_or(bytes.len(), |end| end + 2);
+                continue;
+            }
+
+            b';' if statement.depth == 0 => {
+                if let Some(start) = statement.start {
+                    statements.push(sql[start..i].trim_end());
+                }
+
+                statement = Statement::default();
+                i += 1;
+                continue;
+            }
+
+            b if b.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+
+            _ => {}
+        }
+
+        statement.start.get_or_insert(i);
+
+        i = match bytes[i] {
+            quote @ b'\'' | quote @ b'"' | quote @ b'`' => {
+                skip_quoted(bytes, i, quote, backslash_escapes)
+            }
+
+            b'$' => skip_dollar_quoted(bytes, i),
+
+            b if is_word_start(b) => {
+                let end = bytes[i..]
+                    .iter()
+                    .position(|&b| !is_word_char(b))
+                    .map_or(bytes.len(), |len| i + len);
+
+                let word = &sql[i..end];
+
+                if bytes.get(end) == Some(&b'\'') && word.eq_ignore_ascii_case("e") {
+                    // an escape string constant of PostgreSQL, e.g. E'\n'
+                    skip_quoted(bytes, end, b'\'', true)
+                } else {
+                    statement.push_word(word);
+                    end
+                }
+            }
+
+            _ => i + 1,
+        };
+    }
+
+    if let Some(start) = statement.start {
+        statements.push(sql[start..].trim_end());
+    }
+
+    statements
+}
+
+#[derive(Default)]
+struct Statement {
+    // the offset of the first token which is not a comment
+    start: Option<usize>,
+
+    words: usize,
+    create: bool,
+    routine: bool,
+
+    // the number of `BEGIN ... END` and `CASE ... END` blocks the body of a routine is in
+    depth: usize,
+    after_end: bool,
+}
+
+impl Statement {
+    fn push_word(&mut self, word: &str) {
+        let is = |keyword: &str| word.eq_ignore_ascii_case(keyword);
+
+        self.words += 1;
+
+        if self.words == 1 {
+            self.create = is("CREATE");
+            return;
+        }
+
+        if self.create && self.words <= 5 && (is("TRIGGER") || is("FUNCTION") || is("PROCEDURE")) {
+            // e.g. CREATE OR REPLACE FUNCTION, or CREATE TEMPORARY TRIGGER
+            self.routine = true;
+            return;
+        }
+
+        if !self.routine {
+            return;
+        }
+
+        let after_end = std::mem::replace(&mut self.after_end, false);
+
+        if is("BEGIN") || (is("CASE") && !after_end) {
+            self.depth += 1;
+        } else if is("END") && self.depth > 0 {
+            self.depth -= 1;
+            self.after_end = true;
+        } else if after_end && (is("IF") || is("LOOP") || is("WHILE") || is("REPEAT")) {
+            // `END IF` closes a block which was not counted
+            self.depth += 1;
+        }
+    }
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|offset| from + offset)
+}
+
+// returns the offset after the closing quote; a doubled quote is an escaped quote
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8, backslash_escapes: bool) -> usize {
+    let mut i = start + 1;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if backslash_escapes => i += 2,
+            b if b == quote && bytes.get(i + 1) == Some(&quote) => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+// skips a dollar-quoted string of PostgreSQL, e.g. $$ ... $$ or $body$ ... $body$
+fn skip_dollar_quoted(bytes: &[u8], start: usize) -> usize {
+    let tag_len = bytes[start + 1..]
+        .iter()
+        .position(|&b| !is_word_char(b) || b == b'$');
+
+    let tag_end = match tag_len {
+        Some(len) if bytes[start + 1 + len] == b'$' => start + 1 + len,
+
+        // a parameter, such as $1, or a lone dollar sign
+        _ => return start + 1,
+    };
+
+    if tag_end > start + 1 && bytes[start + 1].is_ascii_digit() {
+        return start + 1;
+    }
+
+    let tag = &bytes[start..=tag_end];
+
+    find(bytes, tag_end + 1, tag).map_or(bytes.len(), |end| end + tag.len())
+}
+
+fn is_word_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || !b.is_ascii()
+}
+
+fn is_word_char(b: u8) -> bool {
+    is_word_start(b) || b.is_ascii_digit() || b == b'$'
+}
+
+#[test]
+fn test_split_statements() {
+    assert_eq!(
+        split_statements(
+            "-- create a table\nCREATE TABLE t (a TEXT);\n\nINSERT INTO t VALUES ('a;''b'); ;\n",
+            false
+        ),
+        ["CREATE TABLE t (a TEXT)", "INSERT INTO t VALUES ('a;''b')"]
+    );
+
+    assert_eq!(
+        split_statements("SELECT \"a;\", /* ; */ $1; SELECT E'\\';'", false),
+        ["SELECT \"a;\", /* ; */ $1", "SELECT E'\\';'"]
+    );
+
+    assert_eq!(
+        split_statements("SELECT 'a\\'; b'; SELECT `c;`", true),
+        ["SELECT 'a\\'; b'", "SELECT `c;`"]
+    );
+
+    // only comments
+    assert!(split_statements("-- nothing to do\n/* really */", false).is_empty());
+}
+
+#[test]
+fn test_split_statements_with_bodies() {
+    assert_eq!(
+        split_statements(
+            "CREATE FUNCTION f() RETURNS int AS $body$ BEGIN RETURN 1; END $body$ LANGUAGE plpgsql;\
+             SELECT f()",
+            false
+        ),
+        [
+            "CREATE FUNCTION f() RETURNS int AS $body$ BEGIN RETURN 1; END $body$ LANGUAGE plpgsql",
+            "SELECT f()"
+        ]
+    );
+
+    assert_eq!(
+        split_statements(
+            "CREATE TRIGGER t AFTER INSERT ON a BEGIN \
+             UPDATE b SET c = CASE WHEN 1 THEN 2 END; DELETE FROM d; END; BEGIN; END",
+            false
+        ),
+        [
+            "CREATE TRIGGER t AFTER INSERT ON a BEGIN \
+             UPDATE b SET c = CASE WHEN 1 THEN 2 END; DELETE FROM d; END",
+            "BEGIN",
+            "END"
+        ]
+    );
+
+    assert_eq!(
+        split_statements(
+            "CREATE PROCEDURE p() BEGIN IF 1 THEN SELECT 1; END IF; END; SELECT 2",
+            true
+        ),
+        [
+            "CREATE PROCEDURE p() BEGIN IF 1 THEN SELECT 1; END IF; END",
+            "SELECT 2"
+        ]
+    );
+}
+
+#[test]
+fn test_sql_preview() {
+    let migration = Migration::new(
+        1,
+        "test".into(),
+        crate::migrate::MigrationType::Simple,
+        "".into(),
+    );
+
+    let progress = MigrationProgress {
+        migration: &migration,
+        statement: 0,
+        sql: "CREATE TABLE t (\n    a TEXT\n)",
+        elapsed: Default::default(),
+    };
+
+    assert_eq!(progress.sql_preview(), "CREATE TABLE t (");
+}
@@ -36,6 +36,10 @@ impl Column for MssqlColumn {
         &*self.name
     }
 
+    fn name_shared(&self) -> UStr {
+        self.name.clone()
+    }
+
     fn type_info(&self) -> &MssqlTypeInfo {
         &self.type_info
     }
@@ -80,8 +80,13 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
         let sql = query.sql();
         let arguments = query.take_arguments();
         let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let deferred_check = query.deferred_check();
 
         Box::pin(try_stream! {
+            if let Some(check) = deferred_check {
+                check.run(&mut *self, sql).await?;
+            }
+
             self.run(sql, arguments).await?;
 
             loop {
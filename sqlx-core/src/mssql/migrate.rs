@@ -0,0 +1,298 @@
+use crate::connection::{ConnectOptions, Connection};
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::migrate::MigrateError;
+use crate::migrate::Migration;
+use crate::migrate::{execute_statements, split_statements};
+use crate::migrate::{Migrate, MigrateDatabase, MigrationProgress};
+use crate::mssql::{Mssql, MssqlConnectOptions, MssqlConnection};
+use crate::query::query;
+use crate::query_as::query_as;
+use crate::query_scalar::query_scalar;
+use crc::crc32;
+use futures_core::future::BoxFuture;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+
+fn parse_for_maintenance(uri: &str) -> Result<(MssqlConnectOptions, String), Error> {
+    let mut options = MssqlConnectOptions::from_str(uri)?;
+
+    let database = options.database.clone();
+
+    // switch us to the maintenance database
+    options.database = "master".into();
+
+    Ok((options, database))
+}
+
+impl MigrateDatabase for Mssql {
+    fn create_database(uri: &str) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let (options, database) = parse_for_maintenance(uri)?;
+            let mut conn = options.connect().await?;
+
+            let _ = conn
+                .execute(&*format!(
+                    "CREATE DATABASE [{}]",
+                    database.replace(']', "]]")
+                ))
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn database_exists(uri: &str) -> BoxFuture<'_, Result<bool, Error>> {
+        Box::pin(async move {
+            let (options, database) = parse_for_maintenance(uri)?;
+            let mut conn = options.connect().await?;
+
+            let exists: bool =
+                query_scalar("SELECT CAST(CASE WHEN DB_ID(@p1) IS NULL THEN 0 ELSE 1 END AS BIT)")
+                    .bind(database)
+                    .fetch_one(&mut conn)
+                    .await?;
+
+            Ok(exists)
+        })
+    }
+
+    fn drop_database(uri: &str) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            let (options, database) = parse_for_maintenance(uri)?;
+            let mut conn = options.connect().await?;
+
+            let _ = conn
+                .execute(&*format!(
+                    "DROP DATABASE IF EXISTS [{}]",
+                    database.replace(']', "]]")
+                ))
+                .await?;
+
+            Ok(())
+        })
+    }
+}
+
+impl Migrate for MssqlConnection {
+    fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // the checksum is stored in hexadecimal, as the driver does not support binary
+            // strings yet
+
+            // language=TSQL
+            self.execute(
+                r#"
+IF OBJECT_ID(N'_sqlx_migrations', N'U') IS NULL
+CREATE TABLE _sqlx_migrations (
+    version BIGINT PRIMARY KEY,
+    description NVARCHAR(MAX) NOT NULL,
+    installed_on DATETIME2 NOT NULL DEFAULT SYSDATETIME(),
+    success BIT NOT NULL,
+    checksum NVARCHAR(128) NOT NULL,
+    execution_time BIGINT NOT NULL
+);
+                "#,
+            )
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn version(&mut self) -> BoxFuture<'_, Result<Option<(i64, bool)>, MigrateError>> {
+        Box::pin(async move {
+            // language=TSQL
+            let row = query_as(
+                "SELECT TOP 1 version, CAST(1 - success AS BIT) FROM _sqlx_migrations ORDER BY version DESC",
+            )
+            .fetch_optional(self)
+            .await?;
+
+            Ok(row)
+        })
+    }
+
+    fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            let database_name = current_database(self).await?;
+            let lock_id = generate_lock_id(&database_name);
+
+            // create an application lock over the database, held by the session
+            // this function will not return until the lock is acquired
+
+            // https://docs.microsoft.com/en-us/sql/relational-databases/system-stored-procedures/sp-getapplock-transact-sql
+
+            // language=TSQL
+            let _ = query(
+                "EXEC sp_getapplock @Resource = @p1, @LockMode = 'Exclusive', \
+                 @LockOwner = 'Session', @LockTimeout = -1",
+            )
+            .bind(lock_id)
+            .execute(self)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            let database_name = current_database(self).await?;
+            let lock_id = generate_lock_id(&database_name);
+
+            // language=TSQL
+            let _ = query("EXEC sp_releaseapplock @Resource = @p1, @LockOwner = 'Session'")
+                .bind(lock_id)
+                .execute(self)
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn validate<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // language=TSQL
+            let checksum: Option<String> =
+                query_scalar("SELECT checksum FROM _sqlx_migrations WHERE version = @p1")
+                    .bind(migration.version)
+                    .fetch_optional(self)
+                    .await?;
+
+            if let Some(checksum) = checksum {
+                if checksum == encode_checksum(&migration.checksum) {
+                    Ok(())
+                } else {
+                    Err(MigrateError::VersionMismatch(migration.version))
+                }
+            } else {
+                Err(MigrateError::VersionMissing(migration.version))
+            }
+        })
+    }
+
+    fn apply_with_progress<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        progress: &'m mut (dyn FnMut(MigrationProgress<'_>) + Send),
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            let statements = split_statements(&migration.sql, false);
+            let start = Instant::now();
+
+            let res = if migration.no_tx() {
+                // each statement of the batch is committed on its own
+                let results = self.execute_many(&*migration.sql);
+
+                execute_statements(results, migration, &statements, progress).await
+            } else {
+                let mut tx = self.begin().await?;
+
+                let results = tx.execute_many(&*migration.sql);
+
+                execute_statements(results, migration, &statements, progress).await?;
+
+                tx.commit().await?;
+
+                Ok(())
+            };
+
+            let elapsed = start.elapsed();
+
+            // a migration run in a transaction is only recorded once it is applied, while
+            // a failed migration run without one leaves the database dirty
+            self.record(migration, res.is_ok(), elapsed).await?;
+
+            res?;
+
+            Ok(elapsed)
+        })
+    }
+
+    fn record<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        success: bool,
+        elapsed: Duration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // language=TSQL
+            let _ = query(
+                r#"
+    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
+    VALUES ( @p1, @p2, @p3, @p4, @p5 )
+                "#,
+            )
+            .bind(migration.version)
+            .bind(&*migration.description)
+            .bind(success)
+            .bind(encode_checksum(&migration.checksum))
+            .bind(elapsed.as_nanos() as i64)
+            .execute(self)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn revert<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            let start = Instant::now();
+
+            if migration.no_tx() {
+                let _ = self.execute(&*migration.sql).await?;
+            } else {
+                let mut tx = self.begin().await?;
+
+                let _ = tx.execute(&*migration.sql).await?;
+
+                tx.commit().await?;
+            }
+
+            let elapsed = start.elapsed();
+
+            // language=TSQL
+            let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = @p1"#)
+                .bind(migration.version)
+                .execute(self)
+                .await?;
+
+            Ok(elapsed)
+        })
+    }
+}
+
+async fn current_database(conn: &mut MssqlConnection) -> Result<String, MigrateError> {
+    // language=TSQL
+    Ok(query_scalar("SELECT DB_NAME()").fetch_one(conn).await?)
+}
+
+fn encode_checksum(checksum: &[u8]) -> String {
+    checksum.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+// inspired from rails: https://github.com/rails/rails/blob/6e49cc77ab3d16c06e12f93158eaf3e507d4120e/activerecord/lib/active_record/migration.rb#L1308
+fn generate_lock_id(database_name: &str) -> String {
+    // 0x3d32ad9e chosen by fair dice roll
+    format!(
+        "{:x}",
+        0x3d32ad9e * (crc32::checksum_ieee(database_name.as_bytes()) as i64)
+    )
+}
+
+#[test]
+fn test_encode_checksum() {
+    assert_eq!(encode_checksum(&[0x00, 0x0f, 0xab, 0xff]), "000fabff");
+}
@@ -6,6 +6,8 @@ mod connection;
 mod database;
 mod error;
 mod io;
+#[cfg(feature = "migrate")]
+mod migrate;
 mod options;
 mod protocol;
 mod query_result;
@@ -498,14 +498,25 @@ impl TypeInfo {
                 4 => "INT",
                 8 => "BIGINT",
 
-                _ => unreachable!("invalid size {} for int"),
+                _ => unreachable!("invalid size {} for int", self.size),
             },
 
             DataType::FloatN => match self.size {
                 4 => "REAL",
                 8 => "FLOAT",
 
-                _ => unreachable!("invalid size {} for float"),
+                _ => unreachable!("invalid size {} for float", self.size),
+            },
+
+            DataType::SmallDateTime => "SMALLDATETIME",
+            DataType::DateTime => "DATETIME",
+            DataType::TimeN => "TIME",
+
+            DataType::DateTimeN => match self.size {
+                4 => "SMALLDATETIME",
+                8 => "DATETIME",
+
+                _ => unreachable!("invalid size {} for datetime", self.size),
             },
 
             DataType::VarChar => "VARCHAR",
@@ -536,16 +547,32 @@ impl TypeInfo {
                 4 => "int",
                 8 => "bigint",
 
-                _ => unreachable!("invalid size {} for int"),
+                _ => unreachable!("invalid size {} for int", self.size),
             }),
 
             DataType::FloatN => s.push_str(match self.size {
                 4 => "real",
                 8 => "float",
 
-                _ => unreachable!("invalid size {} for float"),
+                _ => unreachable!("invalid size {} for float", self.size),
+            }),
+
+            DataType::SmallDateTime => s.push_str("smalldatetime"),
+            DataType::DateTime => s.push_str("datetime"),
+
+            DataType::DateTimeN => s.push_str(match self.size {
+                4 => "smalldatetime",
+                8 => "datetime",
+
+                _ => unreachable!("invalid size {} for datetime", self.size),
             }),
 
+            DataType::TimeN => {
+                s.push_str("time(");
+                let _ = itoa::fmt(&mut *s, self.scale);
+                s.push_str(")");
+            }
+
             DataType::VarChar
             | DataType::NVarChar
             | DataType::BigVarChar
@@ -7,7 +7,7 @@ use crate::executor::Executor;
 use crate::mssql::protocol::packet::PacketType;
 use crate::mssql::protocol::sql_batch::SqlBatch;
 use crate::mssql::{Mssql, MssqlConnection};
-use crate::transaction::TransactionManager;
+use crate::transaction::{SavepointCommand, TransactionManager};
 
 /// Implementation of [`TransactionManager`] for MSSQL.
 pub struct MssqlTransactionManager;
@@ -91,4 +91,47 @@ impl TransactionManager for MssqlTransactionManager {
             conn.stream.transaction_depth = depth - 1;
         }
     }
+
+    fn execute_savepoint<'c>(
+        conn: &'c mut MssqlConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let name = quote_savepoint_name(name);
+
+            match command {
+                SavepointCommand::Establish => {
+                    conn.execute(&*format!("SAVE TRAN {}", name)).await?;
+                }
+
+                // savepoints are not released in MSSQL
+                SavepointCommand::Release => {}
+
+                SavepointCommand::RollbackTo => {
+                    conn.execute(&*format!("ROLLBACK TRAN {}", name)).await?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn start_rollback_to_savepoint(conn: &mut MssqlConnection, name: &str) {
+        let query = format!("ROLLBACK TRAN {}", quote_savepoint_name(name));
+
+        conn.stream.pending_done_count += 1;
+
+        conn.stream.write_packet(
+            PacketType::SqlBatch,
+            SqlBatch {
+                transaction_descriptor: conn.stream.transaction_descriptor,
+                sql: &*query,
+            },
+        );
+    }
+}
+
+fn quote_savepoint_name(name: &str) -> String {
+    format!("[{}]", name.replace(']', "]]"))
 }
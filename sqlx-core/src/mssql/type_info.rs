@@ -7,6 +7,20 @@ use crate::type_info::TypeInfo;
 #[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct MssqlTypeInfo(pub(crate) ProtocolTypeInfo);
 
+impl MssqlTypeInfo {
+    #[doc(hidden)]
+    pub fn __type_feature_gate(&self) -> Option<&'static str> {
+        match self.0.ty {
+            DataType::SmallDateTime
+            | DataType::DateTime
+            | DataType::DateTimeN
+            | DataType::TimeN => Some("chrono"),
+
+            _ => None,
+        }
+    }
+}
+
 impl TypeInfo for MssqlTypeInfo {
     fn is_null(&self) -> bool {
         matches!(self.0.ty, DataType::Null)
@@ -0,0 +1,251 @@
+use std::cmp;
+
+use byteorder::{ByteOrder, LittleEndian};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::mssql::protocol::type_info::{DataType, TypeInfo};
+use crate::mssql::{Mssql, MssqlTypeInfo, MssqlValueRef};
+use crate::types::Type;
+
+// https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-tds/786f5b8a-f87d-4980-9070-b9b7274c681d
+
+// `DATETIME` is stored as the number of days since 1900-01-01, and the number of 1/300 of a
+// second since midnight
+const DATETIME_TICKS_PER_SECOND: u64 = 300;
+const DATETIME_TICKS_PER_DAY: u64 = DATETIME_TICKS_PER_SECOND * 86_400;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+fn datetime_epoch() -> NaiveDate {
+    NaiveDate::from_ymd(1900, 1, 1)
+}
+
+// the nanoseconds since midnight, including a leap second
+fn nanos_of_day(time: &NaiveTime) -> u64 {
+    u64::from(time.num_seconds_from_midnight()) * NANOS_PER_SECOND + u64::from(time.nanosecond())
+}
+
+fn time_from_nanos_of_day(nanos: u64) -> Result<NaiveTime, BoxDynError> {
+    NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanos / NANOS_PER_SECOND) as u32,
+        (nanos % NANOS_PER_SECOND) as u32,
+    )
+    .ok_or_else(|| format!("time out of range: {} ns since midnight", nanos).into())
+}
+
+impl Type<Mssql> for NaiveDateTime {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo::new(DataType::DateTimeN, 8))
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(
+            ty.0.ty,
+            DataType::DateTime | DataType::SmallDateTime | DataType::DateTimeN
+        )
+    }
+}
+
+/// Encoded as a `DATETIME`, rounded to the nearest 1/300 of a second (i.e. the nearest of
+/// `.000`, `.003` and `.007`), halves rounded up, like SQL Server converts a `DATETIME2` to a
+/// `DATETIME`. The rounding may carry to the next day.
+impl Encode<'_, Mssql> for NaiveDateTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        let mut days = (self.date() - datetime_epoch()).num_days() as i32;
+
+        let mut ticks = (nanos_of_day(&self.time()) * DATETIME_TICKS_PER_SECOND
+            + NANOS_PER_SECOND / 2)
+            / NANOS_PER_SECOND;
+
+        if ticks >= DATETIME_TICKS_PER_DAY {
+            ticks -= DATETIME_TICKS_PER_DAY;
+            days += 1;
+        }
+
+        buf.extend(&days.to_le_bytes());
+        buf.extend(&(ticks as u32).to_le_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Decode<'_, Mssql> for NaiveDateTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let buf = value.as_bytes()?;
+
+        let (days, nanos) = match buf.len() {
+            // SMALLDATETIME, as the number of days since 1900-01-01, and the number of minutes
+            // since midnight
+            4 => (
+                i64::from(LittleEndian::read_u16(&buf[0..2])),
+                u64::from(LittleEndian::read_u16(&buf[2..4])) * 60 * NANOS_PER_SECOND,
+            ),
+
+            8 => {
+                let ticks = u64::from(LittleEndian::read_u32(&buf[4..8]));
+
+                // 1/300 of a second is not a whole number of nanoseconds; it is rounded to the
+                // nearest, which encodes back to the same tick
+                (
+                    i64::from(LittleEndian::read_i32(&buf[0..4])),
+                    (ticks * NANOS_PER_SECOND + DATETIME_TICKS_PER_SECOND / 2)
+                        / DATETIME_TICKS_PER_SECOND,
+                )
+            }
+
+            len => {
+                return Err(format!("invalid length {} for DATETIME", len).into());
+            }
+        };
+
+        let date = datetime_epoch()
+            .checked_add_signed(Duration::days(days))
+            .ok_or("date out of range for NaiveDateTime")?;
+
+        Ok(date.and_time(time_from_nanos_of_day(nanos)?))
+    }
+}
+
+impl Type<Mssql> for NaiveTime {
+    fn type_info() -> MssqlTypeInfo {
+        MssqlTypeInfo(TypeInfo {
+            ty: DataType::TimeN,
+            size: 5,
+            scale: 7,
+            precision: 0,
+            collation: None,
+        })
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        matches!(ty.0.ty, DataType::TimeN)
+    }
+}
+
+/// Encoded as a `TIME(7)`; the nanoseconds below its precision of 100 nanoseconds are
+/// truncated.
+impl Encode<'_, Mssql> for NaiveTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        let units = nanos_of_day(self) / 100;
+
+        buf.extend(&units.to_le_bytes()[..5]);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        5
+    }
+}
+
+impl Decode<'_, Mssql> for NaiveTime {
+    fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let buf = value.as_bytes()?;
+
+        if buf.len() > 5 {
+            return Err(format!("invalid length {} for TIME", buf.len()).into());
+        }
+
+        // the number of units of 10^-scale seconds since midnight
+        let units = LittleEndian::read_uint(buf, buf.len());
+        let scale = u32::from(cmp::min(value.type_info.0.scale, 7));
+
+        time_from_nanos_of_day(units * 10_u64.pow(9 - scale))
+    }
+}
+
+#[cfg(test)]
+fn decode_value<T: for<'r> Decode<'r, Mssql>>(ty: TypeInfo, buf: &[u8]) -> T {
+    let data = bytes::Bytes::copy_from_slice(buf);
+
+    T::decode(MssqlValueRef {
+        type_info: MssqlTypeInfo(ty),
+        data: Some(&data),
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_encode_datetime_rounds_to_the_nearest_tick() {
+    let encode = |value: NaiveDateTime| {
+        let mut buf = Vec::new();
+        let _ = Encode::<Mssql>::encode(value, &mut buf);
+        buf
+    };
+
+    // 2000-01-01 is 36524 days after 1900-01-01
+    let date = NaiveDate::from_ymd(2000, 1, 1);
+
+    let mut expected = 36524_i32.to_le_bytes().to_vec();
+    expected.extend(&(12 * 3600 * 300 + 1_u32).to_le_bytes());
+    assert_eq!(encode(date.and_hms_milli(12, 0, 0, 3)), expected);
+
+    let mut expected = 36524_i32.to_le_bytes().to_vec();
+    expected.extend(&(12 * 3600 * 300 + 2_u32).to_le_bytes());
+    assert_eq!(encode(date.and_hms_micro(12, 0, 0, 5_000)), expected);
+
+    // rounded up to midnight of the next day
+    let mut expected = 36525_i32.to_le_bytes().to_vec();
+    expected.extend(&0_u32.to_le_bytes());
+    assert_eq!(encode(date.and_hms_milli(23, 59, 59, 999)), expected);
+}
+
+#[test]
+fn test_decode_datetime() {
+    let mut buf = 36524_i32.to_le_bytes().to_vec();
+    buf.extend(&(12 * 3600 * 300 + 1_u32).to_le_bytes());
+
+    let value: NaiveDateTime = decode_value(TypeInfo::new(DataType::DateTimeN, 8), &buf);
+    assert_eq!(
+        value,
+        NaiveDate::from_ymd(2000, 1, 1).and_hms_nano(12, 0, 0, 3_333_333)
+    );
+
+    // the decoded value encodes back to the same tick
+    let mut encoded = Vec::new();
+    let _ = Encode::<Mssql>::encode(value, &mut encoded);
+    assert_eq!(encoded, buf);
+
+    // before 1900-01-01
+    let mut buf = (-1_i32).to_le_bytes().to_vec();
+    buf.extend(&0_u32.to_le_bytes());
+
+    let value: NaiveDateTime = decode_value(TypeInfo::new(DataType::DateTime, 8), &buf);
+    assert_eq!(value, NaiveDate::from_ymd(1899, 12, 31).and_hms(0, 0, 0));
+}
+
+#[test]
+fn test_decode_smalldatetime() {
+    let mut buf = 36524_u16.to_le_bytes().to_vec();
+    buf.extend(&(12 * 60 + 34_u16).to_le_bytes());
+
+    let value: NaiveDateTime = decode_value(TypeInfo::new(DataType::SmallDateTime, 4), &buf);
+    assert_eq!(value, NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 34, 0));
+}
+
+#[test]
+fn test_time_round_trip() {
+    let time = NaiveTime::from_hms_nano(12, 34, 56, 123_456_789);
+
+    let mut buf = Vec::new();
+    let _ = Encode::<Mssql>::encode(time, &mut buf);
+    assert_eq!(buf.len(), 5);
+
+    let value: NaiveTime = decode_value(<NaiveTime as Type<Mssql>>::type_info().0, &buf);
+    assert_eq!(value, NaiveTime::from_hms_nano(12, 34, 56, 123_456_700));
+
+    // TIME(3), in 4 bytes
+    let mut ty = <NaiveTime as Type<Mssql>>::type_info().0;
+    ty.scale = 3;
+
+    let units = (12 * 3600 + 34 * 60 + 56) * 1000 + 123_u32;
+    let value: NaiveTime = decode_value(ty, &units.to_le_bytes()[..4]);
+    assert_eq!(value, NaiveTime::from_hms_milli(12, 34, 56, 123));
+}
@@ -7,6 +7,9 @@ mod float;
 mod int;
 mod str;
 
+#[cfg(feature = "chrono")]
+mod chrono;
+
 impl<'q, T: 'q + Encode<'q, Mssql>> Encode<'q, Mssql> for Option<T> {
     fn encode(self, buf: &mut Vec<u8>) -> IsNull {
         if let Some(v) = self {
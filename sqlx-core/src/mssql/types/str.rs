@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
@@ -72,12 +74,28 @@ impl Encode<'_, Mssql> for String {
 
 impl Decode<'_, Mssql> for String {
     fn decode(value: MssqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        <Cow<'_, str> as Decode<Mssql>>::decode(value).map(Cow::into_owned)
+    }
+}
+
+impl Type<Mssql> for Cow<'_, str> {
+    fn type_info() -> MssqlTypeInfo {
+        <str as Type<Mssql>>::type_info()
+    }
+
+    fn compatible(ty: &MssqlTypeInfo) -> bool {
+        <str as Type<Mssql>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Mssql> for Cow<'r, str> {
+    fn decode(value: MssqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        // borrowed when the bytes are valid UTF-8 as-is, e.g. ASCII in a single-byte encoding
         Ok(value
             .type_info
             .0
             .encoding()?
             .decode_without_bom_handling(value.as_bytes()?)
-            .0
-            .into_owned())
+            .0)
     }
 }
@@ -4,11 +4,17 @@ use crate::mysql::{MySql, MySqlTypeInfo};
 use crate::types::Type;
 
 /// Implementation of [`Arguments`] for MySQL.
-#[derive(Debug, Default)]
+///
+/// The arguments are encoded as they are added; they can be cloned to be sent with several
+/// queries, and the arguments of a part of a query can be appended to the others.
+#[derive(Debug, Default, Clone)]
 pub struct MySqlArguments {
     pub(crate) values: Vec<u8>,
     pub(crate) types: Vec<MySqlTypeInfo>,
     pub(crate) null_bitmap: Vec<u8>,
+
+    // query attributes, sent after the parameters
+    pub(crate) attributes: Vec<(String, String)>,
 }
 
 impl MySqlArguments {
@@ -26,6 +32,28 @@ impl MySqlArguments {
             self.null_bitmap[index / 8] |= (1 << (index % 8)) as u8;
         }
     }
+
+    /// Adds the arguments of `other` to the end of these arguments, e.g. for the bind
+    /// parameters of a condition built separately from the rest of the query.
+    pub fn append(&mut self, other: MySqlArguments) {
+        for (i, ty) in other.types.into_iter().enumerate() {
+            let index = self.types.len();
+
+            self.types.push(ty);
+            self.null_bitmap.resize((index / 8) + 1, 0);
+
+            if other.null_bitmap[i / 8] & (1 << (i % 8)) as u8 != 0 {
+                self.null_bitmap[index / 8] |= (1 << (index % 8)) as u8;
+            }
+        }
+
+        self.values.extend_from_slice(&other.values);
+        self.attributes.extend(other.attributes);
+    }
+
+    pub(crate) fn add_attribute(&mut self, key: &str, value: &str) {
+        self.attributes.push((key.to_owned(), value.to_owned()));
+    }
 }
 
 impl<'q> Arguments<'q> for MySqlArguments {
@@ -43,3 +71,23 @@ impl<'q> Arguments<'q> for MySqlArguments {
         self.add(value)
     }
 }
+
+#[test]
+fn test_append_arguments() {
+    let mut args = MySqlArguments::default();
+
+    for i in 0..7 {
+        args.add(i);
+    }
+
+    let mut other = MySqlArguments::default();
+    other.add(None::<i32>);
+    other.add(8);
+    other.add(None::<i32>);
+
+    args.append(other);
+
+    assert_eq!(args.types.len(), 10);
+    assert_eq!(args.values.len(), 8 * 4);
+    assert_eq!(args.null_bitmap, vec![0b1000_0000, 0b0000_0010]);
+}
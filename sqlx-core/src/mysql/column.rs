@@ -12,6 +12,17 @@ pub struct MySqlColumn {
 
     #[cfg_attr(feature = "offline", serde(skip))]
     pub(crate) flags: Option<ColumnFlags>,
+
+    #[cfg_attr(feature = "offline", serde(skip))]
+    pub(crate) table: Option<UStr>,
+}
+
+impl MySqlColumn {
+    /// Gets the alias of the table of the column in the query, or the name of the table if it
+    /// has no alias, if the column is a column of a table and not an expression.
+    pub fn table_name(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
 }
 
 impl crate::column::private_column::Sealed for MySqlColumn {}
@@ -27,6 +38,10 @@ impl Column for MySqlColumn {
         &*self.name
     }
 
+    fn name_shared(&self) -> UStr {
+        self.name.clone()
+    }
+
     fn type_info(&self) -> &MySqlTypeInfo {
         &self.type_info
     }
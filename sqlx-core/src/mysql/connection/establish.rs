@@ -129,6 +129,8 @@ impl MySqlConnection {
             stream,
             transaction_depth: 0,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            zero_date_mode: options.zero_date_mode,
+            strict_column_names: options.strict_column_names,
             log_settings: options.log_settings.clone(),
         })
     }
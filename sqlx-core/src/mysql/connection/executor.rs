@@ -1,6 +1,6 @@
 use super::MySqlStream;
 use crate::describe::Describe;
-use crate::error::Error;
+use crate::error::{Error, Limit};
 use crate::executor::{Execute, Executor};
 use crate::ext::ustr::UStr;
 use crate::logger::QueryLogger;
@@ -11,6 +11,7 @@ use crate::mysql::protocol::statement::{
     BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose,
 };
 use crate::mysql::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
+use crate::mysql::protocol::Capabilities;
 use crate::mysql::statement::{MySqlStatement, MySqlStatementMetadata};
 use crate::mysql::{
     MySql, MySqlArguments, MySqlColumn, MySqlConnection, MySqlQueryResult, MySqlRow, MySqlTypeInfo,
@@ -24,6 +25,12 @@ use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
 use std::{borrow::Cow, sync::Arc};
 
+// https://dev.mysql.com/doc/refman/8.0/en/prepared-statements.html
+const MAX_BIND_PARAMETERS: usize = u16::MAX as usize;
+
+// the payload of a single packet; larger payloads have to be split, which is not supported yet
+const MAX_PACKET_SIZE: usize = 0xFF_FF_FE;
+
 impl MySqlConnection {
     async fn get_or_prepare<'c>(
         &mut self,
@@ -38,6 +45,8 @@ impl MySqlConnection {
         // https://dev.mysql.com/doc/internals/en/com-stmt-prepare.html
         // https://dev.mysql.com/doc/internals/en/com-stmt-prepare-response.html#packet-COM_STMT_PREPARE_OK
 
+        Error::check_limit(Limit::MessageSize, MAX_PACKET_SIZE, 1 + sql.len())?;
+
         self.stream.send_packet(Prepare { query: sql }).await?;
 
         let ok: PrepareOk = self.stream.recv().await?;
@@ -92,6 +101,43 @@ impl MySqlConnection {
     {
         let mut logger = QueryLogger::new(sql, self.log_settings.clone());
 
+        Error::check_limit(Limit::MessageSize, MAX_PACKET_SIZE, 1 + sql.len())?;
+
+        if let Some(arguments) = &arguments {
+            Error::check_limit(
+                Limit::BindParameters,
+                MAX_BIND_PARAMETERS,
+                arguments.types.len(),
+            )?;
+
+            if !arguments.attributes.is_empty()
+                && !self
+                    .stream
+                    .capabilities
+                    .contains(Capabilities::QUERY_ATTRIBUTES)
+            {
+                return Err(Error::Configuration(
+                    "query attributes are not supported by the server (requires MySQL 8.0.23+)"
+                        .into(),
+                ));
+            }
+
+            // see `Execute` for the layout of the packet; the lengths of the names and values
+            // of the attributes are sent in up to 9 bytes
+            Error::check_limit(
+                Limit::MessageSize,
+                MAX_PACKET_SIZE,
+                20 + arguments.null_bitmap.len()
+                    + 3 * arguments.types.len()
+                    + arguments.values.len()
+                    + arguments
+                        .attributes
+                        .iter()
+                        .map(|(name, value)| 20 + name.len() + value.len())
+                        .sum::<usize>(),
+            )?;
+        }
+
         self.stream.wait_until_ready().await?;
         self.stream.busy = Busy::Result;
 
@@ -108,6 +154,15 @@ impl MySqlConnection {
                 )
                 .await?;
 
+                logger.prepared();
+
+                if arguments.types.len() != metadata.parameters {
+                    return Err(Error::BindParameterCount {
+                        expected: metadata.parameters,
+                        actual: arguments.types.len(),
+                    });
+                }
+
                 // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
                 self.stream
                     .send_packet(StatementExecute {
@@ -195,6 +250,8 @@ impl MySqlConnection {
                     let v = Either::Right(MySqlRow {
                         row,
                         format,
+                        zero_date_mode: self.zero_date_mode,
+                        strict_column_names: self.strict_column_names,
                         columns: Arc::clone(&columns),
                         column_names: Arc::clone(&column_names),
                     });
@@ -222,8 +279,13 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         let sql = query.sql();
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let deferred_check = query.deferred_check();
 
         Box::pin(try_stream! {
+            if let Some(check) = deferred_check {
+                check.run(&mut *self, sql).await?;
+            }
+
             let s = self.run(sql, arguments, persistent).await?;
             pin_mut!(s);
 
@@ -333,6 +395,13 @@ fn recv_next_result_column(def: &ColumnDefinition, ordinal: usize) -> Result<MyS
         (name, _) => UStr::new(name),
     };
 
+    // the alias of the table in the query, if any, or its name
+    let table = match (def.table()?, def.table_alias()?) {
+        (_, alias) if !alias.is_empty() => Some(UStr::new(alias)),
+        (table, _) if !table.is_empty() => Some(UStr::new(table)),
+        _ => None,
+    };
+
     let type_info = MySqlTypeInfo::from_column(&def);
 
     Ok(MySqlColumn {
@@ -340,6 +409,7 @@ fn recv_next_result_column(def: &ColumnDefinition, ordinal: usize) -> Result<MyS
         type_info,
         ordinal,
         flags: Some(def.flags),
+        table,
     })
 }
 
@@ -361,7 +431,8 @@ async fn recv_result_metadata(
 
         let column = recv_next_result_column(&def, ordinal)?;
 
-        column_names.insert(column.name.clone(), ordinal);
+        // the first of the columns with the same name is found by name
+        column_names.entry(column.name.clone()).or_insert(ordinal);
         columns.push(column);
     }
 
@@ -4,7 +4,7 @@ use crate::error::Error;
 use crate::mysql::protocol::statement::StmtClose;
 use crate::mysql::protocol::text::{Ping, Quit};
 use crate::mysql::statement::MySqlStatementMetadata;
-use crate::mysql::{MySql, MySqlConnectOptions};
+use crate::mysql::{MySql, MySqlConnectOptions, MySqlZeroDateMode};
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use futures_util::FutureExt;
@@ -33,6 +33,12 @@ pub struct MySqlConnection {
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
+    // how zero dates are decoded from the rows of this connection
+    zero_date_mode: MySqlZeroDateMode,
+
+    // whether accessing a column of a row by name fails if more than one column has that name
+    strict_column_names: bool,
+
     log_settings: LogSettings,
 }
 
@@ -42,9 +42,21 @@ impl MySqlStream {
             .transpose()?
             .unwrap_or_else(|| charset.default_collation());
 
-        let socket = match options.socket {
-            Some(ref path) => Socket::connect_uds(path).await?,
-            None => Socket::connect_tcp(&options.host, options.port).await?,
+        #[cfg(feature = "transcripts")]
+        let socket = match options.transcript {
+            // a transcript is replayed without connecting to the server
+            Some(ref transcript) if !transcript.is_recording() => transcript.replay(),
+            Some(ref transcript) => transcript.record(connect_socket(options).await?),
+            None => connect_socket(options).await?,
+        };
+
+        #[cfg(not(feature = "transcripts"))]
+        let socket = connect_socket(options).await?;
+
+        #[cfg(feature = "fault-injection")]
+        let socket = match options.fault_injector {
+            Some(ref injector) => injector.connect(socket)?,
+            None => socket,
         };
 
         let mut capabilities = Capabilities::PROTOCOL_41
@@ -58,6 +70,7 @@ impl MySqlStream {
             | Capabilities::MULTI_RESULTS
             | Capabilities::PLUGIN_AUTH
             | Capabilities::PS_MULTI_RESULTS
+            | Capabilities::QUERY_ATTRIBUTES
             | Capabilities::SSL;
 
         if options.database.is_some() {
@@ -207,3 +220,10 @@ impl DerefMut for MySqlStream {
         &mut self.stream
     }
 }
+
+async fn connect_socket(options: &MySqlConnectOptions) -> Result<Socket, Error> {
+    Ok(match options.socket {
+        Some(ref path) => Socket::connect_uds(path).await?,
+        None => Socket::connect_tcp(&options.host, options.port).await?,
+    })
+}
@@ -3,7 +3,8 @@ use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
 use crate::migrate::Migration;
-use crate::migrate::{Migrate, MigrateDatabase};
+use crate::migrate::{execute_statements, split_statements};
+use crate::migrate::{Migrate, MigrateDatabase, MigrationProgress};
 use crate::mysql::{MySql, MySqlConnectOptions, MySqlConnection};
 use crate::query::query;
 use crate::query_as::query_as;
@@ -170,17 +171,36 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn apply<'e: 'm, 'm>(
+    fn apply_with_progress<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
+        progress: &'m mut (dyn FnMut(MigrationProgress<'_>) + Send),
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
+            // MySQL does not have transactional DDL, so migrations are never run in a transaction
+            let statements = split_statements(&migration.sql, true);
             let start = Instant::now();
 
-            let res = self.execute(&*migration.sql).await;
+            let results = self.execute_many(&*migration.sql);
+            let res = execute_statements(results, migration, &statements, progress).await;
 
             let elapsed = start.elapsed();
 
+            self.record(migration, res.is_ok(), elapsed).await?;
+
+            res?;
+
+            Ok(elapsed)
+        })
+    }
+
+    fn record<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        success: bool,
+        elapsed: Duration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
             // language=MySQL
             let _ = query(
                 r#"
@@ -190,15 +210,13 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             )
             .bind(migration.version)
             .bind(&*migration.description)
-            .bind(res.is_ok())
+            .bind(success)
             .bind(&*migration.checksum)
             .bind(elapsed.as_nanos() as i64)
             .execute(self)
             .await?;
 
-            res?;
-
-            Ok(elapsed)
+            Ok(())
         })
     }
 
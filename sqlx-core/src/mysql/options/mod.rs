@@ -3,9 +3,15 @@ use std::path::{Path, PathBuf};
 mod connect;
 mod parse;
 mod ssl_mode;
+mod zero_date_mode;
 
+#[cfg(feature = "fault-injection")]
+use crate::fault::FaultInjector;
+#[cfg(feature = "transcripts")]
+use crate::transcript::Transcript;
 use crate::{connection::LogSettings, net::CertificateInput};
 pub use ssl_mode::MySqlSslMode;
+pub use zero_date_mode::MySqlZeroDateMode;
 
 /// Options and flags which can be used to configure a MySQL connection.
 ///
@@ -26,6 +32,7 @@ pub use ssl_mode::MySqlSslMode;
 /// | `ssl-ca` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
 /// | `socket` | `None` | Path to the unix domain socket, which will be used instead of TCP if set. |
+/// | `zero-date-mode` | `null` | Determines how zero dates, such as `0000-00-00`, are decoded: `null`, `error`, or a replacement date such as `1970-01-01`. See [`MySqlZeroDateMode`]. |
 ///
 /// # Example
 ///
@@ -64,7 +71,13 @@ pub struct MySqlConnectOptions {
     pub(crate) statement_cache_capacity: usize,
     pub(crate) charset: String,
     pub(crate) collation: Option<String>,
+    pub(crate) zero_date_mode: MySqlZeroDateMode,
+    pub(crate) strict_column_names: bool,
     pub(crate) log_settings: LogSettings,
+    #[cfg(feature = "fault-injection")]
+    pub(crate) fault_injector: Option<FaultInjector>,
+    #[cfg(feature = "transcripts")]
+    pub(crate) transcript: Option<Transcript>,
 }
 
 impl Default for MySqlConnectOptions {
@@ -88,7 +101,13 @@ impl MySqlConnectOptions {
             ssl_mode: MySqlSslMode::Preferred,
             ssl_ca: None,
             statement_cache_capacity: 100,
+            zero_date_mode: MySqlZeroDateMode::Null,
+            strict_column_names: false,
             log_settings: Default::default(),
+            #[cfg(feature = "fault-injection")]
+            fault_injector: None,
+            #[cfg(feature = "transcripts")]
+            transcript: None,
         }
     }
 
@@ -212,4 +231,88 @@ impl MySqlConnectOptions {
         self.collation = Some(collation.to_owned());
         self
     }
+
+    /// Sets how zero dates, such as `0000-00-00` or `0000-00-00 00:00:00`, and dates with a zero
+    /// month or day are decoded with `chrono` or `time`.
+    ///
+    /// By default, they are decoded as a `NULL`, i.e. as `None` for an `Option<T>`. See
+    /// [`MySqlZeroDateMode`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::{MySqlZeroDateMode, MySqlConnectOptions};
+    /// let options = MySqlConnectOptions::new()
+    ///     .zero_date_mode(MySqlZeroDateMode::Replace { year: 1970, month: 1, day: 1 });
+    /// ```
+    pub fn zero_date_mode(mut self, mode: MySqlZeroDateMode) -> Self {
+        self.zero_date_mode = mode;
+        self
+    }
+
+    /// Sets whether accessing a column of a row by name fails with
+    /// [`ColumnAmbiguous`](crate::error::Error::ColumnAmbiguous) if more than one column has
+    /// that name, e.g. the `id` columns of `SELECT a.*, b.* FROM a JOIN b ...`.
+    ///
+    /// Otherwise, the first column of that name is used. The columns of the same name can be
+    /// told apart by their table, or its alias in the query, with a `(table, name)` index, e.g.
+    /// `row.try_get::<i64, _>(("b", "id"))`.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .strict_column_names(true);
+    /// ```
+    pub fn strict_column_names(mut self, strict: bool) -> Self {
+        self.strict_column_names = strict;
+        self
+    }
+
+    /// Sets whether the stages of each statement are timed: its preparation, then the time from
+    /// its execution to its first row, and to its last one, which tells a slow plan from slow
+    /// network or decoding.
+    ///
+    /// With the `tracing` feature, they are reported as a `DEBUG` event of the
+    /// `sqlx::query::stages` target; with the `metrics` feature, they are recorded in seconds
+    /// in the `sqlx_query_prepare_seconds`, `sqlx_query_first_row_seconds` and
+    /// `sqlx_query_drain_seconds` histograms. The preparation is only timed for the queries
+    /// with bind parameters, which are prepared.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .stage_timings(true);
+    /// ```
+    pub fn stage_timings(mut self, enabled: bool) -> Self {
+        self.log_settings.stage_timings = enabled;
+        self
+    }
+
+    /// Routes every connection opened with these options through a [`FaultInjector`],
+    /// for testing.
+    #[cfg(feature = "fault-injection")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fault-injection")))]
+    pub fn fault_injector(mut self, injector: &FaultInjector) -> Self {
+        self.fault_injector = Some(injector.clone());
+        self
+    }
+
+    /// Replays a [`Transcript`] instead of connecting to the server, or records the exchange
+    /// with the server in it, for testing.
+    ///
+    /// The exchange is recorded below TLS, so a transcript should be recorded with TLS disabled.
+    #[cfg(feature = "transcripts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "transcripts")))]
+    pub fn transcript(mut self, transcript: &Transcript) -> Self {
+        self.transcript = Some(transcript.clone());
+        self
+    }
 }
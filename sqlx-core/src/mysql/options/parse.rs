@@ -68,6 +68,10 @@ impl FromStr for MySqlConnectOptions {
                     options = options.socket(&*value);
                 }
 
+                "zero-date-mode" => {
+                    options = options.zero_date_mode(value.parse().map_err(Error::config)?);
+                }
+
                 _ => {}
             }
         }
@@ -91,3 +95,29 @@ fn it_parses_password_with_non_ascii_chars_correctly() {
 
     assert_eq!(Some("p@ssw0rd".into()), opts.password);
 }
+
+#[test]
+fn it_parses_zero_date_mode() {
+    use crate::mysql::MySqlZeroDateMode;
+
+    let uri = "mysql://root@localhost/database?zero-date-mode=1970-01-01";
+    let opts = MySqlConnectOptions::from_str(uri).unwrap();
+
+    assert_eq!(
+        opts.zero_date_mode,
+        MySqlZeroDateMode::Replace {
+            year: 1970,
+            month: 1,
+            day: 1
+        }
+    );
+
+    let uri = "mysql://root@localhost/database?zero-date-mode=error";
+    let opts = MySqlConnectOptions::from_str(uri).unwrap();
+
+    assert_eq!(opts.zero_date_mode, MySqlZeroDateMode::Error);
+
+    let uri = "mysql://root@localhost/database?zero-date-mode=1970-00-01";
+
+    assert!(MySqlConnectOptions::from_str(uri).is_err());
+}
@@ -0,0 +1,62 @@
+use crate::error::Error;
+use std::str::FromStr;
+
+/// Options for controlling how zero dates, such as `0000-00-00` or `0000-00-00 00:00:00`, and
+/// dates with a zero month or day, such as `2020-00-15`, are decoded.
+///
+/// MySQL stores these dates unless the `NO_ZERO_DATE` and `NO_ZERO_IN_DATE` SQL modes are set;
+/// they cannot be represented by the date types of `chrono` or `time`.
+///
+/// It is used by the [`zero_date_mode`](super::MySqlConnectOptions::zero_date_mode) method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MySqlZeroDateMode {
+    /// Decode the date as a `NULL`: `None` for an `Option<T>`, and an error otherwise.
+    ///
+    /// This is the default if `zero_date_mode` is not specified.
+    Null,
+
+    /// Fail to decode the date, even for an `Option<T>`.
+    Error,
+
+    /// Decode the date as the given date, which must be a valid date. The time of a date time
+    /// is kept.
+    Replace { year: u16, month: u8, day: u8 },
+}
+
+impl Default for MySqlZeroDateMode {
+    fn default() -> Self {
+        MySqlZeroDateMode::Null
+    }
+}
+
+impl FromStr for MySqlZeroDateMode {
+    type Err = Error;
+
+    /// Parses `null`, `error`, or a replacement date formatted as `YYYY-MM-DD`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "null" => MySqlZeroDateMode::Null,
+            "error" => MySqlZeroDateMode::Error,
+
+            _ => {
+                let mut parts = s.splitn(3, '-');
+
+                match (
+                    parts.next().and_then(|year| year.parse().ok()),
+                    parts.next().and_then(|month| month.parse().ok()),
+                    parts.next().and_then(|day| day.parse().ok()),
+                ) {
+                    (Some(year), Some(month @ 1..=12), Some(day @ 1..=31)) => {
+                        MySqlZeroDateMode::Replace { year, month, day }
+                    }
+
+                    _ => {
+                        return Err(Error::Configuration(
+                            format!("unknown value {:?} for `zero_date_mode`", s).into(),
+                        ));
+                    }
+                }
+            }
+        })
+    }
+}
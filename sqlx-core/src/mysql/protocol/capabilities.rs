@@ -80,6 +80,9 @@ bitflags::bitflags! {
         // The client can handle optional metadata information in the resultset
         const OPTIONAL_RESULTSET_METADATA = (1 << 25);
 
+        // [MySQL 8.0.23+] Query attributes can be sent with COM_QUERY and COM_STMT_EXECUTE
+        const QUERY_ATTRIBUTES = (1 << 27);
+
         // Don't reset the options after an unsuccessful connect
         const REMEMBER_OPTIONS = (1 << 31);
     }
@@ -1,9 +1,14 @@
 use crate::io::Encode;
-use crate::mysql::protocol::text::ColumnFlags;
+use crate::mysql::io::MySqlBufMutExt;
+use crate::mysql::protocol::text::{ColumnFlags, ColumnType};
 use crate::mysql::protocol::Capabilities;
 use crate::mysql::MySqlArguments;
 
 // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_com_stmt_execute.html
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.26/page_protocol_com_stmt_execute.html
+
+// the number of parameters, including the query attributes, is sent after the iteration count
+const PARAMETER_COUNT_AVAILABLE: u8 = 0x08;
 
 #[derive(Debug)]
 pub struct Execute<'q> {
@@ -12,14 +17,39 @@ pub struct Execute<'q> {
 }
 
 impl<'q> Encode<'_, Capabilities> for Execute<'q> {
-    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+    fn encode_with(&self, buf: &mut Vec<u8>, capabilities: Capabilities) {
+        let with_attributes = capabilities.contains(Capabilities::QUERY_ATTRIBUTES);
+
+        let attributes: &[(String, String)] = if with_attributes {
+            &self.arguments.attributes
+        } else {
+            &[]
+        };
+
+        let count = self.arguments.types.len() + attributes.len();
+
         buf.push(0x17); // COM_STMT_EXECUTE
         buf.extend(&self.statement.to_le_bytes());
-        buf.push(0); // NO_CURSOR
+
+        // NO_CURSOR
+        buf.push(if with_attributes {
+            PARAMETER_COUNT_AVAILABLE
+        } else {
+            0
+        });
+
         buf.extend(&1_u32.to_le_bytes()); // iterations (always 1): int<4>
 
-        if !self.arguments.types.is_empty() {
+        if with_attributes {
+            buf.put_uint_lenenc(count as u64);
+        }
+
+        if count > 0 {
+            // the attributes are never NULL
+            let null_bitmap_len = buf.len() + (count + 7) / 8;
             buf.extend(&*self.arguments.null_bitmap);
+            buf.resize(null_bitmap_len, 0);
+
             buf.push(1); // send type to server
 
             for ty in &self.arguments.types {
@@ -30,9 +60,69 @@ impl<'q> Encode<'_, Capabilities> for Execute<'q> {
                 } else {
                     0
                 });
+
+                if with_attributes {
+                    // parameters have no name
+                    buf.put_str_lenenc("");
+                }
+            }
+
+            for (name, _) in attributes {
+                buf.push(ColumnType::VarString as u8);
+                buf.push(0);
+                buf.put_str_lenenc(name);
             }
 
             buf.extend(&*self.arguments.values);
+
+            for (_, value) in attributes {
+                buf.put_str_lenenc(value);
+            }
         }
     }
 }
+
+#[test]
+fn test_encode_execute() {
+    let mut arguments = MySqlArguments::default();
+    arguments.add(None::<i32>);
+
+    let execute = Execute {
+        statement: 1,
+        arguments: &arguments,
+    };
+
+    let mut buf = Vec::new();
+    execute.encode_with(&mut buf, Capabilities::PROTOCOL_41);
+
+    assert_eq!(buf, b"\x17\x01\0\0\0\0\x01\0\0\0\x01\x01\x03\0" as &[u8]);
+}
+
+#[test]
+fn test_encode_execute_with_attributes() {
+    let mut arguments = MySqlArguments::default();
+    arguments.add(7_i32);
+    arguments.add_attribute("trace", "ab");
+
+    let execute = Execute {
+        statement: 1,
+        arguments: &arguments,
+    };
+
+    let mut buf = Vec::new();
+    execute.encode_with(&mut buf, Capabilities::QUERY_ATTRIBUTES);
+
+    assert_eq!(
+        buf,
+        b"\x17\x01\0\0\0\x08\x01\0\0\0\x02\0\x01\x03\0\0\xfd\0\x05trace\x07\0\0\0\x02ab" as &[u8]
+    );
+
+    // without the capability, the attributes are not sent
+    let mut buf = Vec::new();
+    execute.encode_with(&mut buf, Capabilities::PROTOCOL_41);
+
+    assert_eq!(
+        buf,
+        b"\x17\x01\0\0\0\0\x01\0\0\0\0\x01\x03\0\x07\0\0\0" as &[u8]
+    );
+}
@@ -125,6 +125,14 @@ impl ColumnDefinition {
     pub(crate) fn alias(&self) -> Result<&str, Error> {
         from_utf8(&self.alias).map_err(Error::protocol)
     }
+
+    pub(crate) fn table(&self) -> Result<&str, Error> {
+        from_utf8(&self.table).map_err(Error::protocol)
+    }
+
+    pub(crate) fn table_alias(&self) -> Result<&str, Error> {
+        from_utf8(&self.table_alias).map_err(Error::protocol)
+    }
 }
 
 impl Decode<'_, Capabilities> for ColumnDefinition {
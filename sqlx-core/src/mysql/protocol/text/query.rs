@@ -1,14 +1,34 @@
 use crate::io::Encode;
+use crate::mysql::io::MySqlBufMutExt;
 use crate::mysql::protocol::Capabilities;
 
 // https://dev.mysql.com/doc/internals/en/com-query.html
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.26/page_protocol_com_query.html
 
 #[derive(Debug)]
 pub(crate) struct Query<'q>(pub(crate) &'q str);
 
 impl Encode<'_, Capabilities> for Query<'_> {
-    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+    fn encode_with(&self, buf: &mut Vec<u8>, capabilities: Capabilities) {
         buf.push(0x03); // COM_QUERY
+
+        if capabilities.contains(Capabilities::QUERY_ATTRIBUTES) {
+            // no query attribute, in a single set
+            buf.put_uint_lenenc(0);
+            buf.put_uint_lenenc(1);
+        }
+
         buf.extend(self.0.as_bytes())
     }
 }
+
+#[test]
+fn test_encode_query() {
+    let mut buf = Vec::new();
+    Query("SELECT 1").encode_with(&mut buf, Capabilities::PROTOCOL_41);
+    assert_eq!(buf, b"\x03SELECT 1");
+
+    let mut buf = Vec::new();
+    Query("SELECT 1").encode_with(&mut buf, Capabilities::QUERY_ATTRIBUTES);
+    assert_eq!(buf, b"\x03\0\x01SELECT 1");
+}
@@ -1,7 +1,9 @@
-use crate::column::ColumnIndex;
+use crate::column::{index_by_name, index_by_table_and_name, ColumnIndex};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
-use crate::mysql::{protocol, MySql, MySqlColumn, MySqlValueFormat, MySqlValueRef};
+use crate::mysql::{
+    protocol, MySql, MySqlColumn, MySqlValueFormat, MySqlValueRef, MySqlZeroDateMode,
+};
 use crate::row::Row;
 use crate::HashMap;
 use std::sync::Arc;
@@ -11,6 +13,8 @@ use std::sync::Arc;
 pub struct MySqlRow {
     pub(crate) row: protocol::Row,
     pub(crate) format: MySqlValueFormat,
+    pub(crate) zero_date_mode: MySqlZeroDateMode,
+    pub(crate) strict_column_names: bool,
     pub(crate) columns: Arc<Vec<MySqlColumn>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
 }
@@ -34,6 +38,7 @@ impl Row for MySqlRow {
 
         Ok(MySqlValueRef {
             format: self.format,
+            zero_date_mode: self.zero_date_mode,
             row: Some(&self.row.storage),
             type_info: column.type_info.clone(),
             value,
@@ -43,10 +48,23 @@ impl Row for MySqlRow {
 
 impl ColumnIndex<MySqlRow> for &'_ str {
     fn index(&self, row: &MySqlRow) -> Result<usize, Error> {
-        row.column_names
-            .get(*self)
-            .ok_or_else(|| Error::ColumnNotFound((*self).into()))
-            .map(|v| *v)
+        index_by_name(
+            &row.columns,
+            &row.column_names,
+            self,
+            row.strict_column_names,
+        )
+    }
+}
+
+impl ColumnIndex<MySqlRow> for (&'_ str, &'_ str) {
+    fn index(&self, row: &MySqlRow) -> Result<usize, Error> {
+        index_by_table_and_name(
+            &row.columns,
+            MySqlColumn::table_name,
+            *self,
+            row.strict_column_names,
+        )
     }
 }
 
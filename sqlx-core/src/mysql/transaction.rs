@@ -7,7 +7,7 @@ use crate::mysql::protocol::text::Query;
 use crate::mysql::{MySql, MySqlConnection};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    savepoint_ansi_sql, SavepointCommand, TransactionManager,
 };
 
 /// Implementation of [`TransactionManager`] for MySQL.
@@ -65,4 +65,27 @@ impl TransactionManager for MySqlTransactionManager {
             conn.transaction_depth = depth - 1;
         }
     }
+
+    fn execute_savepoint<'c>(
+        conn: &'c mut MySqlConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*savepoint_ansi_sql(command, name, '`'))
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn start_rollback_to_savepoint(conn: &mut MySqlConnection, name: &str) {
+        conn.stream.busy = Busy::Result;
+        conn.stream.sequence_id = 0;
+        conn.stream.write_packet(Query(&savepoint_ansi_sql(
+            SavepointCommand::RollbackTo,
+            name,
+            '`',
+        )));
+    }
 }
@@ -50,6 +50,12 @@ impl MySqlTypeInfo {
         }
     }
 
+    // `TINYINT(1)` (`BOOLEAN`) and `BIT(1)` columns, which are used for booleans
+    #[doc(hidden)]
+    pub fn __is_bool_like(&self) -> bool {
+        matches!(self.r#type, ColumnType::Tiny | ColumnType::Bit) && self.max_size == Some(1)
+    }
+
     pub(crate) fn from_column(column: &ColumnDefinition) -> Self {
         Self {
             r#type: column.r#type,
@@ -39,6 +39,11 @@ impl Encode<'_, MySql> for bool {
 
 impl Decode<'_, MySql> for bool {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info.r#type == ColumnType::Bit {
+            // NOTE: Regardless of the value format, there is raw binary data here
+            return Ok(value.as_bytes()?.iter().any(|b| *b != 0));
+        }
+
         Ok(<i8 as Decode<MySql>>::decode(value)? != 0)
     }
 }
@@ -5,7 +5,7 @@ use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike,
 
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
-use crate::error::{BoxDynError, UnexpectedNullError};
+use crate::error::BoxDynError;
 use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::type_info::MySqlTypeInfo;
 use crate::mysql::{MySql, MySqlValueFormat, MySqlValueRef};
@@ -127,13 +127,11 @@ impl Encode<'_, MySql> for NaiveDate {
 impl<'r> Decode<'r, MySql> for NaiveDate {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
         match value.format() {
-            MySqlValueFormat::Binary => {
-                decode_date(&value.as_bytes()?[1..]).ok_or_else(|| UnexpectedNullError.into())
-            }
+            MySqlValueFormat::Binary => decode_date(&value, &value.as_bytes()?[1..]),
 
             MySqlValueFormat::Text => {
-                let s = value.as_str()?;
-                NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(Into::into)
+                let s = value.as_date_str()?;
+                NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(Into::into)
             }
         }
     }
@@ -188,7 +186,7 @@ impl<'r> Decode<'r, MySql> for NaiveDateTime {
                 let buf = value.as_bytes()?;
 
                 let len = buf[0];
-                let date = decode_date(&buf[1..]).ok_or(UnexpectedNullError)?;
+                let date = decode_date(&value, &buf[1..])?;
 
                 let dt = if len > 4 {
                     date.and_time(decode_time(len - 4, &buf[5..]))
@@ -200,8 +198,8 @@ impl<'r> Decode<'r, MySql> for NaiveDateTime {
             }
 
             MySqlValueFormat::Text => {
-                let s = value.as_str()?;
-                NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").map_err(Into::into)
+                let s = value.as_date_str()?;
+                NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S%.f").map_err(Into::into)
             }
         }
     }
@@ -217,18 +215,21 @@ fn encode_date(date: &NaiveDate, buf: &mut Vec<u8>) {
     buf.push(date.day() as u8);
 }
 
-fn decode_date(mut buf: &[u8]) -> Option<NaiveDate> {
-    if buf.len() == 0 {
+fn decode_date(value: &MySqlValueRef<'_>, mut buf: &[u8]) -> Result<NaiveDate, BoxDynError> {
+    let (year, month, day) = if value.is_zero_date() {
         // MySQL specifies that if there are no bytes, this is all zeros
-        None
+        value.zero_date_replacement()?
     } else {
-        let year = buf.get_u16_le();
-        Some(NaiveDate::from_ymd(
-            year as i32,
-            buf[0] as u32,
-            buf[1] as u32,
-        ))
-    }
+        (buf.get_u16_le(), buf[0], buf[1])
+    };
+
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32).ok_or_else(|| {
+        format!(
+            "date out of range for MySQL: {:04}-{:02}-{:02}",
+            year, month, day
+        )
+        .into()
+    })
 }
 
 fn encode_time(time: &NaiveTime, include_micros: bool, buf: &mut Vec<u8>) {
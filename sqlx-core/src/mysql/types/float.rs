@@ -5,7 +5,7 @@ use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueFormat, MySqlValueRef};
-use crate::types::Type;
+use crate::types::{parse_float, Type};
 
 fn real_compatible(ty: &MySqlTypeInfo) -> bool {
     matches!(ty.r#type, ColumnType::Float | ColumnType::Double)
@@ -62,7 +62,7 @@ impl Decode<'_, MySql> for f32 {
                 }
             }
 
-            MySqlValueFormat::Text => value.as_str()?.parse()?,
+            MySqlValueFormat::Text => parse_float(value.as_str()?)?,
         })
     }
 }
@@ -71,7 +71,7 @@ impl Decode<'_, MySql> for f64 {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
             MySqlValueFormat::Binary => LittleEndian::read_f64(value.as_bytes()?),
-            MySqlValueFormat::Text => value.as_str()?.parse()?,
+            MySqlValueFormat::Text => parse_float(value.as_str()?)?,
         })
     }
 }
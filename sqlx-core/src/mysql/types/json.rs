@@ -41,8 +41,6 @@ where
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
         let string_value = <&str as Decode<MySql>>::decode(value)?;
 
-        serde_json::from_str(&string_value)
-            .map(Json)
-            .map_err(Into::into)
+        Json::decode_from_slice(string_value.as_bytes())
     }
 }
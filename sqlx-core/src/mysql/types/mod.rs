@@ -15,7 +15,7 @@
 //! | `u64`                                 | BIGINT UNSIGNED                                      |
 //! | `f32`                                 | FLOAT                                                |
 //! | `f64`                                 | DOUBLE                                               |
-//! | `&str`, [`String`]                    | VARCHAR, CHAR, TEXT                                  |
+//! | `&str`, [`String`], `Cow<str>`        | VARCHAR, CHAR, TEXT                                  |
 //! | `&[u8]`, `Vec<u8>`                    | VARBINARY, BINARY, BLOB                              |
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
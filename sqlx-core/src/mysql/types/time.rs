@@ -7,7 +7,7 @@ use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
-use crate::error::{BoxDynError, UnexpectedNullError};
+use crate::error::BoxDynError;
 use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::type_info::MySqlTypeInfo;
 use crate::mysql::{MySql, MySqlValueFormat, MySqlValueRef};
@@ -143,12 +143,10 @@ impl Encode<'_, MySql> for Date {
 impl<'r> Decode<'r, MySql> for Date {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
         match value.format() {
-            MySqlValueFormat::Binary => {
-                Ok(decode_date(&value.as_bytes()?[1..])?.ok_or(UnexpectedNullError)?)
-            }
+            MySqlValueFormat::Binary => decode_date(&value, &value.as_bytes()?[1..]),
             MySqlValueFormat::Text => {
-                let s = value.as_str()?;
-                Date::parse(s, "%Y-%m-%d").map_err(Into::into)
+                let s = value.as_date_str()?;
+                Date::parse(&*s, "%Y-%m-%d").map_err(Into::into)
             }
         }
     }
@@ -197,7 +195,7 @@ impl<'r> Decode<'r, MySql> for PrimitiveDateTime {
             MySqlValueFormat::Binary => {
                 let buf = value.as_bytes()?;
                 let len = buf[0];
-                let date = decode_date(&buf[1..])?.ok_or(UnexpectedNullError)?;
+                let date = decode_date(&value, &buf[1..])?;
 
                 let dt = if len > 4 {
                     date.with_time(decode_time(len - 4, &buf[5..])?)
@@ -209,7 +207,7 @@ impl<'r> Decode<'r, MySql> for PrimitiveDateTime {
             }
 
             MySqlValueFormat::Text => {
-                let s = value.as_str()?;
+                let s = value.as_date_str()?;
 
                 // If there are less than 9 digits after the decimal point
                 // We need to zero-pad
@@ -222,7 +220,7 @@ impl<'r> Decode<'r, MySql> for PrimitiveDateTime {
                         Cow::Owned(format!("{}.000000000", s))
                     }
                 } else {
-                    Cow::Borrowed(s)
+                    s
                 };
 
                 PrimitiveDateTime::parse(&*s, "%Y-%m-%d %H:%M:%S.%N").map_err(Into::into)
@@ -241,19 +239,15 @@ fn encode_date(date: &Date, buf: &mut Vec<u8>) {
     buf.push(date.day());
 }
 
-fn decode_date(buf: &[u8]) -> Result<Option<Date>, BoxDynError> {
-    if buf.is_empty() {
-        // zero buffer means a zero date (null)
-        return Ok(None);
-    }
+fn decode_date(value: &MySqlValueRef<'_>, buf: &[u8]) -> Result<Date, BoxDynError> {
+    let (year, month, day) = if value.is_zero_date() {
+        // zero buffer means a zero date
+        value.zero_date_replacement()?
+    } else {
+        (LittleEndian::read_u16(buf), buf[2], buf[3])
+    };
 
-    Date::try_from_ymd(
-        LittleEndian::read_u16(buf) as i32,
-        buf[2] as u8,
-        buf[3] as u8,
-    )
-    .map_err(Into::into)
-    .map(Some)
+    Date::try_from_ymd(year as i32, month, day).map_err(Into::into)
 }
 
 fn encode_time(time: &Time, include_micros: bool, buf: &mut Vec<u8>) {
@@ -1,6 +1,6 @@
 use crate::error::{BoxDynError, UnexpectedNullError};
 use crate::mysql::protocol::text::ColumnType;
-use crate::mysql::{MySql, MySqlTypeInfo};
+use crate::mysql::{MySql, MySqlTypeInfo, MySqlZeroDateMode};
 use crate::value::{Value, ValueRef};
 use bytes::Bytes;
 use std::borrow::Cow;
@@ -19,6 +19,7 @@ pub struct MySqlValue {
     value: Option<Bytes>,
     type_info: MySqlTypeInfo,
     format: MySqlValueFormat,
+    zero_date_mode: MySqlZeroDateMode,
 }
 
 /// Implementation of [`ValueRef`] for MySQL.
@@ -28,6 +29,7 @@ pub struct MySqlValueRef<'r> {
     pub(crate) row: Option<&'r Bytes>,
     pub(crate) type_info: MySqlTypeInfo,
     pub(crate) format: MySqlValueFormat,
+    pub(crate) zero_date_mode: MySqlZeroDateMode,
 }
 
 impl<'r> MySqlValueRef<'r> {
@@ -45,6 +47,50 @@ impl<'r> MySqlValueRef<'r> {
     pub(crate) fn as_str(&self) -> Result<&'r str, BoxDynError> {
         Ok(from_utf8(self.as_bytes()?)?)
     }
+
+    /// Returns `true` if this is a zero date, or a date with a zero month or day.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn is_zero_date(&self) -> bool {
+        is_zero_date(self.value, &self.type_info, self.format)
+    }
+
+    /// Returns the `(year, month, day)` to decode a zero date as, according to the
+    /// [`MySqlZeroDateMode`] of the connection.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn zero_date_replacement(&self) -> Result<(u16, u8, u8), BoxDynError> {
+        match self.zero_date_mode {
+            MySqlZeroDateMode::Null => Err(UnexpectedNullError.into()),
+
+            MySqlZeroDateMode::Error => Err(format!(
+                "zero date {:?} cannot be decoded; see `MySqlConnectOptions::zero_date_mode`",
+                String::from_utf8_lossy(self.value.unwrap_or_default())
+            )
+            .into()),
+
+            MySqlZeroDateMode::Replace { year, month, day } => Ok((year, month, day)),
+        }
+    }
+
+    /// Returns the text of a date or a date time, where a zero date is replaced according to the
+    /// [`MySqlZeroDateMode`] of the connection.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn as_date_str(&self) -> Result<Cow<'r, str>, BoxDynError> {
+        let s = self.as_str()?;
+
+        if !self.is_zero_date() {
+            return Ok(Cow::Borrowed(s));
+        }
+
+        let (year, month, day) = self.zero_date_replacement()?;
+
+        Ok(Cow::Owned(format!(
+            "{:04}-{:02}-{:02}{}",
+            year,
+            month,
+            day,
+            &s[10..]
+        )))
+    }
 }
 
 impl Value for MySqlValue {
@@ -56,6 +102,7 @@ impl Value for MySqlValue {
             row: None,
             type_info: self.type_info.clone(),
             format: self.format,
+            zero_date_mode: self.zero_date_mode,
         }
     }
 
@@ -64,7 +111,12 @@ impl Value for MySqlValue {
     }
 
     fn is_null(&self) -> bool {
-        is_null(self.value.as_deref(), &self.type_info)
+        is_null(
+            self.value.as_deref(),
+            &self.type_info,
+            self.format,
+            self.zero_date_mode,
+        )
     }
 }
 
@@ -84,6 +136,7 @@ impl<'r> ValueRef<'r> for MySqlValueRef<'r> {
             value,
             format: self.format,
             type_info: self.type_info.clone(),
+            zero_date_mode: self.zero_date_mode,
         }
     }
 
@@ -93,7 +146,12 @@ impl<'r> ValueRef<'r> for MySqlValueRef<'r> {
 
     #[inline]
     fn is_null(&self) -> bool {
-        is_null(self.value.as_deref(), &self.type_info)
+        is_null(
+            self.value.as_deref(),
+            &self.type_info,
+            self.format,
+            self.zero_date_mode,
+        )
     }
 }
 
@@ -119,17 +177,39 @@ impl From<MySqlValue> for crate::any::AnyValue {
     }
 }
 
-fn is_null(value: Option<&[u8]>, ty: &MySqlTypeInfo) -> bool {
-    if let Some(value) = value {
-        // zero dates and date times should be treated the same as NULL
-        if matches!(
-            ty.r#type,
-            ColumnType::Date | ColumnType::Timestamp | ColumnType::Datetime
-        ) && value.get(0) == Some(&0)
-        {
-            return true;
-        }
+fn is_null(
+    value: Option<&[u8]>,
+    ty: &MySqlTypeInfo,
+    format: MySqlValueFormat,
+    zero_date_mode: MySqlZeroDateMode,
+) -> bool {
+    // zero dates are treated the same as NULL, unless the connection is configured otherwise
+    if zero_date_mode == MySqlZeroDateMode::Null && is_zero_date(value, ty, format) {
+        return true;
     }
 
     value.is_none()
 }
+
+fn is_zero_date(value: Option<&[u8]>, ty: &MySqlTypeInfo, format: MySqlValueFormat) -> bool {
+    if !matches!(
+        ty.r#type,
+        ColumnType::Date | ColumnType::Timestamp | ColumnType::Datetime
+    ) {
+        return false;
+    }
+
+    match (format, value) {
+        // in the binary format, a zero date has a length of zero, otherwise the length is
+        // followed by the year (2 bytes), the month and the day
+        (MySqlValueFormat::Binary, Some([0, ..])) => true,
+        (MySqlValueFormat::Binary, Some([_, _, _, month, day, ..])) => *month == 0 || *day == 0,
+
+        // in the text format, the date is formatted as `YYYY-MM-DD`
+        (MySqlValueFormat::Text, Some(text)) if text.len() >= 10 => {
+            &text[5..7] == b"00" || &text[8..10] == b"00"
+        }
+
+        _ => false,
+    }
+}
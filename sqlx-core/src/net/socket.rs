@@ -4,6 +4,7 @@ use std::io;
 use std::path::Path;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use sqlx_rt::{AsyncRead, AsyncWrite, TcpStream};
 
@@ -13,6 +14,12 @@ pub enum Socket {
 
     #[cfg(unix)]
     Unix(sqlx_rt::UnixStream),
+
+    #[cfg(feature = "fault-injection")]
+    Faulty(Box<crate::fault::FaultySocket>),
+
+    #[cfg(feature = "transcripts")]
+    Transcript(Box<crate::transcript::TranscriptSocket>),
 }
 
 impl Socket {
@@ -20,6 +27,33 @@ impl Socket {
         TcpStream::connect((host, port)).await.map(Socket::Tcp)
     }
 
+    /// Sets the idle time before TCP keepalive probes are sent, and the time after which
+    /// unacknowledged data closes the connection (only on Linux); ignored for other sockets.
+    pub fn set_tcp_timeouts(
+        &self,
+        keepalive: Option<Duration>,
+        user_timeout: Option<Duration>,
+    ) -> io::Result<()> {
+        let socket = match self {
+            Socket::Tcp(s) => socket2::SockRef::from(s),
+            _ => return Ok(()),
+        };
+
+        if let Some(time) = keepalive {
+            socket.set_tcp_keepalive(&socket2::TcpKeepalive::new().with_time(time))?;
+        }
+
+        #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+        if user_timeout.is_some() {
+            socket.set_tcp_user_timeout(user_timeout)?;
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+        let _ = user_timeout;
+
+        Ok(())
+    }
+
     #[cfg(unix)]
     pub async fn connect_uds(path: impl AsRef<Path>) -> io::Result<Self> {
         sqlx_rt::UnixStream::connect(path.as_ref())
@@ -38,14 +72,7 @@ impl Socket {
     pub async fn shutdown(&mut self) -> io::Result<()> {
         #[cfg(feature = "_rt-async-std")]
         {
-            use std::net::Shutdown;
-
-            match self {
-                Socket::Tcp(s) => s.shutdown(Shutdown::Both),
-
-                #[cfg(unix)]
-                Socket::Unix(s) => s.shutdown(Shutdown::Both),
-            }
+            self.shutdown_both()
         }
 
         #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
@@ -57,9 +84,36 @@ impl Socket {
 
                 #[cfg(unix)]
                 Socket::Unix(s) => s.shutdown().await,
+
+                #[cfg(feature = "fault-injection")]
+                Socket::Faulty(s) => s.shutdown().await,
+
+                #[cfg(feature = "transcripts")]
+                Socket::Transcript(s) => s.shutdown().await,
             }
         }
     }
+
+    #[cfg(feature = "_rt-async-std")]
+    fn shutdown_both(&self) -> io::Result<()> {
+        use std::net::Shutdown;
+
+        match self {
+            Socket::Tcp(s) => s.shutdown(Shutdown::Both),
+
+            #[cfg(unix)]
+            Socket::Unix(s) => s.shutdown(Shutdown::Both),
+
+            #[cfg(feature = "fault-injection")]
+            Socket::Faulty(s) => s.inner.shutdown_both(),
+
+            #[cfg(feature = "transcripts")]
+            Socket::Transcript(s) => match &s.inner {
+                Some(inner) => inner.shutdown_both(),
+                None => Ok(()),
+            },
+        }
+    }
 }
 
 impl AsyncRead for Socket {
@@ -73,6 +127,12 @@ impl AsyncRead for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_read(cx, buf),
+
+            #[cfg(feature = "fault-injection")]
+            Socket::Faulty(s) => Pin::new(&mut **s).poll_read(cx, buf),
+
+            #[cfg(feature = "transcripts")]
+            Socket::Transcript(s) => Pin::new(&mut **s).poll_read(cx, buf),
         }
     }
 }
@@ -88,6 +148,12 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_write(cx, buf),
+
+            #[cfg(feature = "fault-injection")]
+            Socket::Faulty(s) => Pin::new(&mut **s).poll_write(cx, buf),
+
+            #[cfg(feature = "transcripts")]
+            Socket::Transcript(s) => Pin::new(&mut **s).poll_write(cx, buf),
         }
     }
 
@@ -97,6 +163,12 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_flush(cx),
+
+            #[cfg(feature = "fault-injection")]
+            Socket::Faulty(s) => Pin::new(&mut **s).poll_flush(cx),
+
+            #[cfg(feature = "transcripts")]
+            Socket::Transcript(s) => Pin::new(&mut **s).poll_flush(cx),
         }
     }
 
@@ -107,6 +179,12 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_shutdown(cx),
+
+            #[cfg(feature = "fault-injection")]
+            Socket::Faulty(s) => Pin::new(&mut **s).poll_shutdown(cx),
+
+            #[cfg(feature = "transcripts")]
+            Socket::Transcript(s) => Pin::new(&mut **s).poll_shutdown(cx),
         }
     }
 
@@ -117,6 +195,12 @@ impl AsyncWrite for Socket {
 
             #[cfg(unix)]
             Socket::Unix(s) => Pin::new(s).poll_close(cx),
+
+            #[cfg(feature = "fault-injection")]
+            Socket::Faulty(s) => Pin::new(&mut **s).poll_close(cx),
+
+            #[cfg(feature = "transcripts")]
+            Socket::Transcript(s) => Pin::new(&mut **s).poll_close(cx),
         }
     }
 }
@@ -12,6 +12,8 @@ use crate::error::Error;
 use std::mem::replace;
 
 /// X.509 Certificate input, either a file path or a PEM encoded inline certificate(s).
+///
+/// It is also used for the private key of a client certificate, in PEM.
 #[derive(Clone, Debug)]
 pub enum CertificateInput {
     /// PEM encoded certificate(s)
@@ -80,11 +82,27 @@ where
         accept_invalid_certs: bool,
         accept_invalid_hostnames: bool,
         root_cert_path: Option<&CertificateInput>,
+        client_cert_path: Option<&CertificateInput>,
+        client_key_path: Option<&CertificateInput>,
     ) -> Result<(), Error> {
+        let client_identity = match (client_cert_path, client_key_path) {
+            (Some(cert), Some(key)) => Some((cert, key)),
+            (None, None) => None,
+
+            _ => {
+                return Err(Error::Configuration(
+                    "both a client certificate and its private key are required \
+                     for client certificate authentication"
+                        .into(),
+                ));
+            }
+        };
+
         let connector = configure_tls_connector(
             accept_invalid_certs,
             accept_invalid_hostnames,
             root_cert_path,
+            client_identity,
         )
         .await?;
 
@@ -110,6 +128,18 @@ where
 
         Ok(())
     }
+
+    /// Returns the `tls-server-end-point` channel binding data of the connection, defined in
+    /// [RFC 5929](https://tools.ietf.org/html/rfc5929#section-4), i.e. the hash of the
+    /// certificate of the server, if TLS is used and the signature algorithm of the
+    /// certificate is supported.
+    #[cfg(feature = "postgres")]
+    pub fn tls_server_end_point(&self) -> Option<Vec<u8>> {
+        match self {
+            MaybeTlsStream::Tls(stream) => tls_server_end_point(stream),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(feature = "_tls-native-tls")]
@@ -117,8 +147,9 @@ async fn configure_tls_connector(
     accept_invalid_certs: bool,
     accept_invalid_hostnames: bool,
     root_cert_path: Option<&CertificateInput>,
+    client_identity: Option<(&CertificateInput, &CertificateInput)>,
 ) -> Result<sqlx_rt::TlsConnector, Error> {
-    use sqlx_rt::native_tls::{Certificate, TlsConnector};
+    use sqlx_rt::native_tls::{Certificate, Identity, TlsConnector};
 
     let mut builder = TlsConnector::builder();
     builder
@@ -134,6 +165,13 @@ async fn configure_tls_connector(
         }
     }
 
+    if let Some((cert, key)) = client_identity {
+        // the key must be in PKCS #8, i.e. `BEGIN PRIVATE KEY`
+        let identity = Identity::from_pkcs8(&cert.data().await?, &key.data().await?)?;
+
+        builder.identity(identity);
+    }
+
     #[cfg(not(feature = "_rt-async-std"))]
     let connector = builder.build()?.into();
 
@@ -146,6 +184,20 @@ async fn configure_tls_connector(
 #[cfg(feature = "_tls-rustls")]
 use self::rustls::configure_tls_connector;
 
+#[cfg(all(feature = "postgres", feature = "_tls-native-tls"))]
+fn tls_server_end_point<S>(stream: &TlsStream<S>) -> Option<Vec<u8>>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    #[cfg(not(feature = "_rt-async-std"))]
+    let stream = stream.get_ref();
+
+    stream.tls_server_end_point().ok().flatten()
+}
+
+#[cfg(all(feature = "postgres", feature = "_tls-rustls"))]
+use self::rustls::tls_server_end_point;
+
 impl<S> AsyncRead for MaybeTlsStream<S>
 where
     S: Unpin + AsyncWrite + AsyncRead,
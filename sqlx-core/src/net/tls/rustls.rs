@@ -1,7 +1,8 @@
 use crate::net::CertificateInput;
+use rustls::internal::pemfile;
 use rustls::{
-    Certificate, ClientConfig, RootCertStore, ServerCertVerified, ServerCertVerifier, TLSError,
-    WebPKIVerifier,
+    Certificate, ClientConfig, PrivateKey, RootCertStore, ServerCertVerified, ServerCertVerifier,
+    TLSError, WebPKIVerifier,
 };
 use std::io::Cursor;
 use std::sync::Arc;
@@ -13,6 +14,7 @@ pub async fn configure_tls_connector(
     accept_invalid_certs: bool,
     accept_invalid_hostnames: bool,
     root_cert_path: Option<&CertificateInput>,
+    client_identity: Option<(&CertificateInput, &CertificateInput)>,
 ) -> Result<sqlx_rt::TlsConnector, Error> {
     let mut config = ClientConfig::new();
 
@@ -41,9 +43,33 @@ pub async fn configure_tls_connector(
         }
     }
 
+    if let Some((cert, key)) = client_identity {
+        let cert_chain = pemfile::certs(&mut Cursor::new(cert.data().await?))
+            .map_err(|_| Error::Tls(format!("Invalid certificate {}", cert).into()))?;
+
+        let key = private_key(key.data().await?)
+            .ok_or_else(|| Error::Tls("Invalid client private key".into()))?;
+
+        config
+            .set_single_client_cert(cert_chain, key)
+            .map_err(|err| Error::Tls(err.into()))?;
+    }
+
     Ok(Arc::new(config).into())
 }
 
+// the first private key of the PEM data, in PKCS #8 (`BEGIN PRIVATE KEY`) or
+// PKCS #1 (`BEGIN RSA PRIVATE KEY`)
+fn private_key(pem: Vec<u8>) -> Option<PrivateKey> {
+    let mut keys = pemfile::pkcs8_private_keys(&mut Cursor::new(&pem)).ok()?;
+
+    if keys.is_empty() {
+        keys = pemfile::rsa_private_keys(&mut Cursor::new(&pem)).ok()?;
+    }
+
+    keys.into_iter().next()
+}
+
 struct DummyTlsVerifier;
 
 impl ServerCertVerifier for DummyTlsVerifier {
@@ -77,3 +103,144 @@ impl ServerCertVerifier for NoHostnameTlsVerifier {
         }
     }
 }
+
+#[cfg(feature = "postgres")]
+pub fn tls_server_end_point<S>(stream: &sqlx_rt::TlsStream<S>) -> Option<Vec<u8>>
+where
+    S: sqlx_rt::AsyncRead + sqlx_rt::AsyncWrite + Unpin,
+{
+    use rustls::Session;
+
+    let certificates = stream.get_ref().1.get_peer_certificates()?;
+
+    certificate_hash(&certificates.first()?.0)
+}
+
+// the object identifiers of the signature algorithms, in DER
+#[cfg(feature = "postgres")]
+mod oid {
+    // 1.2.840.113549.1.1.{4, 5, 11, 12, 13, 14}
+    pub const MD5_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x04];
+    pub const SHA1_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x05];
+    pub const SHA256_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+    pub const SHA384_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c];
+    pub const SHA512_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d];
+    pub const SHA224_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0e];
+
+    // 1.2.840.10045.4.1 and 1.2.840.10045.4.3.{1, 2, 3, 4}
+    pub const ECDSA_WITH_SHA1: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x01];
+    pub const ECDSA_WITH_SHA224: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x01];
+    pub const ECDSA_WITH_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+    pub const ECDSA_WITH_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+    pub const ECDSA_WITH_SHA512: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04];
+}
+
+// the hash of the certificate with the hash function of its signature algorithm, except for
+// MD5 and SHA-1 which are replaced with SHA-256; the other algorithms, such as RSASSA-PSS and
+// Ed25519, are not supported by PostgreSQL either
+#[cfg(feature = "postgres")]
+fn certificate_hash(der: &[u8]) -> Option<Vec<u8>> {
+    use self::oid::*;
+    use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+
+    // Certificate ::= SEQUENCE {
+    //     tbsCertificate       TBSCertificate,
+    //     signatureAlgorithm   AlgorithmIdentifier,
+    //     signatureValue       BIT STRING }
+    //
+    // AlgorithmIdentifier ::= SEQUENCE {
+    //     algorithm            OBJECT IDENTIFIER,
+    //     parameters           ANY DEFINED BY algorithm OPTIONAL }
+    let (certificate, _) = read_der(der, 0x30)?;
+    let (_, certificate) = read_der(certificate, 0x30)?;
+    let (algorithm, _) = read_der(certificate, 0x30)?;
+    let (oid, _) = read_der(algorithm, 0x06)?;
+
+    let hash = match oid {
+        MD5_WITH_RSA | SHA1_WITH_RSA | SHA256_WITH_RSA | ECDSA_WITH_SHA1 | ECDSA_WITH_SHA256 => {
+            Sha256::digest(der).to_vec()
+        }
+
+        SHA224_WITH_RSA | ECDSA_WITH_SHA224 => Sha224::digest(der).to_vec(),
+        SHA384_WITH_RSA | ECDSA_WITH_SHA384 => Sha384::digest(der).to_vec(),
+        SHA512_WITH_RSA | ECDSA_WITH_SHA512 => Sha512::digest(der).to_vec(),
+
+        _ => return None,
+    };
+
+    Some(hash)
+}
+
+// reads a DER value with the given tag, returns its contents and the remaining input
+#[cfg(feature = "postgres")]
+fn read_der(input: &[u8], tag: u8) -> Option<(&[u8], &[u8])> {
+    let (&actual, input) = input.split_first()?;
+
+    if actual != tag {
+        return None;
+    }
+
+    let (&first, input) = input.split_first()?;
+
+    let (len, input) = if first < 0x80 {
+        (first as usize, input)
+    } else {
+        let n = (first & 0x7f) as usize;
+
+        if n == 0 || n > 4 || input.len() < n {
+            return None;
+        }
+
+        let len = input[..n]
+            .iter()
+            .fold(0_usize, |len, &b| (len << 8) | b as usize);
+
+        (len, &input[n..])
+    };
+
+    if input.len() < len {
+        return None;
+    }
+
+    Some(input.split_at(len))
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::certificate_hash;
+    use super::oid::*;
+    use sha2::{Digest, Sha256, Sha384};
+
+    // a certificate reduced to its structure: an empty `tbsCertificate`, the signature
+    // algorithm and an empty signature
+    fn certificate(oid: &[u8]) -> Vec<u8> {
+        let mut algorithm = vec![0x06, oid.len() as u8];
+        algorithm.extend_from_slice(oid);
+        algorithm.extend_from_slice(&[0x05, 0x00]);
+
+        let mut contents = vec![0x30, 0x00, 0x30, algorithm.len() as u8];
+        contents.extend_from_slice(&algorithm);
+        contents.extend_from_slice(&[0x03, 0x01, 0x00]);
+
+        let mut der = vec![0x30, contents.len() as u8];
+        der.extend_from_slice(&contents);
+        der
+    }
+
+    #[test]
+    fn it_hashes_certificates_with_their_signature_algorithm() {
+        let der = certificate(SHA256_WITH_RSA);
+        assert_eq!(certificate_hash(&der), Some(Sha256::digest(&der).to_vec()));
+
+        // SHA-1 is replaced with SHA-256
+        let der = certificate(SHA1_WITH_RSA);
+        assert_eq!(certificate_hash(&der), Some(Sha256::digest(&der).to_vec()));
+
+        let der = certificate(ECDSA_WITH_SHA384);
+        assert_eq!(certificate_hash(&der), Some(Sha384::digest(&der).to_vec()));
+
+        // Ed25519, 1.3.101.112
+        let der = certificate(&[0x2b, 0x65, 0x70]);
+        assert_eq!(certificate_hash(&der), None);
+    }
+}
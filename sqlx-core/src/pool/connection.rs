@@ -1,3 +1,4 @@
+use super::event::{CloseReason, PoolEvent};
 use super::inner::{DecrementSizeGuard, SharedPool};
 use crate::connection::Connection;
 use crate::database::Database;
@@ -89,6 +90,18 @@ impl<DB: Database> Drop for PoolConnection<DB> {
                     // we now consider the connection to be broken; just drop it to close
                     // trying to close gracefully might cause something weird to happen
                     drop(floating);
+
+                    pool.emit(PoolEvent::HealthCheckFailed {
+                        error: e.to_string(),
+                    });
+                    pool.closed(CloseReason::HealthCheckFailed);
+                } else if floating.raw.in_transaction_block() {
+                    // a transaction was left open (or failed) outside of a `Transaction`, e.g.
+                    // by executing `BEGIN` directly; it would leak into the next user
+                    log::warn!("connection was released to the pool inside of a transaction");
+
+                    let _ = floating.close().await;
+                    pool.closed(CloseReason::InTransaction);
                 } else {
                     // if the connection is still viable, release it to th epool
                     pool.release(floating);
@@ -165,6 +178,11 @@ impl<'s, DB: Database> Floating<'s, Live<DB>> {
         self.inner.raw
     }
 
+    pub async fn close(self) -> Result<(), Error> {
+        // `guard` is dropped as intended
+        self.inner.raw.close().await
+    }
+
     pub fn into_idle(self) -> Floating<'s, Idle<DB>> {
         Floating {
             inner: self.inner.into_idle(),
@@ -0,0 +1,87 @@
+use futures_channel::mpsc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+// events are dropped for a subscriber which lags behind by this many events
+const SUBSCRIBER_CAPACITY: usize = 128;
+
+/// An event in the life of a [`Pool`][crate::pool::Pool], see
+/// [`Pool::subscribe_events`][crate::pool::Pool::subscribe_events].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PoolEvent {
+    /// A new connection was opened.
+    ConnectionOpened {
+        /// How long it took to establish the connection.
+        latency: Duration,
+    },
+
+    /// A connection was closed by the pool, or found to be closed.
+    ConnectionClosed { reason: CloseReason },
+
+    /// A connection failed its health check, either before being handed out by the pool or
+    /// when returned to it. It is closed right after.
+    ///
+    /// This usually means the connection was closed by the server or the network.
+    HealthCheckFailed {
+        /// The error returned by the check.
+        error: String,
+    },
+
+    /// [`Pool::acquire`][crate::pool::Pool::acquire] timed out.
+    AcquireTimedOut {
+        /// How long the caller waited for a connection.
+        waited: Duration,
+    },
+}
+
+/// Why a connection was closed, see [`PoolEvent::ConnectionClosed`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CloseReason {
+    /// The connection reached [`PoolOptions::max_lifetime`][crate::pool::PoolOptions::max_lifetime].
+    MaxLifetime,
+
+    /// The connection was idle for longer than
+    /// [`PoolOptions::idle_timeout`][crate::pool::PoolOptions::idle_timeout].
+    IdleTimeout,
+
+    /// The connection failed its health check, see [`PoolEvent::HealthCheckFailed`].
+    HealthCheckFailed,
+
+    /// The connection was rejected by
+    /// [`PoolOptions::before_acquire`][crate::pool::PoolOptions::before_acquire] or
+    /// [`PoolOptions::after_release`][crate::pool::PoolOptions::after_release].
+    Rejected,
+
+    /// The connection was returned to the pool inside of a transaction.
+    InTransaction,
+
+    /// The pool was closed.
+    PoolClosed,
+}
+
+#[derive(Default)]
+pub(super) struct EventSenders(Mutex<Vec<mpsc::Sender<PoolEvent>>>);
+
+impl EventSenders {
+    pub(super) fn subscribe(&self) -> mpsc::Receiver<PoolEvent> {
+        let (sender, receiver) = mpsc::channel(SUBSCRIBER_CAPACITY);
+
+        self.0.lock().unwrap().push(sender);
+
+        receiver
+    }
+
+    pub(super) fn send(&self, event: PoolEvent) {
+        let mut senders = self.0.lock().unwrap();
+
+        for sender in senders.iter_mut() {
+            // a subscriber which is not keeping up misses the event
+            let _ = sender.try_send(event.clone());
+        }
+
+        // forget the subscribers whose stream was dropped
+        senders.retain(|sender| !sender.is_closed());
+    }
+}
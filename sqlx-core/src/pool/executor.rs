@@ -7,6 +7,7 @@ use crate::database::{Database, HasStatement};
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::pool::instrument::{QueryTimer, Span};
 use crate::pool::Pool;
 
 impl<'p, DB: Database> Executor<'p> for &'_ Pool<DB>
@@ -26,7 +27,9 @@ where
 
         Box::pin(try_stream! {
             let mut conn = pool.acquire().await?;
-            let mut s = conn.fetch_many(query);
+
+            let _timer = QueryTimer::start();
+            let mut s = Span::query().instrument_stream(conn.fetch_many(query));
 
             while let Some(v) = s.try_next().await? {
                 r#yield!(v);
@@ -45,7 +48,12 @@ where
     {
         let pool = self.clone();
 
-        Box::pin(async move { pool.acquire().await?.fetch_optional(query).await })
+        Box::pin(async move {
+            let mut conn = pool.acquire().await?;
+
+            let _timer = QueryTimer::start();
+            Span::query().instrument(conn.fetch_optional(query)).await
+        })
     }
 
     fn prepare_with<'e, 'q: 'e>(
@@ -55,7 +63,14 @@ where
     ) -> BoxFuture<'e, Result<<Self::Database as HasStatement<'q>>::Statement, Error>> {
         let pool = self.clone();
 
-        Box::pin(async move { pool.acquire().await?.prepare_with(sql, parameters).await })
+        Box::pin(async move {
+            let mut conn = pool.acquire().await?;
+
+            let _timer = QueryTimer::start();
+            Span::query()
+                .instrument(conn.prepare_with(sql, parameters))
+                .await
+        })
     }
 
     #[doc(hidden)]
@@ -65,7 +80,12 @@ where
     ) -> BoxFuture<'e, Result<Describe<Self::Database>, Error>> {
         let pool = self.clone();
 
-        Box::pin(async move { pool.acquire().await?.describe(sql).await })
+        Box::pin(async move {
+            let mut conn = pool.acquire().await?;
+
+            let _timer = QueryTimer::start();
+            Span::query().instrument(conn.describe(sql)).await
+        })
     }
 }
 
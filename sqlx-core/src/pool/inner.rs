@@ -1,4 +1,6 @@
 use super::connection::{Floating, Idle, Live};
+use super::event::{CloseReason, EventSenders, PoolEvent};
+use super::instrument::{self, Span};
 use crate::connection::ConnectOptions;
 use crate::connection::Connection;
 use crate::database::Database;
@@ -14,7 +16,7 @@ use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Weak};
 use std::task::Context;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 pub(crate) struct SharedPool<DB: Database> {
     pub(super) connect_options: <DB::Connection as Connection>::Options,
@@ -23,6 +25,7 @@ pub(crate) struct SharedPool<DB: Database> {
     pub(super) size: AtomicU32,
     is_closed: AtomicBool,
     pub(super) options: PoolOptions<DB>,
+    pub(super) events: EventSenders,
 }
 
 impl<DB: Database> SharedPool<DB> {
@@ -53,6 +56,8 @@ impl<DB: Database> SharedPool<DB> {
                 if let Err(e) = Floating::from_idle(idle, self).close().await {
                     log::warn!("error occurred while closing the pool connection: {}", e);
                 }
+
+                self.closed(CloseReason::PoolClosed);
             }
 
             // yield to avoid starving the executor
@@ -81,17 +86,23 @@ impl<DB: Database> SharedPool<DB> {
         if let Some(test) = &self.options.after_release {
             if !test(&mut floating.raw) {
                 // drop the connection and do not return to the pool
+                self.closed(CloseReason::Rejected);
                 return;
             }
         }
 
+        self.push_idle(floating);
+    }
+
+    // returns a connection to the idle queue and wakes a task waiting for one
+    pub(super) fn push_idle(&self, floating: Floating<'_, Live<DB>>) {
         let is_ok = self
             .idle_conns
             .push(floating.into_idle().into_leakable())
             .is_ok();
 
         if !is_ok {
-            panic!("BUG: connection queue overflow in release()");
+            panic!("BUG: connection queue overflow in push_idle()");
         }
 
         while let Some(waker) = self.waiters.pop() {
@@ -102,6 +113,14 @@ impl<DB: Database> SharedPool<DB> {
         }
     }
 
+    pub(super) fn emit(&self, event: PoolEvent) {
+        self.events.send(event);
+    }
+
+    pub(super) fn closed(&self, reason: CloseReason) {
+        self.emit(PoolEvent::ConnectionClosed { reason });
+    }
+
     /// Try to atomically increment the pool size for a new connection.
     ///
     /// Returns `None` if we are at max_connections or if the pool is closed.
@@ -168,6 +187,7 @@ impl<DB: Database> SharedPool<DB> {
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
+            events: EventSenders::default(),
         };
 
         let pool = Arc::new(pool);
@@ -179,7 +199,32 @@ impl<DB: Database> SharedPool<DB> {
 
     #[allow(clippy::needless_lifetimes)]
     pub(super) async fn acquire<'s>(&'s self) -> Result<Floating<'s, Live<DB>>, Error> {
+        let span = Span::acquire();
         let start = Instant::now();
+        let mut connecting = Duration::from_secs(0);
+
+        let result = span
+            .instrument(self.acquire_inner(start, &mut connecting))
+            .await;
+
+        // opening a connection is reported separately
+        instrument::record_acquire(&span, start.elapsed().saturating_sub(connecting));
+
+        if let Err(Error::PoolTimedOut) = result {
+            self.emit(PoolEvent::AcquireTimedOut {
+                waited: start.elapsed(),
+            });
+        }
+
+        result
+    }
+
+    // `connecting` is increased by the time spent in opening connections
+    async fn acquire_inner(
+        &self,
+        start: Instant,
+        connecting: &mut Duration,
+    ) -> Result<Floating<'_, Live<DB>>, Error> {
         let deadline = start + self.options.connect_timeout;
         let mut waited = !self.options.fair;
         let mut backoff = 0.01;
@@ -191,7 +236,7 @@ impl<DB: Database> SharedPool<DB> {
                 // Attempt to immediately acquire a connection. This will return Some
                 // if there is an idle connection in our channel.
                 if let Some(conn) = self.pop_idle() {
-                    if let Some(live) = check_conn(conn, &self.options).await {
+                    if let Some(live) = check_conn(conn, self).await {
                         return Ok(live);
                     }
                 }
@@ -199,7 +244,11 @@ impl<DB: Database> SharedPool<DB> {
 
             if let Some(guard) = self.try_increment_size() {
                 // pool has slots available; open a new connection
-                match self.connection(deadline, guard).await {
+                let connect_start = Instant::now();
+                let result = self.connection(deadline, guard).await;
+                *connecting += connect_start.elapsed();
+
+                match result {
                     Ok(Some(conn)) => return Ok(conn),
                     // [size] is internally decremented on _retry_ and _error_
                     Ok(None) => {
@@ -234,11 +283,22 @@ impl<DB: Database> SharedPool<DB> {
         }
 
         let timeout = super::deadline_as_timeout::<DB>(deadline)?;
+        let start = Instant::now();
+
+        let result = Span::connect()
+            .instrument(sqlx_rt::timeout(timeout, self.connect_options.connect()))
+            .await;
+
+        instrument::record_connect(start.elapsed());
 
         // result here is `Result<Result<C, Error>, TimeoutError>`
-        match sqlx_rt::timeout(timeout, self.connect_options.connect()).await {
+        match result {
             // successfully established connection
             Ok(Ok(mut raw)) => {
+                self.emit(PoolEvent::ConnectionOpened {
+                    latency: start.elapsed(),
+                });
+
                 if let Some(callback) = &self.options.after_connect {
                     callback(&mut raw).await?;
                 }
@@ -283,14 +343,17 @@ fn is_beyond_idle<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> b
 
 async fn check_conn<'s: 'p, 'p, DB: Database>(
     mut conn: Floating<'s, Idle<DB>>,
-    options: &'p PoolOptions<DB>,
+    pool: &'p SharedPool<DB>,
 ) -> Option<Floating<'s, Live<DB>>> {
+    let options = &pool.options;
+
     // If the connection we pulled has expired, close the connection and
     // immediately create a new connection
     if is_beyond_lifetime(&conn, options) {
         // we're closing the connection either way
         // close the connection but don't really care about the result
         let _ = conn.close().await;
+        pool.closed(CloseReason::MaxLifetime);
         return None;
     } else if options.test_before_acquire {
         // Check that the connection is still live
@@ -299,6 +362,10 @@ async fn check_conn<'s: 'p, 'p, DB: Database>(
             // either way we're fine to just discard the connection
             // the error itself here isn't necessarily unexpected so WARN is too strong
             log::info!("ping on idle connection returned error: {}", e);
+            pool.emit(PoolEvent::HealthCheckFailed {
+                error: e.to_string(),
+            });
+            pool.closed(CloseReason::HealthCheckFailed);
             // connection is broken so don't try to close nicely
             return None;
         }
@@ -306,11 +373,13 @@ async fn check_conn<'s: 'p, 'p, DB: Database>(
         match test(&mut conn.live.raw).await {
             Ok(false) => {
                 // connection was rejected by user-defined hook
+                pool.closed(CloseReason::Rejected);
                 return None;
             }
 
             Err(error) => {
                 log::info!("in `before_acquire`: {}", error);
+                pool.closed(CloseReason::Rejected);
                 return None;
             }
 
@@ -357,7 +426,14 @@ fn spawn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>) {
             }
 
             for conn in reap {
+                let reason = if is_beyond_lifetime(&conn, &pool.options) {
+                    CloseReason::MaxLifetime
+                } else {
+                    CloseReason::IdleTimeout
+                };
+
                 let _ = conn.close().await;
+                pool.closed(reason);
             }
 
             sleep(period).await;
@@ -0,0 +1,120 @@
+use futures_core::task::{Context, Poll};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+
+// The time spent in an operation of the pool is split between waiting for a free connection,
+// opening a new connection and executing the query, so that a saturated pool is not mistaken
+// for slow queries. Each part is reported as a `tracing` span and as a `metrics` histogram,
+// in seconds, when the features are enabled.
+
+const ACQUIRE_WAIT_HISTOGRAM: &str = "sqlx_pool_acquire_wait_seconds";
+const CONNECT_HISTOGRAM: &str = "sqlx_pool_connect_seconds";
+const QUERY_HISTOGRAM: &str = "sqlx_pool_query_seconds";
+
+/// A span of the pool, a no-op without the `tracing` feature.
+#[derive(Clone)]
+pub(super) struct Span {
+    #[cfg(feature = "tracing")]
+    inner: tracing::Span,
+}
+
+impl Span {
+    /// Covers [`Pool::acquire`][crate::pool::Pool::acquire], with the time spent in waiting for
+    /// a connection, i.e. not in opening one, recorded in `wait`.
+    pub(super) fn acquire() -> Self {
+        Span {
+            #[cfg(feature = "tracing")]
+            inner: tracing::debug_span!("sqlx.pool.acquire", wait = tracing::field::Empty),
+        }
+    }
+
+    /// Covers the opening of a new connection.
+    pub(super) fn connect() -> Self {
+        Span {
+            #[cfg(feature = "tracing")]
+            inner: tracing::debug_span!("sqlx.pool.connect"),
+        }
+    }
+
+    /// Covers the execution of a query on `&Pool`, once a connection is acquired.
+    pub(super) fn query() -> Self {
+        Span {
+            #[cfg(feature = "tracing")]
+            inner: tracing::debug_span!("sqlx.pool.query"),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn record_wait(&self, wait: Duration) {
+        #[cfg(feature = "tracing")]
+        self.inner.record("wait", &tracing::field::debug(wait));
+    }
+
+    pub(super) async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, self.inner.clone());
+
+        future.await
+    }
+
+    pub(super) fn instrument_stream<S: Stream + Unpin>(self, stream: S) -> InSpan<S> {
+        InSpan { stream, span: self }
+    }
+}
+
+/// A stream which is polled in a [`Span`].
+pub(super) struct InSpan<S> {
+    stream: S,
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    span: Span,
+}
+
+impl<S: Stream + Unpin> Stream for InSpan<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        #[cfg(feature = "tracing")]
+        let _entered = this.span.inner.enter();
+
+        Pin::new(&mut this.stream).poll_next(cx)
+    }
+}
+
+/// Measures the execution of a query on `&Pool`, which is reported when it is dropped, i.e.
+/// once the query is complete, failed or cancelled.
+pub(super) struct QueryTimer {
+    start: Instant,
+}
+
+impl QueryTimer {
+    pub(super) fn start() -> Self {
+        QueryTimer {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for QueryTimer {
+    fn drop(&mut self) {
+        record_histogram(QUERY_HISTOGRAM, self.start.elapsed());
+    }
+}
+
+pub(super) fn record_acquire(span: &Span, wait: Duration) {
+    span.record_wait(wait);
+    record_histogram(ACQUIRE_WAIT_HISTOGRAM, wait);
+}
+
+pub(super) fn record_connect(latency: Duration) {
+    record_histogram(CONNECT_HISTOGRAM, latency);
+}
+
+#[allow(unused_variables)]
+fn record_histogram(name: &'static str, duration: Duration) {
+    #[cfg(feature = "metrics")]
+    metrics::histogram!(name, duration.as_secs_f64());
+}
@@ -53,12 +53,25 @@
 //! A connection or transaction may also be manually acquired with
 //! [`Pool::acquire`] or
 //! [`Pool::begin`].
+//!
+//! # Instrumentation
+//!
+//! With the `tracing` feature, the pool reports the time it spends in waiting for a free
+//! connection (`sqlx.pool.acquire`, with the waiting time in its `wait` field), in opening a new
+//! connection (`sqlx.pool.connect`) and in executing a query on `&Pool` (`sqlx.pool.query`) as
+//! spans at the `DEBUG` level.
+//!
+//! With the `metrics` feature, the same durations are recorded in seconds in the
+//! `sqlx_pool_acquire_wait_seconds`, `sqlx_pool_connect_seconds` and `sqlx_pool_query_seconds`
+//! histograms. A wait time which grows with the load, while the query time does not, means the
+//! pool is saturated.
 
 use self::inner::SharedPool;
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
 use crate::transaction::Transaction;
+use futures_core::Stream;
 use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
@@ -71,10 +84,13 @@ mod executor;
 mod maybe;
 
 mod connection;
+mod event;
 mod inner;
+mod instrument;
 mod options;
 
 pub use self::connection::PoolConnection;
+pub use self::event::{CloseReason, PoolEvent};
 pub(crate) use self::maybe::MaybePoolConnection;
 pub use self::options::PoolOptions;
 
@@ -302,6 +318,26 @@ impl<DB: Database> Pool<DB> {
     pub fn num_idle(&self) -> usize {
         self.0.num_idle()
     }
+
+    /// Returns a stream of the [events][PoolEvent] of the pool from now on, e.g. to monitor the
+    /// rate at which connections are opened and closed.
+    ///
+    /// Each subscriber buffers a limited number of events; when the stream is not polled
+    /// quickly enough, new events are dropped until it catches up. The stream ends once the pool
+    /// is dropped.
+    ///
+    /// ```rust,ignore
+    /// let mut events = pool.subscribe_events();
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     if let PoolEvent::ConnectionClosed { reason: CloseReason::HealthCheckFailed } = event {
+    ///         log::warn!("a connection was closed by the server");
+    ///     }
+    /// }
+    /// ```
+    pub fn subscribe_events(&self) -> impl Stream<Item = PoolEvent> + Send + Unpin + 'static {
+        self.0.events.subscribe()
+    }
 }
 
 /// Returns a new [Pool] tied to the same shared connection pool.
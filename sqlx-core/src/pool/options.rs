@@ -237,14 +237,8 @@ async fn init_min_connections<DB: Database>(pool: &SharedPool<DB>) -> Result<(),
             // [connect] will raise an error when past deadline
             // [connect] returns None if its okay to retry
             if let Some(conn) = pool.connection(deadline, guard).await? {
-                let is_ok = pool
-                    .idle_conns
-                    .push(conn.into_idle().into_leakable())
-                    .is_ok();
-
-                if !is_ok {
-                    panic!("BUG: connection queue overflow in init_min_connections");
-                }
+                // an `acquire` may be waiting for this connection, e.g. with `connect_lazy`
+                pool.push_idle(conn);
             }
         }
     }
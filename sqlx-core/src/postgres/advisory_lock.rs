@@ -0,0 +1,181 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Deref, DerefMut};
+
+use crate::error::Error;
+use crate::postgres::message::Query;
+use crate::postgres::{PgArguments, PgConnection};
+use crate::query::query_with;
+use crate::query_scalar::query_scalar_with;
+
+// https://www.postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
+// https://www.postgresql.org/docs/current/functions-admin.html#FUNCTIONS-ADVISORY-LOCKS
+
+/// The key of an advisory lock, either a `BIGINT` or a pair of `INT`s.
+///
+/// The two forms are different keys, even when they have the same bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PgAdvisoryLockKey {
+    /// A key of the `pg_advisory_lock(bigint)` form.
+    BigInt(i64),
+
+    /// A key of the `pg_advisory_lock(int, int)` form.
+    IntPair(i32, i32),
+}
+
+impl PgAdvisoryLockKey {
+    fn placeholders(&self) -> &'static str {
+        match self {
+            PgAdvisoryLockKey::BigInt(_) => "$1",
+            PgAdvisoryLockKey::IntPair(..) => "$1, $2",
+        }
+    }
+
+    fn arguments(&self) -> PgArguments {
+        let mut arguments = PgArguments::default();
+
+        match *self {
+            PgAdvisoryLockKey::BigInt(key) => arguments.add(key),
+
+            PgAdvisoryLockKey::IntPair(key1, key2) => {
+                arguments.add(key1);
+                arguments.add(key2);
+            }
+        }
+
+        arguments
+    }
+}
+
+impl Display for PgAdvisoryLockKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PgAdvisoryLockKey::BigInt(key) => write!(f, "{}", key),
+            PgAdvisoryLockKey::IntPair(key1, key2) => write!(f, "{}, {}", key1, key2),
+        }
+    }
+}
+
+impl From<i64> for PgAdvisoryLockKey {
+    fn from(key: i64) -> Self {
+        PgAdvisoryLockKey::BigInt(key)
+    }
+}
+
+impl From<(i32, i32)> for PgAdvisoryLockKey {
+    fn from((key1, key2): (i32, i32)) -> Self {
+        PgAdvisoryLockKey::IntPair(key1, key2)
+    }
+}
+
+/// A session-level advisory lock, held by a connection until it is released or dropped.
+///
+/// Advisory locks have a meaning defined by the application, e.g. to make sure that only one
+/// of its processes runs a job at a time. They are not released when a transaction is rolled
+/// back, and the same session can hold the same lock more than once.
+///
+/// The connection can still be used while it holds the lock, through `Deref` and `DerefMut`.
+/// If the lock is dropped without calling [`release`](Self::release), it is released with the
+/// next use of the connection.
+///
+/// ```rust,ignore
+/// let mut lock = PgAdvisoryLock::acquire(&mut conn, 42).await?;
+///
+/// sqlx::query("UPDATE jobs SET ...").execute(&mut *lock).await?;
+///
+/// lock.release().await?;
+/// ```
+pub struct PgAdvisoryLock<'c> {
+    conn: &'c mut PgConnection,
+    key: PgAdvisoryLockKey,
+    released: bool,
+}
+
+impl<'c> PgAdvisoryLock<'c> {
+    /// Acquires the lock, waiting until the other sessions holding it release it.
+    pub async fn acquire(
+        conn: &'c mut PgConnection,
+        key: impl Into<PgAdvisoryLockKey>,
+    ) -> Result<PgAdvisoryLock<'c>, Error> {
+        let key = key.into();
+
+        let sql = format!("SELECT pg_advisory_lock({})", key.placeholders());
+        query_with(&sql, key.arguments())
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(Self {
+            conn,
+            key,
+            released: false,
+        })
+    }
+
+    /// Acquires the lock if no other session holds it, or returns `None` without waiting.
+    pub async fn try_acquire(
+        conn: &'c mut PgConnection,
+        key: impl Into<PgAdvisoryLockKey>,
+    ) -> Result<Option<PgAdvisoryLock<'c>>, Error> {
+        let key = key.into();
+
+        let sql = format!("SELECT pg_try_advisory_lock({})", key.placeholders());
+        let locked: bool = query_scalar_with(&sql, key.arguments())
+            .fetch_one(&mut *conn)
+            .await?;
+
+        Ok(if locked {
+            Some(Self {
+                conn,
+                key,
+                released: false,
+            })
+        } else {
+            None
+        })
+    }
+
+    /// Gets the key of the lock.
+    pub fn key(&self) -> PgAdvisoryLockKey {
+        self.key
+    }
+
+    /// Explicitly releases the lock.
+    pub async fn release(mut self) -> Result<(), Error> {
+        let sql = format!("SELECT pg_advisory_unlock({})", self.key.placeholders());
+        let _: bool = query_scalar_with(&sql, self.key.arguments())
+            .fetch_one(&mut *self.conn)
+            .await?;
+
+        self.released = true;
+
+        Ok(())
+    }
+}
+
+impl Deref for PgAdvisoryLock<'_> {
+    type Target = PgConnection;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.conn
+    }
+}
+
+impl DerefMut for PgAdvisoryLock<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn
+    }
+}
+
+impl Drop for PgAdvisoryLock<'_> {
+    fn drop(&mut self) {
+        if !self.released {
+            // sent with the next message to the server, like the rollback of a dropped
+            // transaction
+            self.conn.pending_ready_for_query_count += 1;
+            self.conn
+                .stream
+                .write(Query(&format!("SELECT pg_advisory_unlock({})", self.key)));
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 use crate::arguments::Arguments;
 use crate::encode::{Encode, IsNull};
-use crate::error::Error;
+use crate::error::{BoxDynError, Error};
 use crate::ext::ustr::UStr;
-use crate::postgres::{PgConnection, PgTypeInfo, Postgres};
+use crate::postgres::{PgConnection, PgTypeInfo, PgValueFormat, Postgres};
 use crate::types::Type;
 
 // TODO: buf.patch(|| ...) is a poor name, can we think of a better name? Maybe `buf.lazy(||)` ?
@@ -33,7 +34,7 @@ pub struct PgArgumentBuffer {
     patches: Vec<(
         usize, // offset
         usize, // argument index
-        Box<dyn Fn(&mut [u8], &PgTypeInfo) + 'static + Send + Sync>,
+        Arc<dyn Fn(&mut [u8], &PgTypeInfo) + 'static + Send + Sync>,
     )>,
 
     // Whenever an `Encode` impl encounters a `PgTypeInfo` object that does not have an OID
@@ -44,34 +45,130 @@ pub struct PgArgumentBuffer {
     // function and can just ask postgres.
     //
     type_holes: Vec<(usize, UStr)>, // Vec<{ offset, type_name }>
+
+    // Whether the value being encoded is in the text format. This is set before each
+    // top-level argument and may be set by an `Encode` impl (see `PgRecordEncoder::new_text`).
+    pub(crate) text_format: bool,
+
+    // How many `encode` calls deep we are; `1` while encoding a top-level argument.
+    depth: usize,
+
+    // The first error raised by an `Encode` impl. `Encode` is infallible so the error is
+    // deferred and returned when the arguments are about to be sent.
+    error: Option<BoxDynError>,
 }
 
 /// Implementation of [`Arguments`] for PostgreSQL.
-#[derive(Default)]
+///
+/// The arguments are encoded as they are added; they can be cloned to be sent with several
+/// queries, and the arguments of a part of a query can be appended to the others.
+#[derive(Default, Clone)]
 pub struct PgArguments {
     // Types of each bind parameter
     pub(crate) types: Vec<PgTypeInfo>,
 
     // Buffer of encoded bind parameters
     pub(crate) buffer: PgArgumentBuffer,
+
+    // Wire format of each bind parameter
+    pub(crate) formats: Vec<PgValueFormat>,
 }
 
 impl PgArguments {
     pub(crate) fn add<'q, T>(&mut self, value: T)
     where
         T: Encode<'q, Postgres> + Type<Postgres>,
+    {
+        let type_info = value.produces().unwrap_or_else(T::type_info);
+
+        self.push(value, type_info, false);
+    }
+
+    // Adds a value declared with the given type instead of its own
+    pub(crate) fn add_with_type<'q, T>(&mut self, value: T, type_info: PgTypeInfo)
+    where
+        T: Encode<'q, Postgres> + Type<Postgres>,
+    {
+        // strings are sent in the text format so that the server parses them with the input
+        // function of the declared type, the same as a string literal cast to that type
+        let is_string =
+            <str as Type<Postgres>>::compatible(&value.produces().unwrap_or_else(T::type_info));
+
+        self.push(value, type_info, is_string);
+    }
+
+    pub(crate) fn add_null(&mut self, type_info: PgTypeInfo) {
+        self.push(None::<i32>, type_info, false);
+    }
+
+    /// Adds the arguments of `other` to the end of these arguments, e.g. for the bind
+    /// parameters of a condition built separately from the rest of the query.
+    ///
+    /// The placeholders of the appended arguments are numbered after these ones, e.g. the
+    /// first one is `$3` after 2 arguments.
+    pub fn append(&mut self, other: PgArguments) {
+        let offset = self.buffer.len();
+        let count = self.buffer.count;
+
+        let PgArgumentBuffer {
+            buffer,
+            count: other_count,
+            patches,
+            type_holes,
+            error,
+            ..
+        } = other.buffer;
+
+        self.types.extend(other.types);
+        self.formats.extend(other.formats);
+
+        self.buffer.buffer.extend_from_slice(&buffer);
+        self.buffer.count += other_count;
+
+        self.buffer.patches.extend(
+            patches
+                .into_iter()
+                .map(|(at, index, callback)| (at + offset, index + count, callback)),
+        );
+
+        self.buffer.type_holes.extend(
+            type_holes
+                .into_iter()
+                .map(|(at, type_name)| (at + offset, type_name)),
+        );
+
+        if let Some(err) = error {
+            self.buffer.encode_error(err);
+        }
+    }
+
+    fn push<'q, T>(&mut self, value: T, type_info: PgTypeInfo, text_format: bool)
+    where
+        T: Encode<'q, Postgres>,
     {
         // remember the type information for this value
-        self.types
-            .push(value.produces().unwrap_or_else(T::type_info));
+        self.types.push(type_info);
 
         // encode the value into our buffer
+        self.buffer.text_format = text_format;
         self.buffer.encode(value);
 
+        // remember the format the value was encoded in
+        self.formats.push(if self.buffer.text_format {
+            PgValueFormat::Text
+        } else {
+            PgValueFormat::Binary
+        });
+
         // increment the number of arguments we are tracking
         self.buffer.count += 1;
     }
 
+    // Returns the first error raised while encoding the arguments, if any
+    pub(crate) fn take_error(&mut self) -> Option<BoxDynError> {
+        self.buffer.error.take()
+    }
+
     // Apply patches
     // This should only go out and ask postgres if we have not seen the type name yet
     pub(crate) async fn apply_patches(
@@ -79,26 +176,72 @@ impl PgArguments {
         conn: &mut PgConnection,
         parameters: &[PgTypeInfo],
     ) -> Result<(), Error> {
+        self.apply_callback_patches(parameters);
+
+        let PgArgumentBuffer {
+            ref type_holes,
+            ref mut buffer,
+            ..
+        } = self.buffer;
+
+        for (offset, name) in type_holes {
+            let oid = conn.fetch_type_id_by_name(&*name).await?;
+            buffer[*offset..(*offset + 4)].copy_from_slice(&oid.to_be_bytes());
+        }
+
+        Ok(())
+    }
+
+    // Apply patches with the type OIDs the connection already knows, for when it cannot ask
+    // postgres, e.g. during a COPY
+    pub(crate) fn apply_cached_patches(
+        &mut self,
+        conn: &PgConnection,
+        parameters: &[PgTypeInfo],
+    ) -> Result<(), Error> {
+        self.apply_callback_patches(parameters);
+
         let PgArgumentBuffer {
-            ref patches,
             ref type_holes,
             ref mut buffer,
             ..
         } = self.buffer;
 
+        for (offset, name) in type_holes {
+            let oid = conn
+                .cached_type_id_by_name(&*name)
+                .ok_or_else(|| Error::TypeNotFound {
+                    type_name: name.to_string(),
+                })?;
+
+            buffer[*offset..(*offset + 4)].copy_from_slice(&oid.to_be_bytes());
+        }
+
+        Ok(())
+    }
+
+    fn apply_callback_patches(&mut self, parameters: &[PgTypeInfo]) {
+        let PgArgumentBuffer {
+            ref patches,
+            ref mut buffer,
+            ..
+        } = self.buffer;
+
         for (offset, ty, callback) in patches {
             let buf = &mut buffer[*offset..];
             let ty = &parameters[*ty];
 
             callback(buf, ty);
         }
+    }
 
-        for (offset, name) in type_holes {
-            let oid = conn.fetch_type_id_by_name(&*name).await?;
-            buffer[*offset..(*offset + 4)].copy_from_slice(&oid.to_be_bytes());
+    // Parameter format codes for `Bind`; a single code applies to every parameter
+    pub(crate) fn formats(&self) -> &[PgValueFormat] {
+        if self.formats.iter().all(|&f| f == PgValueFormat::Binary) {
+            &[PgValueFormat::Binary]
+        } else {
+            &self.formats
         }
-
-        Ok(())
     }
 }
 
@@ -107,6 +250,7 @@ impl<'q> Arguments<'q> for PgArguments {
 
     fn reserve(&mut self, additional: usize, size: usize) {
         self.types.reserve(additional);
+        self.formats.reserve(additional);
         self.buffer.reserve(size);
     }
 
@@ -118,6 +262,21 @@ impl<'q> Arguments<'q> for PgArguments {
     }
 }
 
+impl Clone for PgArgumentBuffer {
+    fn clone(&self) -> Self {
+        PgArgumentBuffer {
+            buffer: self.buffer.clone(),
+            count: self.count,
+            patches: self.patches.clone(),
+            type_holes: self.type_holes.clone(),
+            text_format: self.text_format,
+            depth: self.depth,
+            // errors cannot be cloned, only their message is kept
+            error: self.error.as_ref().map(|err| err.to_string().into()),
+        }
+    }
+}
+
 impl PgArgumentBuffer {
     pub(crate) fn encode<'q, T>(&mut self, value: T)
     where
@@ -128,7 +287,11 @@ impl PgArgumentBuffer {
         self.extend(&[0; 4]);
 
         // encode the value into our buffer
-        let len = if let IsNull::No = value.encode(self) {
+        self.depth += 1;
+        let is_null = value.encode(self);
+        self.depth -= 1;
+
+        let len = if let IsNull::No = is_null {
             (self.len() - offset - 4) as i32
         } else {
             // Write a -1 to indicate NULL
@@ -150,7 +313,33 @@ impl PgArgumentBuffer {
         let offset = self.len();
         let index = self.count;
 
-        self.patches.push((offset, index, Box::new(callback)));
+        self.patches.push((offset, index, Arc::new(callback)));
+    }
+
+    // Records an error for a value that cannot be encoded; the query will fail with
+    // `Error::Encode` instead of being sent. Only the first error is kept.
+    pub(crate) fn encode_error(&mut self, err: impl Into<BoxDynError>) {
+        if self.error.is_none() {
+            self.error = Some(err.into());
+        }
+    }
+
+    // Moves an error recorded in a scratch buffer into this buffer
+    pub(crate) fn adopt_error(&mut self, other: &mut PgArgumentBuffer) {
+        if let Some(err) = other.error.take() {
+            self.encode_error(err);
+        }
+    }
+
+    // Returns `true` if some of the value can only be written when the query is sent
+    pub(crate) fn has_patches(&self) -> bool {
+        !self.patches.is_empty() || !self.type_holes.is_empty()
+    }
+
+    // Returns `true` while encoding a top-level argument (as opposed to an element of an
+    // array or a field of a record)
+    pub(crate) fn is_top_level(&self) -> bool {
+        self.depth <= 1
     }
 
     // Extends the inner buffer by enough space to have an OID
@@ -0,0 +1,119 @@
+use futures_util::TryStreamExt;
+use sha2::digest::Digest;
+use sha2::Sha256;
+
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::postgres::{PgRow, PgValueFormat, Postgres};
+use crate::type_info::TypeInfo;
+
+/// A checksum of the rows returned by a query, to verify that two databases hold the same data,
+/// e.g. a primary and a replica or the source and the target of a migration.
+///
+/// Each row is hashed (SHA-256) from the values as sent by the server along with the name of
+/// their type, so that `1::int4` and `1::int8` differ. The checksum of the result set hashes
+/// the checksums of the rows in order, so the query should have an `ORDER BY` clause.
+///
+/// ```rust,ignore
+/// let query = "SELECT * FROM accounts ORDER BY id";
+///
+/// let primary = PgChecksum::of(&primary_pool, query).await?;
+/// let replica = PgChecksum::of(&replica_pool, query).await?;
+///
+/// assert_eq!(primary, replica);
+/// ```
+///
+/// Values are hashed in the format they were received in, so checksums of prepared queries
+/// (binary format) cannot be compared to checksums of unprepared ones (text format).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PgChecksum {
+    /// The number of rows.
+    pub rows: u64,
+
+    /// The SHA-256 digest of the checksums of the rows.
+    pub digest: [u8; 32],
+}
+
+impl PgChecksum {
+    /// Executes the query and computes the checksum of the rows it returns, as they are
+    /// streamed.
+    pub async fn of<'e, 'q: 'e, E, X>(executor: X, query: E) -> Result<PgChecksum, Error>
+    where
+        E: 'q + Execute<'q, Postgres>,
+        X: Executor<'e, Database = Postgres>,
+    {
+        let mut hasher = PgChecksumHasher::new();
+        let mut rows = executor.fetch(query);
+
+        while let Some(row) = rows.try_next().await? {
+            hasher.update(&row);
+        }
+
+        Ok(hasher.finish())
+    }
+}
+
+/// Computes a [`PgChecksum`] from rows fetched one by one, and the checksum of each row.
+#[derive(Debug, Clone, Default)]
+pub struct PgChecksumHasher {
+    hasher: Sha256,
+    rows: u64,
+}
+
+impl PgChecksumHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a row to the result set, returning its checksum.
+    pub fn update(&mut self, row: &PgRow) -> [u8; 32] {
+        let checksum = row_checksum(row);
+
+        self.hasher.update(&checksum);
+        self.rows += 1;
+
+        checksum
+    }
+
+    /// Returns the number of rows added so far.
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+
+    pub fn finish(self) -> PgChecksum {
+        PgChecksum {
+            rows: self.rows,
+            digest: self.hasher.finalize().into(),
+        }
+    }
+}
+
+fn row_checksum(row: &PgRow) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+
+    hasher.update(&(row.metadata.columns.len() as u32).to_be_bytes());
+
+    hasher.update(&[match row.format {
+        PgValueFormat::Text => 0,
+        PgValueFormat::Binary => 1,
+    }]);
+
+    for (index, column) in row.metadata.columns.iter().enumerate() {
+        // every field is prefixed with its length so that their boundaries are hashed as well
+        let type_name = column.type_info.name();
+
+        hasher.update(&(type_name.len() as u32).to_be_bytes());
+        hasher.update(type_name.as_bytes());
+
+        match row.data.get(index) {
+            Some(value) => {
+                hasher.update(&(value.len() as i32).to_be_bytes());
+                hasher.update(value);
+            }
+
+            None => hasher.update(&(-1_i32).to_be_bytes()),
+        }
+    }
+
+    hasher.finalize().into()
+}
@@ -12,6 +12,19 @@ pub struct PgColumn {
     pub(crate) relation_id: Option<i32>,
     #[cfg_attr(feature = "offline", serde(skip))]
     pub(crate) relation_attribute_no: Option<i16>,
+    #[cfg_attr(feature = "offline", serde(skip))]
+    pub(crate) relation_name: Option<UStr>,
+}
+
+impl PgColumn {
+    /// Gets the name of the table of the column, if the column is a column of a table and not
+    /// an expression.
+    ///
+    /// It is not known for the columns of the queries without bind parameters, which are sent
+    /// with the simple query protocol, unless the table was already seen by the connection.
+    pub fn table_name(&self) -> Option<&str> {
+        self.relation_name.as_deref()
+    }
 }
 
 impl crate::column::private_column::Sealed for PgColumn {}
@@ -27,6 +40,10 @@ impl Column for PgColumn {
         &*self.name
     }
 
+    fn name_shared(&self) -> UStr {
+        self.name.clone()
+    }
+
     fn type_info(&self) -> &PgTypeInfo {
         &self.type_info
     }
@@ -0,0 +1,86 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::postgres::connection::{stream::PgStream, tls};
+use crate::postgres::message::CancelRequest;
+use crate::postgres::{PgConnectOptions, PgConnection};
+
+// https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.10
+
+impl PgConnection {
+    /// Returns a [`PgCancelToken`] to cancel the query running on this connection from
+    /// another task.
+    ///
+    /// ```rust,ignore
+    /// let token = conn.cancel_token();
+    ///
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_secs(5)).await;
+    ///     let _ = token.cancel().await;
+    /// });
+    ///
+    /// // fails with `query_canceled` (57014) if it takes more than 5 seconds
+    /// let report = sqlx::query("SELECT build_report()").execute(&mut conn).await;
+    /// ```
+    pub fn cancel_token(&self) -> PgCancelToken {
+        PgCancelToken {
+            options: Arc::clone(&self.options),
+            process_id: self.process_id,
+            secret_key: self.secret_key,
+        }
+    }
+}
+
+/// A handle to cancel the query running on a [`PgConnection`], returned by
+/// [`PgConnection::cancel_token`].
+///
+/// The token can be used any number of times, for as long as the connection is open.
+#[derive(Clone)]
+pub struct PgCancelToken {
+    options: Arc<PgConnectOptions>,
+    process_id: u32,
+    secret_key: u32,
+}
+
+impl PgCancelToken {
+    /// Asks the server to cancel the query running on the connection, if any.
+    ///
+    /// The request is sent on a new connection, opened with the options of the connection.
+    /// The server does not report whether a query was canceled: if there is one, it fails
+    /// with a `query_canceled` (`57014`) error. The cancellation may also arrive too late
+    /// and have no effect, or interrupt a later query if the connection was used in the
+    /// meantime.
+    pub async fn cancel(&self) -> Result<(), Error> {
+        let mut stream = PgStream::connect(&self.options).await?;
+
+        tls::maybe_upgrade(&mut stream, &self.options).await?;
+
+        stream
+            .send(CancelRequest {
+                process_id: self.process_id,
+                secret_key: self.secret_key,
+            })
+            .await?;
+
+        // the server closes the connection, without a response, once the request is processed
+        let _ = stream.read::<Bytes>(1).await;
+
+        Ok(())
+    }
+
+    /// The process ID of the backend of the connection.
+    pub fn process_id(&self) -> u32 {
+        self.process_id
+    }
+}
+
+impl Debug for PgCancelToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgCancelToken")
+            .field("process_id", &self.process_id)
+            .finish()
+    }
+}
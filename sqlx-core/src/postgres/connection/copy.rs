@@ -0,0 +1,237 @@
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+
+use crate::error::{Error, Limit};
+use crate::postgres::connection::executor::MAX_MESSAGE_SIZE;
+use crate::postgres::message::{
+    CommandComplete, CopyData, CopyDone, CopyFail, MessageFormat, Query,
+};
+use crate::postgres::PgConnection;
+
+// https://www.postgresql.org/docs/current/sql-copy.html
+// https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-COPY
+
+// the data sent to the server is flushed once this many bytes are buffered
+const COPY_IN_BUFFER_SIZE: usize = 8192;
+
+impl PgConnection {
+    /// Starts a `COPY ... FROM STDIN` and returns a [`PgCopyIn`] to send the data to the server.
+    ///
+    /// The data is sent as-is, in the format given in the `COPY` statement (e.g. the default
+    /// text format, `CSV` or `BINARY`), and is only checked by the server. The `COPY` must be
+    /// completed with [`PgCopyIn::finish`] or [`PgCopyIn::abort`].
+    ///
+    /// ```rust,ignore
+    /// let mut copy = conn
+    ///     .copy_in_raw("COPY users (id, name) FROM STDIN WITH (FORMAT CSV)")
+    ///     .await?;
+    ///
+    /// copy.send("1,alice\n").await?;
+    /// copy.send("2,bob\n").await?;
+    ///
+    /// let rows = copy.finish().await?;
+    /// ```
+    pub async fn copy_in_raw(&mut self, statement: &str) -> Result<PgCopyIn<'_>, Error> {
+        self.start_copy(statement, MessageFormat::CopyInResponse)
+            .await?;
+
+        Ok(PgCopyIn::new(self))
+    }
+
+    /// Starts a `COPY ... TO STDOUT` and returns the data sent by the server, as a stream
+    /// of chunks.
+    ///
+    /// The data is in the format given in the `COPY` statement. The chunks are not aligned
+    /// to rows; with the text and `CSV` formats, the server currently sends one row per chunk.
+    ///
+    /// ```rust,ignore
+    /// let mut stream = conn
+    ///     .copy_out_raw("COPY users TO STDOUT WITH (FORMAT CSV)")
+    ///     .await?;
+    ///
+    /// while let Some(chunk) = stream.try_next().await? {
+    ///     file.write_all(&chunk).await?;
+    /// }
+    /// ```
+    pub async fn copy_out_raw<'c>(
+        &'c mut self,
+        statement: &str,
+    ) -> Result<BoxStream<'c, Result<Bytes, Error>>, Error> {
+        self.start_copy(statement, MessageFormat::CopyOutResponse)
+            .await?;
+
+        Ok(Box::pin(try_stream! {
+            loop {
+                let message = self.stream.recv().await?;
+
+                match message.format {
+                    MessageFormat::CopyData => {
+                        let data: CopyData<Bytes> = message.decode()?;
+
+                        r#yield!(data.0);
+                    }
+
+                    MessageFormat::CopyDone | MessageFormat::CommandComplete => {}
+
+                    MessageFormat::ReadyForQuery => {
+                        self.handle_ready_for_query(message)?;
+                        break;
+                    }
+
+                    _ => {
+                        return Err(err_protocol!(
+                            "copy_out_raw: unexpected message: {:?}",
+                            message.format
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        }))
+    }
+
+    pub(crate) async fn start_copy(
+        &mut self,
+        statement: &str,
+        format: MessageFormat,
+    ) -> Result<(), Error> {
+        Error::check_limit(Limit::MessageSize, MAX_MESSAGE_SIZE, statement.len())?;
+
+        self.wait_until_ready().await?;
+
+        // Query will trigger a ReadyForQuery, once the COPY is complete
+        self.stream.write(Query(statement));
+        self.pending_ready_for_query_count += 1;
+
+        self.stream.flush().await?;
+
+        let message = self.stream.recv().await?;
+
+        if message.format == format {
+            return Ok(());
+        }
+
+        if message.format == MessageFormat::CopyInResponse {
+            // the server waits for the data of a COPY FROM STDIN before completing the query,
+            // and then reports the failure of the COPY
+            self.stream
+                .send(CopyFail("expected a COPY TO STDOUT"))
+                .await?;
+
+            if let Ok(message) = self.stream.recv().await {
+                return Err(err_protocol!(
+                    "expecting ErrorResponse but received {:?}",
+                    message.format
+                ));
+            }
+
+            self.recv_ready_for_query().await?;
+        }
+
+        Err(err_protocol!(
+            "expected the statement to start a COPY, but received {:?} instead of {:?}",
+            message.format,
+            format
+        ))
+    }
+}
+
+/// A `COPY ... FROM STDIN` in progress, started with [`PgConnection::copy_in_raw`].
+///
+/// If it is dropped before [`finish`](PgCopyIn::finish) or [`abort`](PgCopyIn::abort) is
+/// called, the `COPY` is aborted, and the error of the server is returned by the next use of the
+/// connection.
+pub struct PgCopyIn<'c> {
+    conn: Option<&'c mut PgConnection>,
+}
+
+impl<'c> PgCopyIn<'c> {
+    pub(super) fn new(conn: &'c mut PgConnection) -> Self {
+        PgCopyIn { conn: Some(conn) }
+    }
+
+    pub(super) fn conn(&self) -> &PgConnection {
+        self.conn
+            .as_deref()
+            .expect("BUG: PgCopyIn used after completion")
+    }
+
+    /// Sends a chunk of data to the server.
+    ///
+    /// The chunks do not need to be aligned to rows. The data is buffered, and errors in the
+    /// data are only reported by [`finish`](PgCopyIn::finish).
+    pub async fn send(&mut self, data: impl AsRef<[u8]>) -> Result<(), Error> {
+        let data = data.as_ref();
+
+        Error::check_limit(Limit::MessageSize, MAX_MESSAGE_SIZE, data.len())?;
+
+        let conn = self
+            .conn
+            .as_mut()
+            .expect("BUG: PgCopyIn used after completion");
+
+        conn.stream.write(CopyData(data));
+
+        if conn.stream.wbuf.len() >= COPY_IN_BUFFER_SIZE {
+            conn.stream.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Completes the `COPY` and returns the number of rows copied.
+    pub async fn finish(mut self) -> Result<u64, Error> {
+        let conn = self
+            .conn
+            .take()
+            .expect("BUG: PgCopyIn used after completion");
+
+        conn.stream.send(CopyDone).await?;
+
+        let cc: CommandComplete = conn
+            .stream
+            .recv_expect(MessageFormat::CommandComplete)
+            .await?;
+
+        conn.recv_ready_for_query().await?;
+
+        Ok(cc.rows_affected())
+    }
+
+    /// Aborts the `COPY`, with a message for the error reported by the server. No row is copied.
+    pub async fn abort(mut self, message: &str) -> Result<(), Error> {
+        let conn = self
+            .conn
+            .take()
+            .expect("BUG: PgCopyIn used after completion");
+
+        conn.stream.send(CopyFail(message)).await?;
+
+        match conn.stream.recv().await {
+            // the server reports the abort as `query_canceled`
+            Err(Error::Database(ref error)) if error.code().as_deref() == Some("57014") => {}
+
+            Err(error) => return Err(error),
+
+            Ok(message) => {
+                return Err(err_protocol!(
+                    "abort: expecting ErrorResponse but received {:?}",
+                    message.format
+                ));
+            }
+        }
+
+        conn.recv_ready_for_query().await
+    }
+}
+
+impl Drop for PgCopyIn<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            // sent with the next message to the server
+            conn.stream
+                .write(CopyFail("PgCopyIn dropped before finish() or abort()"));
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use std::mem;
+
+use crate::encode::Encode;
+use crate::error::Error;
+use crate::postgres::connection::copy::PgCopyIn;
+use crate::postgres::message::MessageFormat;
+use crate::postgres::{PgArguments, PgConnection, PgValueFormat, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.4
+
+// signature, flags and length of the header extension
+const HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+
+// a field count of -1
+const TRAILER: &[u8] = b"\xff\xff";
+
+impl PgConnection {
+    /// Prepares a `COPY ... FROM STDIN WITH (FORMAT BINARY)` and returns a [`PgCopyInEncoder`]
+    /// to write rows of Rust values, encoded with their [`Encode`] implementations.
+    ///
+    /// The `COPY` is only started when the first row is written, so that the OIDs of the
+    /// user-defined types in the row can be resolved first (see [`PgCopyInEncoder::write_row`]).
+    ///
+    /// ```rust,ignore
+    /// let mut encoder =
+    ///     conn.copy_in_binary("COPY users (id, name) FROM STDIN WITH (FORMAT BINARY)");
+    ///
+    /// for user in users {
+    ///     encoder.write_row((user.id, &user.name)).await?;
+    /// }
+    ///
+    /// let rows = encoder.finish().await?;
+    /// ```
+    pub fn copy_in_binary(&mut self, statement: &str) -> PgCopyInEncoder<'_> {
+        PgCopyInEncoder {
+            state: State::Pending(self, statement.to_owned()),
+        }
+    }
+}
+
+/// A row written by a [`PgCopyInEncoder`], with one value per column of the `COPY`.
+///
+/// This is implemented for tuples of up to 16 values, and can be implemented for a struct by
+/// adding its fields in order:
+///
+/// ```rust,ignore
+/// use sqlx::Arguments;
+/// use sqlx::postgres::{PgArguments, PgCopyRow};
+///
+/// impl PgCopyRow<'_> for User {
+///     fn encode_fields(self, fields: &mut PgArguments) {
+///         fields.add(self.id);
+///         fields.add(self.name);
+///     }
+/// }
+/// ```
+pub trait PgCopyRow<'q> {
+    fn encode_fields(self, fields: &mut PgArguments);
+}
+
+/// A binary `COPY ... FROM STDIN` in progress, started with [`PgConnection::copy_in_binary`].
+///
+/// Unlike bind parameters, the values are not converted by the server: each one must be of
+/// the exact type of its column, e.g. `i32` for an `INT4` column and `i64` for an `INT8` one.
+///
+/// If it is dropped before [`finish`](PgCopyInEncoder::finish) or
+/// [`abort`](PgCopyInEncoder::abort) is called, the `COPY` is aborted as by [`PgCopyIn`].
+pub struct PgCopyInEncoder<'c> {
+    state: State<'c>,
+}
+
+enum State<'c> {
+    // the COPY is not started yet
+    Pending(&'c mut PgConnection, String),
+    Started(PgCopyIn<'c>),
+
+    // the COPY failed to start, or is complete
+    Failed,
+}
+
+impl<'c> PgCopyInEncoder<'c> {
+    /// Encodes and sends a row.
+    ///
+    /// The OIDs of the user-defined types in the values, e.g. for an array of an enumeration,
+    /// are resolved before the `COPY` is started, as no other query can be sent during
+    /// a `COPY`. A type which is first seen in a later row, e.g. after `None` values in the
+    /// previous rows, fails with [`Error::TypeNotFound`] unless it was used before on the
+    /// connection; the `COPY` should then be aborted.
+    pub async fn write_row<'q, R: PgCopyRow<'q>>(&mut self, row: R) -> Result<(), Error> {
+        let mut fields = PgArguments::default();
+        row.encode_fields(&mut fields);
+
+        if let Some(err) = fields.take_error() {
+            return Err(Error::Encode(err));
+        }
+
+        if fields.formats.contains(&PgValueFormat::Text) {
+            return Err(Error::Encode(
+                "a value encoded in the text format cannot be written to a binary COPY".into(),
+            ));
+        }
+
+        // the values are received with their own types, as the server does not convert them
+        let types = fields.types.clone();
+
+        match &mut self.state {
+            State::Pending(conn, _) => fields.apply_patches(conn, &types).await?,
+            State::Started(copy) => fields.apply_cached_patches(copy.conn(), &types)?,
+            State::Failed => {}
+        }
+
+        let copy = self.start().await?;
+
+        let mut tuple = Vec::with_capacity(2 + fields.buffer.len());
+        tuple.extend(&(types.len() as i16).to_be_bytes());
+        tuple.extend_from_slice(&fields.buffer);
+
+        copy.send(tuple).await
+    }
+
+    /// Completes the `COPY` and returns the number of rows copied.
+    pub async fn finish(mut self) -> Result<u64, Error> {
+        // an empty COPY is still started, to check the statement
+        self.start().await?.send(TRAILER).await?;
+
+        match mem::replace(&mut self.state, State::Failed) {
+            State::Started(copy) => copy.finish().await,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Aborts the `COPY`, with a message for the error reported by the server. No row is copied.
+    pub async fn abort(mut self, message: &str) -> Result<(), Error> {
+        match mem::replace(&mut self.state, State::Failed) {
+            State::Started(copy) => copy.abort(message).await,
+
+            // nothing was sent yet
+            _ => Ok(()),
+        }
+    }
+
+    // starts the COPY, if needed, and sends the header
+    async fn start(&mut self) -> Result<&mut PgCopyIn<'c>, Error> {
+        if let State::Pending(conn, statement) = &mut self.state {
+            conn.start_copy(statement, MessageFormat::CopyInResponse)
+                .await?;
+
+            let conn = match mem::replace(&mut self.state, State::Failed) {
+                State::Pending(conn, _) => conn,
+                _ => unreachable!(),
+            };
+
+            let mut copy = PgCopyIn::new(conn);
+            copy.send(HEADER).await?;
+
+            self.state = State::Started(copy);
+        }
+
+        match &mut self.state {
+            State::Started(copy) => Ok(copy),
+
+            _ => Err(err_protocol!(
+                "PgCopyInEncoder used after the COPY failed to start"
+            )),
+        }
+    }
+}
+
+macro_rules! impl_copy_row_for_tuple {
+    ($( ($idx:tt) -> $T:ident );+;) => {
+        impl<'q, $($T,)+> PgCopyRow<'q> for ($($T,)+)
+        where
+            $($T: Encode<'q, Postgres> + Type<Postgres>,)+
+        {
+            fn encode_fields(self, fields: &mut PgArguments) {
+                $(fields.add(self.$idx);)+
+            }
+        }
+    };
+}
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+    (15) -> T16;
+);
@@ -3,8 +3,8 @@ use crate::ext::ustr::UStr;
 use crate::postgres::message::{ParameterDescription, RowDescription};
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::type_info::{PgCustomType, PgType, PgTypeKind};
-use crate::postgres::{PgArguments, PgColumn, PgConnection, PgTypeInfo};
-use crate::query_as::query_as;
+use crate::postgres::{PgArguments, PgColumn, PgConnection, PgDialect, PgTypeInfo};
+use crate::query_as::{query_as, query_as_with};
 use crate::query_scalar::{query_scalar, query_scalar_with};
 use crate::types::Json;
 use crate::HashMap;
@@ -44,15 +44,65 @@ impl PgConnection {
                 type_info,
                 relation_id: field.relation_id,
                 relation_attribute_no: field.relation_attribute_no,
+                relation_name: None,
             };
 
             columns.push(column);
-            column_names.insert(name, index);
+
+            // the first of the columns with the same name is found by name
+            column_names.entry(name).or_insert(index);
+        }
+
+        if should_fetch {
+            self.fetch_relation_names(&columns).await?;
+        }
+
+        for column in &mut columns {
+            column.relation_name = column
+                .relation_id
+                .and_then(|id| self.cache_relation_name.get(&(id as u32)))
+                .cloned();
         }
 
         Ok((columns, column_names))
     }
 
+    // fetches the names of the tables of the columns, which are not in the cache yet
+    fn fetch_relation_names<'c>(
+        &'c mut self,
+        columns: &'c [PgColumn],
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            let mut oids: Vec<u32> = columns
+                .iter()
+                .filter_map(|column| column.relation_id)
+                .map(|id| id as u32)
+                .filter(|oid| !self.cache_relation_name.contains_key(oid))
+                .collect();
+
+            if oids.is_empty() {
+                return Ok(());
+            }
+
+            oids.sort_unstable();
+            oids.dedup();
+
+            // the columns are expressions, which are not looked up themselves
+            let names: Vec<(i64, String)> = query_as(
+                "SELECT oid::int8, relname::text FROM pg_catalog.pg_class WHERE oid = ANY($1)",
+            )
+            .bind(oids)
+            .fetch_all(&mut *self)
+            .await?;
+
+            for (oid, name) in names {
+                self.cache_relation_name.insert(oid as u32, name.into());
+            }
+
+            Ok(())
+        })
+    }
+
     pub(super) async fn handle_parameter_description(
         &mut self,
         desc: ParameterDescription,
@@ -118,14 +168,27 @@ impl PgConnection {
                     kind: PgTypeKind::Array(self.fetch_type_by_oid(element).await?),
                     name: name.into(),
                     oid,
+                    codec: None,
                 })))),
 
                 b'P' => Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
                     kind: PgTypeKind::Pseudo,
                     name: name.into(),
                     oid,
+                    codec: None,
                 })))),
 
+                // CockroachDB does not fill the catalogs of range and composite types; their
+                // values are decoded like those of a simple type
+                b'R' | b'C' if self.dialect == PgDialect::CockroachDb => {
+                    Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+                        kind: PgTypeKind::Simple,
+                        codec: self.type_codecs.get(&*name).cloned(),
+                        name: name.into(),
+                        oid,
+                    }))))
+                }
+
                 b'R' => self.fetch_range_by_oid(oid, name).await,
 
                 b'E' => self.fetch_enum_by_oid(oid, name).await,
@@ -134,6 +197,7 @@ impl PgConnection {
 
                 _ => Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
                     kind: PgTypeKind::Simple,
+                    codec: self.type_codecs.get(&*name).cloned(),
                     name: name.into(),
                     oid,
                 })))),
@@ -158,6 +222,7 @@ ORDER BY enumsortorder
             oid,
             name: name.into(),
             kind: PgTypeKind::Enum(Arc::from(variants)),
+            codec: None,
         }))))
     }
 
@@ -194,6 +259,7 @@ ORDER BY attnum
                 oid,
                 name: name.into(),
                 kind: PgTypeKind::Composite(Arc::from(fields)),
+                codec: None,
             }))))
         })
     }
@@ -221,25 +287,52 @@ WHERE rngtypid = $1
                 kind: PgTypeKind::Range(element),
                 name: name.into(),
                 oid,
+                codec: None,
             }))))
         })
     }
 
+    pub(crate) fn cached_type_id_by_name(&self, name: &str) -> Option<u32> {
+        self.cache_type_oid.get(name).copied()
+    }
+
     pub(crate) async fn fetch_type_id_by_name(&mut self, name: &str) -> Result<u32, Error> {
-        if let Some(oid) = self.cache_type_oid.get(name) {
-            return Ok(*oid);
+        if let Some(oid) = self.cached_type_id_by_name(name) {
+            return Ok(oid);
         }
 
-        // language=SQL
-        let (oid,): (u32,) = query_as(
-            "
+        let row: Option<(u32,)> = match name.rfind('.') {
+            // a name qualified by its schema, e.g. `inventory._item`
+            Some(dot) => {
+                // language=SQL
+                query_as(
+                    "
+SELECT t.oid
+FROM pg_catalog.pg_type t
+INNER JOIN pg_catalog.pg_namespace n ON n.oid = t.typnamespace
+WHERE n.nspname ILIKE $1 AND t.typname ILIKE $2
+                    ",
+                )
+                .bind(&name[..dot])
+                .bind(&name[dot + 1..])
+                .fetch_optional(&mut *self)
+                .await?
+            }
+
+            None => {
+                // language=SQL
+                query_as(
+                    "
 SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
-                ",
-        )
-        .bind(name)
-        .fetch_optional(&mut *self)
-        .await?
-        .ok_or_else(|| Error::TypeNotFound {
+                    ",
+                )
+                .bind(name)
+                .fetch_optional(&mut *self)
+                .await?
+            }
+        };
+
+        let (oid,) = row.ok_or_else(|| Error::TypeNotFound {
             type_name: String::from(name),
         })?;
 
@@ -291,12 +384,15 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
             .await?;
 
         // patch up our null inference with data from EXPLAIN
-        let nullable_patch = self
-            .nullables_from_explain(stmt_id, meta.parameters.len())
-            .await?;
+        // NOTE: `EXPLAIN (FORMAT JSON)` requires postgres 9.0+, and is not supported by CockroachDB
+        if self.server_version_at_least(90000) {
+            let nullable_patch = self
+                .nullables_from_explain(stmt_id, meta.parameters.len())
+                .await?;
 
-        for (nullable, patch) in nullables.iter_mut().zip(nullable_patch) {
-            *nullable = patch.or(*nullable);
+            for (nullable, patch) in nullables.iter_mut().zip(nullable_patch) {
+                *nullable = patch.or(*nullable);
+            }
         }
 
         Ok(nullables)
@@ -304,14 +400,20 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
 
     /// Infer nullability for columns of this statement using EXPLAIN VERBOSE.
     ///
-    /// This currently only marks columns that are on the inner half of an outer join
-    /// and returns `None` for all others.
+    /// This marks columns that are on the inner half of an outer join as nullable and looks up
+    /// the columns returned by data-modifying CTEs (`WITH .. INSERT .. RETURNING`) in their table.
+    /// It returns `None` for all others.
     async fn nullables_from_explain(
         &mut self,
         stmt_id: u32,
         params_len: usize,
     ) -> Result<Vec<Option<bool>>, Error> {
-        let mut explain = format!("EXPLAIN (VERBOSE, FORMAT JSON) EXECUTE sqlx_s_{}", stmt_id);
+        // the name given to the statement by the protocol is case-sensitive
+        let mut explain = format!(
+            "EXPLAIN (VERBOSE, FORMAT JSON) EXECUTE \"{}{}\"",
+            self.options.statement_name_prefix.replace('"', "\"\""),
+            stmt_id
+        );
         let mut comma = false;
 
         if params_len > 0 {
@@ -330,17 +432,153 @@ SELECT oid FROM pg_catalog.pg_type WHERE typname ILIKE $1
             explain += ")";
         }
 
-        let (Json([explain]),): (Json<[Explain; 1]>,) = query_as(&explain).fetch_one(self).await?;
+        let (Json([explain]),): (Json<[Explain; 1]>,) =
+            query_as(&explain).fetch_one(&mut *self).await?;
 
         let mut nullables = Vec::new();
 
         if let Some(outputs) = &explain.plan.output {
             nullables.resize(outputs.len(), None);
+
+            // the outer joins are visited last as they may make any column nullable
+            // NOTE: `to_regclass` requires postgres 9.4+
+            if self.server_version_at_least(90400) {
+                self.nullables_from_ctes(&explain.plan, outputs, &mut nullables)
+                    .await?;
+            }
+
             visit_plan(&explain.plan, outputs, &mut nullables);
         }
 
         Ok(nullables)
     }
+
+    /// Infer nullability for the outputs of the statement which are columns returned by
+    /// a data-modifying CTE, from the `NOT NULL` constraints of the modified table.
+    ///
+    /// The columns of the CTE are matched to those of the table by name, as the plan does not
+    /// preserve the aliases given in `RETURNING`.
+    async fn nullables_from_ctes(
+        &mut self,
+        plan: &Plan,
+        outputs: &[String],
+        nullables: &mut [Option<bool>],
+    ) -> Result<(), Error> {
+        let mut nodes = Vec::new();
+        collect_plans(plan, &mut nodes);
+
+        // the data-modifying CTEs appear as `ModifyTable` nodes in an init plan
+        let ctes: HashMap<&str, &Plan> = nodes
+            .iter()
+            .filter(|node| node.node_type == "ModifyTable")
+            .filter_map(|node| {
+                let name = node.subplan_name.as_deref()?.strip_prefix("CTE ")?;
+
+                Some((name, *node))
+            })
+            .collect();
+
+        if ctes.is_empty() {
+            return Ok(());
+        }
+
+        let mut nullable_query =
+            String::from("SELECT idx, NOT pg_attribute.attnotnull FROM (VALUES ");
+        let mut args = PgArguments::default();
+        let mut bind = 1;
+
+        for scan in nodes.iter().filter(|node| node.node_type == "CTE Scan") {
+            let (cte, scan_alias, scan_outputs) = match (
+                scan.cte_name.as_deref().and_then(|name| ctes.get(name)),
+                &scan.alias,
+                &scan.output,
+            ) {
+                (Some(cte), Some(alias), Some(outputs)) => (cte, alias, outputs),
+                _ => continue,
+            };
+
+            let (schema, relation, alias, cte_outputs) =
+                match (&cte.schema, &cte.relation_name, &cte.alias, &cte.output) {
+                    (Some(schema), Some(relation), Some(alias), Some(outputs)) => {
+                        (schema, relation, alias, outputs)
+                    }
+                    _ => continue,
+                };
+
+            for scan_output in scan_outputs {
+                // e.g. `ins.id` in the outputs of the statement and `t.id` in those of the CTE
+                let column = match strip_alias(scan_output, scan_alias) {
+                    Some(column) => column,
+                    None => continue,
+                };
+
+                let is_returned = cte_outputs
+                    .iter()
+                    .any(|output| strip_alias(output, alias) == Some(column));
+
+                let i = match outputs.iter().position(|o| o == scan_output) {
+                    Some(i) if is_returned => i,
+                    _ => continue,
+                };
+
+                if !args.buffer.is_empty() {
+                    nullable_query += ", ";
+                }
+
+                let _ = write!(
+                    nullable_query,
+                    "(${}::int4, ${}::text, ${}::text, ${}::text)",
+                    bind,
+                    bind + 1,
+                    bind + 2,
+                    bind + 3
+                );
+
+                bind += 4;
+
+                args.add(i as i32);
+                args.add(schema.as_str());
+                args.add(relation.as_str());
+                args.add(column);
+            }
+        }
+
+        if args.buffer.is_empty() {
+            return Ok(());
+        }
+
+        // the column names in the plan are quoted as needed
+        nullable_query.push_str(
+            ") as col(idx, schema_name, table_name, col_name) \
+            JOIN pg_catalog.pg_attribute \
+                ON attrelid = to_regclass(quote_ident(schema_name) || '.' || quote_ident(table_name)) \
+               AND quote_ident(attname) = col_name \
+               AND attnum > 0 \
+               AND NOT attisdropped",
+        );
+
+        let patch: Vec<(i32, bool)> = query_as_with(&nullable_query, args)
+            .fetch_all(&mut *self)
+            .await?;
+
+        for (i, nullable) in patch {
+            nullables[i as usize] = Some(nullable);
+        }
+
+        Ok(())
+    }
+}
+
+fn collect_plans<'a>(plan: &'a Plan, nodes: &mut Vec<&'a Plan>) {
+    nodes.push(plan);
+
+    for plan in plan.plans.iter().flatten() {
+        collect_plans(plan, nodes);
+    }
+}
+
+fn strip_alias<'a>(output: &'a str, alias: &str) -> Option<&'a str> {
+    output.strip_prefix(alias)?.strip_prefix('.')
 }
 
 fn visit_plan(plan: &Plan, outputs: &[String], nullables: &mut Vec<Option<bool>>) {
@@ -378,6 +616,18 @@ struct Explain {
 
 #[derive(serde::Deserialize)]
 struct Plan {
+    #[serde(rename = "Node Type")]
+    node_type: String,
+    #[serde(rename = "Subplan Name")]
+    subplan_name: Option<String>,
+    #[serde(rename = "CTE Name")]
+    cte_name: Option<String>,
+    #[serde(rename = "Schema")]
+    schema: Option<String>,
+    #[serde(rename = "Relation Name")]
+    relation_name: Option<String>,
+    #[serde(rename = "Alias")]
+    alias: Option<String>,
     #[serde(rename = "Join Type")]
     join_type: Option<String>,
     #[serde(rename = "Parent Relationship")]
@@ -1,19 +1,137 @@
+use std::io;
+use std::sync::Arc;
+
 use crate::HashMap;
 
 use crate::common::StatementCache;
+use crate::connection::Connection;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::io::Decode;
+#[cfg(feature = "gssapi")]
+use crate::postgres::connection::gss;
 use crate::postgres::connection::{sasl, stream::PgStream, tls};
 use crate::postgres::message::{
     Authentication, BackendKeyData, MessageFormat, Password, ReadyForQuery, Startup,
 };
-use crate::postgres::{PgConnectOptions, PgConnection};
+use crate::postgres::{PgConnectOptions, PgConnection, PgDialect, PgTargetSessionAttrs};
+use crate::row::Row;
 
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.3
 // https://www.postgresql.org/docs/current/protocol-flow.html#id-1.10.5.7.11
 
+// the names of statements are truncated to 63 bytes, and the ids take up to 10 digits
+const MAX_STATEMENT_NAME_PREFIX_LEN: usize = 63 - 10;
+
 impl PgConnection {
     pub(crate) async fn establish(options: &PgConnectOptions) -> Result<Self, Error> {
+        let prefix = &options.statement_name_prefix;
+
+        if prefix.is_empty()
+            || prefix.contains('\0')
+            || prefix.len() > MAX_STATEMENT_NAME_PREFIX_LEN
+        {
+            return Err(Error::Configuration(
+                format!("invalid statement name prefix {:?}", prefix).into(),
+            ));
+        }
+
+        if options.fallback_hosts.is_empty()
+            && options.target_session_attrs == PgTargetSessionAttrs::Any
+        {
+            return Self::establish_host(options).await;
+        }
+
+        // like libpq, with `prefer-standby`, the hosts are tried again in `any` mode if none
+        // of them is a standby
+        let passes: &[PgTargetSessionAttrs] = match options.target_session_attrs {
+            PgTargetSessionAttrs::PreferStandby => {
+                &[PgTargetSessionAttrs::Standby, PgTargetSessionAttrs::Any]
+            }
+
+            ref attrs => std::slice::from_ref(attrs),
+        };
+
+        let mut last_error = None;
+
+        for &attrs in passes {
+            for options in options.hosts() {
+                let error = match Self::establish_host(&options).await {
+                    Ok(mut conn) => match conn.matches_session_attrs(attrs).await {
+                        Ok(true) => return Ok(conn),
+
+                        Ok(false) => {
+                            let _ = conn.close().await;
+
+                            Error::Configuration(
+                                format!(
+                                    "the session of {}:{} does not match `target_session_attrs={}`",
+                                    options.host, options.port, attrs
+                                )
+                                .into(),
+                            )
+                        }
+
+                        Err(error) => error,
+                    },
+
+                    Err(error) => error,
+                };
+
+                log::debug!(
+                    "failed to connect to {}:{}: {}",
+                    options.host,
+                    options.port,
+                    error
+                );
+
+                last_error = Some(error);
+            }
+        }
+
+        // there is at least one host
+        Err(last_error.unwrap())
+    }
+
+    async fn matches_session_attrs(&mut self, attrs: PgTargetSessionAttrs) -> Result<bool, Error> {
+        let query = match attrs {
+            PgTargetSessionAttrs::Any => return Ok(true),
+            PgTargetSessionAttrs::ReadWrite => {
+                "SELECT current_setting('transaction_read_only') = 'off'"
+            }
+            PgTargetSessionAttrs::ReadOnly => {
+                "SELECT current_setting('transaction_read_only') = 'on'"
+            }
+            PgTargetSessionAttrs::Primary => "SELECT NOT pg_is_in_recovery()",
+            PgTargetSessionAttrs::Standby | PgTargetSessionAttrs::PreferStandby => {
+                "SELECT pg_is_in_recovery()"
+            }
+        };
+
+        self.fetch_one(query).await?.try_get(0)
+    }
+
+    // the connect timeout applies to each host, like in libpq
+    async fn establish_host(options: &PgConnectOptions) -> Result<Self, Error> {
+        let timeout = match options.connect_timeout {
+            Some(timeout) => timeout,
+            None => return Self::establish_session(options).await,
+        };
+
+        match sqlx_rt::timeout(timeout, Self::establish_session(options)).await {
+            Ok(result) => result,
+
+            Err(_) => Err(Error::Io(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!(
+                    "connecting to {}:{} timed out after {:?}",
+                    options.host, options.port, timeout
+                ),
+            ))),
+        }
+    }
+
+    async fn establish_session(options: &PgConnectOptions) -> Result<Self, Error> {
         let mut stream = PgStream::connect(options).await?;
 
         // Upgrade to TLS if we were asked to and the server supports it
@@ -31,15 +149,30 @@ impl PgConnection {
             ("client_encoding", "UTF8"),
             // Sets the time zone for displaying and interpreting time stamps.
             ("TimeZone", "UTC"),
-            // Adjust postgres to return precise values for floats
-            // NOTE: This is default in postgres 12+
-            ("extra_float_digits", "3"),
         ];
 
+        // Sets the number of digits of floats in the text format, when given by the user;
+        // sent on startup to save the round trip of a SET
+        let extra_float_digits = options.extra_float_digits.map(|digits| digits.to_string());
+
+        if let Some(ref extra_float_digits) = extra_float_digits {
+            params.push(("extra_float_digits", extra_float_digits));
+        }
+
         if let Some(ref application_name) = options.application_name {
             params.push(("application_name", application_name));
         }
 
+        // run-time parameters of the server, which would be set with `SET` otherwise
+        for (key, value) in &options.options {
+            params.push((key, value));
+        }
+
+        if options.replication {
+            // Starts a walsender in logical replication mode, connected to the database.
+            params.push(("replication", "database"));
+        }
+
         stream
             .send(Startup {
                 username: Some(&options.username),
@@ -96,6 +229,31 @@ impl PgConnection {
                         sasl::authenticate(&mut stream, options, body).await?;
                     }
 
+                    #[cfg(feature = "gssapi")]
+                    Authentication::Gss | Authentication::Sspi => {
+                        gss::authenticate(&mut stream, options).await?;
+                    }
+
+                    #[cfg(not(feature = "gssapi"))]
+                    method @ Authentication::Gss | method @ Authentication::Sspi => {
+                        // the server must be configured to accept a password or SCRAM
+                        // authentication for this user, in `pg_hba.conf`, or else SQLx must be
+                        // built with the `gssapi` feature
+                        let method = match method {
+                            Authentication::Sspi => "SSPI",
+                            _ => "GSSAPI",
+                        };
+
+                        return Err(Error::Configuration(
+                            format!(
+                                "the server requested {} authentication for user {:?}, \
+                                 which requires the `gssapi` feature",
+                                method, options.username
+                            )
+                            .into(),
+                        ));
+                    }
+
                     method => {
                         return Err(err_protocol!(
                             "unsupported authentication method: {:?}",
@@ -131,18 +289,77 @@ impl PgConnection {
             }
         }
 
-        Ok(PgConnection {
+        let server_version_num = stream.server_version_num;
+
+        if let Some(min) = options.min_server_version {
+            match server_version_num {
+                Some(version) if version >= min => {}
+
+                Some(version) => {
+                    return Err(Error::Configuration(
+                        format!(
+                            "the server version {} is older than the minimum version {}",
+                            version, min
+                        )
+                        .into(),
+                    ));
+                }
+
+                None => {
+                    return Err(Error::Configuration(
+                        format!(
+                            "the server did not report its version; the minimum version is {}",
+                            min
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+
+        let dialect = options.dialect.unwrap_or(if stream.is_cockroachdb {
+            PgDialect::CockroachDb
+        } else {
+            PgDialect::Postgres
+        });
+
+        let mut conn = PgConnection {
             stream,
+            dialect,
             process_id,
             secret_key,
+            options: Arc::new(options.clone()),
             transaction_status,
             transaction_depth: 0,
             pending_ready_for_query_count: 0,
             next_statement_id: 1,
+            statement_names_taken: false,
+            taken_statement_ids: None,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            fetch_size: options.fetch_size,
+            strict_column_names: options.strict_column_names,
+            sync_pending: false,
             cache_type_oid: HashMap::new(),
+            cache_relation_name: HashMap::new(),
             cache_type_info: HashMap::new(),
+            type_codecs: HashMap::new(),
             log_settings: options.log_settings.clone(),
-        })
+        };
+
+        if options.extra_float_digits.is_none() {
+            // Adjust postgres to return precise values for floats
+            // NOTE: This is the default in postgres 12+, and 3 is out of range before 9.0
+            match server_version_num {
+                Some(version) if version >= 120000 => {}
+                Some(version) if version < 90000 => {
+                    conn.execute("SET extra_float_digits = 2").await?;
+                }
+                _ => {
+                    conn.execute("SET extra_float_digits = 3").await?;
+                }
+            }
+        }
+
+        Ok(conn)
     }
 }
@@ -1,33 +1,42 @@
 use crate::describe::Describe;
-use crate::error::Error;
+use crate::error::{Error, Limit};
 use crate::executor::{Execute, Executor};
 use crate::logger::QueryLogger;
 use crate::postgres::message::{
-    self, Bind, Close, CommandComplete, DataRow, MessageFormat, ParameterDescription, Parse, Query,
-    RowDescription,
+    self, Bind, Close, CommandComplete, DataRow, Flush, MessageFormat, ParameterDescription, Parse,
+    PgTransactionStatus, Query, RowDescription, StatementName,
 };
 use crate::postgres::statement::PgStatementMetadata;
 use crate::postgres::type_info::PgType;
 use crate::postgres::{
-    statement::PgStatement, PgArguments, PgConnection, PgQueryResult, PgRow, PgTypeInfo,
+    statement::PgStatement, PgArguments, PgColumn, PgConnection, PgQueryResult, PgRow, PgTypeInfo,
     PgValueFormat, Postgres,
 };
+use crate::query_scalar::query_scalar;
+use crate::row::Row;
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
+use std::collections::HashSet;
 use std::{borrow::Cow, sync::Arc};
 
+// the number of parameters is sent as an `Int16`, which the server reads as unsigned
+const MAX_BIND_PARAMETERS: usize = u16::MAX as usize;
+
+// the SQLSTATE of a statement name which is already taken
+const DUPLICATE_PREPARED_STATEMENT: &str = "42P05";
+
+// the server rejects messages of 1 GiB or more
+pub(super) const MAX_MESSAGE_SIZE: usize = 0x3fff_ffff;
+
 async fn prepare(
     conn: &mut PgConnection,
     sql: &str,
     parameters: &[PgTypeInfo],
     metadata: Option<Arc<PgStatementMetadata>>,
 ) -> Result<(u32, Arc<PgStatementMetadata>), Error> {
-    let id = conn.next_statement_id;
-    conn.next_statement_id = conn.next_statement_id.wrapping_add(1);
-
     // build a list of type OIDs to send to the database in the PARSE command
     // we have not yet started the query sequence, so we are *safe* to cleanly make
     // additional queries here to get any missing OIDs
@@ -42,30 +51,51 @@ async fn prepare(
         });
     }
 
-    // flush and wait until we are re-ready
-    conn.wait_until_ready().await?;
+    let id = loop {
+        // flush and wait until we are re-ready
+        conn.wait_until_ready().await?;
 
-    // next we send the PARSE command to the server
-    conn.stream.write(Parse {
-        param_types: &*param_types,
-        query: sql,
-        statement: id,
-    });
+        conn.load_taken_statement_ids().await?;
+        let id = conn.next_statement_id();
 
-    if metadata.is_none() {
-        // get the statement columns and parameters
-        conn.stream.write(message::Describe::Statement(id));
-    }
+        let name = StatementName::Id(&conn.options.statement_name_prefix, id);
+
+        // next we send the PARSE command to the server
+        conn.stream.write(Parse {
+            param_types: &*param_types,
+            query: sql,
+            statement: name,
+        });
 
-    // we ask for the server to immediately send us the result of the PARSE command
-    conn.write_sync();
-    conn.stream.flush().await?;
+        if metadata.is_none() {
+            // get the statement columns and parameters
+            conn.stream.write(message::Describe::Statement(name));
+        }
+
+        // we ask for the server to immediately send us the result of the PARSE command
+        conn.write_sync();
+        conn.stream.flush().await?;
+
+        // indicates that the SQL query string is now successfully parsed and has semantic validity
+        match conn.stream.recv_expect(MessageFormat::ParseComplete).await {
+            Ok(()) => break id,
+
+            // the name is taken by a statement which was not prepared by us, e.g. with `PREPARE`
+            Err(Error::Database(error))
+                if error.code().as_deref() == Some(DUPLICATE_PREPARED_STATEMENT) =>
+            {
+                conn.statement_names_taken = true;
 
-    // indicates that the SQL query string is now successfully parsed and has semantic validity
-    let _ = conn
-        .stream
-        .recv_expect(MessageFormat::ParseComplete)
-        .await?;
+                // in a transaction block, the error aborted the transaction so it cannot be
+                // retried; the next transactions skip the names which are taken
+                if conn.transaction_status != PgTransactionStatus::Idle {
+                    return Err(Error::Database(error));
+                }
+            }
+
+            Err(error) => return Err(error),
+        }
+    };
 
     let metadata = if let Some(metadata) = metadata {
         // each SYNC produces one READY FOR QUERY
@@ -89,16 +119,73 @@ async fn prepare(
         // continuing
         conn.wait_until_ready().await?;
 
+        let result_formats = result_formats(&columns);
+
         Arc::new(PgStatementMetadata {
             parameters,
             columns,
             column_names,
+            result_formats,
         })
     };
 
     Ok((id, metadata))
 }
 
+// columns of the types which cannot be sent in the binary format are requested as text
+fn result_formats(columns: &[PgColumn]) -> Vec<PgValueFormat> {
+    if !columns.iter().any(|column| column.type_info.is_text_only()) {
+        return Vec::new();
+    }
+
+    columns
+        .iter()
+        .map(|column| {
+            if column.type_info.is_text_only() {
+                PgValueFormat::Text
+            } else {
+                PgValueFormat::Binary
+            }
+        })
+        .collect()
+}
+
+// checks that the arguments can be sent, before anything is
+pub(super) fn check_arguments(arguments: &mut PgArguments) -> Result<(), Error> {
+    // a value that failed to encode aborts the query before anything is sent
+    if let Some(err) = arguments.take_error() {
+        return Err(Error::Encode(err));
+    }
+
+    Error::check_limit(
+        Limit::BindParameters,
+        MAX_BIND_PARAMETERS,
+        arguments.types.len(),
+    )?;
+
+    Error::check_limit(
+        Limit::MessageSize,
+        MAX_MESSAGE_SIZE,
+        arguments.buffer.len() + 2 * arguments.formats.len(),
+    )
+}
+
+// each bind parameter of the prepared statement needs an argument; checked before the arguments
+// are patched with the types of the parameters
+pub(super) fn check_parameter_count(
+    arguments: &PgArguments,
+    metadata: &PgStatementMetadata,
+) -> Result<(), Error> {
+    if arguments.types.len() != metadata.parameters.len() {
+        return Err(Error::BindParameterCount {
+            expected: metadata.parameters.len(),
+            actual: arguments.types.len(),
+        });
+    }
+
+    Ok(())
+}
+
 async fn recv_desc_params(conn: &mut PgConnection) -> Result<ParameterDescription, Error> {
     conn.stream
         .recv_expect(MessageFormat::ParameterDescription)
@@ -160,7 +247,37 @@ impl PgConnection {
         self.pending_ready_for_query_count += 1;
     }
 
-    async fn get_or_prepare<'a>(
+    // binds the arguments to the statement in the unnamed portal and executes it
+    pub(super) fn write_execute(
+        &mut self,
+        statement: u32,
+        arguments: &PgArguments,
+        metadata: &PgStatementMetadata,
+        limit: u32,
+    ) {
+        // bind to attach the arguments to the statement and create a portal
+        self.stream.write(Bind {
+            portal: None,
+            statement: StatementName::Id(&self.options.statement_name_prefix, statement),
+            formats: arguments.formats(),
+            num_params: arguments.types.len() as i16,
+            params: &*arguments.buffer,
+            result_formats: if metadata.result_formats.is_empty() {
+                &[PgValueFormat::Binary]
+            } else {
+                &metadata.result_formats
+            },
+        });
+
+        // executes the portal up to the passed limit
+        // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+        self.stream.write(message::Execute {
+            portal: None,
+            limit,
+        });
+    }
+
+    pub(super) async fn get_or_prepare<'a>(
         &mut self,
         sql: &str,
         parameters: &[PgTypeInfo],
@@ -170,25 +287,43 @@ impl PgConnection {
         // a statement object
         metadata: Option<Arc<PgStatementMetadata>>,
     ) -> Result<(u32, Arc<PgStatementMetadata>), Error> {
-        if let Some(statement) = self.cache_statement.get_mut(sql) {
+        if let Some(statement) = self.cache_statement.lookup(sql) {
             return Ok((*statement).clone());
         }
 
         let statement = prepare(self, sql, parameters, metadata).await?;
 
-        if store_to_cache && self.cache_statement.is_enabled() {
-            if let Some((id, _)) = self.cache_statement.insert(sql, statement.clone()) {
-                self.stream.write(Close::Statement(id));
-                self.write_sync();
+        if store_to_cache {
+            self.cache_statement(sql, statement.clone()).await?;
+        }
 
-                self.stream.flush().await?;
+        Ok(statement)
+    }
 
-                self.wait_for_close_complete(1).await?;
-                self.recv_ready_for_query().await?;
-            }
+    // stores a prepared statement to the cache, closing the statement it evicts
+    async fn cache_statement(
+        &mut self,
+        sql: &str,
+        statement: (u32, Arc<PgStatementMetadata>),
+    ) -> Result<(), Error> {
+        if !self.cache_statement.is_enabled() {
+            return Ok(());
         }
 
-        Ok(statement)
+        if let Some((id, _)) = self.cache_statement.insert(sql, statement) {
+            self.stream.write(Close::Statement(StatementName::Id(
+                &self.options.statement_name_prefix,
+                id,
+            )));
+            self.write_sync();
+
+            self.stream.flush().await?;
+
+            self.wait_for_close_complete(1).await?;
+            self.recv_ready_for_query().await?;
+        }
+
+        Ok(())
     }
 
     async fn run<'e, 'c: 'e, 'q: 'e>(
@@ -201,46 +336,59 @@ impl PgConnection {
     ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
         let mut logger = QueryLogger::new(query, self.log_settings.clone());
 
+        Error::check_limit(Limit::MessageSize, MAX_MESSAGE_SIZE, query.len())?;
+
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
 
         let mut metadata: Arc<PgStatementMetadata>;
 
+        // the rows of a query without a limit are fetched in chunks, if configured
+        let fetch_size = if limit == 0 { self.fetch_size } else { 0 };
+        let mut chunked = false;
+
+        // the rows of the chunks before the last, which are not counted by its CommandComplete
+        let mut suspended_rows = 0_u64;
+
         let format = if let Some(mut arguments) = arguments {
+            check_arguments(&mut arguments)?;
+
             // prepare the statement if this our first time executing it
             // always return the statement ID here
             let (statement, metadata_) = self
                 .get_or_prepare(query, &arguments.types, persistent, metadata_opt)
                 .await?;
 
+            logger.prepared();
+
             metadata = metadata_;
 
+            check_parameter_count(&arguments, &metadata)?;
+
             // patch holes created during encoding
             arguments.apply_patches(self, &metadata.parameters).await?;
 
-            // bind to attach the arguments to the statement and create a portal
-            self.stream.write(Bind {
-                portal: None,
-                statement,
-                formats: &[PgValueFormat::Binary],
-                num_params: arguments.types.len() as i16,
-                params: &*arguments.buffer,
-                result_formats: &[PgValueFormat::Binary],
-            });
-
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.stream.write(message::Execute {
-                portal: None,
-                limit: limit.into(),
-            });
+            // patching may have looked up types by name, make sure those queries are finished
+            // before sending this one
+            self.wait_until_ready().await?;
 
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+            if fetch_size > 0 {
+                self.write_execute(statement, &arguments, &metadata, fetch_size);
+
+                // a [Sync] would close the portal, [Flush] only asks for the first chunk of rows;
+                // the [Sync] is sent once the query is complete, or when the connection is used
+                // again if the stream is dropped before that
+                self.stream.write(Flush);
+                self.sync_pending = true;
+                chunked = true;
+            } else {
+                self.write_execute(statement, &arguments, &metadata, limit.into());
+
+                // finally, [Sync] asks postgres to process the messages that we sent and respond
+                // with a [ReadyForQuery] message when it's completely done. Several executions
+                // can also be sent before a single [Sync], see [PgPipeline].
+                self.write_sync();
+            }
 
             // prepared statements are binary
             PgValueFormat::Binary
@@ -274,13 +422,39 @@ impl PgConnection {
                         // a SQL command completed normally
                         let cc: CommandComplete = message.decode()?;
 
+                        if chunked {
+                            // the portal is complete
+                            self.sync_pending = false;
+                            self.write_sync();
+                            self.stream.flush().await?;
+                        }
+
                         r#yield!(Either::Left(PgQueryResult {
-                            rows_affected: cc.rows_affected(),
+                            rows_affected: cc.rows_affected() + suspended_rows,
                         }));
                     }
 
+                    MessageFormat::PortalSuspended => {
+                        // the rows of the chunk were all consumed, ask for the next one
+                        suspended_rows += u64::from(fetch_size);
+
+                        self.stream.write(message::Execute {
+                            portal: None,
+                            limit: fetch_size,
+                        });
+
+                        self.stream.write(Flush);
+                        self.stream.flush().await?;
+                    }
+
                     MessageFormat::EmptyQueryResponse => {
                         // empty query string passed to an unprepared execute
+
+                        if chunked {
+                            self.sync_pending = false;
+                            self.write_sync();
+                            self.stream.flush().await?;
+                        }
                     }
 
                     MessageFormat::RowDescription => {
@@ -293,6 +467,7 @@ impl PgConnection {
                             column_names,
                             columns,
                             parameters: Vec::default(),
+                            result_formats: Vec::default(),
                         });
                     }
 
@@ -305,6 +480,7 @@ impl PgConnection {
                             data,
                             format,
                             metadata: Arc::clone(&metadata),
+                            strict_column_names: self.strict_column_names,
                         };
 
                         r#yield!(Either::Right(row));
@@ -328,6 +504,419 @@ impl PgConnection {
             Ok(())
         })
     }
+
+    /// Executes the statements of a SQL string, such as a schema script or a seed file, with
+    /// the simple query protocol, yielding the rows and the result of each statement with its
+    /// index in the string.
+    ///
+    /// Unless the string has its own `BEGIN` and `COMMIT`, PostgreSQL runs the statements in a
+    /// single implicit transaction: when a statement fails, the stream ends with its error and
+    /// the statements before it are rolled back.
+    ///
+    /// The rows are in the text format, and the statements cannot have bind parameters.
+    ///
+    /// ```rust,ignore
+    /// let mut results = conn.execute_batch("CREATE TABLE t (x INT); INSERT INTO t VALUES (1)");
+    ///
+    /// while let Some((statement, result)) = results.try_next().await? {
+    ///     if let Either::Left(done) = result {
+    ///         println!("{}: {} rows affected", statement, done.rows_affected());
+    ///     }
+    /// }
+    /// ```
+    pub fn execute_batch<'e>(
+        &'e mut self,
+        sql: &'e str,
+    ) -> BoxStream<'e, Result<(usize, Either<PgQueryResult, PgRow>), Error>> {
+        Box::pin(try_stream! {
+            let s = self.run(sql, None, 0, false, None).await?;
+            pin_mut!(s);
+
+            let mut statement = 0;
+
+            while let Some(v) = s.try_next().await? {
+                // the result of a statement comes after its rows
+                let done = v.is_left();
+
+                r#yield!((statement, v));
+
+                if done {
+                    statement += 1;
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl PgConnection {
+    /// Describes several statements, like [`Executor::describe`] for each of them, in the same
+    /// order.
+    ///
+    /// The statements which are not already prepared on the connection are parsed and described
+    /// in a single round trip, instead of one per statement. Tools which inspect many queries
+    /// save most of the latency this way, although inferring the nullability of the columns
+    /// still takes a round trip per statement that returns rows.
+    ///
+    /// ```rust,ignore
+    /// let describes = conn
+    ///     .describe_all(&["SELECT id, name FROM users", "DELETE FROM users WHERE id = $1"])
+    ///     .await?;
+    ///
+    /// assert_eq!(describes[0].columns().len(), 2);
+    /// ```
+    ///
+    /// If a statement is invalid, its error is returned and none of them is described.
+    pub async fn describe_all(
+        &mut self,
+        queries: &[&str],
+    ) -> Result<Vec<Describe<Postgres>>, Error> {
+        for sql in queries {
+            Error::check_limit(Limit::MessageSize, MAX_MESSAGE_SIZE, sql.len())?;
+        }
+
+        loop {
+            match self.try_describe_all(queries).await {
+                // a name is taken by a statement which was not prepared by us, e.g. with
+                // `PREPARE`; the statements are described again with the next names, unless
+                // the error aborted a transaction
+                Err(Error::Database(error))
+                    if error.code().as_deref() == Some(DUPLICATE_PREPARED_STATEMENT) =>
+                {
+                    self.statement_names_taken = true;
+
+                    if self.transaction_status != PgTransactionStatus::Idle {
+                        return Err(Error::Database(error));
+                    }
+                }
+
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_describe_all(
+        &mut self,
+        queries: &[&str],
+    ) -> Result<Vec<Describe<Postgres>>, Error> {
+        self.wait_until_ready().await?;
+        self.load_taken_statement_ids().await?;
+
+        // the statements, by their index in `queries`; `None` for those to describe
+        let mut statements: Vec<Option<(u32, Arc<PgStatementMetadata>)>> =
+            Vec::with_capacity(queries.len());
+
+        // the ids of the statements to describe, and the index of their query
+        let mut pending = Vec::new();
+
+        for (i, sql) in queries.iter().enumerate() {
+            let statement = self.cache_statement.lookup(sql).map(|s| (*s).clone());
+
+            // a statement repeated in `queries` is only described once
+            let repeated = pending.iter().any(|&(_, j)| queries[j] == *sql);
+
+            if statement.is_none() && !repeated {
+                let id = self.next_statement_id();
+
+                let name = StatementName::Id(&self.options.statement_name_prefix, id);
+
+                self.stream.write(Parse {
+                    param_types: &[],
+                    query: sql,
+                    statement: name,
+                });
+
+                self.stream.write(message::Describe::Statement(name));
+
+                pending.push((id, i));
+            }
+
+            statements.push(statement);
+        }
+
+        if !pending.is_empty() {
+            self.write_sync();
+            self.stream.flush().await?;
+
+            let mut descriptions = Vec::with_capacity(pending.len());
+
+            for (parsed, &(id, _)) in pending.iter().enumerate() {
+                let description = match self.recv_description().await {
+                    Ok(description) => description,
+
+                    Err(error) => {
+                        // the statements parsed before the error are not needed anymore
+                        let ids = pending[..parsed].iter().map(|&(id, _)| id).collect();
+
+                        if self.wait_until_ready().await.is_ok() {
+                            let _ = self.close_statements(ids).await;
+                        }
+
+                        return Err(error);
+                    }
+                };
+
+                descriptions.push((id, description));
+            }
+
+            // each SYNC produces one READY FOR QUERY
+            self.recv_ready_for_query().await?;
+
+            for ((id, (parameters, rows)), &(_, i)) in descriptions.into_iter().zip(&pending) {
+                let parameters = self.handle_parameter_description(parameters).await?;
+                let (columns, column_names) = self.handle_row_description(rows, true).await?;
+
+                // ensure that if we did fetch custom data, we wait until we are fully ready
+                // before continuing
+                self.wait_until_ready().await?;
+
+                let result_formats = result_formats(&columns);
+
+                statements[i] = Some((
+                    id,
+                    Arc::new(PgStatementMetadata {
+                        parameters,
+                        columns,
+                        column_names,
+                        result_formats,
+                    }),
+                ));
+            }
+        }
+
+        let mut describes = Vec::with_capacity(queries.len());
+
+        for (i, sql) in queries.iter().enumerate() {
+            let (id, metadata) = match &statements[i] {
+                Some(statement) => statement.clone(),
+
+                // the first occurrence of a repeated statement was described
+                None => statements[queries.iter().position(|q| q == sql).unwrap()]
+                    .clone()
+                    .unwrap(),
+            };
+
+            let nullable = self.get_nullable_for_columns(id, &metadata).await?;
+
+            describes.push(Describe {
+                columns: metadata.columns.clone(),
+                nullable,
+                parameters: Some(Either::Left(metadata.parameters.clone())),
+            });
+        }
+
+        // the statements are cached once they are not needed to infer the nullability anymore,
+        // as caching one may close another
+        for (id, i) in pending {
+            let (_, metadata) = statements[i].take().unwrap();
+
+            self.cache_statement(queries[i], (id, metadata)).await?;
+        }
+
+        Ok(describes)
+    }
+
+    /// Executes a statement which was not prepared by SQLx, e.g. with `PREPARE`, by its name,
+    /// yielding its rows and its result.
+    ///
+    /// The statement is described first, to know the types of its parameters and columns, so
+    /// it takes two round trips.
+    ///
+    /// ```rust,ignore
+    /// conn.execute("PREPARE user_name (int8) AS SELECT name FROM users WHERE id = $1").await?;
+    ///
+    /// let mut arguments = PgArguments::default();
+    /// arguments.add(1_i64);
+    ///
+    /// let results: Vec<_> = conn.execute_prepared("user_name", arguments).try_collect().await?;
+    /// ```
+    pub fn execute_prepared<'e>(
+        &'e mut self,
+        name: &'e str,
+        mut arguments: PgArguments,
+    ) -> BoxStream<'e, Result<Either<PgQueryResult, PgRow>, Error>> {
+        let mut logger = QueryLogger::new(name, self.log_settings.clone());
+
+        Box::pin(try_stream! {
+            check_arguments(&mut arguments)?;
+
+            self.wait_until_ready().await?;
+
+            self.stream
+                .write(message::Describe::Statement(StatementName::Named(name)));
+
+            self.write_sync();
+            self.stream.flush().await?;
+
+            let parameters = recv_desc_params(self).await?;
+            let rows = recv_desc_rows(self).await?;
+
+            self.recv_ready_for_query().await?;
+
+            let parameters = self.handle_parameter_description(parameters).await?;
+            let (columns, column_names) = self.handle_row_description(rows, true).await?;
+
+            self.wait_until_ready().await?;
+
+            let metadata = Arc::new(PgStatementMetadata {
+                parameters,
+                result_formats: result_formats(&columns),
+                columns,
+                column_names,
+            });
+
+            check_parameter_count(&arguments, &metadata)?;
+
+            // patch holes created during encoding
+            arguments.apply_patches(self, &metadata.parameters).await?;
+
+            self.wait_until_ready().await?;
+
+            self.stream.write(Bind {
+                portal: None,
+                statement: StatementName::Named(name),
+                formats: arguments.formats(),
+                num_params: arguments.types.len() as i16,
+                params: &*arguments.buffer,
+                result_formats: if metadata.result_formats.is_empty() {
+                    &[PgValueFormat::Binary]
+                } else {
+                    &metadata.result_formats
+                },
+            });
+
+            self.stream.write(message::Execute {
+                portal: None,
+                limit: 0,
+            });
+
+            self.write_sync();
+            self.stream.flush().await?;
+
+            loop {
+                let message = self.stream.recv().await?;
+
+                match message.format {
+                    MessageFormat::BindComplete | MessageFormat::EmptyQueryResponse => {}
+
+                    MessageFormat::CommandComplete => {
+                        let cc: CommandComplete = message.decode()?;
+
+                        r#yield!(Either::Left(PgQueryResult {
+                            rows_affected: cc.rows_affected(),
+                        }));
+                    }
+
+                    MessageFormat::DataRow => {
+                        logger.increment_rows();
+
+                        r#yield!(Either::Right(PgRow {
+                            data: message.decode()?,
+                            format: PgValueFormat::Binary,
+                            metadata: Arc::clone(&metadata),
+                            strict_column_names: self.strict_column_names,
+                        }));
+                    }
+
+                    MessageFormat::ReadyForQuery => {
+                        self.handle_ready_for_query(message)?;
+                        break;
+                    }
+
+                    _ => {
+                        return Err(err_protocol!(
+                            "execute_prepared: unexpected message: {:?}",
+                            message.format
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Returns the names of the statements of the session which were not prepared by SQLx,
+    /// either with `PREPARE` or with a name which does not start with the
+    /// [statement name prefix](crate::postgres::PgConnectOptions::statement_name_prefix).
+    ///
+    /// They can be executed with [`execute_prepared`](Self::execute_prepared).
+    pub async fn user_prepared_statements(&mut self) -> Result<Vec<String>, Error> {
+        let prefix = self.options.statement_name_prefix.clone();
+
+        // language=SQL
+        query_scalar(
+            "SELECT name FROM pg_catalog.pg_prepared_statements \
+            WHERE from_sql OR strpos(name, $1) <> 1 \
+            ORDER BY prepare_time, name",
+        )
+        .bind(prefix)
+        .fetch_all(self)
+        .await
+    }
+
+    // In a transaction block, loads the IDs of the statement names with our prefix which are
+    // taken by statements prepared by the user with `PREPARE`, once per transaction; only once
+    // a name was found taken on the connection
+    async fn load_taken_statement_ids(&mut self) -> Result<(), Error> {
+        if !self.statement_names_taken
+            || self.transaction_status == PgTransactionStatus::Idle
+            || self.taken_statement_ids.is_some()
+        {
+            return Ok(());
+        }
+
+        // the simple query protocol prepares no statement, whose name could be taken
+        // language=SQL
+        let rows = self
+            .fetch_all("SELECT name FROM pg_catalog.pg_prepared_statements WHERE from_sql")
+            .await?;
+
+        let prefix = &*self.options.statement_name_prefix;
+        let mut taken = HashSet::new();
+
+        for row in rows {
+            let name: String = row.try_get(0)?;
+
+            if let Some(id) = name.strip_prefix(prefix).and_then(|id| id.parse().ok()) {
+                taken.insert(id);
+            }
+        }
+
+        self.taken_statement_ids = Some(taken);
+
+        Ok(())
+    }
+
+    // Returns the ID of the next statement to prepare, skipping the names which are known to
+    // be taken
+    fn next_statement_id(&mut self) -> u32 {
+        loop {
+            let id = self.next_statement_id;
+            self.next_statement_id = self.next_statement_id.wrapping_add(1);
+
+            match &self.taken_statement_ids {
+                Some(taken) if taken.contains(&id) => {}
+                _ => return id,
+            }
+        }
+    }
+
+    async fn recv_description(
+        &mut self,
+    ) -> Result<(ParameterDescription, Option<RowDescription>), Error> {
+        // indicates that the SQL query string is now successfully parsed and has semantic validity
+        let _: () = self
+            .stream
+            .recv_expect(MessageFormat::ParseComplete)
+            .await?;
+
+        let parameters = recv_desc_params(self).await?;
+        let rows = recv_desc_rows(self).await?;
+
+        Ok((parameters, rows))
+    }
 }
 
 impl<'c> Executor<'c> for &'c mut PgConnection {
@@ -345,8 +934,13 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let deferred_check = query.deferred_check();
 
         Box::pin(try_stream! {
+            if let Some(check) = deferred_check {
+                check.run(&mut *self, sql).await?;
+            }
+
             let s = self.run(sql, arguments, 0, persistent, metadata).await?;
             pin_mut!(s);
 
@@ -370,8 +964,13 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments();
         let persistent = query.persistent();
+        let deferred_check = query.deferred_check();
 
         Box::pin(async move {
+            if let Some(check) = deferred_check {
+                check.run(&mut *self, sql).await?;
+            }
+
             let s = self.run(sql, arguments, 1, persistent, metadata).await?;
             pin_mut!(s);
 
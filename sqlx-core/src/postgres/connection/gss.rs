@@ -0,0 +1,287 @@
+use libgssapi::context::{ClientCtx, CtxFlags, SecurityContext};
+use libgssapi::credential::{Cred, CredUsage};
+use libgssapi::name::Name;
+use libgssapi::oid::{OidSet, GSS_MECH_KRB5, GSS_NT_HOSTBASED_SERVICE};
+
+use crate::error::Error;
+use crate::postgres::connection::stream::PgStream;
+use crate::postgres::message::{Authentication, MessageFormat, Password};
+use crate::postgres::PgConnectOptions;
+
+// GSSAPI authentication (RFC 2744), as done by `libpq`: the client initiates a security context
+// with the service of the server and exchanges tokens with it until the context is established.
+// SSPI requests are answered in the same way, which is compatible with Kerberos on Windows.
+
+pub(crate) async fn authenticate(
+    stream: &mut PgStream,
+    options: &PgConnectOptions,
+) -> Result<(), Error> {
+    if options.socket.is_some() || options.host.starts_with('/') {
+        return Err(Error::Configuration(
+            "GSSAPI authentication requires the host name of the server, \
+             not a Unix domain socket"
+                .into(),
+        ));
+    }
+
+    let target = format!("{}@{}", options.krb_service_name, options.host);
+    let mut handshake = Handshake::new(KerberosContext::new(&target)?);
+    let mut input = None;
+
+    loop {
+        let step = handshake.step(input.as_deref())?;
+
+        if let Some(token) = step.token {
+            stream.send(Password::Gss(&token)).await?;
+        }
+
+        if step.complete {
+            // the server follows with [Authentication::Ok]
+            return Ok(());
+        }
+
+        match stream.recv_expect(MessageFormat::Authentication).await? {
+            Authentication::GssContinue(data) => {
+                input = Some(data);
+            }
+
+            auth => {
+                return Err(err_protocol!(
+                    "expected GSSContinue but received {:?}",
+                    auth
+                ));
+            }
+        }
+    }
+}
+
+// The security context of the client, initiated with the service of the server.
+trait ClientContext {
+    // Processes the token of the server, if any; returns the token to send to the server, if any.
+    fn step(&mut self, input: Option<&[u8]>) -> Result<Option<Vec<u8>>, Error>;
+
+    fn is_complete(&self) -> bool;
+}
+
+// The result of a step of the handshake.
+#[derive(Debug, PartialEq)]
+struct Step {
+    // the token to send to the server in a GSSResponse
+    token: Option<Vec<u8>>,
+    // whether the context is established, after which the server sends AuthenticationOk
+    complete: bool,
+}
+
+// The exchange of tokens with the server, until the security context is established.
+struct Handshake<C> {
+    context: C,
+    started: bool,
+}
+
+impl<C: ClientContext> Handshake<C> {
+    fn new(context: C) -> Self {
+        Self {
+            context,
+            started: false,
+        }
+    }
+
+    // Starts the handshake without `input`, then continues it with the data of each GSSContinue
+    fn step(&mut self, input: Option<&[u8]>) -> Result<Step, Error> {
+        if self.context.is_complete() {
+            return Err(err_protocol!(
+                "received GSSContinue after the GSSAPI security context was established"
+            ));
+        }
+
+        if self.started && input.is_none() {
+            return Err(err_protocol!(
+                "the GSSAPI handshake continues with the data of a GSSContinue"
+            ));
+        }
+
+        self.started = true;
+
+        // an empty token is not sent, as the server would take it for the end of the exchange
+        let token = self.context.step(input)?.filter(|token| !token.is_empty());
+
+        Ok(Step {
+            token,
+            complete: self.context.is_complete(),
+        })
+    }
+}
+
+// A Kerberos security context, from the credentials of the user (e.g. obtained with `kinit`).
+struct KerberosContext(ClientCtx);
+
+impl KerberosContext {
+    fn new(target: &str) -> Result<Self, Error> {
+        let name = Name::new(target.as_bytes(), Some(&GSS_NT_HOSTBASED_SERVICE))
+            .and_then(|name| name.canonicalize(Some(&GSS_MECH_KRB5)))
+            .map_err(|error| {
+                gss_error(
+                    &format!("failed to import the name of the service {:?}", target),
+                    error,
+                )
+            })?;
+
+        let mut mechanisms = OidSet::new()
+            .map_err(|error| gss_error("failed to select the Kerberos mechanism", error))?;
+
+        mechanisms
+            .add(&GSS_MECH_KRB5)
+            .map_err(|error| gss_error("failed to select the Kerberos mechanism", error))?;
+
+        let credentials = Cred::acquire(None, None, CredUsage::Initiate, Some(&mechanisms))
+            .map_err(|error| gss_error("failed to acquire the Kerberos credentials", error))?;
+
+        Ok(Self(ClientCtx::new(
+            credentials,
+            name,
+            CtxFlags::GSS_C_MUTUAL_FLAG,
+            Some(&GSS_MECH_KRB5),
+        )))
+    }
+}
+
+impl ClientContext for KerberosContext {
+    fn step(&mut self, input: Option<&[u8]>) -> Result<Option<Vec<u8>>, Error> {
+        let token = self
+            .0
+            .step(input)
+            .map_err(|error| gss_error("failed to initiate the GSSAPI security context", error))?;
+
+        Ok(token.map(|token| token.to_vec()))
+    }
+
+    fn is_complete(&self) -> bool {
+        self.0.is_complete()
+    }
+}
+
+fn gss_error(context: &str, error: libgssapi::error::Error) -> Error {
+    Error::Configuration(format!("{}: {}", context, error).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClientContext, Handshake, Step};
+    use crate::error::Error;
+    use std::collections::VecDeque;
+
+    // A context returning the given tokens, in turn, for the expected tokens of the server;
+    // established after the last one.
+    struct MockContext {
+        steps: VecDeque<(Option<&'static [u8]>, Result<Option<&'static [u8]>, ()>)>,
+    }
+
+    impl MockContext {
+        fn new(steps: &[(Option<&'static [u8]>, Result<Option<&'static [u8]>, ()>)]) -> Self {
+            Self {
+                steps: steps.iter().cloned().collect(),
+            }
+        }
+    }
+
+    impl ClientContext for MockContext {
+        fn step(&mut self, input: Option<&[u8]>) -> Result<Option<Vec<u8>>, Error> {
+            let (expected, output) = self.steps.pop_front().expect("unexpected step");
+
+            assert_eq!(input, expected);
+
+            match output {
+                Ok(token) => Ok(token.map(<[u8]>::to_vec)),
+                Err(()) => Err(Error::Configuration("mock failure".into())),
+            }
+        }
+
+        fn is_complete(&self) -> bool {
+            self.steps.is_empty()
+        }
+    }
+
+    fn step(token: Option<&[u8]>, complete: bool) -> Step {
+        Step {
+            token: token.map(<[u8]>::to_vec),
+            complete,
+        }
+    }
+
+    #[test]
+    fn it_exchanges_tokens_until_the_context_is_established() {
+        let mut handshake = Handshake::new(MockContext::new(&[
+            (None, Ok(Some(b"c1"))),
+            (Some(b"s1"), Ok(Some(b"c2"))),
+            (Some(b"s2"), Ok(None)),
+        ]));
+
+        assert_eq!(handshake.step(None).unwrap(), step(Some(b"c1"), false));
+        assert_eq!(
+            handshake.step(Some(b"s1")).unwrap(),
+            step(Some(b"c2"), false)
+        );
+        assert_eq!(handshake.step(Some(b"s2")).unwrap(), step(None, true));
+    }
+
+    #[test]
+    fn it_sends_the_last_token_of_the_client() {
+        let mut handshake = Handshake::new(MockContext::new(&[
+            (None, Ok(Some(b"c1"))),
+            (Some(b"s1"), Ok(Some(b"c2"))),
+        ]));
+
+        assert_eq!(handshake.step(None).unwrap(), step(Some(b"c1"), false));
+        assert_eq!(
+            handshake.step(Some(b"s1")).unwrap(),
+            step(Some(b"c2"), true)
+        );
+    }
+
+    #[test]
+    fn it_does_not_send_empty_tokens() {
+        let mut handshake = Handshake::new(MockContext::new(&[
+            (None, Ok(Some(b""))),
+            (Some(b"s1"), Ok(Some(b""))),
+        ]));
+
+        assert_eq!(handshake.step(None).unwrap(), step(None, false));
+        assert_eq!(handshake.step(Some(b"s1")).unwrap(), step(None, true));
+    }
+
+    #[test]
+    fn it_rejects_a_token_after_the_context_is_established() {
+        let mut handshake = Handshake::new(MockContext::new(&[(None, Ok(Some(b"c1")))]));
+
+        assert_eq!(handshake.step(None).unwrap(), step(Some(b"c1"), true));
+        assert!(matches!(
+            handshake.step(Some(b"s1")),
+            Err(Error::Protocol(_))
+        ));
+    }
+
+    #[test]
+    fn it_rejects_a_step_without_the_token_of_the_server() {
+        let mut handshake = Handshake::new(MockContext::new(&[
+            (None, Ok(Some(b"c1"))),
+            (Some(b"s1"), Ok(None)),
+        ]));
+
+        assert_eq!(handshake.step(None).unwrap(), step(Some(b"c1"), false));
+        assert!(matches!(handshake.step(None), Err(Error::Protocol(_))));
+    }
+
+    #[test]
+    fn it_fails_with_the_context() {
+        let mut handshake = Handshake::new(MockContext::new(&[
+            (None, Ok(Some(b"c1"))),
+            (Some(b"s1"), Err(())),
+        ]));
+
+        assert_eq!(handshake.step(None).unwrap(), step(Some(b"c1"), false));
+        assert!(matches!(
+            handshake.step(Some(b"s1")),
+            Err(Error::Configuration(_))
+        ));
+    }
+}
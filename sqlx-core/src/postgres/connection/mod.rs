@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 
@@ -7,25 +8,41 @@ use futures_util::{FutureExt, TryFutureExt};
 
 use crate::common::StatementCache;
 use crate::connection::{Connection, LogSettings};
-use crate::error::Error;
+use crate::error::{BoxDynError, Error};
 use crate::executor::Executor;
 use crate::ext::ustr::UStr;
 use crate::io::Decode;
 use crate::postgres::connection::stream::PgStream;
 use crate::postgres::message::{
-    Close, Message, MessageFormat, ReadyForQuery, Terminate, TransactionStatus,
+    Message, MessageFormat, PgTransactionStatus, ReadyForQuery, Terminate,
 };
 use crate::postgres::statement::PgStatementMetadata;
-use crate::postgres::{PgConnectOptions, PgTypeInfo, Postgres};
+use crate::postgres::types::{PgAnyTypeCodec, PgMoney, PgTypeCodec};
+use crate::postgres::{
+    PgConnectOptions, PgDialect, PgTransactionManager, PgTransactionOptions, PgTypeInfo, Postgres,
+};
 use crate::transaction::Transaction;
 
+mod cancel;
+mod copy;
+mod copy_encoder;
 pub(crate) mod describe;
 mod establish;
 mod executor;
+#[cfg(feature = "gssapi")]
+mod gss;
+mod pipeline;
 mod sasl;
+mod statement_cache;
 mod stream;
 mod tls;
 
+pub use cancel::PgCancelToken;
+pub use copy::PgCopyIn;
+pub use copy_encoder::{PgCopyInEncoder, PgCopyRow};
+pub use pipeline::{PgPipeline, PgPipelineResult};
+pub use statement_cache::PgStatementCacheStats;
+
 /// A connection to a PostgreSQL database.
 pub struct PgConnection {
     // underlying TCP or UDS stream,
@@ -35,18 +52,30 @@ pub struct PgConnection {
 
     // process id of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
     process_id: u32,
 
     // secret key of this backend
     // used to send cancel requests
-    #[allow(dead_code)]
     secret_key: u32,
 
+    // dialect of the server, detected or forced by the options
+    dialect: PgDialect,
+
+    // options the connection was opened with
+    // used to open the connections of cancel requests
+    options: Arc<PgConnectOptions>,
+
     // sequence of statement IDs for use in preparing statements
     // in PostgreSQL, the statement is prepared to a user-supplied identifier
     next_statement_id: u32,
 
+    // whether a statement name was found taken by a statement prepared by the user
+    statement_names_taken: bool,
+
+    // IDs of the statement names taken by statements prepared by the user, which are skipped;
+    // loaded in a transaction block, where a name which is taken aborts the transaction
+    taken_statement_ids: Option<HashSet<u32>>,
+
     // cache statement by query string to the id and columns
     cache_statement: StatementCache<(u32, Arc<PgStatementMetadata>)>,
 
@@ -54,11 +83,26 @@ pub struct PgConnection {
     cache_type_info: HashMap<u32, PgTypeInfo>,
     cache_type_oid: HashMap<UStr, u32>,
 
+    // cache the names of tables by id
+    cache_relation_name: HashMap<u32, UStr>,
+
+    // codecs registered for types unknown to SQLx, by type name
+    type_codecs: HashMap<UStr, PgAnyTypeCodec>,
+
+    // number of rows fetched at a time by `fetch_many`, or 0 to fetch them all at once
+    fetch_size: u32,
+
+    // whether accessing a column of a row by name fails if more than one column has that name
+    strict_column_names: bool,
+
+    // set while the rows of a query are fetched in chunks, until it is followed by a Sync
+    sync_pending: bool,
+
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
     // current transaction status
-    transaction_status: TransactionStatus,
+    transaction_status: PgTransactionStatus,
     pub(crate) transaction_depth: usize,
 
     log_settings: LogSettings,
@@ -67,6 +111,13 @@ pub struct PgConnection {
 impl PgConnection {
     // will return when the connection is ready for another query
     async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        if self.sync_pending {
+            // the rest of the rows of the query were not fetched; the Sync closes the portal
+            // and the server skips anything up to it after an error
+            self.sync_pending = false;
+            self.write_sync();
+        }
+
         if !self.stream.wbuf.is_empty() {
             self.stream.flush().await?;
         }
@@ -94,17 +145,159 @@ impl PgConnection {
         Ok(())
     }
 
-    fn handle_ready_for_query(&mut self, message: Message) -> Result<(), Error> {
+    pub(crate) fn handle_ready_for_query(&mut self, message: Message) -> Result<(), Error> {
         self.pending_ready_for_query_count -= 1;
         self.transaction_status = ReadyForQuery::decode(message.contents)?.transaction_status;
 
+        if self.transaction_status == PgTransactionStatus::Idle {
+            // outside of a transaction block, a name which is taken is retried instead
+            self.taken_statement_ids = None;
+        }
+
         Ok(())
     }
 }
 
+impl PgConnection {
+    /// Returns the transaction status of the connection, as last reported by the server.
+    ///
+    /// The server reports the status once it is ready for the next query. When a query fails,
+    /// the status is only received when the connection is used again (e.g. with
+    /// [`ping`][Connection::ping]).
+    ///
+    /// A connection in [`PgTransactionStatus::Error`] rejects every query until the transaction
+    /// is rolled back.
+    pub fn transaction_status(&self) -> PgTransactionStatus {
+        self.transaction_status
+    }
+
+    /// Begins a new transaction with the given options, e.g. to set its isolation level.
+    ///
+    /// Within a transaction, a savepoint is established instead, and `options` are ignored.
+    pub fn begin_with(
+        &mut self,
+        options: PgTransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Postgres>, Error>> {
+        Box::pin(async move {
+            PgTransactionManager::begin_with(self, &options).await?;
+
+            Ok(Transaction::begun(self))
+        })
+    }
+
+    /// Returns the version of the server in the format of `server_version_num`
+    /// (e.g. `150004` for PostgreSQL 15.4), as reported when connecting.
+    ///
+    /// Returns `None` if the server did not report a version which could be parsed.
+    pub fn server_version_num(&self) -> Option<u32> {
+        self.stream.server_version_num
+    }
+
+    // features of newer servers are still attempted if the version is unknown; CockroachDB
+    // reports the version of PostgreSQL it is compatible with, not the features it supports
+    pub(crate) fn server_version_at_least(&self, version_num: u32) -> bool {
+        self.dialect == PgDialect::Postgres
+            && self
+                .server_version_num()
+                .map_or(true, |version| version >= version_num)
+    }
+
+    /// Returns the dialect of the server, detected when connecting or forced with
+    /// [`PgConnectOptions::dialect`].
+    pub fn dialect(&self) -> PgDialect {
+        self.dialect
+    }
+
+    /// Returns the number of fractional digits of `MONEY` values, as set by the `lc_monetary`
+    /// setting of the server, to be passed to the conversions of [`PgMoney`].
+    ///
+    /// `lc_monetary` may be changed within a session, so the server is asked every time.
+    ///
+    /// ```rust,ignore
+    /// let scale = conn.money_scale().await?;
+    ///
+    /// let price: PgMoney = sqlx::query_scalar("SELECT price FROM products")
+    ///     .fetch_one(&mut conn)
+    ///     .await?;
+    ///
+    /// let price = price.to_decimal(scale);
+    /// ```
+    pub async fn money_scale(&mut self) -> Result<u32, Error> {
+        // MONEY is stored as an integer count of the smallest currency unit
+        let one: PgMoney = crate::query_scalar::query_scalar("SELECT 1::money")
+            .fetch_one(&mut *self)
+            .await?;
+
+        let mut units = one.0;
+        let mut scale = 0;
+
+        while units >= 10 && units % 10 == 0 {
+            units /= 10;
+            scale += 1;
+        }
+
+        if units != 1 {
+            return Err(err_protocol!(
+                "unexpected value of 1::money: {} (expected a power of 10)",
+                one.0
+            ));
+        }
+
+        Ok(scale)
+    }
+
+    /// Registers the functions converting the values of a type unknown to SQLx, such as the
+    /// type of an extension, from and to its binary format.
+    ///
+    /// Columns of this type can then be decoded as [`PgCodecValue<T>`], and values are bound with
+    /// the returned codec (see [`PgTypeCodec::value`]).
+    ///
+    /// The codec only applies to statements prepared after it is registered, so it is best done
+    /// right after connecting, e.g. in [`PoolOptions::after_connect`].
+    ///
+    /// ```rust,ignore
+    /// let ltree = conn.register_type_codec(
+    ///     "ltree",
+    ///     |path: &String, buf| {
+    ///         // version of the binary format
+    ///         buf.push(1);
+    ///         buf.extend_from_slice(path.as_bytes());
+    ///         Ok(())
+    ///     },
+    ///     |bytes| Ok(std::str::from_utf8(&bytes[1..])?.to_owned()),
+    /// );
+    /// ```
+    ///
+    /// [`PgCodecValue<T>`]: crate::postgres::types::PgCodecValue
+    /// [`PoolOptions::after_connect`]: crate::pool::PoolOptions::after_connect
+    pub fn register_type_codec<T, E, D>(
+        &mut self,
+        type_name: &str,
+        encode: E,
+        decode: D,
+    ) -> PgTypeCodec<T>
+    where
+        T: Send + Sync + 'static,
+        E: Fn(&T, &mut Vec<u8>) -> Result<(), BoxDynError> + Send + Sync + 'static,
+        D: Fn(&[u8]) -> Result<T, BoxDynError> + Send + Sync + 'static,
+    {
+        let codec = PgTypeCodec::new(type_name, encode, decode);
+
+        self.type_codecs
+            .insert(type_name.to_owned().into(), codec.erase());
+
+        // the type information fetched so far does not carry the codec
+        self.cache_type_info.clear();
+
+        codec
+    }
+}
+
 impl Debug for PgConnection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("PgConnection").finish()
+        f.debug_struct("PgConnection")
+            .field("transaction_status", &self.transaction_status)
+            .finish()
     }
 }
 
@@ -146,24 +339,17 @@ impl Connection for PgConnection {
 
     fn clear_cached_statements(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
-            let mut cleared = 0_usize;
-
             self.wait_until_ready().await?;
 
-            while let Some((id, _)) = self.cache_statement.remove_lru() {
-                self.stream.write(Close::Statement(id));
-                cleared += 1;
-            }
-
-            if cleared > 0 {
-                self.write_sync();
-                self.stream.flush().await?;
+            // the pinned statements are cleared too
+            let ids = self
+                .cache_statement
+                .drain()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
 
-                self.wait_for_close_complete(cleared).await?;
-                self.recv_ready_for_query().await?;
-            }
-
-            Ok(())
+            self.close_statements(ids).await
         })
     }
 
@@ -176,4 +362,9 @@ impl Connection for PgConnection {
     fn should_flush(&self) -> bool {
         !self.stream.wbuf.is_empty()
     }
+
+    #[doc(hidden)]
+    fn in_transaction_block(&self) -> bool {
+        self.transaction_status != PgTransactionStatus::Idle
+    }
 }
@@ -0,0 +1,240 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use crate::error::{Error, Limit};
+use crate::executor::Execute;
+use crate::logger::QueryLogger;
+use crate::postgres::connection::executor::{
+    check_arguments, check_parameter_count, MAX_MESSAGE_SIZE,
+};
+use crate::postgres::message::{CommandComplete, DataRow, MessageFormat};
+use crate::postgres::statement::PgStatementMetadata;
+use crate::postgres::{PgArguments, PgConnection, PgRow, PgValueFormat, Postgres};
+
+/// A list of queries which are sent to the server together and executed in one round trip.
+///
+/// On a link with a high latency, this saves a round trip per query compared to executing them
+/// one after the other:
+///
+/// ```rust,ignore
+/// let mut pipeline = PgPipeline::new();
+///
+/// pipeline
+///     .push(sqlx::query("UPDATE accounts SET balance = balance - $1 WHERE id = $2").bind(10).bind(1))
+///     .push(sqlx::query("UPDATE accounts SET balance = balance + $1 WHERE id = $2").bind(10).bind(2))
+///     .push(sqlx::query("SELECT id, balance FROM accounts WHERE id IN (1, 2)"));
+///
+/// let results = pipeline.run(&mut conn).await?;
+///
+/// assert_eq!(results[0].rows_affected(), 1);
+/// let balances = results[2].rows();
+/// ```
+///
+/// The queries are executed as prepared statements; those which are not already prepared on
+/// the connection are prepared first, which takes a round trip for each of them.
+///
+/// As there is a single synchronization point at the end of the pipeline, the queries are
+/// executed in an implicit transaction unless one is already open: if one of them fails,
+/// the following ones are skipped and, outside of a transaction, none of them is committed.
+/// Statements which cannot be executed in a transaction, such as `VACUUM`, cannot be pipelined.
+#[derive(Default)]
+pub struct PgPipeline<'q> {
+    queries: Vec<PipelinedQuery<'q>>,
+}
+
+struct PipelinedQuery<'q> {
+    sql: &'q str,
+    arguments: PgArguments,
+    persistent: bool,
+    metadata: Option<Arc<PgStatementMetadata>>,
+}
+
+/// The result of a query of a [`PgPipeline`].
+pub struct PgPipelineResult {
+    rows_affected: u64,
+    rows: Vec<PgRow>,
+}
+
+impl<'q> PgPipeline<'q> {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a query to the pipeline.
+    pub fn push<E>(&mut self, mut query: E) -> &mut Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        self.queries.push(PipelinedQuery {
+            sql: query.sql(),
+            metadata: query.statement().map(|s| Arc::clone(&s.metadata)),
+            arguments: query.take_arguments().unwrap_or_default(),
+            persistent: query.persistent(),
+        });
+
+        self
+    }
+
+    /// Returns the number of queries in the pipeline.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Returns `true` if there is no query in the pipeline.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Executes the queries of the pipeline, and returns their results in the same order.
+    ///
+    /// The error of the first query which failed is returned, if any.
+    pub async fn run(self, conn: &mut PgConnection) -> Result<Vec<PgPipelineResult>, Error> {
+        if self.queries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        conn.wait_until_ready().await?;
+
+        let mut prepared = Vec::with_capacity(self.queries.len());
+
+        for mut query in self.queries {
+            Error::check_limit(Limit::MessageSize, MAX_MESSAGE_SIZE, query.sql.len())?;
+            check_arguments(&mut query.arguments)?;
+
+            let (statement, metadata) = conn
+                .get_or_prepare(
+                    query.sql,
+                    &query.arguments.types,
+                    query.persistent,
+                    query.metadata.clone(),
+                )
+                .await?;
+
+            check_parameter_count(&query.arguments, &metadata)?;
+
+            prepared.push((query, statement, metadata));
+        }
+
+        for (query, statement, metadata) in &mut prepared {
+            // preparing the statements may have evicted (and closed) those prepared before
+            let evicted = query.persistent
+                && conn.cache_statement.is_enabled()
+                && conn
+                    .cache_statement
+                    .get_mut(query.sql)
+                    .map_or(true, |(id, _)| *id != *statement);
+
+            if evicted {
+                let (id, _) = conn
+                    .get_or_prepare(query.sql, &query.arguments.types, false, None)
+                    .await?;
+
+                *statement = id;
+            }
+
+            // patch holes created during encoding
+            query
+                .arguments
+                .apply_patches(conn, &metadata.parameters)
+                .await?;
+        }
+
+        // patching may have looked up types by name, make sure those queries are finished
+        // before sending the pipeline
+        conn.wait_until_ready().await?;
+
+        for (query, statement, metadata) in &prepared {
+            conn.write_execute(*statement, &query.arguments, metadata, 0);
+        }
+
+        conn.write_sync();
+        conn.stream.flush().await?;
+
+        let mut results = Vec::with_capacity(prepared.len());
+
+        for (query, _, metadata) in &prepared {
+            let mut logger = QueryLogger::new(query.sql, conn.log_settings.clone());
+            let mut rows = Vec::new();
+
+            let _: () = conn.stream.recv_expect(MessageFormat::BindComplete).await?;
+
+            loop {
+                let message = conn.stream.recv().await?;
+
+                match message.format {
+                    MessageFormat::DataRow => {
+                        logger.increment_rows();
+
+                        let data: DataRow = message.decode()?;
+
+                        rows.push(PgRow {
+                            data,
+                            format: PgValueFormat::Binary,
+                            metadata: Arc::clone(metadata),
+                            strict_column_names: conn.strict_column_names,
+                        });
+                    }
+
+                    MessageFormat::CommandComplete => {
+                        let cc: CommandComplete = message.decode()?;
+
+                        results.push(PgPipelineResult {
+                            rows_affected: cc.rows_affected(),
+                            rows,
+                        });
+
+                        break;
+                    }
+
+                    // the statement was empty
+                    MessageFormat::EmptyQueryResponse => {
+                        results.push(PgPipelineResult {
+                            rows_affected: 0,
+                            rows,
+                        });
+
+                        break;
+                    }
+
+                    _ => {
+                        return Err(err_protocol!(
+                            "pipeline: unexpected message: {:?}",
+                            message.format
+                        ));
+                    }
+                }
+            }
+        }
+
+        conn.recv_ready_for_query().await?;
+
+        Ok(results)
+    }
+}
+
+impl PgPipelineResult {
+    /// The number of rows inserted, updated or deleted by the query, or returned by a `SELECT`.
+    pub fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+
+    /// The rows returned by the query.
+    pub fn rows(&self) -> &[PgRow] {
+        &self.rows
+    }
+
+    /// Returns the rows returned by the query.
+    pub fn into_rows(self) -> Vec<PgRow> {
+        self.rows
+    }
+}
+
+impl Debug for PgPipelineResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgPipelineResult")
+            .field("rows_affected", &self.rows_affected)
+            .field("rows", &self.rows.len())
+            .finish()
+    }
+}
@@ -10,7 +10,12 @@ use sha2::digest::Digest;
 use sha2::Sha256;
 use stringprep::saslprep;
 
+// the client does not support channel binding
 const GS2_HEADER: &str = "n,,";
+// the client supports channel binding, but the server does not seem to
+const GS2_HEADER_BINDING_SUPPORTED: &str = "y,,";
+// the client uses the `tls-server-end-point` channel binding, with `SCRAM-SHA-256-PLUS`
+const GS2_HEADER_BINDING: &str = "p=tls-server-end-point,,";
 const CHANNEL_ATTR: &str = "c";
 const USERNAME_ATTR: &str = "n";
 const CLIENT_PROOF_ATTR: &str = "p";
@@ -48,8 +53,36 @@ pub(crate) async fn authenticate(
         ));
     }
 
+    // the hash of the certificate of the server, if the connection uses TLS
+    let (gs2_header, channel_binding_data) = match stream.tls_server_end_point() {
+        Some(data) if has_sasl_plus => (GS2_HEADER_BINDING, Some(data)),
+        Some(_) => (GS2_HEADER_BINDING_SUPPORTED, None),
+
+        None if !has_sasl => {
+            return Err(err_protocol!(
+                "the server requires SCRAM-SHA-256-PLUS, which needs the channel binding data \
+                 of a TLS connection"
+            ));
+        }
+
+        None => (GS2_HEADER, None),
+    };
+
+    let plus = channel_binding_data.is_some();
+
+    // cbind-input = gs2-header [ cbind-data ]
+    let mut channel_binding_input = gs2_header.as_bytes().to_vec();
+
+    if let Some(data) = &channel_binding_data {
+        channel_binding_input.extend_from_slice(data);
+    }
+
     // channel-binding = "c=" base64
-    let channel_binding = format!("{}={}", CHANNEL_ATTR, base64::encode(GS2_HEADER));
+    let channel_binding = format!(
+        "{}={}",
+        CHANNEL_ATTR,
+        base64::encode(&channel_binding_input)
+    );
 
     // "n=" saslname ;; Usernames are prepared using SASLprep.
     let username = format!("{}={}", USERNAME_ATTR, options.username);
@@ -68,14 +101,14 @@ pub(crate) async fn authenticate(
 
     let client_first_message = format!(
         "{gs2_header}{client_first_message_bare}",
-        gs2_header = GS2_HEADER,
+        gs2_header = gs2_header,
         client_first_message_bare = client_first_message_bare
     );
 
     stream
         .send(SaslInitialResponse {
             response: &client_first_message,
-            plus: false,
+            plus,
         })
         .await?;
 
@@ -0,0 +1,136 @@
+use crate::error::Error;
+use crate::postgres::message::{Close, StatementName};
+use crate::postgres::PgConnection;
+
+/// The state of the cache of prepared statements of a [`PgConnection`], returned by
+/// [`PgConnection::statement_cache_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PgStatementCacheStats {
+    /// The number of statements in the cache, including the pinned ones.
+    pub len: usize,
+
+    /// The number of pinned statements, which are not counted in the capacity.
+    pub pinned: usize,
+
+    /// The maximum number of statements which are not pinned.
+    pub capacity: usize,
+
+    /// The number of queries which found their statement in the cache.
+    pub hits: u64,
+
+    /// The number of queries which prepared their statement.
+    pub misses: u64,
+}
+
+impl PgConnection {
+    /// Returns the number of statements in the cache of prepared statements, its capacity and
+    /// the number of hits and misses since the connection was opened.
+    pub fn statement_cache_stats(&self) -> PgStatementCacheStats {
+        PgStatementCacheStats {
+            len: self.cache_statement.len(),
+            pinned: self.cache_statement.len_pinned(),
+            capacity: self.cache_statement.capacity(),
+            hits: self.cache_statement.hits(),
+            misses: self.cache_statement.misses(),
+        }
+    }
+
+    /// Prepares a statement, if it is not already in the cache, and pins it: it is then never
+    /// evicted as the least recently used statement, until it is unpinned.
+    ///
+    /// A pinned statement is still removed by
+    /// [`clear_cached_statements`](crate::connection::Connection::clear_cached_statements) and
+    /// [`uncache_statement`](Self::uncache_statement).
+    pub async fn pin_statement(&mut self, sql: &str) -> Result<(), Error> {
+        self.wait_until_ready().await?;
+
+        if self.cache_statement.is_pinned(sql) {
+            return Ok(());
+        }
+
+        let statement = self.get_or_prepare(sql, &[], false, None).await?;
+
+        // the statement was only moved within the cache, if it was already in it
+        if let Some((id, _)) = self.cache_statement.pin(sql, statement.clone()) {
+            if id != statement.0 {
+                self.close_statements(vec![id]).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unpins a statement, which is then evicted like the other statements of the cache.
+    ///
+    /// Returns `false` if the statement was not pinned.
+    pub async fn unpin_statement(&mut self, sql: &str) -> Result<bool, Error> {
+        if !self.cache_statement.is_pinned(sql) {
+            return Ok(false);
+        }
+
+        self.wait_until_ready().await?;
+
+        // the cache is disabled, or full
+        match self.cache_statement.unpin(sql) {
+            Err((id, _)) | Ok(Some((id, _))) => self.close_statements(vec![id]).await?,
+            Ok(None) => {}
+        }
+
+        Ok(true)
+    }
+
+    /// Removes a statement from the cache, pinned or not, and closes it on the server, e.g.
+    /// after a change of the schema made its result type stale.
+    ///
+    /// Returns `false` if the statement was not in the cache.
+    pub async fn uncache_statement(&mut self, sql: &str) -> Result<bool, Error> {
+        if !self.cache_statement.contains_key(sql) {
+            return Ok(false);
+        }
+
+        self.wait_until_ready().await?;
+
+        if let Some((id, _)) = self.cache_statement.remove(sql) {
+            self.close_statements(vec![id]).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Changes the maximum number of statements in the cache, besides the pinned ones, and
+    /// closes the least recently used statements which no longer fit.
+    ///
+    /// A capacity of `0` disables the cache, except for pinned statements.
+    ///
+    /// See [`PgConnectOptions::statement_cache_capacity`].
+    ///
+    /// [`PgConnectOptions::statement_cache_capacity`]: crate::postgres::PgConnectOptions::statement_cache_capacity
+    pub async fn set_statement_cache_capacity(&mut self, capacity: usize) -> Result<(), Error> {
+        self.wait_until_ready().await?;
+
+        let evicted = self.cache_statement.set_capacity(capacity);
+
+        self.close_statements(evicted.into_iter().map(|(id, _)| id).collect())
+            .await
+    }
+
+    pub(super) async fn close_statements(&mut self, ids: Vec<u32>) -> Result<(), Error> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        for id in &ids {
+            self.stream.write(Close::Statement(StatementName::Id(
+                &self.options.statement_name_prefix,
+                *id,
+            )));
+        }
+
+        self.write_sync();
+        self.stream.flush().await?;
+
+        self.wait_for_close_complete(ids.len()).await?;
+        self.recv_ready_for_query().await
+    }
+}
@@ -8,8 +8,10 @@ use log::Level;
 use crate::error::Error;
 use crate::io::{BufStream, Decode, Encode};
 use crate::net::{MaybeTlsStream, Socket};
-use crate::postgres::message::{Message, MessageFormat, Notice, Notification};
-use crate::postgres::{PgConnectOptions, PgDatabaseError, PgSeverity};
+use crate::postgres::message::{Message, MessageFormat, Notice, Notification, ParameterStatus};
+#[cfg(feature = "protocol-hooks")]
+use crate::postgres::{protocol_hook, PgProtocolMessage};
+use crate::postgres::{PgConnectOptions, PgDatabaseError, PgNotice, PgSeverity};
 
 // the stream is a separate type from the connection to uphold the invariant where an instantiated
 // [PgConnection] is a **valid** connection to postgres
@@ -27,13 +29,38 @@ pub struct PgStream {
     // this is set when creating a PgListener and only written to if that listener is
     // re-used for query execution in-between receiving messages
     pub(crate) notifications: Option<UnboundedSender<Notification>>,
+
+    // version of the server in the format of `server_version_num`, as reported on startup
+    pub(crate) server_version_num: Option<u32>,
+
+    // set if the server reported a `crdb_version`, i.e. it is CockroachDB
+    pub(crate) is_cockroachdb: bool,
+
+    // called with the notices received from the server, in addition to logging them
+    notice_handler: Option<fn(PgNotice)>,
+
+    // called with every message sent or received
+    #[cfg(feature = "protocol-hooks")]
+    protocol_hook: Option<fn(&PgProtocolMessage)>,
 }
 
 impl PgStream {
     pub(super) async fn connect(options: &PgConnectOptions) -> Result<Self, Error> {
-        let socket = match options.fetch_socket() {
-            Some(ref path) => Socket::connect_uds(path).await?,
-            None => Socket::connect_tcp(&options.host, options.port).await?,
+        #[cfg(feature = "transcripts")]
+        let socket = match options.transcript {
+            // a transcript is replayed without connecting to the server
+            Some(ref transcript) if !transcript.is_recording() => transcript.replay(),
+            Some(ref transcript) => transcript.record(connect_socket(options).await?),
+            None => connect_socket(options).await?,
+        };
+
+        #[cfg(not(feature = "transcripts"))]
+        let socket = connect_socket(options).await?;
+
+        #[cfg(feature = "fault-injection")]
+        let socket = match options.fault_injector {
+            Some(ref injector) => injector.connect(socket)?,
+            None => socket,
         };
 
         let inner = BufStream::new(MaybeTlsStream::Raw(socket));
@@ -41,9 +68,30 @@ impl PgStream {
         Ok(Self {
             inner,
             notifications: None,
+            server_version_num: None,
+            is_cockroachdb: false,
+            notice_handler: options.notice_handler,
+            #[cfg(feature = "protocol-hooks")]
+            protocol_hook: options.protocol_hook,
         })
     }
 
+    // shadows `BufStream::write` so that every message written is given to the protocol hook
+    pub(crate) fn write<'en, T>(&mut self, message: T)
+    where
+        T: Encode<'en>,
+    {
+        #[cfg(feature = "protocol-hooks")]
+        let start = self.inner.wbuf.len();
+
+        self.inner.write(message);
+
+        #[cfg(feature = "protocol-hooks")]
+        if let Some(hook) = self.protocol_hook {
+            protocol_hook::frontend_messages(&self.inner.wbuf[start..], hook);
+        }
+    }
+
     pub(crate) async fn send<'en, T>(&mut self, message: T) -> Result<(), Error>
     where
         T: Encode<'en>,
@@ -75,11 +123,17 @@ impl PgStream {
         // this header contains the message type and the total length of the message
         let mut header: Bytes = self.inner.read(5).await?;
 
-        let format = MessageFormat::try_from_u8(header.get_u8())?;
+        let tag = header.get_u8();
+        let format = MessageFormat::try_from_u8(tag)?;
         let size = (header.get_u32() - 4) as usize;
 
         let contents = self.inner.read(size).await?;
 
+        #[cfg(feature = "protocol-hooks")]
+        if let Some(hook) = self.protocol_hook {
+            hook(&PgProtocolMessage::backend(tag, size + 5));
+        }
+
         Ok(Message { format, contents })
     }
 
@@ -108,14 +162,20 @@ impl PgStream {
                     // informs the frontend about the current (initial)
                     // setting of backend parameters
 
-                    // we currently have no use for that data so we promptly ignore this message
+                    // we only keep the version and the kind of the server, to know which
+                    // features it supports
+                    let status: ParameterStatus = message.decode()?;
+
+                    if status.name == "server_version" {
+                        self.server_version_num = parse_server_version_num(&status.value);
+                    } else if status.name == "crdb_version" {
+                        self.is_cockroachdb = true;
+                    }
+
                     continue;
                 }
 
                 MessageFormat::NoticeResponse => {
-                    // do we need this to be more configurable?
-                    // if you are reading this comment and think so, open an issue
-
                     let notice: Notice = message.decode()?;
 
                     let lvl = match notice.severity() {
@@ -139,6 +199,10 @@ impl PgStream {
                         );
                     }
 
+                    if let Some(handler) = self.notice_handler {
+                        handler(PgNotice(notice));
+                    }
+
                     continue;
                 }
 
@@ -150,6 +214,45 @@ impl PgStream {
     }
 }
 
+async fn connect_socket(options: &PgConnectOptions) -> Result<Socket, Error> {
+    Ok(match options.fetch_socket() {
+        Some(ref path) => Socket::connect_uds(path).await?,
+
+        None => {
+            let socket = Socket::connect_tcp(&options.host, options.port).await?;
+            socket.set_tcp_timeouts(options.tcp_keepalive, options.tcp_user_timeout)?;
+
+            socket
+        }
+    })
+}
+
+// parses a `server_version` such as `15.4 (Debian 15.4-1)`, `9.6.24` or `16beta1`
+// into the format of `server_version_num` (`150004`, `90624`, `160000`)
+fn parse_server_version_num(version: &str) -> Option<u32> {
+    let version = version.split_whitespace().next()?;
+
+    let mut parts = version.split('.').map(|part| {
+        let end = part
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or_else(|| part.len());
+
+        part[..end].parse::<u32>().ok()
+    });
+
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+
+    if major >= 10 {
+        Some(major * 10000 + minor)
+    } else {
+        // before PostgreSQL 10, the major version has two parts
+        let patch = parts.next().flatten().unwrap_or(0);
+
+        Some(major * 10000 + minor * 100 + patch)
+    }
+}
+
 impl Deref for PgStream {
     type Target = BufStream<MaybeTlsStream<Socket>>;
 
@@ -165,3 +268,16 @@ impl DerefMut for PgStream {
         &mut self.inner
     }
 }
+
+#[test]
+fn test_parse_server_version_num() {
+    assert_eq!(
+        parse_server_version_num("15.4 (Debian 15.4-1)"),
+        Some(150004)
+    );
+    assert_eq!(parse_server_version_num("9.6.24"), Some(90624));
+    assert_eq!(parse_server_version_num("16beta1"), Some(160000));
+    assert_eq!(parse_server_version_num("10devel"), Some(100000));
+    assert_eq!(parse_server_version_num("9.4"), Some(90400));
+    assert_eq!(parse_server_version_num("unknown"), None);
+}
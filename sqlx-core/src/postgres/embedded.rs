@@ -0,0 +1,268 @@
+//! A temporary PostgreSQL server, started from the binaries installed on the machine.
+//!
+//! The server only listens on a Unix domain socket in a private temporary directory, trusts
+//! every local connection, and is stopped and removed with the process that started it.
+//!
+//! Nothing is downloaded: the PostgreSQL server binaries (`initdb` and `postgres`) must be
+//! installed, e.g. with the `postgresql` package of the distribution.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::OnceCell;
+use url::Url;
+
+use crate::error::Error;
+use crate::postgres::PgConnectOptions;
+
+// the port of a socket is only part of its name, so it never conflicts with another server
+const PORT: u16 = 5432;
+
+const START_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Starts the server in the background and waits for the standard input to be closed, which
+// happens on drop or when the process exits, however it exits; then stops the server and removes
+// its directory. `stopped` is created when the server exits, to report a failure to start.
+const WATCHDOG: &str = r#"
+exec 3<&0
+"$1" -D "$2/data" -k "$2" -p "$3" -F -c listen_addresses= >"$2/postgres.log" 2>&1 &
+pid=$!
+{ read _ <&3; kill -INT $pid 2>/dev/null; } <&3 &
+wait $pid
+touch "$2/stopped"
+wait
+rm -rf "$2"
+"#;
+
+/// A temporary PostgreSQL server, for tests.
+///
+/// With the `embedded-postgres` feature, the tests of SQLx run without `DATABASE_URL` connect to
+/// the [shared](PgEmbedded::shared) server.
+///
+/// The database cluster is created with `initdb` in a new temporary directory and served by
+/// `postgres`, both looked up in the directory set by `SQLX_EMBEDDED_POSTGRES_BIN`, then in the
+/// one reported by `pg_config --bindir`, then in `PATH`. The superuser is `postgres`, without a
+/// password.
+///
+/// The server is stopped, and its directory removed, when the `PgEmbedded` is dropped or the
+/// process exits. Like `initdb`, it cannot be started as `root`.
+///
+/// ```rust,no_run
+/// # use sqlx_core::connection::Connection;
+/// # use sqlx_core::error::Error;
+/// # use sqlx_core::postgres::{PgConnection, PgEmbedded};
+/// #
+/// # #[cfg(feature = "_rt-async-std")]
+/// # sqlx_rt::block_on::<_, Result<(), Error>>(async move {
+/// let server = PgEmbedded::shared()?;
+/// let conn = PgConnection::connect_with(&server.connect_options()).await?;
+/// # Ok(())
+/// # }).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct PgEmbedded {
+    dir: PathBuf,
+    watchdog: Child,
+}
+
+impl PgEmbedded {
+    /// Creates a database cluster and starts a server for it.
+    ///
+    /// This blocks until the server accepts connections.
+    pub fn start() -> Result<Self, Error> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let bin = bin_dir();
+        let dir = env::temp_dir().join(format!(
+            "sqlx-embedded-postgres-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        fs::create_dir_all(&dir)?;
+
+        let initdb = Command::new(program(&bin, "initdb"))
+            .arg("-D")
+            .arg(dir.join("data"))
+            .args([
+                "-U",
+                "postgres",
+                "-A",
+                "trust",
+                "-E",
+                "UTF8",
+                "--locale=C",
+                "--no-sync",
+            ])
+            .stdin(Stdio::null())
+            .output();
+
+        let initdb = match initdb {
+            Ok(initdb) => initdb,
+            Err(error) => {
+                let _ = fs::remove_dir_all(&dir);
+
+                return Err(spawn_error("initdb", error));
+            }
+        };
+
+        if !initdb.status.success() {
+            let _ = fs::remove_dir_all(&dir);
+
+            return Err(Error::Configuration(
+                format!(
+                    "failed to create the database cluster of the embedded server: {}",
+                    String::from_utf8_lossy(&initdb.stderr).trim()
+                )
+                .into(),
+            ));
+        }
+
+        let watchdog = Command::new("sh")
+            .arg("-c")
+            .arg(WATCHDOG)
+            .arg("sh")
+            .arg(program(&bin, "postgres"))
+            .arg(&dir)
+            .arg(PORT.to_string())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+
+        let watchdog = match watchdog {
+            Ok(watchdog) => watchdog,
+            Err(error) => {
+                let _ = fs::remove_dir_all(&dir);
+
+                return Err(spawn_error("sh", error));
+            }
+        };
+
+        // from here, the directory is removed by the watchdog
+        let server = PgEmbedded { dir, watchdog };
+
+        server.wait_ready()?;
+
+        Ok(server)
+    }
+
+    /// Returns the server shared by the whole process, started on first use.
+    pub fn shared() -> Result<&'static Self, Error> {
+        static SHARED: OnceCell<PgEmbedded> = OnceCell::new();
+
+        SHARED.get_or_try_init(Self::start)
+    }
+
+    /// Returns the options to connect to the `postgres` database of the server.
+    pub fn connect_options(&self) -> PgConnectOptions {
+        PgConnectOptions::new()
+            .socket(&self.dir)
+            .port(PORT)
+            .username("postgres")
+            .database("postgres")
+    }
+
+    /// Returns the URL of the `postgres` database of the server, as accepted by `DATABASE_URL`.
+    pub fn url(&self) -> String {
+        let mut url = Url::parse(&format!("postgres://postgres@localhost:{}/postgres", PORT))
+            .expect("BUG: the URL of the embedded server is valid");
+
+        url.query_pairs_mut()
+            .append_pair("host", &self.dir.to_string_lossy());
+
+        url.to_string()
+    }
+
+    // the server writes `ready` on the 8th line of its pid file once it accepts connections
+    fn wait_ready(&self) -> Result<(), Error> {
+        let started = Instant::now();
+
+        loop {
+            let pid_file = fs::read_to_string(self.dir.join("data/postmaster.pid"));
+
+            if let Ok(pid_file) = pid_file {
+                if pid_file.lines().nth(7).map(str::trim) == Some("ready") {
+                    return Ok(());
+                }
+            }
+
+            if self.dir.join("stopped").exists() {
+                return Err(self.start_error("exited"));
+            }
+
+            if started.elapsed() > START_TIMEOUT {
+                return Err(self.start_error("did not accept connections in time"));
+            }
+
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    fn start_error(&self, reason: &str) -> Error {
+        let log = fs::read_to_string(self.dir.join("postgres.log")).unwrap_or_default();
+
+        Error::Configuration(
+            format!(
+                "the embedded server {} while starting: {}",
+                reason,
+                log.trim()
+            )
+            .into(),
+        )
+    }
+}
+
+impl Drop for PgEmbedded {
+    fn drop(&mut self) {
+        // closing the standard input of the watchdog stops the server
+        drop(self.watchdog.stdin.take());
+
+        let _ = self.watchdog.wait();
+    }
+}
+
+fn bin_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("SQLX_EMBEDDED_POSTGRES_BIN") {
+        return Some(dir.into());
+    }
+
+    let output = Command::new("pg_config")
+        .arg("--bindir")
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let dir = String::from_utf8(output.stdout).ok()?;
+
+    Some(dir.trim().into())
+}
+
+fn program(bin: &Option<PathBuf>, name: &str) -> PathBuf {
+    match bin {
+        Some(bin) => bin.join(name),
+        None => Path::new(name).to_path_buf(),
+    }
+}
+
+fn spawn_error(program: &str, error: io::Error) -> Error {
+    Error::Configuration(
+        format!(
+            "failed to run `{}` for the embedded server \
+             (are the PostgreSQL server binaries installed?): {}",
+            program, error
+        )
+        .into(),
+    )
+}
@@ -67,6 +67,7 @@ impl PgDatabaseError {
     /// An indication of the context in which the error occurred. Presently this includes a call
     /// stack traceback of active procedural language functions and internally-generated queries.
     /// The trace is one entry per line, most recent first.
+    #[doc(alias = "where_")]
     pub fn r#where(&self) -> Option<&str> {
         self.0.get(b'W')
     }
@@ -88,6 +89,7 @@ impl PgDatabaseError {
     }
 
     /// If the error is with a specific data type, the name of the data type.
+    #[doc(alias = "datatype")]
     pub fn data_type(&self) -> Option<&str> {
         self.0.get(b'd')
     }
@@ -113,6 +115,52 @@ impl PgDatabaseError {
     pub fn routine(&self) -> Option<&str> {
         self.0.get(b'R')
     }
+
+    /// Returns `true` if a unique constraint, or a unique index, is violated (`23505`).
+    /// The name of the constraint is given by [`constraint`](Self::constraint).
+    #[inline]
+    pub fn is_unique_violation(&self) -> bool {
+        self.code() == "23505"
+    }
+
+    /// Returns `true` if a foreign key constraint is violated (`23503`).
+    #[inline]
+    pub fn is_foreign_key_violation(&self) -> bool {
+        self.code() == "23503"
+    }
+
+    /// Returns `true` if a `NOT NULL` constraint is violated (`23502`). The column is given by
+    /// [`column`](Self::column).
+    #[inline]
+    pub fn is_not_null_violation(&self) -> bool {
+        self.code() == "23502"
+    }
+
+    /// Returns `true` if a check constraint is violated (`23514`).
+    #[inline]
+    pub fn is_check_violation(&self) -> bool {
+        self.code() == "23514"
+    }
+
+    /// Returns `true` if an exclusion constraint is violated (`23P01`).
+    #[inline]
+    pub fn is_exclusion_violation(&self) -> bool {
+        self.code() == "23P01"
+    }
+
+    /// Returns `true` if the transaction could not be serialized with concurrent transactions
+    /// (`40001`), in which case it can be retried.
+    #[inline]
+    pub fn is_serialization_failure(&self) -> bool {
+        self.code() == "40001"
+    }
+
+    /// Returns `true` if a deadlock was detected (`40P01`), in which case the transaction can
+    /// be retried.
+    #[inline]
+    pub fn is_deadlock_detected(&self) -> bool {
+        self.code() == "40P01"
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -1,9 +1,11 @@
+use crate::postgres::message::StatementName;
+
 pub trait PgBufMutExt {
     fn put_length_prefixed<F>(&mut self, f: F)
     where
         F: FnOnce(&mut Vec<u8>);
 
-    fn put_statement_name(&mut self, id: u32);
+    fn put_statement_name(&mut self, name: StatementName<'_>);
 
     fn put_portal_name(&mut self, id: Option<u32>);
 }
@@ -27,13 +29,19 @@ impl PgBufMutExt for Vec<u8> {
         self[offset..(offset + 4)].copy_from_slice(&size.to_be_bytes());
     }
 
-    // writes a statement name by ID
+    // writes a statement name
     #[inline]
-    fn put_statement_name(&mut self, id: u32) {
-        // N.B. if you change this don't forget to update it in ../describe.rs
-        self.extend(b"sqlx_s_");
+    fn put_statement_name(&mut self, name: StatementName<'_>) {
+        match name {
+            StatementName::Id(prefix, id) => {
+                // N.B. if you change this don't forget to update it in ../describe.rs
+                self.extend(prefix.as_bytes());
+
+                itoa::write(&mut *self, id).unwrap();
+            }
 
-        itoa::write(&mut *self, id).unwrap();
+            StatementName::Named(name) => self.extend(name.as_bytes()),
+        }
 
         self.push(0);
     }
@@ -0,0 +1,415 @@
+use std::cmp;
+use std::io::{self, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures_core::future::BoxFuture;
+use futures_util::FutureExt;
+use sqlx_rt::{AsyncRead, AsyncWrite};
+
+use crate::error::Error;
+use crate::net::{PollReadBuf, PollReadOut};
+use crate::postgres::PgConnection;
+use crate::query_scalar::query_scalar;
+
+// https://www.postgresql.org/docs/current/lo-interfaces.html
+// https://www.postgresql.org/docs/current/lo-funcs.html
+
+// the flags of `lo_open`, from `libpq/libpq-fs.h`
+const INV_WRITE: i32 = 0x0002_0000;
+const INV_READ: i32 = 0x0004_0000;
+
+// the most bytes read or written by a single call of `loread` or `lowrite` through `AsyncRead`
+// and `AsyncWrite`
+const MAX_CHUNK_SIZE: usize = 1 << 20;
+
+/// The access mode of a [`PgLargeObject`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgLargeObjectMode {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl PgLargeObjectMode {
+    fn flags(self) -> i32 {
+        match self {
+            PgLargeObjectMode::Read => INV_READ,
+            PgLargeObjectMode::Write => INV_WRITE,
+            PgLargeObjectMode::ReadWrite => INV_READ | INV_WRITE,
+        }
+    }
+}
+
+/// An open large object, stored in `pg_largeobject` and identified by its OID.
+///
+/// Large objects are read and written in chunks, with a position like a file. A large object
+/// can only be opened in a transaction, and it is closed at the end of the transaction if it
+/// was not [closed](Self::close) before.
+///
+/// It implements both the `AsyncRead` and `AsyncWrite` traits of the selected runtime, which
+/// call `loread` and `lowrite` with at most 1 MiB at a time.
+///
+/// ```rust,ignore
+/// let mut tx = conn.begin().await?;
+///
+/// let oid = PgLargeObject::create(&mut tx).await?;
+///
+/// let mut object = PgLargeObject::open(&mut tx, oid, PgLargeObjectMode::Write).await?;
+/// tokio::io::copy(&mut file, &mut object).await?;
+/// object.close().await?;
+///
+/// tx.commit().await?;
+/// ```
+pub struct PgLargeObject<'c> {
+    // `None` while an operation of `AsyncRead` or `AsyncWrite` holds the connection
+    conn: Option<&'c mut PgConnection>,
+    operation: Option<Operation<'c>>,
+    fd: i32,
+
+    // the bytes read by `AsyncRead` which did not fit in its buffer; the position on the
+    // server is after them
+    read_buf: Bytes,
+}
+
+enum Operation<'c> {
+    Read(BoxFuture<'c, (&'c mut PgConnection, Result<Vec<u8>, Error>)>),
+    Write(BoxFuture<'c, (&'c mut PgConnection, Result<usize, Error>)>),
+}
+
+impl<'c> PgLargeObject<'c> {
+    /// Creates an empty large object, returning its OID.
+    pub async fn create(conn: &mut PgConnection) -> Result<u32, Error> {
+        // language=PostgreSQL
+        let oid = query_scalar("SELECT lo_creat(-1)").fetch_one(conn).await?;
+
+        Ok(oid)
+    }
+
+    /// Deletes a large object.
+    pub async fn unlink(conn: &mut PgConnection, oid: u32) -> Result<(), Error> {
+        // language=PostgreSQL
+        let _: i32 = query_scalar("SELECT lo_unlink($1)")
+            .bind(oid)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Opens a large object, at the position `0`. The connection must be in a transaction.
+    pub async fn open(
+        conn: &'c mut PgConnection,
+        oid: u32,
+        mode: PgLargeObjectMode,
+    ) -> Result<PgLargeObject<'c>, Error> {
+        // language=PostgreSQL
+        let fd = query_scalar("SELECT lo_open($1, $2)")
+            .bind(oid)
+            .bind(mode.flags())
+            .fetch_one(&mut *conn)
+            .await?;
+
+        Ok(PgLargeObject {
+            conn: Some(conn),
+            operation: None,
+            fd,
+            read_buf: Bytes::new(),
+        })
+    }
+
+    /// Reads up to `len` bytes from the current position. An empty result means that the
+    /// position is at the end of the object.
+    pub async fn read_chunk(&mut self, len: usize) -> Result<Vec<u8>, Error> {
+        let fd = self.fd;
+        let (conn, read_buf) = self.parts().await?;
+
+        if !read_buf.is_empty() {
+            let n = cmp::min(len, read_buf.len());
+
+            return Ok(read_buf.split_to(n).to_vec());
+        }
+
+        read(conn, fd, len).await
+    }
+
+    /// Writes the bytes at the current position, returning how many were written.
+    pub async fn write_chunk(&mut self, data: &[u8]) -> Result<usize, Error> {
+        let fd = self.fd;
+        let (conn, read_buf) = self.parts().await?;
+        let rewind = take_len(read_buf);
+
+        write(conn, fd, rewind, data).await
+    }
+
+    /// Moves the current position, returning the new one.
+    pub async fn seek(&mut self, pos: SeekFrom) -> Result<u64, Error> {
+        let fd = self.fd;
+        let (conn, read_buf) = self.parts().await?;
+        let rewind = take_len(read_buf) as i64;
+
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (offset as i64, 0),
+            SeekFrom::Current(offset) => (offset - rewind, 1),
+            SeekFrom::End(offset) => (offset, 2),
+        };
+
+        seek(conn, fd, offset, whence).await
+    }
+
+    /// Returns the current position.
+    pub async fn tell(&mut self) -> Result<u64, Error> {
+        let fd = self.fd;
+        let (conn, read_buf) = self.parts().await?;
+
+        // language=PostgreSQL
+        let pos: i64 = query_scalar("SELECT lo_tell64($1)")
+            .bind(fd)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(pos as u64 - read_buf.len() as u64)
+    }
+
+    /// Truncates the object to `len` bytes, or extends it with zeros. The current position is
+    /// unchanged.
+    pub async fn truncate(&mut self, len: u64) -> Result<(), Error> {
+        let fd = self.fd;
+        let (conn, read_buf) = self.parts().await?;
+        let rewind = take_len(read_buf) as i64;
+
+        if rewind > 0 {
+            seek(conn, fd, -rewind, 1).await?;
+        }
+
+        // language=PostgreSQL
+        let _: i32 = query_scalar("SELECT lo_truncate64($1, $2)")
+            .bind(fd)
+            .bind(len as i64)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Explicitly closes the large object.
+    pub async fn close(mut self) -> Result<(), Error> {
+        let fd = self.fd;
+        let (conn, _) = self.parts().await?;
+
+        // language=PostgreSQL
+        let _: i32 = query_scalar("SELECT lo_close($1)")
+            .bind(fd)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    // completes the operation started by `AsyncRead` or `AsyncWrite`, if any
+    async fn parts(&mut self) -> Result<(&mut PgConnection, &mut Bytes), Error> {
+        match self.operation.take() {
+            Some(Operation::Read(future)) => {
+                let (conn, res) = future.await;
+                self.conn = Some(conn);
+                self.read_buf = res?.into();
+            }
+
+            Some(Operation::Write(future)) => {
+                let (conn, res) = future.await;
+                self.conn = Some(conn);
+                res?;
+            }
+
+            None => {}
+        }
+
+        let conn = self
+            .conn
+            .as_deref_mut()
+            .expect("the connection is only taken by an operation");
+
+        Ok((conn, &mut self.read_buf))
+    }
+
+    // polls the operation in progress, if any, to completion
+    fn poll_operation(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<usize>>> {
+        let res = match &mut self.operation {
+            Some(Operation::Read(future)) => {
+                let (conn, res) = futures_core::ready!(future.poll_unpin(cx));
+                self.conn = Some(conn);
+
+                res.map(|data| {
+                    self.read_buf = data.into();
+                    None
+                })
+            }
+
+            Some(Operation::Write(future)) => {
+                let (conn, res) = futures_core::ready!(future.poll_unpin(cx));
+                self.conn = Some(conn);
+
+                res.map(Some)
+            }
+
+            None => return Poll::Ready(Ok(None)),
+        };
+
+        self.operation = None;
+
+        Poll::Ready(res.map_err(|e| io::Error::new(io::ErrorKind::Other, e)))
+    }
+
+    // reads the next chunk into `read_buf`, which is left empty at the end of the object
+    fn poll_fill(&mut self, cx: &mut Context<'_>, len: usize) -> Poll<io::Result<()>> {
+        if !self.read_buf.is_empty() || len == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        loop {
+            let reading = matches!(self.operation, Some(Operation::Read(_)));
+
+            futures_core::ready!(self.poll_operation(cx))?;
+
+            if reading {
+                return Poll::Ready(Ok(()));
+            }
+
+            if let Some(conn) = self.conn.take() {
+                let (fd, len) = (self.fd, cmp::min(len, MAX_CHUNK_SIZE));
+
+                self.operation = Some(Operation::Read(Box::pin(async move {
+                    let res = read(&mut *conn, fd, len).await;
+                    (conn, res)
+                })));
+            }
+        }
+    }
+
+    fn consume(&mut self, buf: &mut [u8]) -> usize {
+        let n = cmp::min(buf.len(), self.read_buf.len());
+        buf[..n].copy_from_slice(&self.read_buf.split_to(n));
+
+        n
+    }
+}
+
+impl AsyncRead for PgLargeObject<'_> {
+    #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut PollReadBuf<'_>,
+    ) -> Poll<io::Result<PollReadOut>> {
+        futures_core::ready!(self.poll_fill(cx, buf.remaining()))?;
+
+        let n = self.consume(buf.initialize_unfilled());
+        buf.advance(n);
+
+        Poll::Ready(Ok(()))
+    }
+
+    #[cfg(feature = "_rt-async-std")]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut PollReadBuf<'_>,
+    ) -> Poll<io::Result<PollReadOut>> {
+        futures_core::ready!(self.poll_fill(cx, buf.len()))?;
+
+        Poll::Ready(Ok(self.consume(buf)))
+    }
+}
+
+impl AsyncWrite for PgLargeObject<'_> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            // a write which returned `Pending` is completed by the next call, with the same data
+            if let Some(n) = futures_core::ready!(self.poll_operation(cx))? {
+                return Poll::Ready(Ok(n));
+            }
+
+            if let Some(conn) = self.conn.take() {
+                let fd = self.fd;
+                let rewind = take_len(&mut self.read_buf);
+                let data = buf[..cmp::min(buf.len(), MAX_CHUNK_SIZE)].to_vec();
+
+                self.operation = Some(Operation::Write(Box::pin(async move {
+                    let res = write(&mut *conn, fd, rewind, &data).await;
+                    (conn, res)
+                })));
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        futures_core::ready!(self.poll_operation(cx))?;
+
+        Poll::Ready(Ok(()))
+    }
+
+    #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+
+    #[cfg(feature = "_rt-async-std")]
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+// empties the bytes read ahead, returning how many there were, i.e. how far the position on the
+// server is ahead of the position of the large object
+fn take_len(read_buf: &mut Bytes) -> usize {
+    let len = read_buf.len();
+    read_buf.clear();
+
+    len
+}
+
+async fn read(conn: &mut PgConnection, fd: i32, len: usize) -> Result<Vec<u8>, Error> {
+    // language=PostgreSQL
+    query_scalar("SELECT loread($1, $2)")
+        .bind(fd)
+        .bind(cmp::min(len, i32::MAX as usize) as i32)
+        .fetch_one(conn)
+        .await
+}
+
+async fn write(
+    conn: &mut PgConnection,
+    fd: i32,
+    rewind: usize,
+    data: &[u8],
+) -> Result<usize, Error> {
+    if rewind > 0 {
+        seek(&mut *conn, fd, -(rewind as i64), 1).await?;
+    }
+
+    // language=PostgreSQL
+    let n: i32 = query_scalar("SELECT lowrite($1, $2)")
+        .bind(fd)
+        .bind(data)
+        .fetch_one(conn)
+        .await?;
+
+    Ok(n as usize)
+}
+
+async fn seek(conn: &mut PgConnection, fd: i32, offset: i64, whence: i32) -> Result<u64, Error> {
+    // language=PostgreSQL
+    let pos: i64 = query_scalar("SELECT lo_lseek64($1, $2, $3)")
+        .bind(fd)
+        .bind(offset)
+        .bind(whence)
+        .fetch_one(conn)
+        .await?;
+
+    Ok(pos as u64)
+}
@@ -9,9 +9,15 @@ use either::Either;
 use futures_channel::mpsc;
 use futures_core::future::BoxFuture;
 use futures_core::stream::{BoxStream, Stream};
+use std::cmp;
 use std::fmt::{self, Debug};
-use std::io;
+use std::mem;
 use std::str::from_utf8;
+use std::time::Duration;
+
+// the delay before retrying to reconnect is doubled after each attempt, up to the maximum
+const RECONNECT_BACKOFF_MIN: Duration = Duration::from_millis(100);
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(10);
 
 /// A stream of asynchronous notifications from Postgres.
 ///
@@ -19,17 +25,37 @@ use std::str::from_utf8;
 /// connection being used ever dies, this listener will detect that event, create a
 /// new connection, will re-subscribe to all of the originally specified channels, and will resume
 /// operations as normal.
+///
+/// While the server cannot be reached, reconnecting is retried with an exponential backoff,
+/// up to 10 seconds between attempts. The notifications sent while the connection was lost are
+/// missed; use [`recv_event`](PgListener::recv_event) to know when to re-sync the state they
+/// were about.
 pub struct PgListener {
     pool: Pool<Postgres>,
     connection: Option<PoolConnection<Postgres>>,
     buffer_rx: mpsc::UnboundedReceiver<Notification>,
     buffer_tx: Option<mpsc::UnboundedSender<Notification>>,
     channels: Vec<String>,
+
+    // set once the connection is re-established, until reported by `recv_event`
+    reconnected: bool,
 }
 
 /// An asynchronous notification from Postgres.
 pub struct PgNotification(Notification);
 
+/// An event received by a [`PgListener`], see [`PgListener::recv_event`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PgListenerEvent {
+    /// A notification was received on one of the subscribed channels.
+    Notification(PgNotification),
+
+    /// The connection was lost and has been re-established, with all the channels subscribed
+    /// again. The notifications sent in between were missed.
+    Reconnected,
+}
+
 impl PgListener {
     pub async fn connect(uri: &str) -> Result<Self, Error> {
         // Create a pool of 1 without timeouts (as they don't apply here)
@@ -58,14 +84,14 @@ impl PgListener {
             buffer_rx: receiver,
             buffer_tx: None,
             channels: Vec::new(),
+            reconnected: false,
         })
     }
 
     /// Starts listening for notifications on a channel.
     /// The channel name is quoted here to ensure case sensitivity.
     pub async fn listen(&mut self, channel: &str) -> Result<(), Error> {
-        self.connection()
-            .execute(&*format!(r#"LISTEN "{}""#, ident(channel)))
+        self.execute(&format!(r#"LISTEN "{}""#, ident(channel)))
             .await?;
 
         self.channels.push(channel.to_owned());
@@ -78,14 +104,11 @@ impl PgListener {
         &mut self,
         channels: impl IntoIterator<Item = &str>,
     ) -> Result<(), Error> {
-        let beg = self.channels.len();
-        self.channels.extend(channels.into_iter().map(|s| s.into()));
+        let channels: Vec<String> = channels.into_iter().map(|s| s.into()).collect();
 
-        self.connection
-            .as_mut()
-            .unwrap()
-            .execute(&*build_listen_all_query(&self.channels[beg..]))
-            .await?;
+        self.execute(&build_listen_all_query(&channels)).await?;
+
+        self.channels.extend(channels);
 
         Ok(())
     }
@@ -93,8 +116,7 @@ impl PgListener {
     /// Stops listening for notifications on a channel.
     /// The channel name is quoted here to ensure case sensitivity.
     pub async fn unlisten(&mut self, channel: &str) -> Result<(), Error> {
-        self.connection()
-            .execute(&*format!(r#"UNLISTEN "{}""#, ident(channel)))
+        self.execute(&format!(r#"UNLISTEN "{}""#, ident(channel)))
             .await?;
 
         if let Some(pos) = self.channels.iter().position(|s| s == channel) {
@@ -106,32 +128,87 @@ impl PgListener {
 
     /// Stops listening for notifications on all channels.
     pub async fn unlisten_all(&mut self) -> Result<(), Error> {
-        self.connection().execute("UNLISTEN *").await?;
+        self.execute("UNLISTEN *").await?;
 
         self.channels.clear();
 
         Ok(())
     }
 
-    #[inline]
+    // executes a query on the connection, reconnecting first if it was lost
+    async fn execute(&mut self, query: &str) -> Result<(), Error> {
+        self.connect_if_needed().await?;
+
+        let result = self.connection().execute(query).await;
+
+        if let Err(Error::Io(_)) = result {
+            self.disconnect();
+        }
+
+        result.map(|_| ())
+    }
+
     async fn connect_if_needed(&mut self) -> Result<(), Error> {
-        if self.connection.is_none() {
-            let mut connection = self.pool.acquire().await?;
-            connection.stream.notifications = self.buffer_tx.take();
+        let mut backoff = RECONNECT_BACKOFF_MIN;
+
+        while self.connection.is_none() {
+            match self.reconnect().await {
+                Ok(()) => self.reconnected = true,
+
+                Err(error) if is_connection_error(&error) => {
+                    log::warn!(
+                        "PgListener failed to reconnect, retrying in {:?}: {}",
+                        backoff,
+                        error
+                    );
+
+                    sqlx_rt::sleep(backoff).await;
+                    backoff = cmp::min(backoff * 2, RECONNECT_BACKOFF_MAX);
+                }
+
+                Err(error) => return Err(error),
+            }
+        }
 
-            connection
-                .execute(&*build_listen_all_query(&self.channels))
-                .await?;
+        Ok(())
+    }
+
+    async fn reconnect(&mut self) -> Result<(), Error> {
+        let mut connection = self.pool.acquire().await?;
+        connection.stream.notifications = self.buffer_tx.take();
+
+        let result = connection
+            .execute(&*build_listen_all_query(&self.channels))
+            .await;
 
-            self.connection = Some(connection);
+        if let Err(error) = result {
+            self.buffer_tx = connection.stream.notifications.take();
+
+            // the connection is likely dead, do not return it to the pool
+            drop(connection.release());
+
+            return Err(error);
         }
 
+        self.connection = Some(connection);
+
         Ok(())
     }
 
+    // forgets a lost connection, keeping the buffer of notifications for the next one
+    fn disconnect(&mut self) {
+        if let Some(mut connection) = self.connection.take() {
+            self.buffer_tx = connection.stream.notifications.take();
+
+            drop(connection.release());
+        }
+    }
+
     #[inline]
     fn connection(&mut self) -> &mut PgConnection {
-        self.connection.as_mut().unwrap()
+        self.connection
+            .as_mut()
+            .expect("PgListener used as an executor after its connection was lost")
     }
 
     /// Receives the next notification available from any of the subscribed channels.
@@ -196,24 +273,67 @@ impl PgListener {
     /// # }).unwrap();
     /// ```
     pub async fn try_recv(&mut self) -> Result<Option<PgNotification>, Error> {
+        loop {
+            match self.next_event().await? {
+                Some(PgListenerEvent::Notification(notification)) => return Ok(Some(notification)),
+
+                Some(PgListenerEvent::Reconnected) => {}
+
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Receives the next notification available from any of the subscribed channels, or
+    /// [`PgListenerEvent::Reconnected`] once the connection was lost and re-established.
+    ///
+    /// Unlike [`recv`](PgListener::recv), this lets the caller know that notifications may have
+    /// been missed, e.g. to reload a cache which is kept up to date by the notifications.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// loop {
+    ///     match listener.recv_event().await? {
+    ///         PgListenerEvent::Notification(notification) => cache.invalidate(notification.payload()),
+    ///         PgListenerEvent::Reconnected => cache.clear(),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub async fn recv_event(&mut self) -> Result<PgListenerEvent, Error> {
+        loop {
+            if let Some(event) = self.next_event().await? {
+                return Ok(event);
+            }
+        }
+    }
+
+    // returns `None` when the connection is lost
+    async fn next_event(&mut self) -> Result<Option<PgListenerEvent>, Error> {
         // Flush the buffer first, if anything
         // This would only fill up if this listener is used as a connection
         if let Ok(Some(notification)) = self.buffer_rx.try_next() {
-            return Ok(Some(PgNotification(notification)));
+            return Ok(Some(PgListenerEvent::Notification(PgNotification(
+                notification,
+            ))));
         }
 
         loop {
             // Ensure we have an active connection to work with.
             self.connect_if_needed().await?;
 
+            if mem::replace(&mut self.reconnected, false) {
+                return Ok(Some(PgListenerEvent::Reconnected));
+            }
+
             let message = match self.connection().stream.recv_unchecked().await {
                 Ok(message) => message,
 
-                // The connection is dead, ensure that it is dropped,
-                // update self state, and loop to try again.
-                Err(Error::Io(err)) if err.kind() == io::ErrorKind::ConnectionAborted => {
-                    self.buffer_tx = self.connection().stream.notifications.take();
-                    self.connection = None;
+                // The connection is dead (e.g. closed by the server or reset), ensure that it
+                // is dropped, update self state, and loop to try again.
+                Err(Error::Io(_)) => {
+                    self.disconnect();
 
                     // lost connection
                     return Ok(None);
@@ -228,12 +348,14 @@ impl PgListener {
             match message.format {
                 // We've received an async notification, return it.
                 MessageFormat::NotificationResponse => {
-                    return Ok(Some(PgNotification(message.decode()?)));
+                    return Ok(Some(PgListenerEvent::Notification(PgNotification(
+                        message.decode()?,
+                    ))));
                 }
 
                 // Mark the connection as ready for another query
                 MessageFormat::ReadyForQuery => {
-                    self.connection().pending_ready_for_query_count -= 1;
+                    self.connection().handle_ready_for_query(message)?;
                 }
 
                 // Ignore unexpected messages
@@ -343,7 +465,19 @@ impl Debug for PgNotification {
     }
 }
 
-fn ident(mut name: &str) -> String {
+// errors which may go away by reconnecting later
+fn is_connection_error(error: &Error) -> bool {
+    match error {
+        Error::Io(_) | Error::PoolTimedOut => true,
+
+        // e.g. `cannot_connect_now` while the server is starting up
+        Error::Database(error) => error.code().map_or(false, |code| code.starts_with("57P")),
+
+        _ => false,
+    }
+}
+
+pub(crate) fn ident(mut name: &str) -> String {
     // If the input string contains a NUL byte, we should truncate the
     // identifier.
     if let Some(index) = name.find('\0') {
@@ -34,6 +34,21 @@ pub enum Authentication {
     /// again using the 4-byte random salt.
     Md5Password(AuthenticationMd5Password),
 
+    /// The frontend must now initiate a GSSAPI negotiation, by sending a [GSSResponse]
+    /// with the first part of the GSSAPI data stream.
+    ///
+    /// [GSSResponse]: https://www.postgresql.org/docs/current/protocol-message-formats.html#PROTOCOL-MESSAGE-FORMATS-GSSRESPONSE
+    Gss,
+
+    /// The frontend must now initiate a SSPI negotiation, which is the same as for GSSAPI
+    /// on the wire.
+    Sspi,
+
+    /// This message contains the response data from the previous step of GSSAPI
+    /// or SSPI negotiation.
+    #[cfg(feature = "gssapi")]
+    GssContinue(Bytes),
+
     /// The frontend must now initiate a SASL negotiation,
     /// using one of the SASL mechanisms listed in the message.
     ///
@@ -72,6 +87,11 @@ impl Decode<'_> for Authentication {
                 Authentication::Md5Password(AuthenticationMd5Password { salt })
             }
 
+            7 => Authentication::Gss,
+            #[cfg(feature = "gssapi")]
+            8 => Authentication::GssContinue(buf),
+            9 => Authentication::Sspi,
+
             10 => Authentication::Sasl(AuthenticationSasl(buf)),
             11 => Authentication::SaslContinue(AuthenticationSaslContinue::decode(buf)?),
             12 => Authentication::SaslFinal(AuthenticationSaslFinal::decode(buf)?),
@@ -187,3 +207,22 @@ impl Decode<'_> for AuthenticationSaslFinal {
         Ok(Self { verifier })
     }
 }
+
+#[test]
+fn test_decode_authentication_gss() {
+    const DATA: &[u8] = b"\0\0\0\x07";
+
+    let m = Authentication::decode(Bytes::from_static(DATA)).unwrap();
+
+    assert!(matches!(m, Authentication::Gss));
+}
+
+#[cfg(feature = "gssapi")]
+#[test]
+fn test_decode_authentication_gss_continue() {
+    const DATA: &[u8] = b"\0\0\0\x08\x60\x81\x9b";
+
+    let m = Authentication::decode(Bytes::from_static(DATA)).unwrap();
+
+    assert!(matches!(m, Authentication::GssContinue(data) if data == b"\x60\x81\x9b"[..]));
+}
@@ -1,5 +1,6 @@
 use crate::io::Encode;
 use crate::postgres::io::PgBufMutExt;
+use crate::postgres::message::StatementName;
 use crate::postgres::PgValueFormat;
 
 #[derive(Debug)]
@@ -7,8 +8,8 @@ pub struct Bind<'a> {
     /// The ID of the destination portal (`None` selects the unnamed portal).
     pub portal: Option<u32>,
 
-    /// The id of the source prepared statement.
-    pub statement: u32,
+    /// The name of the source prepared statement.
+    pub statement: StatementName<'a>,
 
     /// The parameter format codes. Each must presently be zero (text) or one (binary).
     ///
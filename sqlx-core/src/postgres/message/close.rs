@@ -1,26 +1,27 @@
 use crate::io::Encode;
 use crate::postgres::io::PgBufMutExt;
+use crate::postgres::message::StatementName;
 
 const CLOSE_PORTAL: u8 = b'P';
 const CLOSE_STATEMENT: u8 = b'S';
 
 #[derive(Debug)]
 #[allow(dead_code)]
-pub enum Close {
-    Statement(u32),
+pub enum Close<'a> {
+    Statement(StatementName<'a>),
     Portal(u32),
 }
 
-impl Encode<'_> for Close {
+impl Encode<'_> for Close<'_> {
     fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
         // 15 bytes for 1-digit statement/portal IDs
         buf.reserve(20);
         buf.push(b'C');
 
         buf.put_length_prefixed(|buf| match self {
-            Close::Statement(id) => {
+            Close::Statement(name) => {
                 buf.push(CLOSE_STATEMENT);
-                buf.put_statement_name(*id);
+                buf.put_statement_name(*name);
             }
 
             Close::Portal(id) => {
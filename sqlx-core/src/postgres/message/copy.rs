@@ -0,0 +1,71 @@
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::io::{BufMutExt, Decode, Encode};
+use crate::postgres::io::PgBufMutExt;
+
+// https://www.postgresql.org/docs/current/protocol-flow.html#PROTOCOL-COPY
+
+/// A chunk of the data of a `COPY`, sent in either direction.
+#[derive(Debug)]
+pub struct CopyData<B>(pub B);
+
+impl<B: AsRef<[u8]>> Encode<'_> for CopyData<B> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        let data = self.0.as_ref();
+
+        buf.reserve(5 + data.len());
+        buf.push(b'd');
+        buf.put_length_prefixed(|buf| buf.extend_from_slice(data));
+    }
+}
+
+impl Decode<'_> for CopyData<Bytes> {
+    #[inline]
+    fn decode_with(buf: Bytes, _: ()) -> Result<Self, Error> {
+        Ok(CopyData(buf))
+    }
+}
+
+/// Marks the end of the data of a `COPY FROM STDIN`.
+#[derive(Debug)]
+pub struct CopyDone;
+
+impl Encode<'_> for CopyDone {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.push(b'c');
+        buf.extend(&4_i32.to_be_bytes());
+    }
+}
+
+/// Aborts a `COPY FROM STDIN`, with an error message.
+#[derive(Debug)]
+pub struct CopyFail<'a>(pub &'a str);
+
+impl Encode<'_> for CopyFail<'_> {
+    fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
+        buf.reserve(6 + self.0.len());
+        buf.push(b'f');
+        buf.put_length_prefixed(|buf| buf.put_str_nul(self.0));
+    }
+}
+
+#[test]
+fn test_encode_copy_data() {
+    const EXPECTED: &[u8] = b"d\0\0\0\x081\t2\n";
+
+    let mut buf = Vec::new();
+    CopyData(b"1\t2\n").encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
+
+#[test]
+fn test_encode_copy_fail() {
+    const EXPECTED: &[u8] = b"f\0\0\0\x0aabort\0";
+
+    let mut buf = Vec::new();
+    CopyFail("abort").encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
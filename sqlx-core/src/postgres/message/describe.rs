@@ -1,5 +1,6 @@
 use crate::io::Encode;
 use crate::postgres::io::PgBufMutExt;
+use crate::postgres::message::StatementName;
 
 const DESCRIBE_PORTAL: u8 = b'P';
 const DESCRIBE_STATEMENT: u8 = b'S';
@@ -8,15 +9,15 @@ const DESCRIBE_STATEMENT: u8 = b'S';
 
 #[derive(Debug)]
 #[allow(dead_code)]
-pub enum Describe {
+pub enum Describe<'a> {
     UnnamedStatement,
-    Statement(u32),
+    Statement(StatementName<'a>),
 
     UnnamedPortal,
     Portal(u32),
 }
 
-impl Encode<'_> for Describe {
+impl Encode<'_> for Describe<'_> {
     fn encode_with(&self, buf: &mut Vec<u8>, _: ()) {
         // 15 bytes for 1-digit statement/portal IDs
         buf.reserve(20);
@@ -25,9 +26,9 @@ impl Encode<'_> for Describe {
         buf.put_length_prefixed(|buf| {
             match self {
                 // #[likely]
-                Describe::Statement(id) => {
+                Describe::Statement(name) => {
                     buf.push(DESCRIBE_STATEMENT);
-                    buf.put_statement_name(*id);
+                    buf.put_statement_name(*name);
                 }
 
                 Describe::UnnamedPortal => {
@@ -78,7 +79,19 @@ fn test_encode_describe_statement() {
     const EXPECTED: &[u8] = b"D\0\0\0\x0ESsqlx_s_5\0";
 
     let mut buf = Vec::new();
-    let m = Describe::Statement(5);
+    let m = Describe::Statement(StatementName::Id("sqlx_s_", 5));
+
+    m.encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
+
+#[test]
+fn test_encode_describe_named_statement() {
+    const EXPECTED: &[u8] = b"D\0\0\0\x0CSreport\0";
+
+    let mut buf = Vec::new();
+    let m = Describe::Statement(StatementName::Named("report"));
 
     m.encode(&mut buf);
 
@@ -6,14 +6,17 @@ use crate::io::Decode;
 mod authentication;
 mod backend_key_data;
 mod bind;
+mod cancel_request;
 mod close;
 mod command_complete;
+mod copy;
 mod data_row;
 mod describe;
 mod execute;
 mod flush;
 mod notification;
 mod parameter_description;
+mod parameter_status;
 mod parse;
 mod password;
 mod query;
@@ -29,18 +32,21 @@ mod terminate;
 pub use authentication::{Authentication, AuthenticationSasl};
 pub use backend_key_data::BackendKeyData;
 pub use bind::Bind;
+pub use cancel_request::CancelRequest;
 pub use close::Close;
 pub use command_complete::CommandComplete;
+pub use copy::{CopyData, CopyDone, CopyFail};
 pub use data_row::DataRow;
 pub use describe::Describe;
 pub use execute::Execute;
 pub use flush::Flush;
 pub use notification::Notification;
 pub use parameter_description::ParameterDescription;
+pub use parameter_status::ParameterStatus;
 pub use parse::Parse;
 pub use password::Password;
 pub use query::Query;
-pub use ready_for_query::{ReadyForQuery, TransactionStatus};
+pub use ready_for_query::{PgTransactionStatus, ReadyForQuery};
 pub use response::{Notice, PgSeverity};
 pub use row_description::RowDescription;
 pub use sasl::{SaslInitialResponse, SaslResponse};
@@ -49,6 +55,16 @@ pub use startup::Startup;
 pub use sync::Sync;
 pub use terminate::Terminate;
 
+/// The name of a prepared statement.
+#[derive(Debug, Clone, Copy)]
+pub enum StatementName<'a> {
+    /// A statement prepared by SQLx, named by the prefix of the connection and its id.
+    Id(&'a str, u32),
+
+    /// A statement prepared by the user, e.g. with `PREPARE`.
+    Named(&'a str),
+}
+
 #[derive(Debug, PartialOrd, PartialEq)]
 #[repr(u8)]
 pub enum MessageFormat {
@@ -57,6 +73,11 @@ pub enum MessageFormat {
     BindComplete,
     CloseComplete,
     CommandComplete,
+    CopyBothResponse,
+    CopyData,
+    CopyDone,
+    CopyInResponse,
+    CopyOutResponse,
     DataRow,
     EmptyQueryResponse,
     ErrorResponse,
@@ -97,6 +118,9 @@ impl MessageFormat {
             b'3' => MessageFormat::CloseComplete,
             b'C' => MessageFormat::CommandComplete,
             b'D' => MessageFormat::DataRow,
+            b'G' => MessageFormat::CopyInResponse,
+            b'H' => MessageFormat::CopyOutResponse,
+            b'W' => MessageFormat::CopyBothResponse,
             b'E' => MessageFormat::ErrorResponse,
             b'I' => MessageFormat::EmptyQueryResponse,
             b'A' => MessageFormat::NotificationResponse,
@@ -106,6 +130,8 @@ impl MessageFormat {
             b'S' => MessageFormat::ParameterStatus,
             b'T' => MessageFormat::RowDescription,
             b'Z' => MessageFormat::ReadyForQuery,
+            b'c' => MessageFormat::CopyDone,
+            b'd' => MessageFormat::CopyData,
             b'n' => MessageFormat::NoData,
             b's' => MessageFormat::PortalSuspended,
             b't' => MessageFormat::ParameterDescription,
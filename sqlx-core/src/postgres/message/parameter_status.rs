@@ -0,0 +1,29 @@
+use bytes::Bytes;
+
+use crate::error::Error;
+use crate::io::{BufExt, Decode};
+
+#[derive(Debug)]
+pub struct ParameterStatus {
+    pub name: String,
+    pub value: String,
+}
+
+impl Decode<'_> for ParameterStatus {
+    fn decode_with(mut buf: Bytes, _: ()) -> Result<Self, Error> {
+        let name = buf.get_str_nul()?;
+        let value = buf.get_str_nul()?;
+
+        Ok(Self { name, value })
+    }
+}
+
+#[test]
+fn test_decode_parameter_status() {
+    const PARAMETER_STATUS: &[u8] = b"server_version\x0015.4 (Debian 15.4-1)\0";
+
+    let message = ParameterStatus::decode(Bytes::from(PARAMETER_STATUS)).unwrap();
+
+    assert_eq!(message.name, "server_version");
+    assert_eq!(message.value, "15.4 (Debian 15.4-1)");
+}
@@ -1,12 +1,13 @@
-use std::i16;
+use std::u16;
 
 use crate::io::{BufMutExt, Encode};
 use crate::postgres::io::PgBufMutExt;
+use crate::postgres::message::StatementName;
 
 #[derive(Debug)]
 pub struct Parse<'a> {
-    /// The ID of the destination prepared statement.
-    pub statement: u32,
+    /// The name of the destination prepared statement.
+    pub statement: StatementName<'a>,
 
     /// The query string to be parsed.
     pub query: &'a str,
@@ -26,10 +27,10 @@ impl Encode<'_> for Parse<'_> {
 
             buf.put_str_nul(self.query);
 
-            // TODO: Return an error here instead
-            assert!(self.param_types.len() <= (i16::MAX as usize));
+            // the server reads the count as unsigned; checked by the executor beforehand
+            assert!(self.param_types.len() <= (u16::MAX as usize));
 
-            buf.extend(&(self.param_types.len() as i16).to_be_bytes());
+            buf.extend(&(self.param_types.len() as u16).to_be_bytes());
 
             for &oid in self.param_types {
                 buf.extend(&oid.to_be_bytes());
@@ -44,7 +45,7 @@ fn test_encode_parse() {
 
     let mut buf = Vec::new();
     let m = Parse {
-        statement: 1,
+        statement: StatementName::Id("sqlx_s_", 1),
         query: "SELECT $1",
         param_types: &[25],
     };
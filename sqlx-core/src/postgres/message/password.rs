@@ -14,6 +14,12 @@ pub enum Password<'a> {
         username: &'a str,
         salt: [u8; 4],
     },
+
+    /// A [GSSResponse], with the GSSAPI or SSPI data of the client.
+    ///
+    /// [GSSResponse]: https://www.postgresql.org/docs/current/protocol-message-formats.html#PROTOCOL-MESSAGE-FORMATS-GSSRESPONSE
+    #[cfg(feature = "gssapi")]
+    Gss(&'a [u8]),
 }
 
 impl Password<'_> {
@@ -22,6 +28,8 @@ impl Password<'_> {
         match self {
             Password::Cleartext(s) => s.len() + 5,
             Password::Md5 { .. } => 35 + 5,
+            #[cfg(feature = "gssapi")]
+            Password::Gss(data) => data.len() + 4,
         }
     }
 }
@@ -65,6 +73,12 @@ impl Encode<'_> for Password<'_> {
 
                     buf.put_str_nul(&output);
                 }
+
+                #[cfg(feature = "gssapi")]
+                Password::Gss(data) => {
+                    // the data is not terminated by a NUL
+                    buf.extend_from_slice(data);
+                }
             }
         });
     }
@@ -98,6 +112,19 @@ fn test_encode_md5_password() {
     assert_eq!(buf, EXPECTED);
 }
 
+#[cfg(feature = "gssapi")]
+#[test]
+fn test_encode_gss_response() {
+    const EXPECTED: &[u8] = b"p\0\0\0\x07\x60\x81\x9b";
+
+    let mut buf = Vec::new();
+    let m = Password::Gss(b"\x60\x81\x9b");
+
+    m.encode(&mut buf);
+
+    assert_eq!(buf, EXPECTED);
+}
+
 #[cfg(all(test, not(debug_assertions)))]
 #[bench]
 fn bench_encode_clear_password(b: &mut test::Bencher) {
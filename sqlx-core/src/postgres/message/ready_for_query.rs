@@ -3,9 +3,10 @@ use bytes::Bytes;
 use crate::error::Error;
 use crate::io::Decode;
 
-#[derive(Debug)]
+/// The transaction status of a connection, as last reported by the server.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u8)]
-pub enum TransactionStatus {
+pub enum PgTransactionStatus {
     /// Not in a transaction block.
     Idle = b'I',
 
@@ -18,15 +19,15 @@ pub enum TransactionStatus {
 
 #[derive(Debug)]
 pub struct ReadyForQuery {
-    pub transaction_status: TransactionStatus,
+    pub transaction_status: PgTransactionStatus,
 }
 
 impl Decode<'_> for ReadyForQuery {
     fn decode_with(buf: Bytes, _: ()) -> Result<Self, Error> {
         let status = match buf[0] {
-            b'I' => TransactionStatus::Idle,
-            b'T' => TransactionStatus::Transaction,
-            b'E' => TransactionStatus::Error,
+            b'I' => PgTransactionStatus::Idle,
+            b'T' => PgTransactionStatus::Transaction,
+            b'E' => PgTransactionStatus::Error,
 
             status => {
                 return Err(err_protocol!(
@@ -48,7 +49,7 @@ fn test_decode_ready_for_query() -> Result<(), Error> {
 
     let m = ReadyForQuery::decode(Bytes::from_static(DATA))?;
 
-    assert!(matches!(m.transaction_status, TransactionStatus::Error));
+    assert!(matches!(m.transaction_status, PgTransactionStatus::Error));
 
     Ok(())
 }
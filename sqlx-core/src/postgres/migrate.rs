@@ -3,8 +3,9 @@ use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
 use crate::migrate::Migration;
-use crate::migrate::{Migrate, MigrateDatabase};
-use crate::postgres::{PgConnectOptions, PgConnection, Postgres};
+use crate::migrate::{execute_statements, split_statements};
+use crate::migrate::{Migrate, MigrateDatabase, MigrationProgress};
+use crate::postgres::{PgConnectOptions, PgConnection, PgDialect, Postgres};
 use crate::query::query;
 use crate::query_as::query_as;
 use crate::query_scalar::query_scalar;
@@ -122,6 +123,11 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
 
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
+            // CockroachDB does not implement advisory locks
+            if self.dialect() == PgDialect::CockroachDb {
+                return Ok(());
+            }
+
             let database_name = current_database(self).await?;
             let lock_id = generate_lock_id(&database_name);
 
@@ -143,6 +149,10 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
 
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
+            if self.dialect() == PgDialect::CockroachDb {
+                return Ok(());
+            }
+
             let database_name = current_database(self).await?;
             let lock_id = generate_lock_id(&database_name);
 
@@ -180,35 +190,66 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn apply<'e: 'm, 'm>(
+    fn apply_with_progress<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
+        progress: &'m mut (dyn FnMut(MigrationProgress<'_>) + Send),
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
+            let statements = split_statements(&migration.sql, false);
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            let res = if migration.no_tx() {
+                // each statement is sent separately, as PostgreSQL runs the statements of a
+                // single query in an implicit transaction
+                execute_one_by_one(self, migration, &statements, progress).await
+            } else {
+                let mut tx = self.begin().await?;
+
+                let results = tx.execute_many(&*migration.sql);
+
+                execute_statements(results, migration, &statements, progress).await?;
 
-            tx.commit().await?;
+                tx.commit().await?;
+
+                Ok(())
+            };
 
             let elapsed = start.elapsed();
 
+            // a migration run in a transaction is only recorded once it is applied, while
+            // a failed migration run without one leaves the database dirty
+            self.record(migration, res.is_ok(), elapsed).await?;
+
+            res?;
+
+            Ok(elapsed)
+        })
+    }
+
+    fn record<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        success: bool,
+        elapsed: Duration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
             // language=SQL
             let _ = query(
                 r#"
     INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( $1, $2, TRUE, $3, $4 )
+    VALUES ( $1, $2, $3, $4, $5 )
                 "#,
             )
             .bind(migration.version)
             .bind(&*migration.description)
+            .bind(success)
             .bind(&*migration.checksum)
             .bind(elapsed.as_nanos() as i64)
             .execute(self)
             .await?;
 
-            Ok(elapsed)
+            Ok(())
         })
     }
 
@@ -217,12 +258,19 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            if migration.no_tx() {
+                let statements = split_statements(&migration.sql, false);
+
+                execute_one_by_one(self, migration, &statements, &mut |_| {}).await?;
+            } else {
+                let mut tx = self.begin().await?;
+
+                let _ = tx.execute(&*migration.sql).await?;
 
-            tx.commit().await?;
+                tx.commit().await?;
+            }
 
             let elapsed = start.elapsed();
 
@@ -237,6 +285,28 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     }
 }
 
+async fn execute_one_by_one(
+    conn: &mut PgConnection,
+    migration: &Migration,
+    statements: &[&str],
+    progress: &mut (dyn FnMut(MigrationProgress<'_>) + Send),
+) -> Result<(), MigrateError> {
+    for (statement, sql) in statements.iter().enumerate() {
+        let start = Instant::now();
+
+        let _ = conn.execute(*sql).await?;
+
+        progress(MigrationProgress {
+            migration,
+            statement,
+            sql,
+            elapsed: start.elapsed(),
+        });
+    }
+
+    Ok(())
+}
+
 async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateError> {
     // language=SQL
     Ok(query_scalar("SELECT current_database()")
@@ -1,15 +1,24 @@
 //! **PostgreSQL** database driver.
 
+mod advisory_lock;
 mod arguments;
+mod checksum;
 mod column;
 mod connection;
 mod database;
+#[cfg(all(unix, feature = "embedded-postgres"))]
+mod embedded;
 mod error;
 mod io;
+mod large_object;
 mod listener;
 mod message;
+mod notice;
 mod options;
+#[cfg(feature = "protocol-hooks")]
+mod protocol_hook;
 mod query_result;
+mod replication;
 mod row;
 mod statement;
 mod transaction;
@@ -20,18 +29,32 @@ mod value;
 #[cfg(feature = "migrate")]
 mod migrate;
 
+pub use advisory_lock::{PgAdvisoryLock, PgAdvisoryLockKey};
 pub use arguments::{PgArgumentBuffer, PgArguments};
+pub use checksum::{PgChecksum, PgChecksumHasher};
 pub use column::PgColumn;
-pub use connection::PgConnection;
+pub use connection::{
+    PgCancelToken, PgConnection, PgCopyIn, PgCopyInEncoder, PgCopyRow, PgPipeline,
+    PgPipelineResult, PgStatementCacheStats,
+};
 pub use database::Postgres;
+#[cfg(all(unix, feature = "embedded-postgres"))]
+pub use embedded::PgEmbedded;
 pub use error::{PgDatabaseError, PgErrorPosition};
-pub use listener::{PgListener, PgNotification};
-pub use message::PgSeverity;
-pub use options::{PgConnectOptions, PgSslMode};
+pub use large_object::{PgLargeObject, PgLargeObjectMode};
+pub use listener::{PgListener, PgListenerEvent, PgNotification};
+pub use message::{PgSeverity, PgTransactionStatus};
+pub use notice::PgNotice;
+pub use options::{PgConnectOptions, PgDialect, PgSslMode, PgTargetSessionAttrs};
+#[cfg(feature = "protocol-hooks")]
+pub use protocol_hook::{PgMessageDirection, PgProtocolMessage};
 pub use query_result::PgQueryResult;
+pub use replication::{
+    PgLsn, PgRelation, PgReplicationConnection, PgReplicationEvent, PgReplicationStream,
+};
 pub use row::PgRow;
 pub use statement::PgStatement;
-pub use transaction::PgTransactionManager;
+pub use transaction::{PgIsolationLevel, PgTransactionManager, PgTransactionOptions};
 pub use type_info::{PgTypeInfo, PgTypeKind};
 pub use value::{PgValue, PgValueFormat, PgValueRef};
 
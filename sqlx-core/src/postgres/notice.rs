@@ -0,0 +1,67 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::postgres::message::{Notice, PgSeverity};
+
+/// A notice or a warning sent by the server, e.g. with `RAISE NOTICE`, and delivered to the
+/// handler set with [`PgConnectOptions::notice_handler`][crate::postgres::PgConnectOptions::notice_handler].
+pub struct PgNotice(pub(crate) Notice);
+
+// Notice message fields are the same as the error message fields:
+// https://www.postgresql.org/docs/current/protocol-error-fields.html
+
+impl PgNotice {
+    #[inline]
+    pub fn severity(&self) -> PgSeverity {
+        self.0.severity()
+    }
+
+    /// The [SQLSTATE](https://www.postgresql.org/docs/current/errcodes-appendix.html) code for
+    /// this notice, e.g. `01000` for a warning.
+    #[inline]
+    pub fn code(&self) -> &str {
+        self.0.code()
+    }
+
+    /// The primary human-readable message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        self.0.message()
+    }
+
+    /// An optional secondary message carrying more detail. Might run to multiple lines.
+    #[inline]
+    pub fn detail(&self) -> Option<&str> {
+        self.0.get(b'D')
+    }
+
+    /// An optional suggestion of what to do about it. Might run to multiple lines.
+    #[inline]
+    pub fn hint(&self) -> Option<&str> {
+        self.0.get(b'H')
+    }
+
+    /// An indication of the context in which the notice was raised, such as a call stack
+    /// traceback of active procedural language functions.
+    pub fn r#where(&self) -> Option<&str> {
+        self.0.get(b'W')
+    }
+}
+
+impl Debug for PgNotice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgNotice")
+            .field("severity", &self.severity())
+            .field("code", &self.code())
+            .field("message", &self.message())
+            .field("detail", &self.detail())
+            .field("hint", &self.hint())
+            .field("where", &self.r#where())
+            .finish()
+    }
+}
+
+impl Display for PgNotice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
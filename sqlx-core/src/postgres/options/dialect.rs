@@ -0,0 +1,55 @@
+use crate::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The dialect of the server, for the servers which speak the PostgreSQL protocol without
+/// supporting all of its features.
+///
+/// It is detected when connecting, or forced with the [`dialect`](super::PgConnectOptions::dialect)
+/// method, and returned by [`PgConnection::dialect`](crate::postgres::PgConnection::dialect).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgDialect {
+    /// PostgreSQL, or a server which supports the same features.
+    Postgres,
+
+    /// [CockroachDB](https://www.cockroachlabs.com/), detected from the `crdb_version`
+    /// parameter it reports.
+    ///
+    /// Its `server_version` is a compatibility claim rather than a feature set, so no
+    /// version-specific syntax is assumed. The nullability of columns is not inferred from
+    /// `EXPLAIN`, the catalogs of range and composite types are not queried, and migrations
+    /// are not locked with advisory locks, which CockroachDB does not implement.
+    CockroachDb,
+}
+
+impl Default for PgDialect {
+    fn default() -> Self {
+        PgDialect::Postgres
+    }
+}
+
+impl Display for PgDialect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PgDialect::Postgres => "postgres",
+            PgDialect::CockroachDb => "cockroachdb",
+        })
+    }
+}
+
+impl FromStr for PgDialect {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "postgres" | "postgresql" => PgDialect::Postgres,
+            "cockroachdb" | "crdb" => PgDialect::CockroachDb,
+
+            _ => {
+                return Err(Error::Configuration(
+                    format!("unknown value {:?} for `dialect`", s).into(),
+                ));
+            }
+        })
+    }
+}
@@ -1,11 +1,25 @@
+use std::borrow::Borrow;
 use std::env::var;
+use std::fmt::Display;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod connect;
+mod dialect;
 mod parse;
 mod ssl_mode;
+mod target_session_attrs;
+#[cfg(feature = "fault-injection")]
+use crate::fault::FaultInjector;
+use crate::postgres::PgNotice;
+#[cfg(feature = "protocol-hooks")]
+use crate::postgres::PgProtocolMessage;
+#[cfg(feature = "transcripts")]
+use crate::transcript::Transcript;
 use crate::{connection::LogSettings, net::CertificateInput};
+pub use dialect::PgDialect;
 pub use ssl_mode::PgSslMode;
+pub use target_session_attrs::PgTargetSessionAttrs;
 
 /// Options and flags which can be used to configure a PostgreSQL connection.
 ///
@@ -24,14 +38,25 @@ pub use ssl_mode::PgSslMode;
 /// |---------|-------|-----------|
 /// | `sslmode` | `prefer` | Determines whether or with what priority a secure SSL TCP/IP connection will be negotiated. See [`PgSslMode`]. |
 /// | `sslrootcert` | `None` | Sets the name of a file containing a list of trusted SSL Certificate Authorities. |
+/// | `sslcert` | `None` | Sets the name of a file containing the SSL client certificate, sent to the server if it requests one. |
+/// | `sslkey` | `None` | Sets the name of a file containing the private key of the SSL client certificate. |
 /// | `statement-cache-capacity` | `100` | The maximum number of prepared statements stored in the cache. Set to `0` to disable. |
+/// | `statement-name-prefix` | `sqlx_s_` | The prefix of the names of the statements prepared by SQLx. |
+/// | `fetch-size` | `0` | The number of rows fetched at a time by the queries streamed with `fetch`. Set to `0` to fetch all the rows at once. |
 /// | `host` | `None` | Path to the directory containing a PostgreSQL unix domain socket, which will be used instead of TCP if set. |
 /// | `hostaddr` | `None` | Same as `host`, but only accepts IP addresses. |
+/// | `extra-float-digits` | `None` | The number of digits of floats in the text format, beyond the default. Values from `1` give the shortest exact representation on PostgreSQL 12+. By default, it is set to the most precise value the server supports. |
 /// | `application-name` | `None` | The name will be displayed in the pg_stat_activity view and included in CSV log entries. |
 /// | `user` | result of `whoami` | PostgreSQL user name to connect as. |
 /// | `password` | `None` | Password to be used if the server demands password authentication. |
 /// | `port` | `5432` | Port number to connect to at the server host, or socket file name extension for Unix-domain connections. |
 /// | `dbname` | `None` | The database name. |
+/// | `target_session_attrs` | `any` | Determines which of the hosts is connected to, from the properties of its session. See [`PgTargetSessionAttrs`]. |
+/// | `connect_timeout` | `None` | The number of seconds to wait for a connection to each host, like the `connect_timeout` of libpq. |
+/// | `keepalives_idle` | `None` | The number of seconds of inactivity after which TCP keepalive probes are sent. |
+/// | `tcp_user_timeout` | `None` | The number of milliseconds after which unacknowledged data closes the connection, on Linux. |
+/// | `krbsrvname` | `postgres` | The Kerberos service name of the server, with GSSAPI authentication (the `gssapi` feature). |
+/// | `dialect` | detected | Forces the dialect of the server, `postgres` or `cockroachdb`. See [`PgDialect`]. |
 ///
 /// The URI scheme designator can be either `postgresql://` or `postgres://`.
 /// Each of the URI parts is optional.
@@ -46,6 +71,13 @@ pub use ssl_mode::PgSslMode;
 /// postgresql://localhost?dbname=mydb&user=postgres&password=postgres
 /// ```
 ///
+/// Several hosts, each with an optional port, can be separated with commas; they are tried in
+/// order until a connection succeeds and matches `target_session_attrs`:
+///
+/// ```text
+/// postgresql://host1:5432,host2:5433/mydb?target_session_attrs=read-write
+/// ```
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -75,15 +107,40 @@ pub use ssl_mode::PgSslMode;
 pub struct PgConnectOptions {
     pub(crate) host: String,
     pub(crate) port: u16,
+    pub(crate) fallback_hosts: Vec<(String, Option<u16>)>,
+    pub(crate) target_session_attrs: PgTargetSessionAttrs,
     pub(crate) socket: Option<PathBuf>,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: PgSslMode,
     pub(crate) ssl_root_cert: Option<CertificateInput>,
+    pub(crate) ssl_client_cert: Option<CertificateInput>,
+    pub(crate) ssl_client_key: Option<CertificateInput>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) statement_name_prefix: String,
+    pub(crate) fetch_size: u32,
+    pub(crate) strict_column_names: bool,
+    pub(crate) extra_float_digits: Option<i8>,
     pub(crate) application_name: Option<String>,
+    pub(crate) options: Vec<(String, String)>,
+    pub(crate) min_server_version: Option<u32>,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) tcp_keepalive: Option<Duration>,
+    pub(crate) tcp_user_timeout: Option<Duration>,
+    pub(crate) dialect: Option<PgDialect>,
     pub(crate) log_settings: LogSettings,
+    pub(crate) notice_handler: Option<fn(PgNotice)>,
+    // set by `PgReplicationConnection` to start the connection in logical replication mode
+    pub(crate) replication: bool,
+    #[cfg(feature = "fault-injection")]
+    pub(crate) fault_injector: Option<FaultInjector>,
+    #[cfg(feature = "transcripts")]
+    pub(crate) transcript: Option<Transcript>,
+    #[cfg(feature = "protocol-hooks")]
+    pub(crate) protocol_hook: Option<fn(&PgProtocolMessage)>,
+    #[cfg(feature = "gssapi")]
+    pub(crate) krb_service_name: String,
 }
 
 impl Default for PgConnectOptions {
@@ -104,8 +161,12 @@ impl PgConnectOptions {
     ///  * `PGPASSWORD`
     ///  * `PGDATABASE`
     ///  * `PGSSLROOTCERT`
+    ///  * `PGSSLCERT`
+    ///  * `PGSSLKEY`
     ///  * `PGSSLMODE`
     ///  * `PGAPPNAME`
+    ///  * `PGOPTIONS`
+    ///  * `PGKRBSRVNAME`, with the `gssapi` feature
     ///
     /// # Example
     ///
@@ -124,18 +185,49 @@ impl PgConnectOptions {
         PgConnectOptions {
             port,
             host,
+            fallback_hosts: Vec::new(),
+            target_session_attrs: PgTargetSessionAttrs::Any,
             socket: None,
             username: var("PGUSER").ok().unwrap_or_else(whoami::username),
             password: var("PGPASSWORD").ok(),
             database: var("PGDATABASE").ok(),
             ssl_root_cert: var("PGSSLROOTCERT").ok().map(CertificateInput::from),
+            ssl_client_cert: var("PGSSLCERT").ok().map(CertificateInput::from),
+            ssl_client_key: var("PGSSLKEY").ok().map(CertificateInput::from),
             ssl_mode: var("PGSSLMODE")
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or_default(),
             statement_cache_capacity: 100,
+            statement_name_prefix: String::from("sqlx_s_"),
+            fetch_size: 0,
+            strict_column_names: false,
+            extra_float_digits: None,
             application_name: var("PGAPPNAME").ok(),
+            options: var("PGOPTIONS")
+                .ok()
+                .and_then(|v| parse::parse_options(&v).ok())
+                .unwrap_or_default(),
+            min_server_version: None,
+            connect_timeout: var("PGCONNECT_TIMEOUT")
+                .ok()
+                .and_then(|v| parse::parse_seconds(&v).ok().flatten()),
+            tcp_keepalive: None,
+            tcp_user_timeout: None,
+            dialect: None,
             log_settings: Default::default(),
+            notice_handler: None,
+            replication: false,
+            #[cfg(feature = "fault-injection")]
+            fault_injector: None,
+            #[cfg(feature = "transcripts")]
+            transcript: None,
+            #[cfg(feature = "protocol-hooks")]
+            protocol_hook: None,
+            #[cfg(feature = "gssapi")]
+            krb_service_name: var("PGKRBSRVNAME")
+                .ok()
+                .unwrap_or_else(|| String::from("postgres")),
         }
     }
 
@@ -176,6 +268,44 @@ impl PgConnectOptions {
         self
     }
 
+    /// Adds a host to try, after the previous hosts, if connecting to them fails or their
+    /// session does not match the [`target_session_attrs`](Self::target_session_attrs).
+    ///
+    /// As with [`host`](Self::host), a host name which begins with a slash is the directory
+    /// of a Unix-domain socket.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgConnectOptions, PgTargetSessionAttrs};
+    /// let options = PgConnectOptions::new()
+    ///     .host("db1.example.com")
+    ///     .fallback_host("db2.example.com", 5432)
+    ///     .target_session_attrs(PgTargetSessionAttrs::ReadWrite);
+    /// ```
+    pub fn fallback_host(mut self, host: &str, port: u16) -> Self {
+        self.fallback_hosts.push((host.to_owned(), Some(port)));
+        self
+    }
+
+    /// Sets the properties the session must have for a host to be connected to; if it does
+    /// not, the next host is tried, as with libpq.
+    ///
+    /// By default, any host which can be connected to is accepted. See
+    /// [`PgTargetSessionAttrs`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgConnectOptions, PgTargetSessionAttrs};
+    /// let options = PgConnectOptions::new()
+    ///     .target_session_attrs(PgTargetSessionAttrs::Primary);
+    /// ```
+    pub fn target_session_attrs(mut self, attrs: PgTargetSessionAttrs) -> Self {
+        self.target_session_attrs = attrs;
+        self
+    }
+
     /// Sets a custom path to a directory containing a unix domain socket,
     /// switching the connection method from TCP to the corresponding socket.
     ///
@@ -285,6 +415,67 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets the name of a file containing the SSL client certificate, to authenticate with
+    /// the `cert` method of `pg_hba.conf`, or if the server requires one.
+    ///
+    /// The private key of the certificate must be set too, with
+    /// [`ssl_client_key`](Self::ssl_client_key).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgSslMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyFull)
+    ///     .ssl_client_cert("./client.crt")
+    ///     .ssl_client_key("./client.key");
+    /// ```
+    pub fn ssl_client_cert(mut self, cert: impl AsRef<Path>) -> Self {
+        self.ssl_client_cert = Some(CertificateInput::File(cert.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Sets the PEM encoded SSL client certificate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgSslMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyFull)
+    ///     .ssl_client_cert_from_pem(vec![])
+    ///     .ssl_client_key_from_pem(vec![]);
+    /// ```
+    pub fn ssl_client_cert_from_pem(mut self, pem_certificate: Vec<u8>) -> Self {
+        self.ssl_client_cert = Some(CertificateInput::Inline(pem_certificate));
+        self
+    }
+
+    /// Sets the name of a file containing the private key of the SSL client certificate.
+    ///
+    /// The key must be in PKCS #8 (`BEGIN PRIVATE KEY`), or also in PKCS #1
+    /// (`BEGIN RSA PRIVATE KEY`) with `rustls`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgSslMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyFull)
+    ///     .ssl_client_cert("./client.crt")
+    ///     .ssl_client_key("./client.key");
+    /// ```
+    pub fn ssl_client_key(mut self, key: impl AsRef<Path>) -> Self {
+        self.ssl_client_key = Some(CertificateInput::File(key.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Sets the PEM encoded private key of the SSL client certificate.
+    pub fn ssl_client_key_from_pem(mut self, pem_key: Vec<u8>) -> Self {
+        self.ssl_client_key = Some(CertificateInput::Inline(pem_key));
+        self
+    }
+
     /// Sets the capacity of the connection's statement cache in a number of stored
     /// distinct statements. Caching is handled using LRU, meaning when the
     /// amount of queries hits the defined limit, the oldest statement will get
@@ -296,6 +487,153 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets the prefix of the names of the statements prepared by SQLx, followed by a number.
+    ///
+    /// The default prefix is `sqlx_s_`. A different prefix avoids any confusion with the
+    /// statements prepared by the application with `PREPARE`, or by another library sharing
+    /// the session through a proxy. A statement name which is already taken is skipped
+    /// whatever the prefix.
+    ///
+    /// The prefix cannot be empty, and cannot be longer than 53 bytes as the server truncates
+    /// the names to 63 bytes; connecting fails with [`Error::Configuration`] otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .statement_name_prefix("my_app_s_");
+    /// ```
+    ///
+    /// [`Error::Configuration`]: crate::error::Error::Configuration
+    pub fn statement_name_prefix(mut self, prefix: &str) -> Self {
+        self.statement_name_prefix = prefix.to_owned();
+        self
+    }
+
+    /// Sets the number of rows fetched at a time by the queries streamed with
+    /// [`fetch`][crate::executor::Executor::fetch] and
+    /// [`fetch_many`][crate::executor::Executor::fetch_many].
+    ///
+    /// Instead of executing a query to completion, the rows of its result are then requested
+    /// from the server in chunks of `rows`, and the next chunk is only requested once the rows
+    /// of the previous one are consumed. This keeps the memory used by huge result sets
+    /// constant, and a stream dropped early leaves the rest of the rows unfetched.
+    ///
+    /// This applies to the queries with bind parameters, which are prepared; the others are sent
+    /// with the simple query protocol, which cannot fetch rows in chunks.
+    ///
+    /// Outside of a transaction, the query is in an implicit transaction until all of its rows
+    /// are fetched, or the connection is used again.
+    ///
+    /// The default is `0`, which fetches all the rows at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .fetch_size(1000);
+    /// ```
+    pub fn fetch_size(mut self, rows: u32) -> Self {
+        self.fetch_size = rows;
+        self
+    }
+
+    /// Sets whether accessing a column of a row by name fails with
+    /// [`ColumnAmbiguous`](crate::error::Error::ColumnAmbiguous) if more than one column has
+    /// that name, e.g. the `id` columns of `SELECT a.*, b.* FROM a JOIN b ...`.
+    ///
+    /// Otherwise, the first column of that name is used. The columns of the same name can be
+    /// told apart by the name of their table, with a `(table, name)` index, e.g.
+    /// `row.try_get::<i64, _>(("b", "id"))`.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .strict_column_names(true);
+    /// ```
+    pub fn strict_column_names(mut self, strict: bool) -> Self {
+        self.strict_column_names = strict;
+        self
+    }
+
+    /// Sets whether the stages of each statement are timed: its preparation, then the time from
+    /// its execution to its first row, and to its last one, which tells a slow plan from slow
+    /// network or decoding.
+    ///
+    /// With the `tracing` feature, they are reported as a `DEBUG` event of the
+    /// `sqlx::query::stages` target; with the `metrics` feature, they are recorded in seconds
+    /// in the `sqlx_query_prepare_seconds`, `sqlx_query_first_row_seconds` and
+    /// `sqlx_query_drain_seconds` histograms. The preparation is only timed for the queries
+    /// with bind parameters, which are prepared.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .stage_timings(true);
+    /// ```
+    pub fn stage_timings(mut self, enabled: bool) -> Self {
+        self.log_settings.stage_timings = enabled;
+        self
+    }
+
+    /// Sets the value of the [`extra_float_digits`] setting of the connection, which controls
+    /// the precision of the floats sent in the text format, e.g. by queries without bind
+    /// parameters.
+    ///
+    /// A value given here is sent when the connection starts up. By default (`None`), floats
+    /// are sent with enough digits to be decoded exactly: the setting of the server is kept
+    /// on PostgreSQL 12+, where this is already the case, and it is set to `3` on older
+    /// servers (`2` before 9.0, which does not accept higher values) after start up.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .extra_float_digits(0);
+    /// ```
+    ///
+    /// [`extra_float_digits`]: https://www.postgresql.org/docs/current/runtime-config-client.html#GUC-EXTRA-FLOAT-DIGITS
+    pub fn extra_float_digits(mut self, extra_float_digits: impl Into<Option<i8>>) -> Self {
+        self.extra_float_digits = extra_float_digits.into();
+        self
+    }
+
+    /// Sets a function called with each notice or warning sent by the server, e.g. with
+    /// `RAISE NOTICE` in PL/pgSQL, while a query is executed on the connection.
+    ///
+    /// The notices are logged under `sqlx::postgres::notice` regardless, at a level depending
+    /// on their severity. The server only sends those whose severity is at least
+    /// [`client_min_messages`], `NOTICE` by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgConnectOptions, PgNotice};
+    /// fn print_notice(notice: PgNotice) {
+    ///     eprintln!("{:?}: {}", notice.severity(), notice.message());
+    /// }
+    ///
+    /// let options = PgConnectOptions::new()
+    ///     .notice_handler(print_notice);
+    /// ```
+    ///
+    /// [`client_min_messages`]: https://www.postgresql.org/docs/current/runtime-config-client.html#GUC-CLIENT-MIN-MESSAGES
+    pub fn notice_handler(mut self, handler: fn(PgNotice)) -> Self {
+        self.notice_handler = Some(handler);
+        self
+    }
+
     /// Sets the application name. Defaults to None
     ///
     /// # Example
@@ -310,6 +648,253 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets the Kerberos service name of the server, used with GSSAPI authentication.
+    /// Defaults to `postgres`.
+    ///
+    /// The server is authenticated as the service `<name>@<host>`, e.g.
+    /// `postgres@db.example.com`, like with the `krbsrvname` parameter of `libpq`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .krb_service_name("pgsql");
+    /// ```
+    #[cfg(feature = "gssapi")]
+    pub fn krb_service_name(mut self, name: &str) -> Self {
+        self.krb_service_name = name.to_owned();
+        self
+    }
+
+    /// Sets run-time parameters of the server for the connections, e.g. `search_path`, which
+    /// are sent when connecting instead of with a `SET` after each connection is opened.
+    ///
+    /// They are added to the parameters already set; a parameter set more than once takes the
+    /// last value. In a connection string, they can be set by the `options` parameter, e.g.
+    /// `options=-c%20search_path%3Dapp`, like with `libpq`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .options(&[("search_path", "app"), ("lock_timeout", "1s")]);
+    /// ```
+    pub fn options<K, V, I>(mut self, options: I) -> Self
+    where
+        K: Display,
+        V: Display,
+        I: IntoIterator,
+        I::Item: Borrow<(K, V)>,
+    {
+        for option in options {
+            let (key, value) = option.borrow();
+
+            self.options.push((key.to_string(), value.to_string()));
+        }
+
+        self
+    }
+
+    /// Sets the maximum time to wait for a connection to be established with each host, from
+    /// opening the socket to the end of the authentication.
+    ///
+    /// Unlike the [`connect_timeout`](crate::pool::PoolOptions::connect_timeout) of a pool,
+    /// which bounds the time to acquire a connection from it, this applies to every host of
+    /// the connection string in turn: a host which does not answer is given up on in time to
+    /// try the next one. Timing out fails with an I/O error of the [`TimedOut`] kind.
+    ///
+    /// By default, there is no timeout other than the one of the operating system.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .connect_timeout(Duration::from_secs(5));
+    /// ```
+    ///
+    /// [`TimedOut`]: std::io::ErrorKind::TimedOut
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Enables TCP keepalive on the connections, with probes sent after they are idle for
+    /// `idle`.
+    ///
+    /// A server which disappeared without closing the connection, e.g. after a network
+    /// partition or a failover, is then detected even if the connection is idle in a pool.
+    /// The interval and the number of the probes are those of the operating system.
+    ///
+    /// By default, the keepalive setting of the operating system is used, which is usually
+    /// disabled. It does not apply to Unix domain sockets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .tcp_keepalive(Duration::from_secs(60));
+    /// ```
+    pub fn tcp_keepalive(mut self, idle: Duration) -> Self {
+        self.tcp_keepalive = Some(idle);
+        self
+    }
+
+    /// Sets the maximum time that data sent on the connections may remain unacknowledged
+    /// before the connection is closed (`TCP_USER_TIMEOUT`).
+    ///
+    /// Unlike keepalive probes, which are only sent on idle connections, this detects a dead
+    /// server while a query is waiting for its response to be acknowledged.
+    ///
+    /// It is only supported on Linux and is ignored on other platforms, and for Unix domain
+    /// sockets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .tcp_user_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn tcp_user_timeout(mut self, timeout: Duration) -> Self {
+        self.tcp_user_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the [`statement_timeout`] of the connections, after which a statement is aborted.
+    ///
+    /// It is sent with the [`options`](Self::options) of the connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .statement_timeout(Duration::from_secs(5));
+    /// ```
+    ///
+    /// [`statement_timeout`]: https://www.postgresql.org/docs/current/runtime-config-client.html#GUC-STATEMENT-TIMEOUT
+    pub fn statement_timeout(self, timeout: Duration) -> Self {
+        self.options(&[("statement_timeout", format!("{}ms", timeout.as_millis()))])
+    }
+
+    /// Sets the minimum version of the server, in the format of `server_version_num`
+    /// (e.g. `100000` for PostgreSQL 10 or `90624` for 9.6.24).
+    ///
+    /// Connecting to an older server fails with [`Error::Configuration`] instead of failing
+    /// later on a feature the server does not support. By default any version is accepted, and
+    /// features missing from older servers are avoided where possible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .min_server_version(100000);
+    /// ```
+    ///
+    /// [`Error::Configuration`]: crate::error::Error::Configuration
+    pub fn min_server_version(mut self, version_num: u32) -> Self {
+        self.min_server_version = Some(version_num);
+        self
+    }
+
+    /// Forces the dialect of the server, instead of detecting it when connecting.
+    ///
+    /// This is useful for a server which speaks the dialect of another but does not identify
+    /// itself, e.g. CockroachDB behind a proxy which drops its `crdb_version` parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgConnectOptions, PgDialect};
+    /// let options = PgConnectOptions::new()
+    ///     .dialect(PgDialect::CockroachDb);
+    /// ```
+    pub fn dialect(mut self, dialect: PgDialect) -> Self {
+        self.dialect = Some(dialect);
+        self
+    }
+
+    /// Routes every connection opened with these options through a [`FaultInjector`],
+    /// for testing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::fault::FaultInjector;
+    /// # use sqlx_core::postgres::PgConnectOptions;
+    /// let faults = FaultInjector::new();
+    /// let options = PgConnectOptions::new()
+    ///     .fault_injector(&faults);
+    /// ```
+    #[cfg(feature = "fault-injection")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "fault-injection")))]
+    pub fn fault_injector(mut self, injector: &FaultInjector) -> Self {
+        self.fault_injector = Some(injector.clone());
+        self
+    }
+
+    /// Replays a [`Transcript`] instead of connecting to the server, or records the exchange
+    /// with the server in it, for testing.
+    ///
+    /// The exchange is recorded below TLS, so a transcript should be recorded with TLS disabled.
+    #[cfg(feature = "transcripts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "transcripts")))]
+    pub fn transcript(mut self, transcript: &Transcript) -> Self {
+        self.transcript = Some(transcript.clone());
+        self
+    }
+
+    /// Sets a function called with every message of the wire protocol sent or received on the
+    /// connections opened with these options, for debugging or to collect metrics about them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::{PgConnectOptions, PgProtocolMessage};
+    /// fn trace_message(message: &PgProtocolMessage) {
+    ///     eprintln!(
+    ///         "{:?} {:?} ({} bytes)",
+    ///         message.direction(),
+    ///         message.tag().map(char::from),
+    ///         message.size()
+    ///     );
+    /// }
+    ///
+    /// let options = PgConnectOptions::new()
+    ///     .protocol_hook(trace_message);
+    /// ```
+    #[cfg(feature = "protocol-hooks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "protocol-hooks")))]
+    pub fn protocol_hook(mut self, hook: fn(&PgProtocolMessage)) -> Self {
+        self.protocol_hook = Some(hook);
+        self
+    }
+
+    /// Returns the options to connect to each of the hosts, in order.
+    pub(crate) fn hosts(&self) -> impl Iterator<Item = PgConnectOptions> + '_ {
+        let fallback_hosts = self.fallback_hosts.iter().map(move |(host, port)| {
+            let mut options = self.clone();
+
+            options.host = host.clone();
+            options.port = port.unwrap_or(self.port);
+            options.socket = None;
+            options.fallback_hosts = Vec::new();
+
+            options
+        });
+
+        std::iter::once(self.clone()).chain(fallback_hosts)
+    }
+
     /// We try using a socket if hostname starts with `/` or if socket parameter
     /// is specified.
     pub(crate) fn fetch_socket(&self) -> Option<String> {
@@ -0,0 +1,121 @@
+use std::convert::TryInto;
+use std::time::Instant;
+
+/// The direction of a [`PgProtocolMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgMessageDirection {
+    /// A message sent by the client.
+    Frontend,
+
+    /// A message sent by the server.
+    Backend,
+}
+
+/// A message of the wire protocol, sent or received on a connection, as given to the hook set
+/// with [`PgConnectOptions::protocol_hook`][crate::postgres::PgConnectOptions::protocol_hook].
+///
+/// The [message formats] are documented by PostgreSQL.
+///
+/// [message formats]: https://www.postgresql.org/docs/current/protocol-message-formats.html
+#[derive(Debug, Clone, Copy)]
+pub struct PgProtocolMessage {
+    direction: PgMessageDirection,
+    tag: Option<u8>,
+    size: usize,
+    at: Instant,
+}
+
+impl PgProtocolMessage {
+    pub(crate) fn backend(tag: u8, size: usize) -> Self {
+        Self {
+            direction: PgMessageDirection::Backend,
+            tag: Some(tag),
+            size,
+            at: Instant::now(),
+        }
+    }
+
+    /// Whether the message was sent by the client or the server.
+    #[inline]
+    pub fn direction(&self) -> PgMessageDirection {
+        self.direction
+    }
+
+    /// The type of the message, e.g. `b'Q'` for a simple query, or `None` for the messages
+    /// sent before the startup of the connection, which have no type.
+    #[inline]
+    pub fn tag(&self) -> Option<u8> {
+        self.tag
+    }
+
+    /// The size of the message in bytes, including its type and length.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// When the message was written to the buffer of the connection, before it is flushed, or
+    /// was completely received.
+    #[inline]
+    pub fn at(&self) -> Instant {
+        self.at
+    }
+}
+
+// calls the hook with each of the messages encoded in `buf`
+pub(crate) fn frontend_messages(buf: &[u8], hook: fn(&PgProtocolMessage)) {
+    let at = Instant::now();
+    let mut buf = buf;
+
+    while buf.len() >= 4 {
+        // the messages which have no type start with their length, whose first byte is always 0
+        let (tag, header_len) = if buf[0] == 0 {
+            (None, 0)
+        } else {
+            (Some(buf[0]), 1)
+        };
+
+        let size = match buf.get(header_len..header_len + 4) {
+            Some(len) => header_len + u32::from_be_bytes(len.try_into().unwrap()) as usize,
+            None => return,
+        };
+
+        hook(&PgProtocolMessage {
+            direction: PgMessageDirection::Frontend,
+            tag,
+            size,
+            at,
+        });
+
+        buf = buf.get(size..).unwrap_or_default();
+    }
+}
+
+#[test]
+fn test_frontend_messages() {
+    use once_cell::sync::Lazy;
+    use std::sync::Mutex;
+
+    static MESSAGES: Lazy<Mutex<Vec<(Option<u8>, usize)>>> = Lazy::new(Default::default);
+
+    fn hook(message: &PgProtocolMessage) {
+        assert_eq!(message.direction(), PgMessageDirection::Frontend);
+
+        MESSAGES
+            .lock()
+            .unwrap()
+            .push((message.tag(), message.size()));
+    }
+
+    // an SSL request, a `Sync` and a `Query` of `SELECT 1`
+    let mut buf = vec![0, 0, 0, 8, 4, 210, 22, 47];
+    buf.extend_from_slice(b"S\0\0\0\x04");
+    buf.extend_from_slice(b"Q\0\0\0\x0dSELECT 1\0");
+
+    frontend_messages(&buf, hook);
+
+    assert_eq!(
+        *MESSAGES.lock().unwrap(),
+        vec![(None, 8), (Some(b'S'), 5), (Some(b'Q'), 14)]
+    );
+}
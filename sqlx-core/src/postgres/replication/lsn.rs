@@ -0,0 +1,43 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// A position in the write-ahead log (WAL), formatted as two hexadecimal numbers, e.g.
+/// `16/B374D848`, like the `pg_lsn` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgLsn(pub u64);
+
+impl Display for PgLsn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}/{:X}", self.0 >> 32, self.0 & 0xFFFF_FFFF)
+    }
+}
+
+impl FromStr for PgLsn {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut parts = s.splitn(2, '/');
+
+        match (
+            parts.next().and_then(|hi| u32::from_str_radix(hi, 16).ok()),
+            parts.next().and_then(|lo| u32::from_str_radix(lo, 16).ok()),
+        ) {
+            (Some(hi), Some(lo)) => Ok(PgLsn((u64::from(hi) << 32) | u64::from(lo))),
+
+            _ => Err(Error::Decode(format!("invalid LSN {:?}", s).into())),
+        }
+    }
+}
+
+#[test]
+fn test_lsn_round_trip() {
+    let lsn: PgLsn = "16/B374D848".parse().unwrap();
+
+    assert_eq!(lsn, PgLsn(0x16_B374_D848));
+    assert_eq!(lsn.to_string(), "16/B374D848");
+    assert_eq!(PgLsn(0).to_string(), "0/0");
+
+    assert!("16B374D848".parse::<PgLsn>().is_err());
+}
@@ -0,0 +1,291 @@
+use std::time::SystemTime;
+
+use bytes::{Buf, BufMut, Bytes};
+
+use crate::connection::Connection;
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::postgres::listener::ident;
+use crate::postgres::message::{CopyData, CopyDone, MessageFormat};
+use crate::postgres::{PgConnectOptions, PgConnection};
+use crate::row::Row;
+
+mod lsn;
+mod pgoutput;
+
+pub use lsn::PgLsn;
+pub use pgoutput::{PgRelation, PgReplicationEvent};
+
+use pgoutput::{pg_timestamp, PgOutputDecoder};
+
+// https://www.postgresql.org/docs/current/protocol-replication.html
+
+/// A connection in logical replication mode, to stream the changes committed to the database,
+/// decoded by the `pgoutput` plugin of Postgres 10+.
+///
+/// The server must be configured with `wal_level = logical`, and the user needs the
+/// `REPLICATION` attribute. The changes of the tables of the publications, created with
+/// `CREATE PUBLICATION`, are kept by a replication slot until they are acknowledged.
+///
+/// ```rust,ignore
+/// let mut conn = PgReplicationConnection::connect("postgres://localhost/mydb").await?;
+/// let start = conn.create_replication_slot("my_slot", false).await?;
+///
+/// let mut stream = conn.start_replication("my_slot", &["my_publication"], start).await?;
+///
+/// while let Some(event) = stream.recv().await? {
+///     match event {
+///         PgReplicationEvent::Insert { relation, new } => {
+///             println!("{}: {}", relation.name(), new.try_get::<i64, _>("id")?);
+///         }
+///
+///         PgReplicationEvent::Commit { end_lsn, .. } => {
+///             stream.send_status_update(end_lsn).await?;
+///         }
+///
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct PgReplicationConnection {
+    conn: PgConnection,
+}
+
+impl PgReplicationConnection {
+    pub async fn connect(url: &str) -> Result<Self, Error> {
+        Self::connect_with(&url.parse()?).await
+    }
+
+    pub async fn connect_with(options: &PgConnectOptions) -> Result<Self, Error> {
+        let mut options = options.clone();
+        options.replication = true;
+
+        Ok(Self {
+            conn: PgConnection::establish(&options).await?,
+        })
+    }
+
+    /// Creates a logical replication slot for `pgoutput`, and returns the position from which
+    /// it keeps the changes.
+    ///
+    /// A temporary slot is dropped when the connection is closed.
+    pub async fn create_replication_slot(
+        &mut self,
+        slot: &str,
+        temporary: bool,
+    ) -> Result<PgLsn, Error> {
+        let row = self
+            .conn
+            .fetch_one(&*format!(
+                r#"CREATE_REPLICATION_SLOT "{}" {}LOGICAL pgoutput NOEXPORT_SNAPSHOT"#,
+                ident(slot),
+                if temporary { "TEMPORARY " } else { "" }
+            ))
+            .await?;
+
+        row.try_get::<&str, _>("consistent_point")?.parse()
+    }
+
+    /// Drops a replication slot, which must not be in use.
+    pub async fn drop_replication_slot(&mut self, slot: &str) -> Result<(), Error> {
+        self.conn
+            .execute(&*format!(r#"DROP_REPLICATION_SLOT "{}""#, ident(slot)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Starts streaming the changes of the tables of the publications, from the replication
+    /// slot.
+    ///
+    /// The changes are sent from `start`, or from the last position acknowledged to the slot,
+    /// whichever is later.
+    pub async fn start_replication(
+        &mut self,
+        slot: &str,
+        publications: &[&str],
+        start: PgLsn,
+    ) -> Result<PgReplicationStream<'_>, Error> {
+        // the names of the publications are a list of identifiers, within a string literal
+        let publications = publications
+            .iter()
+            .map(|publication| format!(r#""{}""#, ident(publication)))
+            .collect::<Vec<_>>()
+            .join(",")
+            .replace('\'', "''");
+
+        let statement = format!(
+            r#"START_REPLICATION SLOT "{}" LOGICAL {} (proto_version '1', publication_names '{}')"#,
+            ident(slot),
+            start,
+            publications
+        );
+
+        self.conn
+            .start_copy(&statement, MessageFormat::CopyBothResponse)
+            .await?;
+
+        Ok(PgReplicationStream {
+            conn: Some(&mut self.conn),
+            decoder: PgOutputDecoder::default(),
+            acknowledged: start,
+            done_sent: false,
+        })
+    }
+
+    /// Explicitly closes the connection, which drops its temporary replication slots.
+    pub async fn close(self) -> Result<(), Error> {
+        self.conn.close().await
+    }
+}
+
+/// The changes streamed from a replication slot, started with
+/// [`PgReplicationConnection::start_replication`].
+///
+/// The server is kept informed of the position acknowledged with
+/// [`send_status_update`](Self::send_status_update), when it asks for it while
+/// [`recv`](Self::recv) waits for changes; it can then free the WAL up to that position. If the
+/// stream is dropped before [`stop`](Self::stop) is called, it is stopped with the next use of
+/// the connection.
+pub struct PgReplicationStream<'c> {
+    conn: Option<&'c mut PgConnection>,
+    decoder: PgOutputDecoder,
+    acknowledged: PgLsn,
+    // set once the client sent `CopyDone`, to end the stream
+    done_sent: bool,
+}
+
+impl PgReplicationStream<'_> {
+    fn conn(&mut self) -> &mut PgConnection {
+        self.conn
+            .as_deref_mut()
+            .expect("BUG: PgReplicationStream used after completion")
+    }
+
+    /// Waits for the next change, or returns `None` if the server ended the stream.
+    pub async fn recv(&mut self) -> Result<Option<PgReplicationEvent>, Error> {
+        loop {
+            let conn = match self.conn.as_deref_mut() {
+                Some(conn) => conn,
+                None => return Ok(None),
+            };
+
+            let message = conn.stream.recv().await?;
+
+            match message.format {
+                MessageFormat::CopyData => {
+                    let mut data: CopyData<Bytes> = message.decode()?;
+
+                    match data.0.get_u8() {
+                        // XLogData
+                        b'w' => {
+                            // the start and end positions of the data in the WAL, and the time
+                            // it was sent
+                            data.0.advance(24);
+
+                            if let Some(event) = self.decoder.decode(data.0)? {
+                                return Ok(Some(event));
+                            }
+                        }
+
+                        // Primary keepalive message
+                        b'k' => {
+                            // the end of the WAL on the server, and the time it was sent
+                            data.0.advance(16);
+
+                            if data.0.get_u8() == 1 {
+                                self.write_status_update();
+                                self.conn().stream.flush().await?;
+                            }
+                        }
+
+                        tag => {
+                            return Err(err_protocol!(
+                                "unexpected replication message {:?}",
+                                tag as char
+                            ));
+                        }
+                    }
+                }
+
+                MessageFormat::CopyDone => {
+                    // the server ended the stream, which the client must confirm
+                    if !self.done_sent {
+                        self.done_sent = true;
+                        conn.stream.send(CopyDone).await?;
+                    }
+                }
+
+                MessageFormat::CommandComplete => {}
+
+                MessageFormat::ReadyForQuery => {
+                    conn.handle_ready_for_query(message)?;
+                    self.conn = None;
+
+                    return Ok(None);
+                }
+
+                _ => {
+                    return Err(err_protocol!(
+                        "recv: unexpected message: {:?}",
+                        message.format
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Acknowledges to the server that the changes up to `lsn` are processed, i.e. the
+    /// `end_lsn` of the last [`Commit`](PgReplicationEvent::Commit) handled. The changes before
+    /// it are not sent again to the replication slot.
+    pub async fn send_status_update(&mut self, lsn: PgLsn) -> Result<(), Error> {
+        self.acknowledged = lsn;
+        self.write_status_update();
+
+        self.conn().stream.flush().await
+    }
+
+    /// Stops streaming the changes; the connection can then start streaming again.
+    pub async fn stop(mut self) -> Result<(), Error> {
+        if self.conn.is_none() {
+            return Ok(());
+        }
+
+        self.done_sent = true;
+        self.conn().stream.send(CopyDone).await?;
+
+        // the changes sent until the server received the `CopyDone` are skipped
+        while self.recv().await?.is_some() {}
+
+        Ok(())
+    }
+
+    fn write_status_update(&mut self) {
+        let lsn = self.acknowledged.0;
+
+        // Standby status update, with the positions written, flushed and applied by the client
+        let mut buf = Vec::with_capacity(34);
+        buf.put_u8(b'r');
+        buf.put_u64(lsn);
+        buf.put_u64(lsn);
+        buf.put_u64(lsn);
+        buf.put_i64(pg_timestamp(SystemTime::now()));
+        // no reply is requested
+        buf.put_u8(0);
+
+        self.conn().stream.write(CopyData(buf));
+    }
+}
+
+impl Drop for PgReplicationStream<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            if self.done_sent {
+                return;
+            }
+
+            // sent with the next message to the server, which waits for the end of the stream
+            conn.stream.write(CopyDone);
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::{Buf, Bytes};
+
+use crate::error::Error;
+use crate::ext::ustr::UStr;
+use crate::io::BufExt;
+use crate::postgres::message::DataRow;
+use crate::postgres::replication::PgLsn;
+use crate::postgres::statement::PgStatementMetadata;
+use crate::postgres::type_info::{PgCustomType, PgType, PgTypeKind};
+use crate::postgres::{PgColumn, PgRow, PgTypeInfo, PgValueFormat};
+use crate::HashMap;
+
+// https://www.postgresql.org/docs/current/protocol-logicalrep-message-formats.html
+
+// 2000-01-01 00:00:00 UTC, the epoch of the timestamps of Postgres, in seconds since 1970
+const POSTGRES_EPOCH: u64 = 946_684_800;
+
+/// A change decoded from the `pgoutput` logical replication stream.
+///
+/// The changes of a transaction are sent once it is committed, between a `Begin` and a
+/// `Commit`. The rows are decoded with the types of the columns of the table, e.g.
+/// `new.try_get::<i32, _>("id")`.
+#[non_exhaustive]
+pub enum PgReplicationEvent {
+    /// The start of a transaction.
+    Begin {
+        /// The LSN of the commit of the transaction.
+        final_lsn: PgLsn,
+        commit_time: SystemTime,
+        xid: u32,
+    },
+
+    /// The end of a transaction. `end_lsn` is the position to acknowledge with
+    /// [`send_status_update`](super::PgReplicationStream::send_status_update) once the
+    /// transaction is processed.
+    Commit {
+        commit_lsn: PgLsn,
+        end_lsn: PgLsn,
+        commit_time: SystemTime,
+    },
+
+    /// A row was inserted.
+    Insert {
+        relation: Arc<PgRelation>,
+        new: PgRow,
+    },
+
+    /// A row was updated. The old row is only sent if the replica identity of the table is
+    /// `FULL`, or its key changed; then, only the key columns are set, unless it is `FULL`.
+    Update {
+        relation: Arc<PgRelation>,
+        old: Option<PgRow>,
+        new: PgRow,
+        /// The indexes of the columns of `new` with a TOASTed value which did not change and
+        /// was not sent; they are `NULL` in `new`.
+        unchanged_toast: Vec<usize>,
+    },
+
+    /// A row was deleted. Only the key columns are set, unless the replica identity of the
+    /// table is `FULL`.
+    Delete {
+        relation: Arc<PgRelation>,
+        old: PgRow,
+    },
+
+    /// The tables were truncated.
+    Truncate {
+        relations: Vec<Arc<PgRelation>>,
+        cascade: bool,
+        restart_identity: bool,
+    },
+}
+
+/// A table of a logical replication stream, as described by the server before its first change.
+#[derive(Debug)]
+pub struct PgRelation {
+    oid: u32,
+    namespace: String,
+    name: String,
+    key_columns: Vec<bool>,
+    metadata: Arc<PgStatementMetadata>,
+}
+
+impl PgRelation {
+    /// The OID of the table.
+    pub fn oid(&self) -> u32 {
+        self.oid
+    }
+
+    /// The schema of the table.
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// The name of the table.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The columns of the table, with their types.
+    pub fn columns(&self) -> &[PgColumn] {
+        &self.metadata.columns
+    }
+
+    /// Returns `true` if the column at the index is part of the replica identity of the table,
+    /// i.e. its primary key by default.
+    pub fn is_key(&self, index: usize) -> bool {
+        self.key_columns.get(index).copied().unwrap_or(false)
+    }
+}
+
+/// Decodes the messages of `pgoutput`, and keeps the tables and types they refer to.
+#[derive(Default)]
+pub(super) struct PgOutputDecoder {
+    relations: HashMap<u32, Arc<PgRelation>>,
+    types: HashMap<u32, PgTypeInfo>,
+}
+
+impl PgOutputDecoder {
+    /// Decodes the message, or returns `None` for a message which describes a table or a type
+    /// for the next changes, or which is not supported.
+    pub(super) fn decode(&mut self, data: Bytes) -> Result<Option<PgReplicationEvent>, Error> {
+        let mut buf = data.clone();
+
+        let event = match buf.get_u8() {
+            b'B' => PgReplicationEvent::Begin {
+                final_lsn: PgLsn(buf.get_u64()),
+                commit_time: pg_time(buf.get_i64()),
+                xid: buf.get_u32(),
+            },
+
+            b'C' => {
+                // flags, currently unused
+                buf.advance(1);
+
+                PgReplicationEvent::Commit {
+                    commit_lsn: PgLsn(buf.get_u64()),
+                    end_lsn: PgLsn(buf.get_u64()),
+                    commit_time: pg_time(buf.get_i64()),
+                }
+            }
+
+            b'R' => {
+                self.decode_relation(buf)?;
+                return Ok(None);
+            }
+
+            b'Y' => {
+                let oid = buf.get_u32();
+                let _namespace = buf.get_str_nul()?;
+                let name = buf.get_str_nul()?;
+
+                let ty = PgTypeInfo::try_from_oid(oid).unwrap_or_else(|| {
+                    PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+                        oid,
+                        name: name.into(),
+                        kind: PgTypeKind::Simple,
+                        codec: None,
+                    })))
+                });
+
+                self.types.insert(oid, ty);
+                return Ok(None);
+            }
+
+            b'I' => {
+                let relation = self.relation(buf.get_u32())?;
+                expect_tuple(&mut buf, b'N')?;
+
+                let (new, _) = decode_tuple(&data, &mut buf, &relation)?;
+
+                PgReplicationEvent::Insert { relation, new }
+            }
+
+            b'U' => {
+                let relation = self.relation(buf.get_u32())?;
+
+                let old = match buf.get_u8() {
+                    b'K' | b'O' => {
+                        let (old, _) = decode_tuple(&data, &mut buf, &relation)?;
+                        expect_tuple(&mut buf, b'N')?;
+
+                        Some(old)
+                    }
+
+                    b'N' => None,
+
+                    kind => {
+                        return Err(err_protocol!("unexpected tuple kind {:?}", kind as char));
+                    }
+                };
+
+                let (new, unchanged_toast) = decode_tuple(&data, &mut buf, &relation)?;
+
+                PgReplicationEvent::Update {
+                    relation,
+                    old,
+                    new,
+                    unchanged_toast,
+                }
+            }
+
+            b'D' => {
+                let relation = self.relation(buf.get_u32())?;
+
+                match buf.get_u8() {
+                    b'K' | b'O' => {}
+
+                    kind => {
+                        return Err(err_protocol!("unexpected tuple kind {:?}", kind as char));
+                    }
+                }
+
+                let (old, _) = decode_tuple(&data, &mut buf, &relation)?;
+
+                PgReplicationEvent::Delete { relation, old }
+            }
+
+            b'T' => {
+                let count = buf.get_u32();
+                let options = buf.get_u8();
+
+                let relations = (0..count)
+                    .map(|_| self.relation(buf.get_u32()))
+                    .collect::<Result<_, _>>()?;
+
+                PgReplicationEvent::Truncate {
+                    relations,
+                    cascade: options & 1 != 0,
+                    restart_identity: options & 2 != 0,
+                }
+            }
+
+            // origins and logical decoding messages are not supported
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+
+    fn decode_relation(&mut self, mut buf: Bytes) -> Result<(), Error> {
+        let oid = buf.get_u32();
+        let namespace = buf.get_str_nul()?;
+        let name = buf.get_str_nul()?;
+
+        // replica identity setting
+        buf.advance(1);
+
+        let count = buf.get_u16() as usize;
+
+        let mut key_columns = Vec::with_capacity(count);
+        let mut columns = Vec::with_capacity(count);
+        let mut column_names = HashMap::with_capacity(count);
+        let relation_name = UStr::new(&name);
+
+        for ordinal in 0..count {
+            key_columns.push(buf.get_u8() & 1 != 0);
+
+            let name = UStr::from(buf.get_str_nul()?);
+            let type_oid = buf.get_u32();
+
+            // type modifier
+            buf.advance(4);
+
+            // the types unknown to SQLx are described by the server before the relation
+            let type_info = PgTypeInfo::try_from_oid(type_oid)
+                .or_else(|| self.types.get(&type_oid).cloned())
+                .unwrap_or_else(|| PgTypeInfo::with_oid(type_oid));
+
+            column_names.insert(name.clone(), ordinal);
+            columns.push(PgColumn {
+                ordinal,
+                name,
+                type_info,
+                relation_id: Some(oid as i32),
+                relation_attribute_no: Some(ordinal as i16 + 1),
+                relation_name: Some(relation_name.clone()),
+            });
+        }
+
+        let relation = PgRelation {
+            oid,
+            namespace,
+            name,
+            key_columns,
+            metadata: Arc::new(PgStatementMetadata {
+                columns,
+                column_names,
+                parameters: Vec::new(),
+                result_formats: Vec::new(),
+            }),
+        };
+
+        self.relations.insert(oid, Arc::new(relation));
+
+        Ok(())
+    }
+
+    fn relation(&self, oid: u32) -> Result<Arc<PgRelation>, Error> {
+        self.relations
+            .get(&oid)
+            .cloned()
+            .ok_or_else(|| err_protocol!("change to the unknown relation {}", oid))
+    }
+}
+
+fn expect_tuple(buf: &mut Bytes, kind: u8) -> Result<(), Error> {
+    match buf.get_u8() {
+        k if k == kind => Ok(()),
+        k => Err(err_protocol!(
+            "expected tuple kind {:?} but received {:?}",
+            kind as char,
+            k as char
+        )),
+    }
+}
+
+/// Decodes the tuple in the text format into a row, which keeps `data` as its storage, and
+/// returns the indexes of the unchanged TOASTed values.
+fn decode_tuple(
+    data: &Bytes,
+    buf: &mut Bytes,
+    relation: &PgRelation,
+) -> Result<(PgRow, Vec<usize>), Error> {
+    let count = buf.get_u16() as usize;
+
+    if count != relation.metadata.columns.len() {
+        return Err(err_protocol!(
+            "expected {} columns for {}.{} but received {}",
+            relation.metadata.columns.len(),
+            relation.namespace,
+            relation.name,
+            count
+        ));
+    }
+
+    let mut values: Vec<Option<Range<u32>>> = Vec::with_capacity(count);
+    let mut unchanged_toast = Vec::new();
+
+    for index in 0..count {
+        match buf.get_u8() {
+            b'n' => values.push(None),
+
+            b'u' => {
+                values.push(None);
+                unchanged_toast.push(index);
+            }
+
+            b't' => {
+                let len = buf.get_u32() as usize;
+                let start = data.len() - buf.len();
+
+                values.push(Some(start as u32..(start + len) as u32));
+                buf.advance(len);
+            }
+
+            kind => {
+                return Err(err_protocol!("unexpected column kind {:?}", kind as char));
+            }
+        }
+    }
+
+    let row = PgRow {
+        data: DataRow {
+            storage: data.clone(),
+            values,
+        },
+        format: PgValueFormat::Text,
+        metadata: Arc::clone(&relation.metadata),
+        strict_column_names: false,
+    };
+
+    Ok((row, unchanged_toast))
+}
+
+/// Converts a timestamp, in microseconds since 2000-01-01, to a `SystemTime`.
+pub(super) fn pg_time(micros: i64) -> SystemTime {
+    let epoch = UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH);
+
+    if micros >= 0 {
+        epoch + Duration::from_micros(micros as u64)
+    } else {
+        epoch - Duration::from_micros(micros.unsigned_abs())
+    }
+}
+
+/// Converts a `SystemTime` to a timestamp, in microseconds since 2000-01-01.
+pub(super) fn pg_timestamp(time: SystemTime) -> i64 {
+    let epoch = UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH);
+
+    match time.duration_since(epoch) {
+        Ok(since) => since.as_micros() as i64,
+        Err(error) => -(error.duration().as_micros() as i64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::row::Row;
+
+    const RELATION: &[u8] = b"R\0\0@\0public\0items\0d\0\x02\x01id\0\0\0\0\x17\xff\xff\xff\xff\0name\0\0\0\0\x19\xff\xff\xff\xff";
+    const INSERT: &[u8] = b"I\0\0@\0N\0\x02t\0\0\0\x0242t\0\0\0\x04sqlx";
+    const UPDATE: &[u8] = b"U\0\0@\0N\0\x02t\0\0\0\x0242u";
+
+    #[test]
+    fn test_decode_insert() {
+        let mut decoder = PgOutputDecoder::default();
+
+        assert!(decoder
+            .decode(Bytes::from_static(RELATION))
+            .unwrap()
+            .is_none());
+
+        let relation = decoder.relation(0x4000).unwrap();
+
+        assert_eq!(relation.namespace(), "public");
+        assert_eq!(relation.name(), "items");
+        assert!(relation.is_key(0));
+        assert!(!relation.is_key(1));
+
+        match decoder.decode(Bytes::from_static(INSERT)).unwrap() {
+            Some(PgReplicationEvent::Insert { relation, new }) => {
+                assert_eq!(relation.oid(), 0x4000);
+                assert_eq!(new.try_get::<i32, _>("id").unwrap(), 42);
+                assert_eq!(new.try_get::<&str, _>("name").unwrap(), "sqlx");
+            }
+
+            _ => panic!("expected an insert"),
+        }
+
+        match decoder.decode(Bytes::from_static(UPDATE)).unwrap() {
+            Some(PgReplicationEvent::Update {
+                old,
+                new,
+                unchanged_toast,
+                ..
+            }) => {
+                assert!(old.is_none());
+                assert_eq!(new.try_get::<i32, _>(0).unwrap(), 42);
+                assert_eq!(new.try_get::<Option<String>, _>(1).unwrap(), None);
+                assert_eq!(unchanged_toast, vec![1]);
+            }
+
+            _ => panic!("expected an update"),
+        }
+    }
+
+    #[test]
+    fn test_pg_time() {
+        assert_eq!(pg_time(0), UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH));
+        assert_eq!(pg_timestamp(pg_time(-1_500_000)), -1_500_000);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::column::ColumnIndex;
+use crate::column::{index_by_name, index_by_table_and_name, ColumnIndex};
 use crate::error::Error;
 use crate::postgres::message::DataRow;
 use crate::postgres::statement::PgStatementMetadata;
@@ -12,6 +12,7 @@ pub struct PgRow {
     pub(crate) data: DataRow,
     pub(crate) format: PgValueFormat,
     pub(crate) metadata: Arc<PgStatementMetadata>,
+    pub(crate) strict_column_names: bool,
 }
 
 impl crate::row::private_row::Sealed for PgRow {}
@@ -31,8 +32,14 @@ impl Row for PgRow {
         let column = &self.metadata.columns[index];
         let value = self.data.get(index);
 
+        // some columns of binary rows may have been requested in the text format
+        let format = match self.metadata.result_formats.get(index) {
+            Some(format) => *format,
+            None => self.format,
+        };
+
         Ok(PgValueRef {
-            format: self.format,
+            format,
             row: Some(&self.data.storage),
             type_info: column.type_info.clone(),
             value,
@@ -42,11 +49,23 @@ impl Row for PgRow {
 
 impl ColumnIndex<PgRow> for &'_ str {
     fn index(&self, row: &PgRow) -> Result<usize, Error> {
-        row.metadata
-            .column_names
-            .get(*self)
-            .ok_or_else(|| Error::ColumnNotFound((*self).into()))
-            .map(|v| *v)
+        index_by_name(
+            &row.metadata.columns,
+            &row.metadata.column_names,
+            self,
+            row.strict_column_names,
+        )
+    }
+}
+
+impl ColumnIndex<PgRow> for (&'_ str, &'_ str) {
+    fn index(&self, row: &PgRow) -> Result<usize, Error> {
+        index_by_table_and_name(
+            &row.metadata.columns,
+            PgColumn::table_name,
+            *self,
+            row.strict_column_names,
+        )
     }
 }
 
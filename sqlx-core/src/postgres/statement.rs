@@ -2,7 +2,7 @@ use super::{PgColumn, PgTypeInfo};
 use crate::column::ColumnIndex;
 use crate::error::Error;
 use crate::ext::ustr::UStr;
-use crate::postgres::{PgArguments, Postgres};
+use crate::postgres::{PgArguments, PgValueFormat, Postgres};
 use crate::statement::Statement;
 use crate::HashMap;
 use either::Either;
@@ -20,6 +20,9 @@ pub(crate) struct PgStatementMetadata {
     pub(crate) columns: Vec<PgColumn>,
     pub(crate) column_names: HashMap<UStr, usize>,
     pub(crate) parameters: Vec<PgTypeInfo>,
+    // format requested for each column of the results when executed,
+    // empty when all of them are binary
+    pub(crate) result_formats: Vec<PgValueFormat>,
 }
 
 impl<'q> Statement<'q> for PgStatement<'q> {
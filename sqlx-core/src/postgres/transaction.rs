@@ -1,17 +1,215 @@
+use std::fmt::{self, Display, Formatter};
+
 use futures_core::future::BoxFuture;
 
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::pool::MaybePoolConnection;
 use crate::postgres::message::Query;
-use crate::postgres::{PgConnection, Postgres};
+use crate::postgres::{PgConnection, PgPool, Postgres};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    savepoint_ansi_sql, SavepointCommand, Transaction, TransactionManager,
 };
 
+// https://www.postgresql.org/docs/current/sql-set-transaction.html
+
+/// The isolation level of a transaction, which defines the changes of the concurrent
+/// transactions it sees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgIsolationLevel {
+    /// Behaves like `ReadCommitted` in PostgreSQL.
+    ReadUncommitted,
+
+    /// Each statement sees the changes committed before it began.
+    ///
+    /// This is the default isolation level of PostgreSQL.
+    ReadCommitted,
+
+    /// The statements see the changes committed before the first statement of the
+    /// transaction began.
+    RepeatableRead,
+
+    /// Like `RepeatableRead`, but the transaction fails if its result could differ from the
+    /// result of running the concurrent transactions one at a time.
+    Serializable,
+}
+
+impl Display for PgIsolationLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PgIsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            PgIsolationLevel::ReadCommitted => "READ COMMITTED",
+            PgIsolationLevel::RepeatableRead => "REPEATABLE READ",
+            PgIsolationLevel::Serializable => "SERIALIZABLE",
+        })
+    }
+}
+
+/// Options for the modes of a transaction, passed to [`PgConnection::begin_with`].
+///
+/// The modes which are not set are the defaults of the session, e.g. as set by
+/// `default_transaction_isolation`.
+///
+/// ```rust,ignore
+/// let mut tx = conn
+///     .begin_with(
+///         PgTransactionOptions::new()
+///             .isolation(PgIsolationLevel::Serializable)
+///             .read_only(true)
+///             .deferrable(true),
+///     )
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PgTransactionOptions {
+    isolation: Option<PgIsolationLevel>,
+    read_only: Option<bool>,
+    deferrable: Option<bool>,
+}
+
+impl PgTransactionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the isolation level of the transaction.
+    pub fn isolation(mut self, isolation: PgIsolationLevel) -> Self {
+        self.isolation = Some(isolation);
+        self
+    }
+
+    /// Sets whether the transaction is `READ ONLY` or `READ WRITE`.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    /// Sets whether the transaction is `DEFERRABLE`.
+    ///
+    /// A transaction which is `SERIALIZABLE`, `READ ONLY` and `DEFERRABLE` may wait when it
+    /// begins, then runs without the overhead and the serialization failures of `SERIALIZABLE`.
+    /// It has no effect on the other transactions.
+    pub fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = Some(deferrable);
+        self
+    }
+
+    fn begin_sql(&self) -> String {
+        let mut sql = String::from("BEGIN");
+
+        if let Some(isolation) = self.isolation {
+            sql.push_str(" ISOLATION LEVEL ");
+            sql.push_str(&isolation.to_string());
+        }
+
+        match self.read_only {
+            Some(true) => sql.push_str(" READ ONLY"),
+            Some(false) => sql.push_str(" READ WRITE"),
+            None => {}
+        }
+
+        match self.deferrable {
+            Some(true) => sql.push_str(" DEFERRABLE"),
+            Some(false) => sql.push_str(" NOT DEFERRABLE"),
+            None => {}
+        }
+
+        sql
+    }
+}
+
+impl PgPool {
+    /// Retrieves a new connection and immediately begins a new transaction with the given
+    /// options.
+    ///
+    /// See [`PgConnection::begin_with`].
+    pub async fn begin_with(
+        &self,
+        options: PgTransactionOptions,
+    ) -> Result<Transaction<'static, Postgres>, Error> {
+        let mut conn = self.acquire().await?;
+
+        PgTransactionManager::begin_with(&mut conn, &options).await?;
+
+        Ok(Transaction::begun(MaybePoolConnection::PoolConnection(
+            conn,
+        )))
+    }
+}
+
+// https://www.postgresql.org/docs/current/sql-prepare-transaction.html
+
+impl Transaction<'_, Postgres> {
+    /// Prepares this transaction for a two-phase commit, with the given global identifier.
+    ///
+    /// The transaction is then no longer associated with the connection: it is kept by the
+    /// server, even after a crash, until it is committed or rolled back with
+    /// [`PgConnection::commit_prepared`] or [`PgConnection::rollback_prepared`], possibly from
+    /// another connection. This requires `max_prepared_transactions` to be set on the server.
+    ///
+    /// A savepoint or a nested transaction cannot be prepared.
+    pub async fn prepare_transaction(mut self, gid: &str) -> Result<(), Error> {
+        if self.savepoint.is_some() || self.transaction_depth != 1 {
+            return Err(Error::Configuration(
+                "only a top-level transaction can be prepared for a two-phase commit".into(),
+            ));
+        }
+
+        self.execute(&*format!("PREPARE TRANSACTION {}", quote_gid(gid)))
+            .await?;
+
+        self.transaction_depth -= 1;
+        self.open = false;
+
+        Ok(())
+    }
+}
+
+impl PgConnection {
+    /// Commits a transaction prepared with [`Transaction::prepare_transaction`]. It must not be run in
+    /// a transaction.
+    pub async fn commit_prepared(&mut self, gid: &str) -> Result<(), Error> {
+        self.execute(&*format!("COMMIT PREPARED {}", quote_gid(gid)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rolls back a transaction prepared with [`Transaction::prepare_transaction`]. It must not be run in
+    /// a transaction.
+    pub async fn rollback_prepared(&mut self, gid: &str) -> Result<(), Error> {
+        self.execute(&*format!("ROLLBACK PREPARED {}", quote_gid(gid)))
+            .await?;
+
+        Ok(())
+    }
+}
+
+// the global identifier of a prepared transaction is a string literal
+fn quote_gid(gid: &str) -> String {
+    format!("'{}'", gid.replace('\'', "''"))
+}
+
 /// Implementation of [`TransactionManager`] for PostgreSQL.
 pub struct PgTransactionManager;
 
+impl PgTransactionManager {
+    pub(crate) async fn begin_with(
+        conn: &mut PgConnection,
+        options: &PgTransactionOptions,
+    ) -> Result<(), Error> {
+        if conn.transaction_depth > 0 {
+            return Self::begin(conn).await;
+        }
+
+        conn.execute(&*options.begin_sql()).await?;
+        conn.transaction_depth += 1;
+
+        Ok(())
+    }
+}
+
 impl TransactionManager for PgTransactionManager {
     type Database = Postgres;
 
@@ -62,4 +260,54 @@ impl TransactionManager for PgTransactionManager {
             conn.transaction_depth -= 1;
         }
     }
+
+    fn execute_savepoint<'c>(
+        conn: &'c mut PgConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*savepoint_ansi_sql(command, name, '"'))
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn start_rollback_to_savepoint(conn: &mut PgConnection, name: &str) {
+        conn.pending_ready_for_query_count += 1;
+        conn.stream.write(Query(&savepoint_ansi_sql(
+            SavepointCommand::RollbackTo,
+            name,
+            '"',
+        )));
+    }
+}
+
+#[test]
+fn test_quote_gid() {
+    assert_eq!(quote_gid("tx-1"), "'tx-1'");
+    assert_eq!(quote_gid("it's"), "'it''s'");
+}
+
+#[test]
+fn test_begin_sql() {
+    assert_eq!(PgTransactionOptions::new().begin_sql(), "BEGIN");
+
+    assert_eq!(
+        PgTransactionOptions::new()
+            .isolation(PgIsolationLevel::Serializable)
+            .read_only(true)
+            .deferrable(true)
+            .begin_sql(),
+        "BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE"
+    );
+
+    assert_eq!(
+        PgTransactionOptions::new()
+            .isolation(PgIsolationLevel::RepeatableRead)
+            .read_only(false)
+            .begin_sql(),
+        "BEGIN ISOLATION LEVEL REPEATABLE READ READ WRITE"
+    );
 }
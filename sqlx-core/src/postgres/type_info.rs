@@ -5,6 +5,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use crate::ext::ustr::UStr;
+use crate::postgres::types::PgAnyTypeCodec;
 use crate::type_info::TypeInfo;
 
 /// Type information for a PostgreSQL type.
@@ -115,9 +116,19 @@ pub enum PgType {
     JsonpathArray,
     Money,
     MoneyArray,
+    Aclitem,
+    AclitemArray,
+    PgSnapshot,
+    PgSnapshotArray,
+    TxidSnapshot,
+    TxidSnapshotArray,
+    Tid,
+    TidArray,
 
     // https://www.postgresql.org/docs/9.3/datatype-pseudo.html
     Void,
+    AnyArray,
+    AnyElement,
 
     // A realized user-defined type. When a connection sees a DeclareXX variant it resolves
     // into this one before passing it along to `accepts` or inside of `Value` objects.
@@ -138,6 +149,9 @@ pub struct PgCustomType {
     pub(crate) oid: u32,
     pub(crate) name: UStr,
     pub(crate) kind: PgTypeKind,
+    // the codec registered for this type on the connection, if any
+    #[cfg_attr(feature = "offline", serde(skip))]
+    pub(crate) codec: Option<PgAnyTypeCodec>,
 }
 
 #[derive(Debug, Clone)]
@@ -205,12 +219,19 @@ impl PgTypeInfo {
         }
     }
 
+    // the pseudo-types of the polymorphic columns of the catalogs (e.g.
+    // `pg_stats.histogram_bounds`), whose values can be decoded as text
+    #[doc(hidden)]
+    pub fn __is_text_pseudo_type(&self) -> bool {
+        matches!(self.0, PgType::AnyArray | PgType::AnyElement)
+    }
+
     /// Create a `PgTypeInfo` from a type name.
     ///
     /// The OID for the type will be fetched from Postgres on use of
     /// a value of this type. The fetched OID will be cached per-connection.
     pub const fn with_name(name: &'static str) -> Self {
-        Self(PgType::DeclareWithName(UStr::Static(name)))
+        Self(PgType::DeclareWithName(UStr::from_static(name)))
     }
 
     /// Create a `PgTypeInfo` from an OID.
@@ -262,6 +283,14 @@ impl PgType {
             775 => PgType::Macaddr8Array,
             790 => PgType::Money,
             791 => PgType::MoneyArray,
+            1033 => PgType::Aclitem,
+            1034 => PgType::AclitemArray,
+            5038 => PgType::PgSnapshot,
+            5039 => PgType::PgSnapshotArray,
+            2970 => PgType::TxidSnapshot,
+            2949 => PgType::TxidSnapshotArray,
+            27 => PgType::Tid,
+            1010 => PgType::TidArray,
             829 => PgType::Macaddr,
             869 => PgType::Inet,
             1000 => PgType::BoolArray,
@@ -305,6 +334,8 @@ impl PgType {
             1563 => PgType::VarbitArray,
             1700 => PgType::Numeric,
             2278 => PgType::Void,
+            2277 => PgType::AnyArray,
+            2283 => PgType::AnyElement,
             2249 => PgType::Record,
             2287 => PgType::RecordArray,
             2950 => PgType::Uuid,
@@ -332,6 +363,12 @@ impl PgType {
         })
     }
 
+    /// Returns `true` if values of this type can only be sent by the server in the text format,
+    /// as the type has no binary output function.
+    pub(crate) fn is_text_only(&self) -> bool {
+        matches!(self, PgType::Aclitem | PgType::AclitemArray)
+    }
+
     pub(crate) fn oid(&self) -> u32 {
         match self.try_oid() {
             Some(oid) => oid,
@@ -370,6 +407,14 @@ impl PgType {
             PgType::Macaddr8Array => 775,
             PgType::Money => 790,
             PgType::MoneyArray => 791,
+            PgType::Aclitem => 1033,
+            PgType::AclitemArray => 1034,
+            PgType::PgSnapshot => 5038,
+            PgType::PgSnapshotArray => 5039,
+            PgType::TxidSnapshot => 2970,
+            PgType::TxidSnapshotArray => 2949,
+            PgType::Tid => 27,
+            PgType::TidArray => 1010,
             PgType::Macaddr => 829,
             PgType::Inet => 869,
             PgType::BoolArray => 1000,
@@ -413,6 +458,8 @@ impl PgType {
             PgType::VarbitArray => 1563,
             PgType::Numeric => 1700,
             PgType::Void => 2278,
+            PgType::AnyArray => 2277,
+            PgType::AnyElement => 2283,
             PgType::Record => 2249,
             PgType::RecordArray => 2287,
             PgType::Uuid => 2950,
@@ -535,7 +582,17 @@ impl PgType {
             PgType::JsonpathArray => "JSONPATH[]",
             PgType::Money => "MONEY",
             PgType::MoneyArray => "MONEY[]",
+            PgType::Aclitem => "ACLITEM",
+            PgType::AclitemArray => "ACLITEM[]",
+            PgType::PgSnapshot => "PG_SNAPSHOT",
+            PgType::PgSnapshotArray => "PG_SNAPSHOT[]",
+            PgType::TxidSnapshot => "TXID_SNAPSHOT",
+            PgType::TxidSnapshotArray => "TXID_SNAPSHOT[]",
+            PgType::Tid => "TID",
+            PgType::TidArray => "TID[]",
             PgType::Void => "VOID",
+            PgType::AnyArray => "ANYARRAY",
+            PgType::AnyElement => "ANYELEMENT",
             PgType::Custom(ty) => &*ty.name,
             PgType::DeclareWithOid(_) => "?",
             PgType::DeclareWithName(name) => name,
@@ -635,7 +692,17 @@ impl PgType {
             PgType::JsonpathArray => "_jsonpath",
             PgType::Money => "money",
             PgType::MoneyArray => "_money",
+            PgType::Aclitem => "aclitem",
+            PgType::AclitemArray => "_aclitem",
+            PgType::PgSnapshot => "pg_snapshot",
+            PgType::PgSnapshotArray => "_pg_snapshot",
+            PgType::TxidSnapshot => "txid_snapshot",
+            PgType::TxidSnapshotArray => "_txid_snapshot",
+            PgType::Tid => "tid",
+            PgType::TidArray => "_tid",
             PgType::Void => "void",
+            PgType::AnyArray => "anyarray",
+            PgType::AnyElement => "anyelement",
             PgType::Custom(ty) => &*ty.name,
             PgType::DeclareWithOid(_) => "?",
             PgType::DeclareWithName(name) => name,
@@ -735,8 +802,18 @@ impl PgType {
             PgType::JsonpathArray => &PgTypeKind::Array(PgTypeInfo(PgType::Jsonpath)),
             PgType::Money => &PgTypeKind::Simple,
             PgType::MoneyArray => &PgTypeKind::Array(PgTypeInfo(PgType::Money)),
+            PgType::Aclitem => &PgTypeKind::Simple,
+            PgType::AclitemArray => &PgTypeKind::Array(PgTypeInfo(PgType::Aclitem)),
+            PgType::PgSnapshot => &PgTypeKind::Simple,
+            PgType::PgSnapshotArray => &PgTypeKind::Array(PgTypeInfo(PgType::PgSnapshot)),
+            PgType::TxidSnapshot => &PgTypeKind::Simple,
+            PgType::TxidSnapshotArray => &PgTypeKind::Array(PgTypeInfo(PgType::TxidSnapshot)),
+            PgType::Tid => &PgTypeKind::Simple,
+            PgType::TidArray => &PgTypeKind::Array(PgTypeInfo(PgType::Tid)),
 
             PgType::Void => &PgTypeKind::Pseudo,
+            PgType::AnyArray => &PgTypeKind::Pseudo,
+            PgType::AnyElement => &PgTypeKind::Pseudo,
 
             PgType::Custom(ty) => &ty.kind,
 
@@ -880,6 +957,19 @@ impl PgTypeInfo {
     pub(crate) const MONEY: Self = Self(PgType::Money);
     pub(crate) const MONEY_ARRAY: Self = Self(PgType::MoneyArray);
 
+    // access privileges, as found in the catalogs
+    pub(crate) const ACLITEM: Self = Self(PgType::Aclitem);
+    pub(crate) const ACLITEM_ARRAY: Self = Self(PgType::AclitemArray);
+
+    // transaction snapshots
+    pub(crate) const PG_SNAPSHOT: Self = Self(PgType::PgSnapshot);
+    pub(crate) const PG_SNAPSHOT_ARRAY: Self = Self(PgType::PgSnapshotArray);
+    pub(crate) const TXID_SNAPSHOT: Self = Self(PgType::TxidSnapshot);
+
+    // physical location of a row (`ctid`)
+    pub(crate) const TID: Self = Self(PgType::Tid);
+    pub(crate) const TID_ARRAY: Self = Self(PgType::TidArray);
+
     //
     // date/time types
     // https://www.postgresql.org/docs/current/datatype-datetime.html
@@ -982,6 +1072,7 @@ impl PgTypeInfo {
     //
 
     pub(crate) const VOID: Self = Self(PgType::Void);
+    pub(crate) const ANYARRAY: Self = Self(PgType::AnyArray);
 }
 
 impl Display for PgTypeInfo {
@@ -1006,11 +1097,21 @@ impl PartialEq<PgType> for PgType {
             true
         } else {
             // Otherwise, perform a match on the name
-            self.name().eq_ignore_ascii_case(other.name())
+            names_eq(self.name(), other.name())
         }
     }
 }
 
+// The names of the types resolved from the server are not qualified by their schema, unlike the
+// names declared by the user may be: a qualified name matches the same unqualified name
+fn names_eq(a: &str, b: &str) -> bool {
+    match (a.rfind('.'), b.rfind('.')) {
+        (Some(dot), None) => a[dot + 1..].eq_ignore_ascii_case(b),
+        (None, Some(dot)) => a.eq_ignore_ascii_case(&b[dot + 1..]),
+        _ => a.eq_ignore_ascii_case(b),
+    }
+}
+
 #[cfg(feature = "any")]
 impl From<PgTypeInfo> for crate::any::AnyTypeInfo {
     #[inline]
@@ -0,0 +1,229 @@
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// An [`ACLITEM`], one entry of the access privileges of an object, as found in the catalogs
+/// (e.g. `pg_class.relacl`).
+///
+/// ```rust,ignore
+/// let acl: Vec<PgAclItem> = sqlx::query_scalar("SELECT relacl FROM pg_class WHERE relname = $1")
+///     .bind("accounts")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// for item in &acl {
+///     if item.grantee.is_none() && item.has_privilege('w') {
+///         println!("accounts is writable by PUBLIC (granted by {})", item.grantor);
+///     }
+/// }
+/// ```
+///
+/// The server only sends `ACLITEM` values in the text format; columns of this type are always
+/// requested as text.
+///
+/// [`ACLITEM`]: https://www.postgresql.org/docs/current/ddl-priv.html#PRIVILEGE-ABBREVS-TABLE
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgAclItem {
+    /// The role the privileges are granted to, or `None` for `PUBLIC`.
+    pub grantee: Option<String>,
+
+    /// The role which granted the privileges.
+    pub grantor: String,
+
+    /// The privileges granted.
+    pub privileges: Vec<PgAclPrivilege>,
+}
+
+/// A privilege of a [`PgAclItem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgAclPrivilege {
+    /// The abbreviation of the privilege, e.g. `r` for `SELECT`.
+    pub code: char,
+
+    /// Whether the grantee may grant the privilege to others (`*` after the abbreviation).
+    pub grant_option: bool,
+}
+
+impl PgAclItem {
+    /// Returns `true` if the privilege with this abbreviation is granted.
+    pub fn has_privilege(&self, code: char) -> bool {
+        self.privileges
+            .iter()
+            .any(|privilege| privilege.code == code)
+    }
+
+    fn from_text(s: &str) -> Result<Self, BoxDynError> {
+        let invalid = || format!("invalid ACLITEM: {:?}", s);
+
+        // e.g. `"some role"=arw*/postgres`, with no grantee for PUBLIC
+        let (grantee, rest) = parse_role(s).ok_or_else(invalid)?;
+        let rest = rest.strip_prefix('=').ok_or_else(invalid)?;
+
+        let slash = rest.find('/').ok_or_else(invalid)?;
+        let (privileges, rest) = rest.split_at(slash);
+
+        let (grantor, rest) = parse_role(&rest[1..]).ok_or_else(invalid)?;
+
+        if !rest.is_empty() {
+            return Err(invalid().into());
+        }
+
+        let mut parsed = Vec::with_capacity(privileges.len());
+        let mut chars = privileges.chars().peekable();
+
+        while let Some(code) = chars.next() {
+            if !code.is_ascii_alphabetic() {
+                return Err(invalid().into());
+            }
+
+            let grant_option = chars.peek() == Some(&'*');
+
+            if grant_option {
+                chars.next();
+            }
+
+            parsed.push(PgAclPrivilege { code, grant_option });
+        }
+
+        Ok(PgAclItem {
+            grantee: if grantee.is_empty() {
+                None
+            } else {
+                Some(grantee)
+            },
+            grantor,
+            privileges: parsed,
+        })
+    }
+}
+
+impl PgAclPrivilege {
+    /// Returns the name of the privilege, e.g. `SELECT`, if the abbreviation is known.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self.code {
+            'r' => "SELECT",
+            'w' => "UPDATE",
+            'a' => "INSERT",
+            'd' => "DELETE",
+            'D' => "TRUNCATE",
+            'x' => "REFERENCES",
+            't' => "TRIGGER",
+            'X' => "EXECUTE",
+            'U' => "USAGE",
+            'C' => "CREATE",
+            'c' => "CONNECT",
+            'T' => "TEMPORARY",
+            's' => "SET",
+            'A' => "ALTER SYSTEM",
+            'm' => "MAINTAIN",
+            _ => return None,
+        })
+    }
+}
+
+// parses a role name, which is double-quoted if it contains special characters
+fn parse_role(s: &str) -> Option<(String, &str)> {
+    if let Some(quoted) = s.strip_prefix('"') {
+        let mut name = String::new();
+        let mut chars = quoted.char_indices();
+
+        while let Some((i, ch)) = chars.next() {
+            if ch != '"' {
+                name.push(ch);
+                continue;
+            }
+
+            // a quote is escaped by doubling it
+            if quoted[i + 1..].starts_with('"') {
+                name.push('"');
+                chars.next();
+            } else {
+                return Some((name, &quoted[i + 1..]));
+            }
+        }
+
+        None
+    } else {
+        let end = s
+            .find(|ch| ch == '=' || ch == '/')
+            .unwrap_or_else(|| s.len());
+
+        Some((s[..end].to_owned(), &s[end..]))
+    }
+}
+
+impl Type<Postgres> for PgAclItem {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::ACLITEM
+    }
+}
+
+impl Type<Postgres> for [PgAclItem] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::ACLITEM_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<PgAclItem>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<PgAclItem> {
+    fn type_info() -> PgTypeInfo {
+        <[PgAclItem] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[PgAclItem] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Decode<'_, Postgres> for PgAclItem {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Text => PgAclItem::from_text(value.as_str()?),
+
+            PgValueFormat::Binary => {
+                Err("ACLITEM values are not supported in the binary format".into())
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parse_aclitem() {
+    assert_eq!(
+        PgAclItem::from_text("postgres=arw*/postgres").unwrap(),
+        PgAclItem {
+            grantee: Some("postgres".into()),
+            grantor: "postgres".into(),
+            privileges: vec![
+                PgAclPrivilege {
+                    code: 'a',
+                    grant_option: false
+                },
+                PgAclPrivilege {
+                    code: 'r',
+                    grant_option: false
+                },
+                PgAclPrivilege {
+                    code: 'w',
+                    grant_option: true
+                },
+            ],
+        }
+    );
+
+    let public = PgAclItem::from_text("=r/\"some \"\"role\"\"\"").unwrap();
+
+    assert_eq!(public.grantee, None);
+    assert_eq!(public.grantor, "some \"role\"");
+    assert!(public.has_privilege('r'));
+    assert_eq!(public.privileges[0].name(), Some("SELECT"));
+
+    assert!(PgAclItem::from_text("postgres=r").is_err());
+    assert!(PgAclItem::from_text("postgres=r/postgres/").is_err());
+    assert!(PgAclItem::from_text("\"postgres=r/postgres").is_err());
+}
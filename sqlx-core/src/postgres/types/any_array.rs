@@ -0,0 +1,266 @@
+use bytes::{Buf, Bytes};
+use std::fmt::{self, Debug, Formatter};
+
+use crate::decode::Decode;
+use crate::error::{BoxDynError, Error};
+use crate::postgres::type_info::PgType;
+use crate::postgres::types::array::parse_text_array;
+use crate::postgres::{PgTypeInfo, PgTypeKind, PgValue, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+use crate::value::Value;
+
+/// A one-dimensional array whose element type is only known at runtime, such as the values of
+/// the `anyarray` pseudo-type found in the catalogs (e.g. `pg_stats.histogram_bounds`).
+///
+/// In the binary format (prepared queries) the array carries the OID of its element type.
+/// Built-in element types are resolved by OID. In the text format the element type is not sent
+/// at all and is reported as `UNKNOWN`.
+///
+/// ```rust,ignore
+/// let bounds: PgAnyArray = sqlx::query_scalar(
+///     "SELECT histogram_bounds FROM pg_stats WHERE tablename = 'users' AND attname = 'id'",
+/// )
+/// .fetch_one(&mut conn)
+/// .await?;
+///
+/// if bounds.element_type_info().name() == "INT4" {
+///     let bounds: Vec<i32> = bounds.try_decode()?;
+/// }
+/// ```
+///
+/// Any array type can be decoded as a `PgAnyArray`, not only `anyarray`. An `anyarray` can also
+/// be decoded as a `String`, in the text representation of arrays (e.g. `{1,2,3}`), as long as
+/// its elements are of a built-in numeric, boolean or string type.
+#[derive(Clone)]
+pub struct PgAnyArray {
+    element_type_info: PgTypeInfo,
+    elements: Vec<PgValue>,
+}
+
+impl Debug for PgAnyArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgAnyArray")
+            .field("element_type_info", &self.element_type_info)
+            .field("len", &self.elements.len())
+            .finish()
+    }
+}
+
+impl PgAnyArray {
+    /// Returns the type of the elements, as sent by the server.
+    pub fn element_type_info(&self) -> &PgTypeInfo {
+        &self.element_type_info
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the elements of the array.
+    pub fn elements(&self) -> &[PgValue] {
+        &self.elements
+    }
+
+    /// Decodes every element of the array as `T`.
+    ///
+    /// The element type is checked against `T` when it is a built-in type.
+    pub fn try_decode<'r, T>(&'r self) -> Result<Vec<T>, Error>
+    where
+        T: Decode<'r, Postgres> + Type<Postgres>,
+    {
+        self.elements
+            .iter()
+            .map(|element| match element.type_info.0 {
+                PgType::Unknown | PgType::DeclareWithOid(_) => element.try_decode_unchecked(),
+                _ => element.try_decode(),
+            })
+            .collect()
+    }
+}
+
+impl Type<Postgres> for PgAnyArray {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::ANYARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::ANYARRAY || matches!(ty.kind(), PgTypeKind::Array(_))
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgAnyArray {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let format = value.format();
+
+        match format {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+
+                let ndim = buf.get_i32();
+                let _flags = buf.get_i32();
+
+                // an empty array has zero dimensions but still the type of its elements
+                let element_type_info = element_type_info(buf.get_u32());
+
+                if ndim == 0 {
+                    return Ok(PgAnyArray {
+                        element_type_info,
+                        elements: Vec::new(),
+                    });
+                }
+
+                if ndim != 1 {
+                    return Err(format!("encountered an array of {} dimensions; only one-dimensional arrays are supported", ndim).into());
+                }
+
+                let len = buf.get_i32();
+                let _lower = buf.get_i32();
+
+                let mut elements = Vec::with_capacity(len as usize);
+
+                for _ in 0..len {
+                    let element = PgValueRef::get(&mut buf, format, element_type_info.clone());
+
+                    elements.push(PgValue {
+                        value: element.value.map(Bytes::copy_from_slice),
+                        type_info: element_type_info.clone(),
+                        format,
+                    });
+                }
+
+                Ok(PgAnyArray {
+                    element_type_info,
+                    elements,
+                })
+            }
+
+            PgValueFormat::Text => {
+                let elements = parse_text_array(value.as_str()?)?
+                    .into_iter()
+                    .map(|element| PgValue {
+                        value: element.map(Bytes::from),
+                        type_info: PgTypeInfo::UNKNOWN,
+                        format,
+                    })
+                    .collect();
+
+                Ok(PgAnyArray {
+                    element_type_info: PgTypeInfo::UNKNOWN,
+                    elements,
+                })
+            }
+        }
+    }
+}
+
+fn element_type_info(oid: u32) -> PgTypeInfo {
+    PgTypeInfo::try_from_oid(oid).unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(oid)))
+}
+
+// decodes a value of a pseudo-type sent in the binary format, which carries no text
+pub(crate) fn decode_pseudo_type_as_text(value: PgValueRef<'_>) -> Result<String, BoxDynError> {
+    if value.type_info != PgTypeInfo::ANYARRAY {
+        return Err(format!(
+            "{} values cannot be decoded as text in the binary format",
+            value.type_info
+        )
+        .into());
+    }
+
+    let array = PgAnyArray::decode(value)?;
+    let mut text = String::from("{");
+
+    for (i, element) in array.elements.iter().enumerate() {
+        if i > 0 {
+            text.push(',');
+        }
+
+        match element_to_text(element)? {
+            Some(element) => push_text_array_element(&mut text, &element),
+            None => text.push_str("NULL"),
+        }
+    }
+
+    text.push('}');
+
+    Ok(text)
+}
+
+fn element_to_text(element: &PgValue) -> Result<Option<String>, BoxDynError> {
+    if element.value.is_none() {
+        return Ok(None);
+    }
+
+    let text = match element.type_info.0 {
+        PgType::Bool => if element.try_decode::<bool>()? {
+            "t"
+        } else {
+            "f"
+        }
+        .to_owned(),
+        PgType::Int2 => element.try_decode::<i16>()?.to_string(),
+        PgType::Int4 => element.try_decode::<i32>()?.to_string(),
+        PgType::Int8 => element.try_decode::<i64>()?.to_string(),
+        PgType::Oid => element.try_decode::<u32>()?.to_string(),
+        PgType::Float4 => element.try_decode::<f32>()?.to_string(),
+        PgType::Float8 => element.try_decode::<f64>()?.to_string(),
+        PgType::Text | PgType::Varchar | PgType::Bpchar | PgType::Name => {
+            element.try_decode::<String>()?
+        }
+
+        _ => {
+            return Err(format!(
+                "an ANYARRAY of {} cannot be decoded as text in the binary format; \
+                 decode it as a `PgAnyArray` instead",
+                element.type_info
+            )
+            .into())
+        }
+    };
+
+    Ok(Some(text))
+}
+
+// quotes an element of an array literal if needed, see `parse_text_array`
+fn push_text_array_element(text: &mut String, element: &str) {
+    let needs_quotes = element.is_empty()
+        || element.eq_ignore_ascii_case("NULL")
+        || element
+            .chars()
+            .any(|c| matches!(c, '{' | '}' | ',' | '"' | '\\') || c.is_ascii_whitespace());
+
+    if !needs_quotes {
+        text.push_str(element);
+        return;
+    }
+
+    text.push('"');
+
+    for c in element.chars() {
+        if c == '"' || c == '\\' {
+            text.push('\\');
+        }
+
+        text.push(c);
+    }
+
+    text.push('"');
+}
+
+#[test]
+fn test_push_text_array_element() {
+    let mut text = String::new();
+
+    push_text_array_element(&mut text, "abc");
+    push_text_array_element(&mut text, "");
+    push_text_array_element(&mut text, "null");
+    push_text_array_element(&mut text, "a \\\"b\\\"");
+
+    assert_eq!(text, r#"abc"""null""a \\\"b\\\"""#);
+}
@@ -4,9 +4,62 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::type_info::PgType;
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::postgres::{
+    PgArgumentBuffer, PgTypeInfo, PgTypeKind, PgValueFormat, PgValueRef, Postgres,
+};
 use crate::types::Type;
 
+/// Provides the SQL array type of a type, for types that can not implement [`Type`] for
+/// `[Self]` and `Vec<Self>` directly because of the orphan rules.
+///
+/// This is implemented by `#[derive(sqlx::Type)]` for enumerations and records with
+/// `#[sqlx(pg_array)]`, or by hand:
+///
+/// ```rust,ignore
+/// #[derive(sqlx::Type)]
+/// #[sqlx(type_name = "color")]
+/// enum Color { Red, Green, Blue }
+///
+/// impl PgHasArrayType for Color {
+///     fn array_type_info() -> PgTypeInfo {
+///         PgTypeInfo::with_name("_color")
+///     }
+/// }
+/// ```
+pub trait PgHasArrayType {
+    fn array_type_info() -> PgTypeInfo;
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::array_type_info()
+    }
+}
+
+impl<T> Type<Postgres> for [T]
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+impl<T> Type<Postgres> for Vec<T>
+where
+    T: PgHasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
 impl<T> Type<Postgres> for [Option<T>]
 where
     [T]: Type<Postgres>,
@@ -108,8 +161,23 @@ where
 
                 // the OID of the element
                 let element_type_oid = buf.get_u32();
-                element_type_info = PgTypeInfo::try_from_oid(element_type_oid)
-                    .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_type_oid)));
+                element_type_info = match &value.type_info.0 {
+                    // for custom types, the resolved array type knows its element type; this
+                    // is needed to decode elements that are themselves custom (e.g. records)
+                    PgType::Custom(ty) => match &ty.kind {
+                        PgTypeKind::Array(element)
+                            if element.0.try_oid() == Some(element_type_oid) =>
+                        {
+                            Some(element.clone())
+                        }
+
+                        _ => None,
+                    },
+
+                    _ => None,
+                }
+                .or_else(|| PgTypeInfo::try_from_oid(element_type_oid))
+                .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_type_oid)));
 
                 // length of the array axis
                 let len = buf.get_i32();
@@ -138,83 +206,204 @@ where
                 // no type is provided from the database for the element
                 element_type_info = T::type_info();
 
-                let s = value.as_str()?;
+                parse_text_array(value.as_str()?)?
+                    .into_iter()
+                    .map(|element| {
+                        T::decode(PgValueRef {
+                            value: element.as_deref().map(str::as_bytes),
+                            row: None,
+                            type_info: element_type_info.clone(),
+                            format,
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
 
-                // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L718
+// https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L718
 
-                // trim the wrapping braces
-                let s = &s[1..(s.len() - 1)];
+/// Parses the text representation of a one-dimensional array into its elements.
+///
+/// Handles an optional dimension decoration (`[0:2]={..}`), double-quoted elements with
+/// backslash escapes, unquoted `NULL` literals and the whitespace Postgres ignores around
+/// elements. A quoted `"NULL"` is the string `NULL`, not a null element.
+pub(crate) fn parse_text_array(s: &str) -> Result<Vec<Option<String>>, BoxDynError> {
+    // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
+    //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
 
-                if s.is_empty() {
-                    // short-circuit empty arrays up here
-                    return Ok(Vec::new());
+    // TODO: When we add support for BOX we need to figure out some way to make the
+    //       delimiter selection
+
+    let delimiter = ',';
+
+    // skip the dimension decoration, present when the lower bound is not 1
+    let s = match s.find('=') {
+        Some(eq) if s.starts_with('[') => &s[(eq + 1)..],
+        _ => s,
+    };
+
+    let s = s.trim();
+
+    if !s.starts_with('{') || !s.ends_with('}') || s.len() < 2 {
+        return Err(format!("invalid array literal: {:?}", s).into());
+    }
+
+    let mut chars = s[1..(s.len() - 1)].chars().peekable();
+    let mut elements = Vec::with_capacity(4);
+
+    // an array with only whitespace between the braces is empty
+    while chars.peek().map_or(false, |ch| ch.is_whitespace()) {
+        chars.next();
+    }
+
+    if chars.peek().is_none() {
+        return Ok(elements);
+    }
+
+    loop {
+        while chars.peek().map_or(false, |ch| ch.is_whitespace()) {
+            chars.next();
+        }
+
+        let mut element = String::new();
+        let mut quoted = false;
+
+        match chars.peek() {
+            Some('{') => {
+                return Err("encountered a multi-dimensional array; only one-dimensional arrays are supported".into());
+            }
+
+            Some('"') => {
+                chars.next();
+                quoted = true;
+
+                loop {
+                    match chars.next() {
+                        Some('\\') => match chars.next() {
+                            Some(ch) => element.push(ch),
+                            None => return Err("unexpected end of array literal".into()),
+                        },
+
+                        Some('"') => break,
+
+                        Some(ch) => element.push(ch),
+
+                        None => return Err("unterminated quoted array element".into()),
+                    }
                 }
 
-                // NOTE: Nearly *all* types use ',' as the sequence delimiter. Yes, there is one
-                //       that does not. The BOX (not PostGIS) type uses ';' as a delimiter.
+                while chars.peek().map_or(false, |ch| ch.is_whitespace()) {
+                    chars.next();
+                }
+            }
 
-                // TODO: When we add support for BOX we need to figure out some way to make the
-                //       delimiter selection
+            _ => {
+                // trailing whitespace is insignificant unless it was escaped
+                let mut significant_len = 0;
+
+                while let Some(&ch) = chars.peek() {
+                    if ch == delimiter {
+                        break;
+                    }
 
-                let delimiter = ',';
-                let mut done = false;
-                let mut in_quotes = false;
-                let mut in_escape = false;
-                let mut value = String::with_capacity(10);
-                let mut chars = s.chars();
-                let mut elements = Vec::with_capacity(4);
+                    chars.next();
 
-                while !done {
-                    loop {
+                    if ch == '\\' {
                         match chars.next() {
-                            Some(ch) => match ch {
-                                _ if in_escape => {
-                                    value.push(ch);
-                                    in_escape = false;
-                                }
-
-                                '"' => {
-                                    in_quotes = !in_quotes;
-                                }
-
-                                '\\' => {
-                                    in_escape = true;
-                                }
-
-                                _ if ch == delimiter && !in_quotes => {
-                                    break;
-                                }
-
-                                _ => {
-                                    value.push(ch);
-                                }
-                            },
-
-                            None => {
-                                done = true;
-                                break;
-                            }
+                            Some(ch) => element.push(ch),
+                            None => return Err("unexpected end of array literal".into()),
                         }
-                    }
 
-                    let value_opt = if value == "NULL" {
-                        None
+                        significant_len = element.len();
                     } else {
-                        Some(value.as_bytes())
-                    };
-
-                    elements.push(T::decode(PgValueRef {
-                        value: value_opt,
-                        row: None,
-                        type_info: element_type_info.clone(),
-                        format,
-                    })?);
+                        element.push(ch);
 
-                    value.clear();
+                        if !ch.is_whitespace() {
+                            significant_len = element.len();
+                        }
+                    }
                 }
 
-                Ok(elements)
+                element.truncate(significant_len);
             }
         }
+
+        if !quoted && element.eq_ignore_ascii_case("NULL") {
+            elements.push(None);
+        } else {
+            elements.push(Some(element));
+        }
+
+        match chars.next() {
+            Some(ch) if ch == delimiter => {}
+
+            None => break,
+
+            Some(ch) => {
+                return Err(format!("unexpected character {:?} in array literal", ch).into());
+            }
+        }
+    }
+
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_text_array;
+
+    fn parse(s: &str) -> Vec<Option<String>> {
+        parse_text_array(s).unwrap()
+    }
+
+    fn some(s: &str) -> Option<String> {
+        Some(s.to_owned())
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse("{}"), vec![]);
+        assert_eq!(parse("{ }"), vec![]);
+    }
+
+    #[test]
+    fn unquoted() {
+        assert_eq!(parse("{1,2,3}"), vec![some("1"), some("2"), some("3")]);
+        assert_eq!(parse("{ a , b }"), vec![some("a"), some("b")]);
+    }
+
+    #[test]
+    fn quoted() {
+        assert_eq!(
+            parse(r#"{"Hello, World","",Goodbye}"#),
+            vec![some("Hello, World"), some(""), some("Goodbye")]
+        );
+
+        assert_eq!(
+            parse(r#"{"a \"quote\"","back\\slash"}"#),
+            vec![some(r#"a "quote""#), some(r"back\slash")]
+        );
+    }
+
+    #[test]
+    fn nulls() {
+        assert_eq!(
+            parse(r#"{NULL,null,"NULL",x}"#),
+            vec![None, None, some("NULL"), some("x")]
+        );
+    }
+
+    #[test]
+    fn dimension_decoration() {
+        assert_eq!(parse("[0:1]={5,6}"), vec![some("5"), some("6")]);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse_text_array("{{1,2},{3,4}}").is_err());
+        assert!(parse_text_array(r#"{"unterminated}"#).is_err());
+        assert!(parse_text_array("1,2").is_err());
     }
 }
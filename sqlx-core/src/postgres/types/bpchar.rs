@@ -0,0 +1,108 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// A `CHAR(N)` (`BPCHAR`) value without its blank padding.
+///
+/// Postgres pads `CHAR(N)` values with spaces up to their declared length, and a `String`
+/// decoded from one keeps the padding. This wrapper trims trailing spaces on decode, which is
+/// what Postgres itself does when comparing or casting such values to `TEXT`.
+///
+/// ```rust,ignore
+/// // code CHAR(8) NOT NULL
+/// let code: PgBpchar = sqlx::query_scalar("SELECT code FROM products")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// assert_eq!(code.as_str(), "ABC");
+/// ```
+///
+/// Values are bound as-is; Postgres pads them again when they are stored.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgBpchar(pub String);
+
+impl PgBpchar {
+    /// Returns the value as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the inner `String`.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for PgBpchar {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PgBpchar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for PgBpchar {
+    fn from(s: String) -> Self {
+        PgBpchar(s)
+    }
+}
+
+impl From<PgBpchar> for String {
+    fn from(s: PgBpchar) -> Self {
+        s.0
+    }
+}
+
+impl Type<Postgres> for PgBpchar {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BPCHAR
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Type<Postgres> for [PgBpchar] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BPCHAR_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<PgBpchar>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<PgBpchar> {
+    fn type_info() -> PgTypeInfo {
+        <[PgBpchar] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[PgBpchar] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgBpchar {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<Postgres>>::encode(&*self.0, buf)
+    }
+}
+
+impl Decode<'_, Postgres> for PgBpchar {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(PgBpchar(value.as_str()?.trim_end_matches(' ').to_owned()))
+    }
+}
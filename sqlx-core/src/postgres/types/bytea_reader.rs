@@ -0,0 +1,146 @@
+use std::cmp;
+use std::io::{self, Read};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use sqlx_rt::AsyncRead;
+
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::net::{PollReadBuf, PollReadOut};
+use crate::postgres::{PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// A reader over a `BYTEA` value, for consuming large values without copying them into a
+/// `Vec<u8>` first.
+///
+/// The reader shares the buffer of the row it was decoded from, so obtaining it is cheap and
+/// it can outlive the row. It implements both [`std::io::Read`] and the `AsyncRead` trait of
+/// the selected runtime.
+///
+/// ```rust,ignore
+/// let row = sqlx::query("SELECT data FROM attachments WHERE id = $1")
+///     .bind(id)
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// let mut reader: PgByteaReader = row.try_get("data")?;
+///
+/// tokio::io::copy(&mut reader, &mut file).await?;
+/// ```
+///
+/// Values in the text format (e.g., from a simple query) are decoded from hex as they are read.
+#[derive(Debug, Clone)]
+pub struct PgByteaReader {
+    data: Bytes,
+    pos: usize,
+    hex: bool,
+}
+
+impl PgByteaReader {
+    /// Returns the total length of the value, in bytes.
+    pub fn len(&self) -> usize {
+        if self.hex {
+            (self.data.len() - 2) / 2
+        } else {
+            self.data.len()
+        }
+    }
+
+    /// Returns `true` if the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes that have not been read yet.
+    pub fn remaining(&self) -> usize {
+        if self.hex {
+            (self.data.len() - self.pos) / 2
+        } else {
+            self.data.len() - self.pos
+        }
+    }
+}
+
+impl Type<Postgres> for PgByteaReader {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BYTEA
+    }
+}
+
+impl Decode<'_, Postgres> for PgByteaReader {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = value.as_bytes()?;
+
+        // share the buffer of the row instead of copying the value out of it, where possible
+        let data = match value.row {
+            Some(row) => row.slice_ref(bytes),
+            None => Bytes::copy_from_slice(bytes),
+        };
+
+        Ok(match value.format() {
+            PgValueFormat::Binary => PgByteaReader {
+                data,
+                pos: 0,
+                hex: false,
+            },
+
+            PgValueFormat::Text => {
+                // BYTEA is formatted as \x followed by hex characters
+                if !data.starts_with(b"\\x") || data.len() % 2 != 0 {
+                    return Err("expected BYTEA in the hex format".into());
+                }
+
+                PgByteaReader {
+                    data,
+                    pos: 2,
+                    hex: true,
+                }
+            }
+        })
+    }
+}
+
+impl Read for PgByteaReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = cmp::min(buf.len(), self.remaining());
+
+        if self.hex {
+            let src = &self.data[self.pos..(self.pos + n * 2)];
+
+            hex::decode_to_slice(src, &mut buf[..n])
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            self.pos += n * 2;
+        } else {
+            buf[..n].copy_from_slice(&self.data[self.pos..(self.pos + n)]);
+            self.pos += n;
+        }
+
+        Ok(n)
+    }
+}
+
+impl AsyncRead for PgByteaReader {
+    #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut PollReadBuf<'_>,
+    ) -> Poll<io::Result<PollReadOut>> {
+        let n = self.read(buf.initialize_unfilled())?;
+        buf.advance(n);
+
+        Poll::Ready(Ok(()))
+    }
+
+    #[cfg(feature = "_rt-async-std")]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut PollReadBuf<'_>,
+    ) -> Poll<io::Result<PollReadOut>> {
+        Poll::Ready(self.read(buf))
+    }
+}
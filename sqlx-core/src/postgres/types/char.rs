@@ -0,0 +1,58 @@
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+
+// The Postgres `"char"` type is a single byte, used by the catalogs for enumerated flags such
+// as `pg_class.relkind`. Only ASCII values map to a Rust `char` unambiguously, so any
+// other byte is rejected rather than guessed at; use `i8` to read those.
+
+impl Type<Postgres> for char {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::CHAR
+    }
+}
+
+impl Type<Postgres> for [char] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::CHAR_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<char>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<char> {
+    fn type_info() -> PgTypeInfo {
+        <[char] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[char] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Decode<'_, Postgres> for char {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        // note: in the TEXT encoding, a value of "0" here is encoded as an empty string
+        match *value.as_bytes()? {
+            [] => Ok('\0'),
+
+            [byte] if byte.is_ascii() => Ok(byte as char),
+
+            [byte] => Err(format!(
+                "\"char\" value {:#04X} is not an ASCII character; decode as `i8` instead",
+                byte
+            )
+            .into()),
+
+            ref bytes => Err(format!(
+                "expected a single-byte \"char\" value, got {} bytes; decode as `i8` instead",
+                bytes.len()
+            )
+            .into()),
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::postgres::types::date::PgDateRepr;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
 use crate::types::Type;
 use chrono::{Duration, NaiveDate};
 use std::mem;
@@ -38,14 +39,30 @@ impl Encode<'_, Postgres> for NaiveDate {
 
 impl<'r> Decode<'r, Postgres> for NaiveDate {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        Ok(match value.format() {
-            PgValueFormat::Binary => {
-                // DATE is encoded as the days since epoch
-                let days: i32 = Decode::<Postgres>::decode(value)?;
-                NaiveDate::from_ymd(2000, 1, 1) + Duration::days(days.into())
-            }
+        match PgDateRepr::decode(value)? {
+            // DATE is encoded as the days since epoch
+            PgDateRepr::Days(days) => NaiveDate::from_ymd(2000, 1, 1)
+                .checked_add_signed(Duration::days(days.into()))
+                .ok_or_else(|| {
+                    format!(
+                        "DATE of {} days since 2000-01-01 is out of range for NaiveDate",
+                        days
+                    )
+                    .into()
+                }),
+
+            PgDateRepr::Ymd { year, month, day } => NaiveDate::from_ymd_opt(year, month, day)
+                .ok_or_else(|| {
+                    format!(
+                        "DATE {:04}-{:02}-{:02} is out of range for NaiveDate",
+                        year, month, day
+                    )
+                    .into()
+                }),
 
-            PgValueFormat::Text => NaiveDate::parse_from_str(value.as_str()?, "%Y-%m-%d")?,
-        })
+            PgDateRepr::Infinity | PgDateRepr::NegInfinity => {
+                Err("cannot decode an infinite DATE as NaiveDate; use PgDate instead".into())
+            }
+        }
     }
 }
@@ -0,0 +1,217 @@
+use std::any::Any;
+use std::fmt::{self, Debug, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::ext::ustr::UStr;
+use crate::postgres::type_info::PgType;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+type EncodeFn<T> = dyn Fn(&T, &mut Vec<u8>) -> Result<(), BoxDynError> + Send + Sync;
+type DecodeFn<T> = dyn Fn(&[u8]) -> Result<T, BoxDynError> + Send + Sync;
+
+/// The functions converting the values of a type from and to its binary format, as registered
+/// on a connection with [`PgConnection::register_type_codec`][crate::postgres::PgConnection::register_type_codec].
+///
+/// Values are bound and decoded with this codec through [`PgCodecValue<T>`].
+pub struct PgTypeCodec<T> {
+    inner: Arc<CodecInner<T>>,
+}
+
+struct CodecInner<T> {
+    type_name: UStr,
+    encode: Box<EncodeFn<T>>,
+    decode: Box<DecodeFn<T>>,
+}
+
+// a codec of any value type, attached to the type information of the columns of its type
+#[derive(Clone)]
+pub(crate) struct PgAnyTypeCodec(Arc<dyn Any + Send + Sync>);
+
+impl<T> PgTypeCodec<T>
+where
+    T: Send + Sync + 'static,
+{
+    pub(crate) fn new<E, D>(type_name: &str, encode: E, decode: D) -> Self
+    where
+        E: Fn(&T, &mut Vec<u8>) -> Result<(), BoxDynError> + Send + Sync + 'static,
+        D: Fn(&[u8]) -> Result<T, BoxDynError> + Send + Sync + 'static,
+    {
+        PgTypeCodec {
+            inner: Arc::new(CodecInner {
+                type_name: UStr::from(type_name.to_owned()),
+                encode: Box::new(encode),
+                decode: Box::new(decode),
+            }),
+        }
+    }
+
+    /// Returns the name of the type handled by this codec.
+    pub fn type_name(&self) -> &str {
+        &self.inner.type_name
+    }
+
+    /// Wraps a value to be bound with this codec.
+    pub fn value(&self, value: T) -> PgCodecValue<T> {
+        PgCodecValue {
+            value,
+            codec: self.clone(),
+        }
+    }
+
+    pub(crate) fn erase(&self) -> PgAnyTypeCodec {
+        PgAnyTypeCodec(self.inner.clone())
+    }
+}
+
+impl PgAnyTypeCodec {
+    fn downcast<T>(&self) -> Option<PgTypeCodec<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        Arc::clone(&self.0)
+            .downcast::<CodecInner<T>>()
+            .ok()
+            .map(|inner| PgTypeCodec { inner })
+    }
+}
+
+impl<T> Clone for PgTypeCodec<T> {
+    fn clone(&self) -> Self {
+        PgTypeCodec {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T> Debug for PgTypeCodec<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgTypeCodec")
+            .field("type_name", &self.inner.type_name)
+            .finish()
+    }
+}
+
+impl Debug for PgAnyTypeCodec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("PgAnyTypeCodec")
+    }
+}
+
+/// A value of a type handled by a [`PgTypeCodec`].
+///
+/// A `PgCodecValue<T>` can be decoded from the columns of a type for which a codec producing
+/// `T` was registered on the connection, and is bound with the codec it was created with
+/// (see [`PgTypeCodec::value`]).
+///
+/// ```rust,ignore
+/// let ltree = conn.register_type_codec("ltree", encode_ltree, decode_ltree);
+///
+/// let path: PgCodecValue<Ltree> = sqlx::query_scalar("SELECT path FROM nodes WHERE parent = $1")
+///     .bind(ltree.value(parent))
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+///
+/// Only the binary format is supported; values cannot be decoded from the results of
+/// unprepared queries.
+#[derive(Debug, Clone)]
+pub struct PgCodecValue<T> {
+    value: T,
+    codec: PgTypeCodec<T>,
+}
+
+impl<T> PgCodecValue<T> {
+    /// Returns the codec of this value.
+    pub fn codec(&self) -> &PgTypeCodec<T> {
+        &self.codec
+    }
+
+    /// Returns the inner value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for PgCodecValue<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+fn codec_of<T>(ty: &PgTypeInfo) -> Option<PgTypeCodec<T>>
+where
+    T: Send + Sync + 'static,
+{
+    match &ty.0 {
+        PgType::Custom(ty) => ty.codec.as_ref()?.downcast(),
+        _ => None,
+    }
+}
+
+impl<T> Type<Postgres> for PgCodecValue<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn type_info() -> PgTypeInfo {
+        // the actual type is given by the codec of each value
+        PgTypeInfo::UNKNOWN
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        codec_of::<T>(ty).is_some()
+    }
+}
+
+impl<T> Encode<'_, Postgres> for PgCodecValue<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(PgTypeInfo(PgType::DeclareWithName(
+            self.codec.inner.type_name.clone(),
+        )))
+    }
+
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        if let Err(error) = (self.codec.inner.encode)(&self.value, buf) {
+            buf.encode_error(error);
+        }
+
+        IsNull::No
+    }
+}
+
+impl<T> Decode<'_, Postgres> for PgCodecValue<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let codec = codec_of::<T>(&value.type_info).ok_or_else(|| {
+            format!(
+                "no codec producing `{}` is registered for type {}",
+                std::any::type_name::<T>(),
+                value.type_info
+            )
+        })?;
+
+        if value.format() == PgValueFormat::Text {
+            return Err(format!(
+                "the codec of type {} cannot decode values in the text format",
+                value.type_info
+            )
+            .into());
+        }
+
+        Ok(PgCodecValue {
+            value: (codec.inner.decode)(value.as_bytes()?)?,
+            codec,
+        })
+    }
+}
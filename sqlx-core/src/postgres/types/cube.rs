@@ -0,0 +1,196 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+use byteorder::{BigEndian, ByteOrder};
+use std::mem;
+
+// https://github.com/postgres/postgres/blob/REL_15_0/contrib/cube/cubedata.h
+const POINT_BIT: u32 = 0x8000_0000;
+const DIM_MASK: u32 = 0x7fff_ffff;
+const MAX_DIM: usize = 100;
+
+/// A value of the [`cube`] extension type: a point or a box in up to 100 dimensions.
+///
+/// The type is resolved by name, so the extension has to be installed in the database
+/// (`CREATE EXTENSION cube`) but may live in any schema on the search path.
+///
+/// [`cube`]: https://www.postgresql.org/docs/current/cube.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum PgCube {
+    /// A point, given by its coordinates.
+    Point(Vec<f64>),
+
+    /// A box, given by the coordinates of two opposite corners with the same number of
+    /// dimensions.
+    Box(Vec<f64>, Vec<f64>),
+}
+
+impl PgCube {
+    /// Returns the number of dimensions of the cube.
+    pub fn dimensions(&self) -> usize {
+        match self {
+            PgCube::Point(point) => point.len(),
+            PgCube::Box(lower_left, _) => lower_left.len(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), BoxDynError> {
+        if let PgCube::Box(a, b) = self {
+            if a.len() != b.len() {
+                return Err(format!(
+                    "the corners of a cube have different dimensions: {} and {}",
+                    a.len(),
+                    b.len()
+                )
+                .into());
+            }
+        }
+
+        if self.dimensions() > MAX_DIM {
+            return Err(format!(
+                "a cube can have at most {} dimensions, got {}",
+                MAX_DIM,
+                self.dimensions()
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn from_text(s: &str) -> Result<Self, BoxDynError> {
+        // e.g. `(1, 2)` for a point and `(1, 2),(3, 4)` for a box
+        let parse_point = |s: &str| -> Result<Vec<f64>, BoxDynError> {
+            let s = s
+                .trim()
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .ok_or_else(|| format!("invalid cube: {:?}", s))?;
+
+            s.split(',')
+                .map(|coordinate| coordinate.trim().parse().map_err(Into::into))
+                .collect()
+        };
+
+        match s.find("),") {
+            Some(index) => Ok(PgCube::Box(
+                parse_point(&s[..=index])?,
+                parse_point(&s[index + 2..])?,
+            )),
+
+            None => Ok(PgCube::Point(parse_point(s)?)),
+        }
+    }
+}
+
+impl From<Vec<f64>> for PgCube {
+    fn from(point: Vec<f64>) -> Self {
+        PgCube::Point(point)
+    }
+}
+
+impl From<(Vec<f64>, Vec<f64>)> for PgCube {
+    fn from((a, b): (Vec<f64>, Vec<f64>)) -> Self {
+        PgCube::Box(a, b)
+    }
+}
+
+impl Type<Postgres> for PgCube {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("cube")
+    }
+}
+
+impl Type<Postgres> for [PgCube] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_cube")
+    }
+}
+
+impl Type<Postgres> for Vec<PgCube> {
+    fn type_info() -> PgTypeInfo {
+        <[PgCube] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PgCube {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        if let Err(error) = self.validate() {
+            buf.encode_error(error);
+        }
+
+        let header = match self {
+            PgCube::Point(point) => point.len() as u32 | POINT_BIT,
+            PgCube::Box(lower_left, _) => lower_left.len() as u32,
+        };
+
+        buf.extend(&header.to_be_bytes());
+
+        let coordinates: &mut dyn Iterator<Item = &f64> = match self {
+            PgCube::Point(point) => &mut point.iter(),
+            PgCube::Box(a, b) => &mut a.iter().chain(b),
+        };
+
+        for coordinate in coordinates {
+            buf.extend(&coordinate.to_be_bytes());
+        }
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        let coordinates = match self {
+            PgCube::Point(point) => point.len(),
+            PgCube::Box(a, b) => a.len() + b.len(),
+        };
+
+        mem::size_of::<u32>() + coordinates * mem::size_of::<f64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgCube {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let bytes = value.as_bytes()?;
+
+                if bytes.len() < mem::size_of::<u32>() {
+                    return Err("invalid cube: missing header".into());
+                }
+
+                let header = BigEndian::read_u32(bytes);
+                let dimensions = (header & DIM_MASK) as usize;
+                let is_point = header & POINT_BIT != 0;
+
+                let len = if is_point { dimensions } else { dimensions * 2 };
+                let data = &bytes[mem::size_of::<u32>()..];
+
+                if dimensions > MAX_DIM || data.len() != len * mem::size_of::<f64>() {
+                    return Err(format!(
+                        "invalid cube: expected {} coordinates, got {} bytes",
+                        len,
+                        data.len()
+                    )
+                    .into());
+                }
+
+                let mut coordinates: Vec<f64> = data
+                    .chunks_exact(mem::size_of::<f64>())
+                    .map(BigEndian::read_f64)
+                    .collect();
+
+                Ok(if is_point {
+                    PgCube::Point(coordinates)
+                } else {
+                    let upper_right = coordinates.split_off(dimensions);
+
+                    PgCube::Box(coordinates, upper_right)
+                })
+            }
+
+            PgValueFormat::Text => PgCube::from_text(value.as_str()?),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+use std::mem;
+
+#[cfg(feature = "time")]
+type DefaultDate = ::time::Date;
+
+#[cfg(all(not(feature = "time"), feature = "chrono"))]
+type DefaultDate = ::chrono::NaiveDate;
+
+#[cfg(all(not(feature = "time"), not(feature = "chrono"), feature = "jiff"))]
+type DefaultDate = ::jiff::civil::Date;
+
+// the special values of DATE in the binary format
+const INFINITY: i32 = i32::MAX;
+const NEG_INFINITY: i32 = i32::MIN;
+
+/// A `DATE` which may be one of the special values `infinity` and `-infinity`.
+///
+/// Decoding one of the special values into a bare date type is an error.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PgDate<Date = DefaultDate> {
+    /// `-infinity`, earlier than every other date.
+    NegInfinity,
+
+    Date(Date),
+
+    /// `infinity`, later than every other date.
+    Infinity,
+}
+
+/// A `DATE` as sent by the server, before conversion to a date type.
+pub(crate) enum PgDateRepr {
+    NegInfinity,
+
+    /// The number of days since 2000-01-01, in the binary format.
+    Days(i32),
+
+    /// The components of the date in the text format, with the year counted astronomically
+    /// (1 BC is the year 0).
+    Ymd {
+        year: i32,
+        month: u32,
+        day: u32,
+    },
+
+    Infinity,
+}
+
+impl PgDateRepr {
+    pub(crate) fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(match value.format() {
+            PgValueFormat::Binary => match Decode::<Postgres>::decode(value)? {
+                INFINITY => PgDateRepr::Infinity,
+                NEG_INFINITY => PgDateRepr::NegInfinity,
+                days => PgDateRepr::Days(days),
+            },
+
+            PgValueFormat::Text => {
+                let s = value.as_str()?;
+
+                match s {
+                    "infinity" => PgDateRepr::Infinity,
+                    "-infinity" => PgDateRepr::NegInfinity,
+                    _ => parse_ymd(s).ok_or_else(|| format!("invalid DATE: {:?}", s))?,
+                }
+            }
+        })
+    }
+}
+
+// parses the ISO format of the server, e.g. `2020-01-01` or `4713-11-24 BC`
+fn parse_ymd(s: &str) -> Option<PgDateRepr> {
+    let (date, bc) = match s.strip_suffix(" BC") {
+        Some(date) => (date, true),
+        None => (s, false),
+    };
+
+    let mut parts = date.splitn(3, '-');
+
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+
+    Some(PgDateRepr::Ymd {
+        year: if bc { 1 - year } else { year },
+        month,
+        day,
+    })
+}
+
+impl<Date> Type<Postgres> for PgDate<Date>
+where
+    Date: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        Date::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        Date::compatible(ty)
+    }
+}
+
+impl<Date> Type<Postgres> for [PgDate<Date>]
+where
+    PgDate<Date>: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_ARRAY
+    }
+}
+
+impl<Date> Type<Postgres> for Vec<PgDate<Date>>
+where
+    PgDate<Date>: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_ARRAY
+    }
+}
+
+impl<'q, Date> Encode<'q, Postgres> for PgDate<Date>
+where
+    Date: Encode<'q, Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        match self {
+            PgDate::NegInfinity => Encode::<Postgres>::encode(&NEG_INFINITY, buf),
+            PgDate::Date(date) => date.encode_by_ref(buf),
+            PgDate::Infinity => Encode::<Postgres>::encode(&INFINITY, buf),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i32>()
+    }
+}
+
+impl<'r, Date> Decode<'r, Postgres> for PgDate<Date>
+where
+    Date: Decode<'r, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(match PgDateRepr::decode(value.clone())? {
+            PgDateRepr::NegInfinity => PgDate::NegInfinity,
+            PgDateRepr::Infinity => PgDate::Infinity,
+            _ => PgDate::Date(Date::decode(value)?),
+        })
+    }
+}
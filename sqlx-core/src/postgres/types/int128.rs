@@ -0,0 +1,236 @@
+use std::cmp;
+use std::convert::TryFrom;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::numeric::{PgNumeric, PgNumericSign};
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+fn numeric_from_u128(sign: PgNumericSign, mut value: u128) -> PgNumeric {
+    // base-10000 digits, least significant first
+    let mut digits = Vec::new();
+
+    while value != 0 {
+        digits.push((value % 10000) as i16);
+        value /= 10000;
+    }
+
+    let weight = digits.len().saturating_sub(1) as i16;
+
+    // Postgres strips trailing zero digits; `weight` keeps the magnitude
+    let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    digits.drain(..zeros);
+    digits.reverse();
+
+    PgNumeric::Number {
+        sign: if digits.is_empty() {
+            PgNumericSign::Positive
+        } else {
+            sign
+        },
+        digits,
+        weight,
+        scale: 0,
+    }
+}
+
+/// Decodes the magnitude and the sign of an integral `NUMERIC` value.
+fn decode_integer(value: PgValueRef<'_>) -> Result<(PgNumericSign, u128), BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let numeric = PgNumeric::decode(value.as_bytes()?)?;
+
+            let (digits, sign, weight) = match &numeric {
+                PgNumeric::Number {
+                    digits,
+                    sign,
+                    weight,
+                    ..
+                } => (digits, *sign, *weight),
+
+                PgNumeric::NotANumber => return Err("cannot decode NaN as an integer".into()),
+            };
+
+            // the digit at index `weight` is the last one before the decimal point
+            let integral_len = if weight < 0 { 0 } else { weight as usize + 1 };
+            let (integral, fraction) = digits.split_at(cmp::min(digits.len(), integral_len));
+
+            if fraction.iter().any(|&digit| digit != 0) {
+                return Err(format!("{} is not an integer", numeric).into());
+            }
+
+            let mut magnitude = 0_u128;
+
+            for index in 0..integral_len {
+                let digit = integral.get(index).copied().unwrap_or(0);
+
+                magnitude = magnitude
+                    .checked_mul(10000)
+                    .and_then(|it| it.checked_add(digit as u128))
+                    .ok_or_else(|| format!("{} is out of range for a 128-bit integer", numeric))?;
+            }
+
+            Ok((sign, magnitude))
+        }
+
+        PgValueFormat::Text => {
+            let s = value.as_str()?;
+
+            if s == "NaN" {
+                return Err("cannot decode NaN as an integer".into());
+            }
+
+            let (sign, unsigned) = match s.strip_prefix('-') {
+                Some(unsigned) => (PgNumericSign::Negative, unsigned),
+                None => (PgNumericSign::Positive, s),
+            };
+
+            let (integral, fraction) = match unsigned.find('.') {
+                Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+                None => (unsigned, ""),
+            };
+
+            if fraction.bytes().any(|b| b != b'0') {
+                return Err(format!("{} is not an integer", s).into());
+            }
+
+            let magnitude = integral
+                .parse()
+                .map_err(|e| format!("{} is out of range for a 128-bit integer: {}", s, e))?;
+
+            Ok((sign, magnitude))
+        }
+    }
+}
+
+impl Type<Postgres> for i128 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+}
+
+impl Type<Postgres> for [i128] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<i128> {
+    fn type_info() -> PgTypeInfo {
+        <[i128] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for i128 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        let sign = if *self < 0 {
+            PgNumericSign::Negative
+        } else {
+            PgNumericSign::Positive
+        };
+
+        numeric_from_u128(sign, self.wrapping_abs() as u128).encode(buf);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for i128 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let (sign, magnitude) = decode_integer(value)?;
+
+        let value = match sign {
+            PgNumericSign::Positive => i128::try_from(magnitude).ok(),
+
+            // the magnitude of `i128::MIN` does not fit in an `i128`
+            PgNumericSign::Negative if magnitude <= i128::MAX as u128 + 1 => {
+                Some((magnitude as i128).wrapping_neg())
+            }
+
+            PgNumericSign::Negative => None,
+        };
+
+        value.ok_or_else(|| {
+            let sign = if sign == PgNumericSign::Negative {
+                "-"
+            } else {
+                ""
+            };
+
+            format!("{}{} is out of range for i128", sign, magnitude).into()
+        })
+    }
+}
+
+impl Type<Postgres> for u128 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+}
+
+impl Type<Postgres> for [u128] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<u128> {
+    fn type_info() -> PgTypeInfo {
+        <[u128] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for u128 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        numeric_from_u128(PgNumericSign::Positive, *self).encode(buf);
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for u128 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match decode_integer(value)? {
+            (PgNumericSign::Negative, magnitude) if magnitude != 0 => {
+                Err(format!("-{} is out of range for u128", magnitude).into())
+            }
+
+            (_, magnitude) => Ok(magnitude),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::numeric_from_u128;
+    use crate::postgres::types::numeric::{PgNumeric, PgNumericSign};
+
+    #[test]
+    fn it_converts_zero() {
+        assert_eq!(
+            numeric_from_u128(PgNumericSign::Negative, 0),
+            PgNumeric::Number {
+                sign: PgNumericSign::Positive,
+                digits: vec![],
+                weight: 0,
+                scale: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn it_strips_trailing_zero_digits() {
+        // 1_2345_0000_0000
+        assert_eq!(
+            numeric_from_u128(PgNumericSign::Negative, 1_2345_0000_0000),
+            PgNumeric::Number {
+                sign: PgNumericSign::Negative,
+                digits: vec![1, 2345],
+                weight: 3,
+                scale: 0,
+            }
+        );
+    }
+}
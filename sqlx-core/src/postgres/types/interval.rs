@@ -201,6 +201,115 @@ impl TryFrom<time::Duration> for PgInterval {
     }
 }
 
+#[cfg(feature = "jiff")]
+impl Type<Postgres> for jiff::Span {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INTERVAL
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl Type<Postgres> for [jiff::Span] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INTERVAL_ARRAY
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl Encode<'_, Postgres> for jiff::Span {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        match PgInterval::try_from(*self) {
+            Ok(pg_interval) => pg_interval.encode_by_ref(buf),
+
+            Err(error) => {
+                buf.encode_error(error);
+                IsNull::No
+            }
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        2 * mem::size_of::<i64>()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl<'de> Decode<'de, Postgres> for jiff::Span {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        PgInterval::decode(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<jiff::Span> for PgInterval {
+    type Error = BoxDynError;
+
+    /// Convert a `jiff::Span` to a `PgInterval`.
+    ///
+    /// Years and weeks are kept as months and days. This returns an error if there is a loss of
+    /// precision using nanoseconds or if there is an overflow of any of the fields.
+    fn try_from(value: jiff::Span) -> Result<Self, BoxDynError> {
+        if value.get_nanoseconds() % 1000 != 0 {
+            return Err("PostgreSQL `INTERVAL` does not support nanoseconds precision".into());
+        }
+
+        let months = i64::from(value.get_years()) * 12 + i64::from(value.get_months());
+        let days = i64::from(value.get_weeks()) * 7 + i64::from(value.get_days());
+
+        let microseconds = [
+            (i64::from(value.get_hours()), 3_600_000_000),
+            (value.get_minutes(), 60_000_000),
+            (value.get_seconds(), 1_000_000),
+            (value.get_milliseconds(), 1_000),
+            (value.get_microseconds(), 1),
+            (value.get_nanoseconds() / 1000, 1),
+        ]
+        .iter()
+        .try_fold(0_i64, |sum, &(n, scale)| {
+            n.checked_mul(scale)?.checked_add(sum)
+        })
+        .ok_or("interval out of range for PostgreSQL `INTERVAL`")?;
+
+        Ok(Self {
+            months: months.try_into()?,
+            days: days.try_into()?,
+            microseconds,
+        })
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<PgInterval> for jiff::Span {
+    type Error = BoxDynError;
+
+    /// Convert a `PgInterval` to a `jiff::Span`, with the microseconds split into hours,
+    /// minutes, seconds and microseconds.
+    ///
+    /// This returns an error if the fields have different signs, which a `jiff::Span` cannot
+    /// represent, or if the interval is out of the range of a `jiff::Span`.
+    fn try_from(value: PgInterval) -> Result<Self, BoxDynError> {
+        let signs = [
+            i64::from(value.months).signum(),
+            i64::from(value.days).signum(),
+            value.microseconds.signum(),
+        ];
+
+        if signs.contains(&1) && signs.contains(&-1) {
+            return Err(format!("cannot represent {:?} as a jiff::Span", value).into());
+        }
+
+        let us = value.microseconds;
+
+        Ok(jiff::Span::new()
+            .try_months(value.months)?
+            .try_days(value.days)?
+            .try_hours(us / 3_600_000_000)?
+            .try_minutes(us / 60_000_000 % 60)?
+            .try_seconds(us / 1_000_000 % 60)?
+            .try_microseconds(us % 1_000_000)?)
+    }
+}
+
 #[test]
 fn test_encode_interval() {
     let mut buf = PgArgumentBuffer::default();
@@ -321,3 +430,30 @@ fn test_pginterval_time() {
         &interval
     );
 }
+
+#[test]
+#[cfg(feature = "jiff")]
+fn test_pginterval_jiff() {
+    let interval = PgInterval {
+        days: 8,
+        months: 13,
+        microseconds: 3_627_000_000,
+    };
+
+    let span = jiff::Span::new()
+        .years(1)
+        .months(1)
+        .weeks(1)
+        .days(1)
+        .hours(1)
+        .seconds(27);
+
+    assert_eq!(&PgInterval::try_from(span).unwrap(), &interval);
+
+    let span = jiff::Span::try_from(interval).unwrap();
+
+    assert_eq!(span.get_months(), 13);
+    assert_eq!(span.get_days(), 8);
+    assert_eq!(span.get_hours(), 1);
+    assert_eq!(span.get_seconds(), 27);
+}
@@ -0,0 +1,167 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// https://github.com/rust-lang/rust/search?q=AF_INET&unscoped_q=AF_INET
+
+#[cfg(windows)]
+const AF_INET: u8 = 2;
+
+#[cfg(not(any(unix, windows)))]
+const AF_INET: u8 = 0;
+
+#[cfg(unix)]
+const AF_INET: u8 = libc::AF_INET as u8;
+
+// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/include/utils/inet.h#L39
+
+pub(crate) const PGSQL_AF_INET: u8 = AF_INET;
+pub(crate) const PGSQL_AF_INET6: u8 = AF_INET + 1;
+
+// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/backend/utils/adt/network.c#L293
+// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/backend/utils/adt/network.c#L271
+
+pub(crate) fn encode_inet(buf: &mut PgArgumentBuffer, addr: IpAddr, prefix: u8) {
+    match addr {
+        IpAddr::V4(addr) => {
+            buf.push(PGSQL_AF_INET); // ip_family
+            buf.push(prefix); // ip_bits
+            buf.push(0); // is_cidr
+            buf.push(4); // nb (number of bytes)
+            buf.extend_from_slice(&addr.octets()) // address
+        }
+
+        IpAddr::V6(addr) => {
+            buf.push(PGSQL_AF_INET6); // ip_family
+            buf.push(prefix); // ip_bits
+            buf.push(0); // is_cidr
+            buf.push(16); // nb (number of bytes)
+            buf.extend_from_slice(&addr.octets()); // address
+        }
+    }
+}
+
+/// Decodes an `INET` or `CIDR` value into its address and prefix length.
+pub(crate) fn decode_inet(value: PgValueRef<'_>) -> Result<(IpAddr, u8), BoxDynError> {
+    let bytes = match value.format() {
+        PgValueFormat::Binary => value.as_bytes()?,
+        PgValueFormat::Text => {
+            let s = value.as_str()?;
+
+            return Ok(match s.find('/') {
+                Some(slash) => (s[..slash].parse()?, s[(slash + 1)..].parse()?),
+
+                None => {
+                    let addr: IpAddr = s.parse()?;
+                    (addr, max_prefix(&addr))
+                }
+            });
+        }
+    };
+
+    if bytes.len() >= 8 {
+        let family = bytes[0];
+        let prefix = bytes[1];
+        let _is_cidr = bytes[2] != 0;
+        let len = bytes[3];
+
+        match family {
+            PGSQL_AF_INET => {
+                if bytes.len() == 8 && len == 4 {
+                    let addr = Ipv4Addr::new(bytes[4], bytes[5], bytes[6], bytes[7]);
+
+                    return Ok((IpAddr::V4(addr), prefix));
+                }
+            }
+
+            PGSQL_AF_INET6 => {
+                if bytes.len() == 20 && len == 16 {
+                    let mut octets = [0_u8; 16];
+                    octets.copy_from_slice(&bytes[4..]);
+
+                    return Ok((IpAddr::V6(Ipv6Addr::from(octets)), prefix));
+                }
+            }
+
+            _ => {
+                return Err(format!("unknown ip family {}", family).into());
+            }
+        }
+    }
+
+    Err("invalid data received when expecting an INET".into())
+}
+
+fn max_prefix(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+impl Type<Postgres> for IpAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR || *ty == PgTypeInfo::INET
+    }
+}
+
+impl Type<Postgres> for [IpAddr] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<IpAddr>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<IpAddr> {
+    fn type_info() -> PgTypeInfo {
+        <[IpAddr] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[IpAddr] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for IpAddr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_inet(buf, *self, max_prefix(self));
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpAddr::V4(_) => 8,
+            IpAddr::V6(_) => 20,
+        }
+    }
+}
+
+impl Decode<'_, Postgres> for IpAddr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let (addr, prefix) = decode_inet(value)?;
+
+        // a network cannot be represented by a single address
+        if prefix != max_prefix(&addr) {
+            return Err(format!(
+                "cannot decode the network {}/{} as an IpAddr; use a network type instead",
+                addr, prefix
+            )
+            .into());
+        }
+
+        Ok(addr)
+    }
+}
@@ -0,0 +1,64 @@
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+use std::net::IpAddr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::types::ipaddr::{decode_inet, encode_inet};
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+
+impl Type<Postgres> for IpNet {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR || *ty == PgTypeInfo::INET
+    }
+}
+
+impl Type<Postgres> for [IpNet] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<IpNet>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<IpNet> {
+    fn type_info() -> PgTypeInfo {
+        <[IpNet] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[IpNet] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for IpNet {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        encode_inet(buf, self.addr(), self.prefix_len());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpNet::V4(_) => 8,
+            IpNet::V6(_) => 20,
+        }
+    }
+}
+
+impl Decode<'_, Postgres> for IpNet {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(match decode_inet(value)? {
+            (IpAddr::V4(addr), prefix) => IpNet::V4(Ipv4Net::new(addr, prefix)?),
+            (IpAddr::V6(addr), prefix) => IpNet::V6(Ipv6Net::new(addr, prefix)?),
+        })
+    }
+}
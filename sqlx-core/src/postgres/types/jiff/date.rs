@@ -0,0 +1,82 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::date::PgDateRepr;
+use crate::postgres::types::jiff::PG_EPOCH;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+use jiff::civil::Date;
+use jiff::{Span, Unit};
+use std::convert::TryFrom;
+use std::mem;
+
+impl Type<Postgres> for Date {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE
+    }
+}
+
+impl Type<Postgres> for [Date] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<Date> {
+    fn type_info() -> PgTypeInfo {
+        <[Date] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Date {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // DATE is encoded as the days since epoch
+        let days = match PG_EPOCH.until((Unit::Day, *self)) {
+            Ok(span) => span.get_days(),
+
+            Err(error) => {
+                buf.encode_error(error);
+                0
+            }
+        };
+
+        Encode::<Postgres>::encode(&days, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i32>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Date {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match PgDateRepr::decode(value)? {
+            // DATE is encoded as the days since epoch
+            PgDateRepr::Days(days) => Span::new()
+                .try_days(days)
+                .and_then(|span| PG_EPOCH.checked_add(span))
+                .map_err(|_| {
+                    format!(
+                        "DATE of {} days since 2000-01-01 is out of range for Date",
+                        days
+                    )
+                    .into()
+                }),
+
+            PgDateRepr::Ymd { year, month, day } => i16::try_from(year)
+                .ok()
+                .and_then(|year| Date::new(year, month as i8, day as i8).ok())
+                .ok_or_else(|| {
+                    format!(
+                        "DATE {:04}-{:02}-{:02} is out of range for Date",
+                        year, month, day
+                    )
+                    .into()
+                }),
+
+            PgDateRepr::Infinity | PgDateRepr::NegInfinity => {
+                Err("cannot decode an infinite DATE as Date; use PgDate instead".into())
+            }
+        }
+    }
+}
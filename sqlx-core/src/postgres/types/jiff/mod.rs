@@ -0,0 +1,8 @@
+mod date;
+mod time;
+mod timestamp;
+
+const PG_EPOCH: ::jiff::civil::Date = ::jiff::civil::Date::constant(2000, 1, 1);
+
+// 2000-01-01T00:00:00Z in microseconds since the Unix epoch
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
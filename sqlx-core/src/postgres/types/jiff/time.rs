@@ -0,0 +1,69 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+use jiff::civil::Time;
+use std::mem;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+
+impl Type<Postgres> for Time {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIME
+    }
+}
+
+impl Type<Postgres> for [Time] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIME_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<Time> {
+    fn type_info() -> PgTypeInfo {
+        <[Time] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Time {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // TIME is encoded as the microseconds since midnight
+        let seconds = i64::from(self.hour()) * 3600
+            + i64::from(self.minute()) * 60
+            + i64::from(self.second());
+
+        let us = seconds * MICROS_PER_SECOND + i64::from(self.subsec_nanosecond()) / 1000;
+
+        Encode::<Postgres>::encode(&us, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<u64>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Time {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                // TIME is encoded as the microseconds since midnight
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                let seconds = us.div_euclid(MICROS_PER_SECOND);
+                let subsec_nanos = us.rem_euclid(MICROS_PER_SECOND) * 1000;
+
+                // `24:00:00` is a valid TIME but not a valid `Time`
+                Time::new(
+                    (seconds / 3600) as i8,
+                    (seconds / 60 % 60) as i8,
+                    (seconds % 60) as i8,
+                    subsec_nanos as i32,
+                )
+                .map_err(|_| format!("TIME of {} microseconds is out of range for Time", us).into())
+            }
+
+            PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::jiff::PG_EPOCH_MICROS;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+use jiff::Timestamp;
+use std::mem;
+
+impl Type<Postgres> for Timestamp {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMPTZ
+    }
+}
+
+impl Type<Postgres> for [Timestamp] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMPTZ_ARRAY
+    }
+}
+
+impl Type<Postgres> for Vec<Timestamp> {
+    fn type_info() -> PgTypeInfo {
+        <[Timestamp] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for Timestamp {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // TIMESTAMPTZ is encoded as the microseconds since the epoch, in UTC; the range of
+        // `Timestamp` is small enough for this not to overflow
+        let us = self.as_microsecond() - PG_EPOCH_MICROS;
+        Encode::<Postgres>::encode(&us, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Timestamp {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                // TIMESTAMPTZ is encoded as the microseconds since the epoch, in UTC
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                us.checked_add(PG_EPOCH_MICROS)
+                    .and_then(|us| Timestamp::from_microsecond(us).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "TIMESTAMPTZ of {} microseconds since 2000-01-01 is out of range \
+                             for Timestamp",
+                            us
+                        )
+                        .into()
+                    })
+            }
+
+            // e.g. `2020-01-01 12:00:00.5+02`
+            PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// the version of the binary format, which is the text prefixed with it
+const JSONPATH_VERSION: u8 = 1;
+
+/// A [`JSONPATH`] expression, as passed to the SQL/JSON path functions.
+///
+/// Binding a `PgJsonPath` sends the expression with its type, so it does not need to be cast
+/// in the query:
+///
+/// ```rust,ignore
+/// let path = PgJsonPath::from("$.tags[*] ? (@ starts with \"db\")");
+///
+/// let tags: Vec<serde_json::Value> =
+///     sqlx::query_scalar("SELECT jsonb_path_query(document, $1) FROM posts")
+///         .bind(&path)
+///         .fetch_all(&mut conn)
+///         .await?;
+/// ```
+///
+/// The expression is only parsed by the server. It normalizes the expressions it sends back,
+/// e.g. `$.a` is decoded as `$."a"`.
+///
+/// [`JSONPATH`]: https://www.postgresql.org/docs/current/datatype-json.html#DATATYPE-JSONPATH
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgJsonPath(pub String);
+
+impl PgJsonPath {
+    /// Returns the expression as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the inner `String`.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for PgJsonPath {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for PgJsonPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for PgJsonPath {
+    fn from(s: String) -> Self {
+        PgJsonPath(s)
+    }
+}
+
+impl From<&str> for PgJsonPath {
+    fn from(s: &str) -> Self {
+        PgJsonPath(s.to_owned())
+    }
+}
+
+impl From<PgJsonPath> for String {
+    fn from(path: PgJsonPath) -> Self {
+        path.0
+    }
+}
+
+impl Type<Postgres> for PgJsonPath {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::JSONPATH
+    }
+}
+
+impl Type<Postgres> for [PgJsonPath] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::JSONPATH_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<PgJsonPath>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<PgJsonPath> {
+    fn type_info() -> PgTypeInfo {
+        <[PgJsonPath] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[PgJsonPath] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgJsonPath {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(JSONPATH_VERSION);
+        buf.extend_from_slice(self.0.as_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.0.len()
+    }
+}
+
+impl Decode<'_, Postgres> for PgJsonPath {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let text = match value.format() {
+            PgValueFormat::Binary => {
+                let bytes = value.as_bytes()?;
+
+                match bytes.split_first() {
+                    Some((&JSONPATH_VERSION, text)) => std::str::from_utf8(text)?,
+
+                    Some((version, _)) => {
+                        return Err(format!(
+                            "unsupported JSONPATH format version {}; please open an issue",
+                            version
+                        )
+                        .into());
+                    }
+
+                    None => return Err("invalid JSONPATH: empty value".into()),
+                }
+            }
+
+            PgValueFormat::Text => value.as_str()?,
+        };
+
+        Ok(PgJsonPath(text.to_owned()))
+    }
+}
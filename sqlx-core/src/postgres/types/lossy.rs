@@ -0,0 +1,103 @@
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::postgres::types::numeric::PgNumeric;
+use crate::postgres::{PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::{parse_float, Lossy, Type};
+
+fn lossy_float_compatible(ty: &PgTypeInfo) -> bool {
+    [PgTypeInfo::NUMERIC, PgTypeInfo::FLOAT4, PgTypeInfo::FLOAT8].contains(ty)
+}
+
+fn decode_lossy_f64(value: PgValueRef<'_>) -> Result<f64, BoxDynError> {
+    if value.type_info == PgTypeInfo::FLOAT4 {
+        return <f32 as Decode<Postgres>>::decode(value).map(f64::from);
+    }
+
+    if value.type_info != PgTypeInfo::NUMERIC {
+        return <f64 as Decode<Postgres>>::decode(value);
+    }
+
+    Ok(match value.format() {
+        PgValueFormat::Binary => PgNumeric::decode(value.as_bytes()?)?.to_f64(),
+        PgValueFormat::Text => parse_float(value.as_str()?)?,
+    })
+}
+
+impl Type<Postgres> for Lossy<f64> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        lossy_float_compatible(ty)
+    }
+}
+
+impl Decode<'_, Postgres> for Lossy<f64> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        decode_lossy_f64(value).map(Lossy)
+    }
+}
+
+impl Type<Postgres> for Lossy<f32> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        lossy_float_compatible(ty)
+    }
+}
+
+impl Decode<'_, Postgres> for Lossy<f32> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.type_info == PgTypeInfo::FLOAT4 {
+            return <f32 as Decode<Postgres>>::decode(value).map(Lossy);
+        }
+
+        decode_lossy_f64(value).map(|v| Lossy(v as f32))
+    }
+}
+
+#[cfg(test)]
+mod numeric_to_f64 {
+    use super::PgNumeric;
+    use crate::postgres::types::numeric::PgNumericSign;
+
+    fn number(sign: PgNumericSign, weight: i16, digits: Vec<i16>) -> PgNumeric {
+        PgNumeric::Number {
+            sign,
+            weight,
+            digits,
+            scale: 0,
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(number(PgNumericSign::Positive, 0, vec![]).to_f64(), 0.0);
+    }
+
+    #[test]
+    fn fractional() {
+        // 12345.6789
+        let n = number(PgNumericSign::Positive, 1, vec![1, 2345, 6789]);
+        assert_eq!(n.to_f64(), 12345.6789);
+
+        // -0.0001
+        let n = number(PgNumericSign::Negative, -1, vec![1]);
+        assert_eq!(n.to_f64(), -0.0001);
+    }
+
+    #[test]
+    fn large() {
+        // 10^40
+        let n = number(PgNumericSign::Positive, 10, vec![1]);
+        assert_eq!(n.to_f64(), 1e40);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(PgNumeric::NotANumber.to_f64().is_nan());
+    }
+}
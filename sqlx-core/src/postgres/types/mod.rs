@@ -6,16 +6,34 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bool`                                | BOOL                                                 |
 //! | `i8`                                  | "CHAR"                                               |
+//! | `char`                                | "CHAR" (ASCII values only, decode only)              |
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
 //! | `i32`                                 | INT, SERIAL, INT4                                    |
 //! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `u32`                                 | OID                                                  |
+//! | `i128`, `u128`                        | NUMERIC (integral values only)                       |
+//! | [`PgU32`]                             | INT, SERIAL, INT4 (checked)                          |
+//! | [`PgU64`]                             | BIGINT, BIGSERIAL, INT8 (checked)                    |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
-//! | `&str`, [`String`]                    | VARCHAR, CHAR(N), TEXT, NAME                         |
+//! | `&str`, [`String`], `Cow<str>`        | VARCHAR, CHAR(N), TEXT, NAME                         |
+//! | [`String`]                            | ANYARRAY, ANYELEMENT (decode only, as text)          |
+//! | [`PgBpchar`]                          | CHAR(N) (without the blank padding)                  |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
+//! | [`PgByteaReader`]                     | BYTEA (streaming, decode only)                       |
+//! | `std::net::IpAddr`                    | INET, CIDR (host addresses only)                     |
 //! | [`PgInterval`]                        | INTERVAL                                             |
 //! | [`PgRange<T>`](PgRange)               | INT8RANGE, INT4RANGE, TSRANGE, TSTZTRANGE, DATERANGE, NUMRANGE |
+//! | [`PgAnyArray`]                        | ANYARRAY, any array type (decode only)               |
 //! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgAclItem`]                         | ACLITEM (decode only)                                |
+//! | [`PgSnapshot`]                        | PG_SNAPSHOT, TXID_SNAPSHOT                           |
+//! | [`PgTid`]                             | TID (e.g. `ctid`)                                    |
+//! | [`PgJsonPath`]                        | JSONPATH                                             |
+//! | [`PgSensitive<T, C>`](PgSensitive)    | BYTEA (encrypted on the client with a [`PgFieldCipher`]) |
+//! | [`PgCube`]                            | CUBE (from the `cube` extension)                     |
+//! | [`PgCodecValue<T>`](PgCodecValue)     | any type with a registered [`PgTypeCodec`]           |
+//! | [`Lossy<f32>`], [`Lossy<f64>`]        | NUMERIC, REAL, DOUBLE PRECISION (decode only)        |
 //!
 //!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
@@ -42,6 +60,7 @@
 //! | `chrono::DateTime<Local>`             | TIMESTAMPTZ                                          |
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
+//! | [`PgDate`]                            | DATE (including `infinity` and `-infinity`)          |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
@@ -54,9 +73,22 @@
 //! | `time::PrimitiveDateTime`             | TIMESTAMP                                            |
 //! | `time::OffsetDateTime`                | TIMESTAMPTZ                                          |
 //! | `time::Date`                          | DATE                                                 |
+//! | [`PgDate`]                            | DATE (including `infinity` and `-infinity`)          |
 //! | `time::Time`                          | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
+//! ### [`jiff`](https://crates.io/crates/jiff)
+//!
+//! Requires the `jiff` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `jiff::Timestamp`                     | TIMESTAMPTZ                                          |
+//! | `jiff::civil::Date`                   | DATE                                                 |
+//! | [`PgDate`]                            | DATE (including `infinity` and `-infinity`)          |
+//! | `jiff::civil::Time`                   | TIME                                                 |
+//! | `jiff::Span`                          | INTERVAL                                             |
+//!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
 //! Requires the `uuid` Cargo feature flag.
@@ -73,6 +105,14 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
 //!
+//! ### [`ipnet`](https://crates.io/crates/ipnet)
+//!
+//! Requires the `ipnet` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `ipnet::IpNet`                        | INET, CIDR                                           |
+//!
 //! ### [`bit-vec`](https://crates.io/crates/bit-vec)
 //!
 //! Requires the `bit-vec` Cargo feature flag.
@@ -90,12 +130,17 @@
 //! | [`Json<T>`]                           | JSON, JSONB                                          |
 //! | `serde_json::Value`                   | JSON, JSONB                                          |
 //! | `&serde_json::value::RawValue`        | JSON, JSONB                                          |
+//! | `Box<serde_json::value::RawValue>`    | JSON, JSONB                                          |
 //!
 //! `Value` and `RawValue` from `serde_json` can be used for unstructured JSON data with
-//! Postgres.
+//! Postgres. `&RawValue` borrows the JSON text from the row and, like `Box<RawValue>`, is
+//! encoded as-is; this lets JSON be forwarded without being deserialized.
 //!
 //! [`Json<T>`](crate::types::Json) can be used for structured JSON data with Postgres.
 //!
+//! [`Lossy<f32>`]: crate::types::Lossy
+//! [`Lossy<f64>`]: crate::types::Lossy
+//!
 //! # [Composite types](https://www.postgresql.org/docs/current/rowtypes.html)
 //!
 //! User-defined composite types are supported through a derive for `Type`.
@@ -121,6 +166,9 @@
 //! Anonymous composite types are represented as tuples. Note that anonymous composites may only
 //! be returned and not sent to Postgres (this is a limitation of postgres).
 //!
+//! Tuples may also be decoded from the rows of tables and other composite types, field by
+//! field, and arrays of either as `Vec<(T1, T2, ..)>` (e.g. `SELECT array_agg(ROW(a, b))`).
+//!
 //! # Arrays
 //!
 //! One-dimensional arrays are supported as `Vec<T>` or `&[T]` where `T` implements `Type`.
@@ -154,28 +202,45 @@ use crate::postgres::type_info::PgTypeKind;
 use crate::postgres::{PgTypeInfo, Postgres};
 use crate::types::Type;
 
+mod aclitem;
+mod any_array;
 mod array;
 mod bool;
+mod bpchar;
+mod bytea_reader;
 mod bytes;
+mod char;
+mod codec;
+mod cube;
 mod float;
 mod int;
+mod int128;
 mod interval;
+mod ipaddr;
+mod jsonpath;
+mod lossy;
 mod money;
+mod numeric;
 mod range;
 mod record;
+mod sensitive;
+mod snapshot;
 mod str;
+mod tid;
+mod to_json;
 mod tuple;
+mod uint;
 mod void;
 
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+mod date;
+
 #[cfg(any(feature = "chrono", feature = "time"))]
 mod time_tz;
 
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
 
-#[cfg(any(feature = "bigdecimal", feature = "decimal"))]
-mod numeric;
-
 #[cfg(feature = "decimal")]
 mod decimal;
 
@@ -185,6 +250,9 @@ mod chrono;
 #[cfg(feature = "time")]
 mod time;
 
+#[cfg(feature = "jiff")]
+mod jiff;
+
 #[cfg(feature = "uuid")]
 mod uuid;
 
@@ -194,16 +262,42 @@ mod json;
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
+#[cfg(feature = "ipnet")]
+mod ipnet;
+
 #[cfg(feature = "bit-vec")]
 mod bit_vec;
 
+pub use aclitem::{PgAclItem, PgAclPrivilege};
+pub use any_array::PgAnyArray;
+pub use array::PgHasArrayType;
+pub use bpchar::PgBpchar;
+pub use bytea_reader::PgByteaReader;
+pub use codec::{PgCodecValue, PgTypeCodec};
+pub use cube::PgCube;
 pub use interval::PgInterval;
+pub use jsonpath::PgJsonPath;
 pub use money::PgMoney;
-pub use range::PgRange;
+pub use range::{PgInt4Range, PgInt8Range, PgRange};
+pub use sensitive::{PgFieldCipher, PgSensitive};
+pub use snapshot::PgSnapshot;
+pub use tid::PgTid;
+pub use uint::{PgU32, PgU64};
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub use date::PgDate;
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 pub use time_tz::PgTimeTz;
 
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use range::{PgDateRange, PgTsTzRange};
+
+#[cfg(feature = "bigdecimal")]
+pub use range::PgNumRange;
+
+pub(crate) use codec::PgAnyTypeCodec;
+
 // used in derive(Type) for `struct`
 // but the interface is not considered part of the public API
 #[doc(hidden)]
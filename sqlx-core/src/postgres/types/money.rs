@@ -16,11 +16,13 @@ use std::{
 /// `lc_monetary` setting.
 ///
 /// Data is read and written as 64-bit signed integers, and conversion into a
-/// decimal should be done using the right precision.
+/// decimal should be done using the right precision, which can be read with
+/// [`PgConnection::money_scale`].
 ///
 /// Reading `MONEY` value in text format is not supported and will cause an error.
 ///
 /// [`MONEY`]: https://www.postgresql.org/docs/current/datatype-money.html
+/// [`PgConnection::money_scale`]: crate::postgres::PgConnection::money_scale
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct PgMoney(pub i64);
 
@@ -1,4 +1,5 @@
 use std::convert::TryInto;
+use std::fmt::{self, Display, Formatter, Write};
 
 use bytes::Buf;
 
@@ -98,6 +99,42 @@ impl PgNumeric {
         }
     }
 
+    /// Converts to the nearest `f64`, losing precision beyond ~17 significant digits.
+    pub(crate) fn to_f64(&self) -> f64 {
+        let (digits, sign, weight) = match self {
+            PgNumeric::Number {
+                digits,
+                sign,
+                weight,
+                ..
+            } => (digits, sign, weight),
+
+            PgNumeric::NotANumber => return f64::NAN,
+        };
+
+        if digits.is_empty() {
+            return 0.0;
+        }
+
+        // render the base-10000 digits as a decimal literal in scientific notation and let
+        // the standard library do the (correctly rounded) conversion
+        let mut literal = String::with_capacity(digits.len() * 4 + 8);
+
+        if *sign == PgNumericSign::Negative {
+            literal.push('-');
+        }
+
+        for digit in digits {
+            literal.push_str(&format!("{:04}", digit));
+        }
+
+        let exponent = (*weight as i64 - (digits.len() as i64 - 1)) * 4;
+        literal.push_str(&format!("e{}", exponent));
+
+        // the literal is always well-formed
+        literal.parse().unwrap_or(f64::NAN)
+    }
+
     /// ### Panics
     ///
     /// * If `digits.len()` overflows `i16`
@@ -136,3 +173,57 @@ impl PgNumeric {
         }
     }
 }
+
+/// Renders the value the way `numeric_out` does, e.g. `-12.3400` for a scale of 4.
+impl Display for PgNumeric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (digits, sign, weight, scale) = match self {
+            PgNumeric::Number {
+                digits,
+                sign,
+                weight,
+                scale,
+            } => (digits, *sign, *weight as isize, *scale as usize),
+
+            PgNumeric::NotANumber => return f.write_str("NaN"),
+        };
+
+        let digit = |index: isize| -> i16 {
+            if index < 0 {
+                0
+            } else {
+                digits.get(index as usize).copied().unwrap_or(0)
+            }
+        };
+
+        if sign == PgNumericSign::Negative {
+            f.write_char('-')?;
+        }
+
+        if weight < 0 {
+            f.write_char('0')?;
+        } else {
+            write!(f, "{}", digit(0))?;
+
+            for index in 1..=weight {
+                write!(f, "{:04}", digit(index))?;
+            }
+        }
+
+        if scale > 0 {
+            let mut fraction = String::with_capacity(scale + 4);
+            let mut index = weight + 1;
+
+            while fraction.len() < scale {
+                write!(fraction, "{:04}", digit(index))?;
+                index += 1;
+            }
+
+            fraction.truncate(scale);
+
+            write!(f, ".{}", fraction)?;
+        }
+
+        Ok(())
+    }
+}
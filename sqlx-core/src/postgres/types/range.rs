@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
 use std::ops::{Bound, Range, RangeBounds, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
 
@@ -31,6 +32,36 @@ pub struct PgRange<T> {
     pub end: Bound<T>,
 }
 
+#[cfg(feature = "time")]
+type DefaultDate = ::time::Date;
+
+#[cfg(all(not(feature = "time"), feature = "chrono"))]
+type DefaultDate = ::chrono::NaiveDate;
+
+#[cfg(feature = "time")]
+type DefaultDateTime = ::time::OffsetDateTime;
+
+#[cfg(all(not(feature = "time"), feature = "chrono"))]
+type DefaultDateTime = ::chrono::DateTime<::chrono::Utc>;
+
+/// An `INT4RANGE`.
+pub type PgInt4Range = PgRange<i32>;
+
+/// An `INT8RANGE`.
+pub type PgInt8Range = PgRange<i64>;
+
+/// A `NUMRANGE`.
+#[cfg(feature = "bigdecimal")]
+pub type PgNumRange = PgRange<bigdecimal::BigDecimal>;
+
+/// A `DATERANGE`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub type PgDateRange<Date = DefaultDate> = PgRange<Date>;
+
+/// A `TSTZRANGE`.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub type PgTsTzRange<DateTime = DefaultDateTime> = PgRange<DateTime>;
+
 impl<T> From<[Bound<T>; 2]> for PgRange<T> {
     fn from(v: [Bound<T>; 2]) -> Self {
         let [start, end] = v;
@@ -93,6 +124,31 @@ impl<T> From<RangeToInclusive<T>> for PgRange<T> {
     }
 }
 
+impl<T> TryFrom<PgRange<T>> for Range<T> {
+    type Error = BoxDynError;
+
+    /// Convert a `PgRange` to a half-open `Range`.
+    ///
+    /// This returns an error unless the lower bound is inclusive and the upper bound is
+    /// exclusive, which is the canonical form of `INT4RANGE`, `INT8RANGE` and `DATERANGE`.
+    /// Empty ranges are decoded without bounds and are rejected as well.
+    fn try_from(value: PgRange<T>) -> Result<Self, BoxDynError> {
+        match (value.start, value.end) {
+            (Bound::Included(start), Bound::Excluded(end)) => Ok(start..end),
+
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => {
+                Err("an unbounded range cannot be converted to a `Range`".into())
+            }
+
+            _ => Err(
+                "only a range with an inclusive lower bound and an exclusive upper bound \
+                 can be converted to a `Range`"
+                    .into(),
+            ),
+        }
+    }
+}
+
 impl<T> RangeBounds<T> for PgRange<T> {
     fn start_bound(&self) -> Bound<&T> {
         match self.start {
@@ -1,9 +1,13 @@
+use std::borrow::Cow;
+use std::str::from_utf8;
+
 use bytes::Buf;
 
 use crate::decode::Decode;
-use crate::encode::Encode;
+use crate::encode::{Encode, IsNull};
 use crate::error::{mismatched_types, BoxDynError};
 use crate::postgres::type_info::{PgType, PgTypeKind};
+use crate::postgres::types::numeric::PgNumeric;
 use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::type_info::TypeInfo;
 use crate::types::Type;
@@ -13,6 +17,7 @@ pub struct PgRecordEncoder<'a> {
     buf: &'a mut PgArgumentBuffer,
     off: usize,
     num: u32,
+    text: bool,
 }
 
 impl<'a> PgRecordEncoder<'a> {
@@ -20,16 +25,54 @@ impl<'a> PgRecordEncoder<'a> {
     pub fn new(buf: &'a mut PgArgumentBuffer) -> Self {
         let off = buf.len();
 
-        // reserve space for a field count
-        buf.extend(&(0_u32).to_be_bytes());
+        // a record nested inside of a text-format record must be text as well
+        let text = buf.text_format;
 
-        Self { buf, off, num: 0 }
+        if text {
+            buf.push(b'(');
+        } else {
+            // reserve space for a field count
+            buf.extend(&(0_u32).to_be_bytes());
+        }
+
+        Self {
+            buf,
+            off,
+            num: 0,
+            text,
+        }
+    }
+
+    /// Encodes the record in the text format, `(field1,"field 2",,...)`, rather than binary.
+    ///
+    /// This is required when binding a composite with a field of a type that lacks binary
+    /// send/receive functions (common with extension types); such fields should be given as
+    /// a `String` holding their text representation.
+    ///
+    /// The text format only applies to the record when it is the bind parameter itself (or
+    /// nested inside another text-format record). When it is an element of an array or a
+    /// field of a binary record, the binary format is used instead.
+    ///
+    /// The query fails with [`Error::Encode`](crate::error::Error::Encode) if a field is of a
+    /// type without a text rendering here; supported are booleans, integers, floats, `NUMERIC`,
+    /// `"char"`, `BYTEA`, `UUID`, `JSON`/`JSONB`, text types, enumerations and nested records.
+    #[doc(hidden)]
+    pub fn new_text(buf: &'a mut PgArgumentBuffer) -> Self {
+        if buf.is_top_level() {
+            buf.text_format = true;
+        }
+
+        Self::new(buf)
     }
 
     #[doc(hidden)]
     pub fn finish(&mut self) {
-        // fill in the record length
-        self.buf[self.off..(self.off + 4)].copy_from_slice(&self.num.to_be_bytes());
+        if self.text {
+            self.buf.push(b')');
+        } else {
+            // fill in the record length
+            self.buf[self.off..(self.off + 4)].copy_from_slice(&self.num.to_be_bytes());
+        }
     }
 
     #[doc(hidden)]
@@ -38,6 +81,13 @@ impl<'a> PgRecordEncoder<'a> {
         'a: 'q,
         T: Encode<'q, Postgres> + Type<Postgres>,
     {
+        if self.text {
+            self.encode_text(value);
+            self.num += 1;
+
+            return self;
+        }
+
         let ty = T::type_info();
 
         if let PgType::DeclareWithName(name) = ty.0 {
@@ -54,6 +104,133 @@ impl<'a> PgRecordEncoder<'a> {
 
         self
     }
+
+    fn encode_text<'q, T>(&mut self, value: T)
+    where
+        T: Encode<'q, Postgres> + Type<Postgres>,
+    {
+        // https://www.postgresql.org/docs/current/rowtypes.html#ROWTYPES-IO-SYNTAX
+
+        if self.num > 0 {
+            self.buf.push(b',');
+        }
+
+        let ty = value.produces().unwrap_or_else(T::type_info);
+
+        // the `Encode` impls write binary, so encode the field on the side and
+        // render the result as text
+        let mut field = PgArgumentBuffer::default();
+        field.text_format = true;
+
+        let is_null = value.encode(&mut field);
+        self.buf.adopt_error(&mut field);
+
+        if let IsNull::Yes = is_null {
+            // an empty field is NULL
+            return;
+        }
+
+        let text = match text_from_binary(&ty, &field) {
+            Some(text) => text,
+
+            None => {
+                // the query fails with `Error::Encode`; leave the field empty in the meantime
+                self.buf.encode_error(format!(
+                    "cannot encode a field of type {} in a text-format record",
+                    ty.display_name()
+                ));
+
+                return;
+            }
+        };
+
+        // always quote so we need not worry about empty strings, whitespace or delimiters
+        self.buf.push(b'"');
+
+        for &byte in text.as_bytes() {
+            if byte == b'"' || byte == b'\\' {
+                self.buf.push(b'\\');
+            }
+
+            self.buf.push(byte);
+        }
+
+        self.buf.push(b'"');
+    }
+}
+
+// Renders a binary-encoded value as its text representation
+pub(crate) fn text_from_binary<'b>(ty: &PgTypeInfo, mut buf: &'b [u8]) -> Option<Cow<'b, str>> {
+    Some(match ty.0 {
+        PgType::Bool => Cow::Borrowed(if buf.get_u8() != 0 { "t" } else { "f" }),
+
+        PgType::Int2 => buf.get_i16().to_string().into(),
+        PgType::Int4 => buf.get_i32().to_string().into(),
+        PgType::Int8 => buf.get_i64().to_string().into(),
+        PgType::Oid => buf.get_u32().to_string().into(),
+
+        PgType::Float4 => float_to_text(buf.get_f32()).into(),
+        PgType::Float8 => float_to_text(buf.get_f64()).into(),
+
+        PgType::Numeric => PgNumeric::decode(buf).ok()?.to_string().into(),
+
+        PgType::Char => match buf.first() {
+            None | Some(0) => Cow::Borrowed(""),
+            Some(&byte) if byte.is_ascii() => (byte as char).to_string().into(),
+            Some(&byte) => format!("\\{:03o}", byte).into(),
+        },
+
+        PgType::Bytea => {
+            let mut text = String::with_capacity(2 + buf.len() * 2);
+            text.push_str("\\x");
+            text.push_str(&hex::encode(buf));
+            text.into()
+        }
+
+        PgType::Uuid => {
+            let hex = hex::encode(buf);
+
+            if hex.len() != 32 {
+                return None;
+            }
+
+            format!(
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            )
+            .into()
+        }
+
+        // skip the JSONB format version; JSON may have a leading space in its place
+        PgType::Jsonb | PgType::Json => from_utf8(buf.get(1..)?).ok()?.into(),
+
+        // the binary and text representation of these are the same; this includes enumerations
+        // and nested records (which were encoded as text) as they are declared by name
+        PgType::Text
+        | PgType::Varchar
+        | PgType::Bpchar
+        | PgType::Name
+        | PgType::Unknown
+        | PgType::DeclareWithName(_) => from_utf8(buf).ok()?.into(),
+
+        _ => return None,
+    })
+}
+
+fn float_to_text<F: Copy + Into<f64> + ToString>(value: F) -> String {
+    let float: f64 = value.into();
+
+    if float.is_nan() {
+        "NaN".into()
+    } else if float.is_infinite() {
+        if float > 0.0 { "Infinity" } else { "-Infinity" }.into()
+    } else {
+        value.to_string()
+    }
 }
 
 #[doc(hidden)]
@@ -62,6 +239,10 @@ pub struct PgRecordDecoder<'r> {
     typ: PgTypeInfo,
     fmt: PgValueFormat,
     ind: usize,
+
+    // in the text format, whether the previous field was followed by a delimiter; as an empty
+    // field is NULL, this is how a trailing NULL field is told apart from the end of the record
+    delimited: bool,
 }
 
 impl<'r> PgRecordDecoder<'r> {
@@ -87,15 +268,26 @@ impl<'r> PgRecordDecoder<'r> {
             fmt,
             typ,
             ind: 0,
+            delimited: false,
         })
     }
 
+    /// Returns `true` if all the fields of the record were decoded.
+    pub(crate) fn is_exhausted(&self) -> bool {
+        match self.fmt {
+            PgValueFormat::Binary => self.buf.is_empty(),
+
+            // the first field of `()` is NULL, as there are no records without fields
+            PgValueFormat::Text => self.buf.is_empty() && self.ind > 0 && !self.delimited,
+        }
+    }
+
     #[doc(hidden)]
     pub fn try_decode<T>(&mut self) -> Result<T, BoxDynError>
     where
         T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
     {
-        if self.buf.is_empty() {
+        if self.is_exhausted() {
             return Err(format!("no field `{0}` found on record", self.ind).into());
         }
 
@@ -139,38 +331,46 @@ impl<'r> PgRecordDecoder<'r> {
             }
 
             PgValueFormat::Text => {
-                let mut element = String::new();
+                // operate on bytes so multi-byte UTF-8 sequences pass through untouched; every
+                // byte we look for is ASCII and cannot occur inside of one
+                let mut element = Vec::new();
                 let mut quoted = false;
                 let mut in_quotes = false;
                 let mut in_escape = false;
-                let mut prev_ch = '\0';
+                let mut prev_ch = 0_u8;
+
+                self.ind += 1;
+                self.delimited = false;
 
                 while !self.buf.is_empty() {
-                    let ch = self.buf.get_u8() as char;
+                    let ch = self.buf.get_u8();
                     match ch {
                         _ if in_escape => {
                             element.push(ch);
                             in_escape = false;
                         }
 
-                        '"' if in_quotes => {
+                        b'"' if in_quotes => {
                             in_quotes = false;
                         }
 
-                        '"' => {
+                        b'"' => {
                             in_quotes = true;
                             quoted = true;
 
-                            if prev_ch == '"' {
-                                element.push('"')
+                            if prev_ch == b'"' {
+                                element.push(b'"')
                             }
                         }
 
-                        '\\' if !in_escape => {
+                        b'\\' if !in_escape => {
                             in_escape = true;
                         }
 
-                        ',' if !in_quotes => break,
+                        b',' if !in_quotes => {
+                            self.delimited = true;
+                            break;
+                        }
 
                         _ => {
                             element.push(ch);
@@ -183,7 +383,7 @@ impl<'r> PgRecordDecoder<'r> {
                     // completely empty input means NULL
                     None
                 } else {
-                    Some(element.as_bytes())
+                    Some(&*element)
                 };
 
                 // NOTE: we do not call [`accepts`] or give a chance to from a user as
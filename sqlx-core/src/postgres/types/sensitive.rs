@@ -0,0 +1,176 @@
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// Encrypts and decrypts the values of [`PgSensitive`] columns, for client-side field-level
+/// encryption.
+///
+/// The cipher is chosen by type, so columns encrypted with different keys use different
+/// implementations. The keys are typically loaded once (e.g. from a key management service)
+/// into a `static` read by the implementation.
+///
+/// ```rust,ignore
+/// use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+/// use aes_gcm::{Aes256Gcm, Nonce};
+///
+/// static KEY: OnceCell<Aes256Gcm> = OnceCell::new();
+///
+/// struct CustomerKey;
+///
+/// impl PgFieldCipher for CustomerKey {
+///     fn encrypt(plaintext: &[u8]) -> Result<Vec<u8>, BoxDynError> {
+///         let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+///         let mut sealed = nonce.to_vec();
+///
+///         sealed.extend(KEY.get().unwrap().encrypt(&nonce, plaintext)?);
+///
+///         Ok(sealed)
+///     }
+///
+///     fn decrypt(sealed: &[u8]) -> Result<Vec<u8>, BoxDynError> {
+///         let (nonce, ciphertext) = sealed.split_at(12);
+///
+///         Ok(KEY.get().unwrap().decrypt(Nonce::from_slice(nonce), ciphertext)?)
+///     }
+/// }
+/// ```
+pub trait PgFieldCipher: 'static {
+    /// Encrypts the binary representation of a value.
+    fn encrypt(plaintext: &[u8]) -> Result<Vec<u8>, BoxDynError>;
+
+    /// Decrypts the binary representation of a value.
+    fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>, BoxDynError>;
+}
+
+/// A value which is encrypted with the cipher `C` before it is sent to the database, and
+/// decrypted when it is received.
+///
+/// The binary representation of `T` is encrypted and stored in a `BYTEA` column, so values
+/// are bound and decoded as usual, without changes to the queries:
+///
+/// ```rust,ignore
+/// sqlx::query("INSERT INTO customers (name, ssn) VALUES ($1, $2)")
+///     .bind(name)
+///     .bind(PgSensitive::<_, CustomerKey>::new(ssn))
+///     .execute(&mut conn)
+///     .await?;
+///
+/// let ssn: PgSensitive<String, CustomerKey> =
+///     sqlx::query_scalar("SELECT ssn FROM customers WHERE name = $1")
+///         .bind(name)
+///         .fetch_one(&mut conn)
+///         .await?;
+/// ```
+///
+/// The value is not printed by `Debug`. Since the server only sees the encrypted bytes, such
+/// columns can only be compared for equality if the cipher is deterministic, and cannot be
+/// ordered or indexed by their value.
+pub struct PgSensitive<T, C> {
+    value: T,
+    cipher: PhantomData<fn() -> C>,
+}
+
+impl<T, C> PgSensitive<T, C> {
+    pub fn new(value: T) -> Self {
+        PgSensitive {
+            value,
+            cipher: PhantomData,
+        }
+    }
+
+    /// Returns the decrypted value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, C> Deref for PgSensitive<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Clone, C> Clone for PgSensitive<T, C> {
+    fn clone(&self) -> Self {
+        PgSensitive::new(self.value.clone())
+    }
+}
+
+impl<T: PartialEq, C> PartialEq for PgSensitive<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, C> Eq for PgSensitive<T, C> {}
+
+impl<T, C> Debug for PgSensitive<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("PgSensitive(..)")
+    }
+}
+
+impl<T, C> Type<Postgres> for PgSensitive<T, C> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BYTEA
+    }
+}
+
+impl<'q, T, C> Encode<'q, Postgres> for PgSensitive<T, C>
+where
+    T: Encode<'q, Postgres>,
+    C: PgFieldCipher,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // encode the value on the side, in its binary format
+        let mut plaintext = PgArgumentBuffer::default();
+
+        let is_null = self.value.encode_by_ref(&mut plaintext);
+        buf.adopt_error(&mut plaintext);
+
+        if let IsNull::Yes = is_null {
+            return IsNull::Yes;
+        }
+
+        if plaintext.has_patches() {
+            // the OIDs of custom types are only written when the query is sent
+            buf.encode_error("cannot encrypt a value containing a type looked up by name");
+            return IsNull::No;
+        }
+
+        match C::encrypt(&plaintext) {
+            Ok(ciphertext) => buf.extend(ciphertext),
+            Err(error) => buf.encode_error(error),
+        }
+
+        IsNull::No
+    }
+}
+
+impl<'r, T, C> Decode<'r, Postgres> for PgSensitive<T, C>
+where
+    T: for<'a> Decode<'a, Postgres> + Type<Postgres>,
+    C: PgFieldCipher,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let ciphertext = <Vec<u8> as Decode<Postgres>>::decode(value)?;
+        let plaintext = C::decrypt(&ciphertext)?;
+
+        let value = T::decode(PgValueRef {
+            value: Some(&plaintext),
+            row: None,
+            type_info: T::type_info(),
+            format: PgValueFormat::Binary,
+        })?;
+
+        Ok(PgSensitive::new(value))
+    }
+}
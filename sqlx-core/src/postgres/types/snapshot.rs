@@ -0,0 +1,148 @@
+use byteorder::{BigEndian, ByteOrder};
+use std::mem;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// the size of the header of the binary format: the number of in-progress transactions,
+// `xmin` and `xmax`
+const HEADER_LEN: usize = mem::size_of::<i32>() + 2 * mem::size_of::<u64>();
+
+/// A [`PG_SNAPSHOT`] (or `TXID_SNAPSHOT`), the set of transactions visible to a transaction,
+/// e.g. as returned by `pg_current_snapshot()`.
+///
+/// Transaction IDs are 64-bit, including the epoch, like `XID8`.
+///
+/// [`PG_SNAPSHOT`]: https://www.postgresql.org/docs/current/functions-info.html#FUNCTIONS-PG-SNAPSHOT-PARTS
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgSnapshot {
+    /// The lowest transaction ID that was still active; all earlier transactions are either
+    /// committed and visible or rolled back.
+    pub xmin: u64,
+
+    /// One past the highest completed transaction ID; all later transactions are not visible.
+    pub xmax: u64,
+
+    /// The transactions between `xmin` and `xmax` which were in progress, in ascending order.
+    pub xip: Vec<u64>,
+}
+
+impl PgSnapshot {
+    /// Returns `true` if the transaction is visible in this snapshot, i.e. it was completed
+    /// (committed or rolled back) when the snapshot was taken.
+    pub fn is_visible(&self, xid: u64) -> bool {
+        xid < self.xmin || (xid < self.xmax && !self.xip.contains(&xid))
+    }
+
+    fn from_text(s: &str) -> Result<Self, BoxDynError> {
+        // e.g. `10:20:10,14,15`
+        let mut parts = s.splitn(3, ':');
+
+        let (xmin, xmax, xip) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(xmin), Some(xmax), Some(xip)) => (xmin, xmax, xip),
+            _ => return Err(format!("invalid PG_SNAPSHOT: {:?}", s).into()),
+        };
+
+        Ok(PgSnapshot {
+            xmin: xmin.parse()?,
+            xmax: xmax.parse()?,
+            xip: if xip.is_empty() {
+                Vec::new()
+            } else {
+                xip.split(',').map(str::parse).collect::<Result<_, _>>()?
+            },
+        })
+    }
+}
+
+impl Type<Postgres> for PgSnapshot {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::PG_SNAPSHOT
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::PG_SNAPSHOT || *ty == PgTypeInfo::TXID_SNAPSHOT
+    }
+}
+
+impl Type<Postgres> for [PgSnapshot] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::PG_SNAPSHOT_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<PgSnapshot>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<PgSnapshot> {
+    fn type_info() -> PgTypeInfo {
+        <[PgSnapshot] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[PgSnapshot] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgSnapshot {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&(self.xip.len() as i32).to_be_bytes());
+        buf.extend(&self.xmin.to_be_bytes());
+        buf.extend(&self.xmax.to_be_bytes());
+
+        for xid in &self.xip {
+            buf.extend(&xid.to_be_bytes());
+        }
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        HEADER_LEN + self.xip.len() * mem::size_of::<u64>()
+    }
+}
+
+impl Decode<'_, Postgres> for PgSnapshot {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let bytes = value.as_bytes()?;
+
+                if bytes.len() < HEADER_LEN {
+                    return Err("invalid PG_SNAPSHOT: missing header".into());
+                }
+
+                let nxip = BigEndian::read_i32(bytes) as usize;
+                let xmin = BigEndian::read_u64(&bytes[4..]);
+                let xmax = BigEndian::read_u64(&bytes[12..]);
+
+                let data = &bytes[HEADER_LEN..];
+
+                if data.len() != nxip * mem::size_of::<u64>() {
+                    return Err(format!(
+                        "invalid PG_SNAPSHOT: expected {} transactions, got {} bytes",
+                        nxip,
+                        data.len()
+                    )
+                    .into());
+                }
+
+                Ok(PgSnapshot {
+                    xmin,
+                    xmax,
+                    xip: data
+                        .chunks_exact(mem::size_of::<u64>())
+                        .map(BigEndian::read_u64)
+                        .collect(),
+                })
+            }
+
+            PgValueFormat::Text => PgSnapshot::from_text(value.as_str()?),
+        }
+    }
+}
@@ -1,8 +1,11 @@
+use std::borrow::Cow;
+
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
+use crate::postgres::types::any_array::decode_pseudo_type_as_text;
 use crate::postgres::types::array_compatible;
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
 use crate::types::Type;
 
 impl Type<Postgres> for str {
@@ -68,7 +71,7 @@ impl Type<Postgres> for String {
     }
 
     fn compatible(ty: &PgTypeInfo) -> bool {
-        <&str as Type<Postgres>>::compatible(ty)
+        <&str as Type<Postgres>>::compatible(ty) || ty.__is_text_pseudo_type()
     }
 }
 
@@ -94,6 +97,27 @@ impl Type<Postgres> for Vec<String> {
 
 impl Decode<'_, Postgres> for String {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Binary && value.type_info.__is_text_pseudo_type() {
+            return decode_pseudo_type_as_text(value);
+        }
+
         Ok(value.as_str()?.to_owned())
     }
 }
+
+impl Type<Postgres> for Cow<'_, str> {
+    fn type_info() -> PgTypeInfo {
+        <&str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Cow<'r, str> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        // text is sent as-is in both formats so it can always be borrowed
+        Ok(Cow::Borrowed(value.as_str()?))
+    }
+}
@@ -0,0 +1,155 @@
+use byteorder::{BigEndian, ByteOrder};
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+const TID_LEN: usize = mem::size_of::<u32>() + mem::size_of::<u16>();
+
+/// A [`TID`], the physical location of a row version in its table, as found in the `ctid`
+/// system column.
+///
+/// A `ctid` changes when the row is updated or the table is rewritten (e.g. by `VACUUM FULL`),
+/// so it only identifies a row within a transaction, e.g. to delete rows in batches:
+///
+/// ```rust,ignore
+/// let ctids: Vec<PgTid> =
+///     sqlx::query_scalar("SELECT ctid FROM events WHERE created_at < $1 LIMIT 1000")
+///         .bind(cutoff)
+///         .fetch_all(&mut tx)
+///         .await?;
+///
+/// sqlx::query("DELETE FROM events WHERE ctid = ANY($1)")
+///     .bind(&ctids)
+///     .execute(&mut tx)
+///     .await?;
+/// ```
+///
+/// `TID`s are ordered by block, then by offset, like in the database.
+///
+/// [`TID`]: https://www.postgresql.org/docs/current/datatype-oid.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgTid {
+    /// The number of the block (page) of the table holding the row.
+    pub block: u32,
+
+    /// The index of the row within its block, starting at 1.
+    pub offset: u16,
+}
+
+impl PgTid {
+    fn from_text(s: &str) -> Result<Self, BoxDynError> {
+        // e.g. `(0,1)`
+        let invalid = || format!("invalid TID: {:?}", s);
+
+        let inner = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or_else(invalid)?;
+
+        let comma = inner.find(',').ok_or_else(invalid)?;
+
+        Ok(PgTid {
+            block: inner[..comma].parse()?,
+            offset: inner[comma + 1..].parse()?,
+        })
+    }
+}
+
+impl Display for PgTid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.block, self.offset)
+    }
+}
+
+impl Type<Postgres> for PgTid {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TID
+    }
+}
+
+impl Type<Postgres> for [PgTid] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TID_ARRAY
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        array_compatible::<PgTid>(ty)
+    }
+}
+
+impl Type<Postgres> for Vec<PgTid> {
+    fn type_info() -> PgTypeInfo {
+        <[PgTid] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[PgTid] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgTid {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend(&self.block.to_be_bytes());
+        buf.extend(&self.offset.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        TID_LEN
+    }
+}
+
+impl Decode<'_, Postgres> for PgTid {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let bytes = value.as_bytes()?;
+
+                if bytes.len() != TID_LEN {
+                    return Err(format!(
+                        "invalid TID: expected {} bytes, got {}",
+                        TID_LEN,
+                        bytes.len()
+                    )
+                    .into());
+                }
+
+                Ok(PgTid {
+                    block: BigEndian::read_u32(bytes),
+                    offset: BigEndian::read_u16(&bytes[4..]),
+                })
+            }
+
+            PgValueFormat::Text => PgTid::from_text(value.as_str()?),
+        }
+    }
+}
+
+#[test]
+fn test_parse_tid() {
+    assert_eq!(
+        PgTid::from_text("(4294967295,65535)").unwrap(),
+        PgTid {
+            block: u32::MAX,
+            offset: u16::MAX
+        }
+    );
+
+    let tid = PgTid {
+        block: 0,
+        offset: 1,
+    };
+
+    assert_eq!(tid.to_string(), "(0,1)");
+
+    assert!(PgTid::from_text("(0,1").is_err());
+    assert!(PgTid::from_text("(0;1)").is_err());
+    assert!(PgTid::from_text("(0,65536)").is_err());
+}
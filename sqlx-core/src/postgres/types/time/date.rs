@@ -1,11 +1,12 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::postgres::types::time::PG_EPOCH;
-use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::postgres::types::date::PgDateRepr;
+use crate::postgres::types::time::{MAX_DATE, MIN_DATE, PG_EPOCH};
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
 use crate::types::Type;
 use std::mem;
-use time::{Date, Duration};
+use time::Date;
 
 impl Type<Postgres> for Date {
     fn type_info() -> PgTypeInfo {
@@ -39,14 +40,35 @@ impl Encode<'_, Postgres> for Date {
 
 impl<'r> Decode<'r, Postgres> for Date {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        Ok(match value.format() {
-            PgValueFormat::Binary => {
-                // DATE is encoded as the days since epoch
-                let days: i32 = Decode::<Postgres>::decode(value)?;
-                PG_EPOCH + Duration::days(days.into())
+        match PgDateRepr::decode(value)? {
+            // DATE is encoded as the days since epoch
+            PgDateRepr::Days(days) => {
+                let julian_day = PG_EPOCH.julian_day() + i64::from(days);
+
+                if julian_day < MIN_DATE.julian_day() || julian_day > MAX_DATE.julian_day() {
+                    return Err(format!(
+                        "DATE of {} days since 2000-01-01 is out of range for Date",
+                        days
+                    )
+                    .into());
+                }
+
+                Ok(Date::from_julian_day(julian_day))
             }
 
-            PgValueFormat::Text => Date::parse(value.as_str()?, "%Y-%m-%d")?,
-        })
+            PgDateRepr::Ymd { year, month, day } => {
+                Date::try_from_ymd(year, month as u8, day as u8).map_err(|_| {
+                    format!(
+                        "DATE {:04}-{:02}-{:02} is out of range for Date",
+                        year, month, day
+                    )
+                    .into()
+                })
+            }
+
+            PgDateRepr::Infinity | PgDateRepr::NegInfinity => {
+                Err("cannot decode an infinite DATE as Date; use PgDate instead".into())
+            }
+        }
     }
 }
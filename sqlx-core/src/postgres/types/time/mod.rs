@@ -4,3 +4,9 @@ mod time;
 
 #[rustfmt::skip]
 const PG_EPOCH: ::time::Date = ::time::date!(2000-1-1);
+
+// the range of `Date`, which panics on overflow
+#[rustfmt::skip]
+const MIN_DATE: ::time::Date = ::time::date!(-100000-01-01);
+#[rustfmt::skip]
+const MAX_DATE: ::time::Date = ::time::date!(100000-12-31);
@@ -21,6 +21,9 @@ type DefaultOffset = ::chrono::FixedOffset;
 
 /// Represents a moment of time, in a specified timezone.
 ///
+/// The offset is kept as it is stored, e.g. `05:10:20+06:30` is not converted to UTC. It is
+/// supported in the text and binary formats, with `chrono` or `time`, and in arrays.
+///
 /// # Warning
 ///
 /// `PgTimeTz` provides `TIMETZ` and is supported only for reading from legacy databases.
@@ -33,6 +36,26 @@ pub struct PgTimeTz<Time = DefaultTime, Offset = DefaultOffset> {
     pub offset: Offset,
 }
 
+/// Splits the text format of a `TIMETZ`, e.g. `05:10:20.1151+06:30`, into the time and the
+/// offset, in seconds east of UTC.
+fn split_offset(s: &str) -> Result<(&str, i32), BoxDynError> {
+    let index = s
+        .rfind(|c| c == '+' || c == '-')
+        .ok_or_else(|| format!("missing offset in TIMETZ {:?}", s))?;
+
+    let (time, offset) = s.split_at(index);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+
+    // the offset is formatted as `+HH`, `+HH:MM` or `+HH:MM:SS`
+    let mut seconds = 0;
+
+    for (part, unit) in offset[1..].split(':').zip(&[3600, 60, 1]) {
+        seconds += part.parse::<i32>()? * unit;
+    }
+
+    Ok((time, sign * seconds))
+}
+
 impl<Time, Offset> Type<Postgres> for [PgTimeTz<Time, Offset>]
 where
     PgTimeTz<Time, Offset>: Type<Postgres>,
@@ -54,7 +77,7 @@ where
 #[cfg(feature = "chrono")]
 mod chrono {
     use super::*;
-    use ::chrono::{DateTime, Duration, FixedOffset, NaiveTime};
+    use ::chrono::{Duration, FixedOffset, NaiveTime};
 
     impl Type<Postgres> for PgTimeTz<NaiveTime, FixedOffset> {
         fn type_info() -> PgTypeInfo {
@@ -95,34 +118,13 @@ mod chrono {
                 }
 
                 PgValueFormat::Text => {
-                    let s = value.as_str()?;
-
-                    let mut tmp = String::with_capacity(11 + s.len());
-                    tmp.push_str("2001-07-08 ");
-                    tmp.push_str(s);
-
-                    let dt = 'out: loop {
-                        let mut err = None;
-
-                        for fmt in &["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%d %H:%M:%S%.f"] {
-                            match DateTime::parse_from_str(&tmp, fmt) {
-                                Ok(dt) => {
-                                    break 'out dt;
-                                }
+                    let (time, offset) = split_offset(value.as_str()?)?;
 
-                                Err(error) => {
-                                    err = Some(error);
-                                }
-                            }
-                        }
-
-                        return Err(err.unwrap().into());
-                    };
-
-                    let time = dt.time();
-                    let offset = *dt.offset();
-
-                    Ok(PgTimeTz { time, offset })
+                    Ok(PgTimeTz {
+                        time: NaiveTime::parse_from_str(time, "%H:%M:%S%.f")?,
+                        offset: FixedOffset::east_opt(offset)
+                            .ok_or_else(|| format!("TIMETZ offset out of range: {}", offset))?,
+                    })
                 }
             }
         }
@@ -168,14 +170,24 @@ mod time {
 
                     Ok(PgTimeTz {
                         time,
-                        offset: UtcOffset::west_seconds(seconds as u32),
+                        offset: UtcOffset::seconds(-seconds),
                     })
                 }
 
                 PgValueFormat::Text => {
-                    // the `time` crate has a limited ability to parse and can't parse the
-                    // timezone format
-                    Err("reading a `TIMETZ` value in text format is not supported.".into())
+                    let (time, offset) = split_offset(value.as_str()?)?;
+
+                    // the `time` crate can only parse exactly 9 digits after the decimal point
+                    let time = if time.contains('.') {
+                        format!("{:0<18}", time)
+                    } else {
+                        format!("{}.000000000", time)
+                    };
+
+                    Ok(PgTimeTz {
+                        time: Time::parse(&*time, "%H:%M:%S.%N")?,
+                        offset: UtcOffset::seconds(offset),
+                    })
                 }
             }
         }
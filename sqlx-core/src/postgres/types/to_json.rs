@@ -0,0 +1,395 @@
+use std::cmp;
+
+use bytes::Buf;
+use serde_json::{Map, Number, Value as JsonValue};
+
+use crate::error::BoxDynError;
+use crate::postgres::type_info::{PgType, PgTypeKind};
+use crate::postgres::types::array::parse_text_array;
+use crate::postgres::types::numeric::PgNumeric;
+use crate::postgres::types::record::{text_from_binary, PgRecordDecoder};
+use crate::postgres::{PgTypeInfo, PgValueFormat, PgValueRef};
+use crate::types::parse_float;
+
+// the number of days between 1970-01-01 and the Postgres epoch, 2000-01-01
+const PG_EPOCH_DAYS: i64 = 10_957;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+impl PgValueRef<'_> {
+    /// Converts the value into JSON, whatever its type, as described by the database.
+    ///
+    /// This is meant for generic tools (e.g. to export or inspect the results of arbitrary
+    /// queries) which do not know the types of the columns at compile time:
+    ///
+    /// ```rust,ignore
+    /// for row in conn.fetch_all(query).await? {
+    ///     let mut object = serde_json::Map::new();
+    ///
+    ///     for column in row.columns() {
+    ///         let value = row.try_get_raw(column.ordinal())?;
+    ///         object.insert(column.name().to_owned(), value.to_json()?);
+    ///     }
+    ///
+    ///     println!("{}", serde_json::Value::Object(object));
+    /// }
+    /// ```
+    ///
+    /// The conversion follows `to_json()` in Postgres:
+    ///
+    /// * `NULL` is `null`, booleans are booleans and integers and floats are numbers, except
+    ///   for `NaN` and the infinities which are strings.
+    /// * `NUMERIC` is a string, as a number could lose precision.
+    /// * `JSON` and `JSONB` are embedded as they are.
+    /// * Dates and times are ISO 8601 strings, with an offset of `+00:00` for `TIMESTAMPTZ`.
+    /// * Arrays are arrays (nested for multi-dimensional arrays), composite types and records
+    ///   are objects keyed by field name (`f1`, `f2`, ... for anonymous records) and domains
+    ///   are converted as their base type.
+    /// * Other types are strings: text, enumeration labels, `UUID`, or `BYTEA` in hex (`\x..`).
+    ///
+    /// Values received in the text format, e.g. from a query without arguments, are always
+    /// converted. In the binary format, this returns an error for the types not listed above
+    /// (e.g. geometric types or ranges), which should be cast to `TEXT` in the query.
+    ///
+    /// The custom types (e.g. composite types) are only described by the database for prepared
+    /// queries. In a query without arguments, those the connection has not seen before are
+    /// converted to a string of their text format.
+    pub fn to_json(&self) -> Result<JsonValue, BoxDynError> {
+        if self.value.is_none() {
+            return Ok(JsonValue::Null);
+        }
+
+        let kind = match &self.type_info.0 {
+            // the type could not be resolved, e.g. the fields of a text-format record
+            PgType::DeclareWithOid(_) | PgType::DeclareWithName(_) => &PgTypeKind::Simple,
+
+            ty => ty.kind(),
+        };
+
+        match kind {
+            PgTypeKind::Domain(base) => self.with_type_info(base.clone()).to_json(),
+
+            PgTypeKind::Array(element) => match self.format {
+                PgValueFormat::Binary => array_from_binary(self.as_bytes()?, element),
+                PgValueFormat::Text => array_from_text(self.as_str()?, element),
+            },
+
+            PgTypeKind::Composite(fields) => {
+                let names = fields.iter().map(|(name, _)| name.clone()).collect();
+                let types = fields.iter().map(|(_, ty)| ty.clone()).collect();
+
+                record_to_json(self, names, Some(types))
+            }
+
+            PgTypeKind::Simple if self.type_info.0 == PgType::Record => {
+                record_to_json(self, Vec::new(), None)
+            }
+
+            PgTypeKind::Enum(_) => Ok(JsonValue::String(self.as_str()?.to_owned())),
+
+            _ => match self.format {
+                PgValueFormat::Binary => scalar_from_binary(&self.type_info, self.as_bytes()?),
+                PgValueFormat::Text => Ok(scalar_from_text(&self.type_info, self.as_str()?)),
+            },
+        }
+    }
+
+    fn with_type_info(&self, type_info: PgTypeInfo) -> Self {
+        PgValueRef {
+            value: self.value,
+            row: self.row,
+            type_info,
+            format: self.format,
+        }
+    }
+}
+
+fn array_from_binary(mut buf: &[u8], element: &PgTypeInfo) -> Result<JsonValue, BoxDynError> {
+    // https://github.com/postgres/postgres/blob/a995b371ae29de2d38c4b7881cf414b1560e9746/src/backend/utils/adt/arrayfuncs.c#L1548
+
+    let ndim = buf.get_i32();
+
+    if ndim <= 0 {
+        return Ok(JsonValue::Array(Vec::new()));
+    }
+
+    let _flags = buf.get_i32();
+    let element_oid = buf.get_u32();
+
+    // prefer the resolved element type, which knows about custom types
+    let element = if element.0.try_oid() == Some(element_oid) {
+        element.clone()
+    } else {
+        PgTypeInfo::try_from_oid(element_oid).unwrap_or_else(|| PgTypeInfo::with_oid(element_oid))
+    };
+
+    let mut dims = Vec::with_capacity(ndim as usize);
+
+    for _ in 0..ndim {
+        let len = buf.get_i32();
+        let _lower = buf.get_i32();
+
+        dims.push(cmp::max(len, 0) as usize);
+    }
+
+    array_dimension(&mut buf, &dims, &element)
+}
+
+// the elements are laid out in row-major order
+fn array_dimension(
+    buf: &mut &[u8],
+    dims: &[usize],
+    element: &PgTypeInfo,
+) -> Result<JsonValue, BoxDynError> {
+    let mut values = Vec::with_capacity(dims[0]);
+
+    for _ in 0..dims[0] {
+        values.push(if dims.len() > 1 {
+            array_dimension(buf, &dims[1..], element)?
+        } else {
+            PgValueRef::get(buf, PgValueFormat::Binary, element.clone()).to_json()?
+        });
+    }
+
+    Ok(JsonValue::Array(values))
+}
+
+fn array_from_text(s: &str, element: &PgTypeInfo) -> Result<JsonValue, BoxDynError> {
+    parse_text_array(s)?
+        .iter()
+        .map(|value| {
+            PgValueRef {
+                value: value.as_deref().map(str::as_bytes),
+                row: None,
+                type_info: element.clone(),
+                format: PgValueFormat::Text,
+            }
+            .to_json()
+        })
+        .collect()
+}
+
+fn record_to_json(
+    value: &PgValueRef<'_>,
+    names: Vec<String>,
+    types: Option<Vec<PgTypeInfo>>,
+) -> Result<JsonValue, BoxDynError> {
+    let mut object = Map::new();
+
+    match value.format {
+        PgValueFormat::Binary => {
+            let mut buf = value.as_bytes()?;
+            let len = buf.get_u32() as usize;
+
+            for index in 0..len {
+                let oid = buf.get_u32();
+
+                let ty = types
+                    .as_ref()
+                    .and_then(|types| types.get(index).cloned())
+                    .or_else(|| PgTypeInfo::try_from_oid(oid))
+                    .unwrap_or_else(|| PgTypeInfo::with_oid(oid));
+
+                let field = PgValueRef::get(&mut buf, PgValueFormat::Binary, ty).to_json()?;
+
+                object.insert(field_name(&names, index), field);
+            }
+        }
+
+        PgValueFormat::Text => {
+            let mut decoder = PgRecordDecoder::new(value.clone())?;
+            let mut index = 0;
+
+            // anonymous records are read up to their last field
+            while types
+                .as_ref()
+                .map_or(!decoder.is_exhausted(), |types| index < types.len())
+            {
+                let text = decoder.try_decode::<Option<String>>()?;
+
+                let field = PgValueRef {
+                    value: text.as_deref().map(str::as_bytes),
+                    row: None,
+                    // the fields of an anonymous record are untyped in the text format
+                    type_info: match &types {
+                        Some(types) => types[index].clone(),
+                        None => PgTypeInfo::with_oid(0),
+                    },
+                    format: PgValueFormat::Text,
+                }
+                .to_json()?;
+
+                object.insert(field_name(&names, index), field);
+                index += 1;
+            }
+        }
+    }
+
+    Ok(JsonValue::Object(object))
+}
+
+fn field_name(names: &[String], index: usize) -> String {
+    names
+        .get(index)
+        .cloned()
+        .unwrap_or_else(|| format!("f{}", index + 1))
+}
+
+fn scalar_from_binary(ty: &PgTypeInfo, mut buf: &[u8]) -> Result<JsonValue, BoxDynError> {
+    Ok(match ty.0 {
+        PgType::Bool => JsonValue::Bool(buf.get_u8() != 0),
+
+        PgType::Int2 => buf.get_i16().into(),
+        PgType::Int4 => buf.get_i32().into(),
+        PgType::Int8 => buf.get_i64().into(),
+        PgType::Oid => buf.get_u32().into(),
+
+        PgType::Float4 => float_to_json(buf.get_f32().into()),
+        PgType::Float8 => float_to_json(buf.get_f64()),
+
+        PgType::Numeric => JsonValue::String(PgNumeric::decode(buf)?.to_string()),
+
+        // skip the JSONB format version
+        PgType::Jsonb => serde_json::from_slice(buf.get(1..).unwrap_or_default())?,
+        PgType::Json => serde_json::from_slice(buf)?,
+
+        PgType::Date => JsonValue::String(match buf.get_i32() {
+            i32::MAX => "infinity".into(),
+            i32::MIN => "-infinity".into(),
+            days => date_to_iso(days.into()),
+        }),
+
+        PgType::Time => JsonValue::String(time_to_iso(buf.get_i64())),
+
+        PgType::Timetz => {
+            let time = time_to_iso(buf.get_i64());
+
+            // the offset is in seconds west of UTC
+            JsonValue::String(time + &offset_to_iso(-buf.get_i32()))
+        }
+
+        PgType::Timestamp | PgType::Timestamptz => JsonValue::String(match buf.get_i64() {
+            i64::MAX => "infinity".into(),
+            i64::MIN => "-infinity".into(),
+            micros => {
+                let days = micros.div_euclid(MICROS_PER_DAY);
+                let time = micros.rem_euclid(MICROS_PER_DAY);
+
+                let mut s = format!("{}T{}", date_to_iso(days), time_to_iso(time));
+
+                if ty.0 == PgType::Timestamptz {
+                    s.push_str("+00:00");
+                }
+
+                s
+            }
+        }),
+
+        _ => match text_from_binary(ty, buf) {
+            Some(text) => JsonValue::String(text.into_owned()),
+
+            None => {
+                return Err(format!(
+                    "cannot convert a value of type {} to JSON in the binary format; \
+                     cast it to TEXT in the query",
+                    ty.display_name()
+                )
+                .into())
+            }
+        },
+    })
+}
+
+fn scalar_from_text(ty: &PgTypeInfo, s: &str) -> JsonValue {
+    let value = match ty.0 {
+        PgType::Bool => Some(JsonValue::Bool(s == "t")),
+
+        PgType::Int2 | PgType::Int4 | PgType::Int8 | PgType::Oid => {
+            s.parse::<i64>().ok().map(JsonValue::from)
+        }
+
+        PgType::Float4 | PgType::Float8 => parse_float::<f64>(s).ok().map(float_to_json),
+
+        PgType::Json | PgType::Jsonb => serde_json::from_str(s).ok(),
+
+        // the text format of dates and times depends on `DateStyle`; keep it as is
+        _ => None,
+    };
+
+    value.unwrap_or_else(|| JsonValue::String(s.to_owned()))
+}
+
+fn float_to_json(value: f64) -> JsonValue {
+    match Number::from_f64(value) {
+        Some(number) => JsonValue::Number(number),
+
+        None if value.is_nan() => JsonValue::String("NaN".into()),
+        None if value > 0.0 => JsonValue::String("Infinity".into()),
+        None => JsonValue::String("-Infinity".into()),
+    }
+}
+
+// Formats a number of days since the Postgres epoch as `YYYY-MM-DD`
+fn date_to_iso(days: i64) -> String {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + PG_EPOCH_DAYS + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// Formats a number of microseconds since midnight as `HH:MM:SS[.ffffff]`
+fn time_to_iso(micros: i64) -> String {
+    let seconds = micros / 1_000_000;
+
+    let mut s = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    let fraction = micros % 1_000_000;
+
+    if fraction != 0 {
+        s.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+    }
+
+    s
+}
+
+// Formats an offset in seconds east of UTC as `+HH:MM[:SS]`
+fn offset_to_iso(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+
+    let mut s = format!("{}{:02}:{:02}", sign, seconds / 3600, seconds / 60 % 60);
+
+    if seconds % 60 != 0 {
+        s.push_str(&format!(":{:02}", seconds % 60));
+    }
+
+    s
+}
+
+#[test]
+fn test_format_dates_and_times() {
+    assert_eq!(date_to_iso(0), "2000-01-01");
+    assert_eq!(date_to_iso(-1), "1999-12-31");
+    assert_eq!(date_to_iso(59), "2000-02-29");
+    assert_eq!(date_to_iso(7_701), "2021-01-31");
+
+    assert_eq!(time_to_iso(0), "00:00:00");
+    assert_eq!(time_to_iso(45_296_500_000), "12:34:56.5");
+    assert_eq!(time_to_iso(86_399_999_999), "23:59:59.999999");
+
+    assert_eq!(offset_to_iso(3600 * 5 + 1800), "+05:30");
+    assert_eq!(offset_to_iso(-3600), "-01:00");
+}
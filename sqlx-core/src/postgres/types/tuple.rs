@@ -1,7 +1,7 @@
 use crate::decode::Decode;
 use crate::error::BoxDynError;
-use crate::postgres::types::PgRecordDecoder;
-use crate::postgres::{PgTypeInfo, PgValueRef, Postgres};
+use crate::postgres::types::{array_compatible, PgRecordDecoder};
+use crate::postgres::{PgTypeInfo, PgTypeKind, PgValueRef, Postgres};
 use crate::types::Type;
 
 macro_rules! impl_type_for_tuple {
@@ -11,6 +11,12 @@ macro_rules! impl_type_for_tuple {
             fn type_info() -> PgTypeInfo {
                 PgTypeInfo::RECORD
             }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                // anonymous records as well as the row types of tables and composite types,
+                // whose fields are checked when they are decoded
+                *ty == PgTypeInfo::RECORD || matches!(ty.kind(), PgTypeKind::Composite(_))
+            }
         }
 
         impl<$($T,)*> Type<Postgres> for [($($T,)*)] {
@@ -18,6 +24,10 @@ macro_rules! impl_type_for_tuple {
             fn type_info() -> PgTypeInfo {
                 PgTypeInfo::RECORD_ARRAY
             }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                array_compatible::<($($T,)*)>(ty)
+            }
         }
 
         impl<$($T,)*> Type<Postgres> for Vec<($($T,)*)> {
@@ -25,6 +35,10 @@ macro_rules! impl_type_for_tuple {
             fn type_info() -> PgTypeInfo {
                 <[($($T,)*)] as Type<Postgres>>::type_info()
             }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                <[($($T,)*)] as Type<Postgres>>::compatible(ty)
+            }
         }
 
         impl<'r, $($T,)*> Decode<'r, Postgres> for ($($T,)*)
@@ -0,0 +1,98 @@
+use std::convert::TryFrom;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::types::array_compatible;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+
+/// A `u32` bound to and read from an `INT4` column, checked for overflow.
+///
+/// A bare `u32` maps to `OID`. Binding a value larger than `i32::MAX` fails the query with
+/// [`Error::Encode`] rather than wrapping around, and decoding a negative value is an error.
+///
+/// [`Error::Encode`]: crate::error::Error::Encode
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgU32(pub u32);
+
+/// A `u64` bound to and read from an `INT8` column, checked for overflow.
+///
+/// Binding a value larger than `i64::MAX` fails the query with [`Error::Encode`] rather than
+/// wrapping around, and decoding a negative value is an error.
+///
+/// [`Error::Encode`]: crate::error::Error::Encode
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PgU64(pub u64);
+
+macro_rules! impl_checked_unsigned {
+    ($ty:ident($unsigned:ty) as $signed:ty, $type_info:ident, $array_type_info:ident) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$type_info
+            }
+        }
+
+        impl Type<Postgres> for [$ty] {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::$array_type_info
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                array_compatible::<$ty>(ty)
+            }
+        }
+
+        impl Type<Postgres> for Vec<$ty> {
+            fn type_info() -> PgTypeInfo {
+                <[$ty] as Type<Postgres>>::type_info()
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                <[$ty] as Type<Postgres>>::compatible(ty)
+            }
+        }
+
+        impl Encode<'_, Postgres> for $ty {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                let value = <$signed>::try_from(self.0).unwrap_or_else(|_| {
+                    buf.encode_error(format!(
+                        "{} is out of range for {}",
+                        self.0,
+                        stringify!($type_info)
+                    ));
+
+                    // never sent; the query fails before the arguments are written
+                    0
+                });
+
+                <$signed as Encode<Postgres>>::encode(value, buf)
+            }
+        }
+
+        impl Decode<'_, Postgres> for $ty {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                let value = <$signed as Decode<Postgres>>::decode(value)?;
+
+                <$unsigned>::try_from(value)
+                    .map($ty)
+                    .map_err(|_| format!("{} is out of range for {}", value, stringify!($ty)).into())
+            }
+        }
+
+        impl From<$unsigned> for $ty {
+            fn from(value: $unsigned) -> Self {
+                $ty(value)
+            }
+        }
+
+        impl From<$ty> for $unsigned {
+            fn from(value: $ty) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+impl_checked_unsigned!(PgU32(u32) as i32, INT4, INT4_ARRAY);
+impl_checked_unsigned!(PgU64(u64) as i64, INT8, INT8_ARRAY);
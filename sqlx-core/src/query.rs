@@ -9,6 +9,7 @@ use crate::database::{Database, HasArguments, HasStatement, HasStatementCache};
 use crate::encode::Encode;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
+use crate::query_macros::DeferredCheck;
 use crate::statement::Statement;
 use crate::types::Type;
 
@@ -19,6 +20,7 @@ pub struct Query<'q, DB: Database, A> {
     pub(crate) arguments: Option<A>,
     pub(crate) database: PhantomData<DB>,
     pub(crate) persistent: bool,
+    pub(crate) deferred_check: Option<DeferredCheck<DB>>,
 }
 
 /// SQL query that will map its results to owned Rust types.
@@ -65,6 +67,11 @@ where
     fn persistent(&self) -> bool {
         self.persistent
     }
+
+    #[inline]
+    fn deferred_check(&self) -> Option<DeferredCheck<DB>> {
+        self.deferred_check
+    }
 }
 
 impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
@@ -83,6 +90,102 @@ impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
 
         self
     }
+
+    /// Bind each value of an iterator as its own bind parameter, following the parameters
+    /// already bound, e.g. for a list of `IN ($1, $2, $3)`.
+    ///
+    /// With PostgreSQL and MySQL, executing the query fails with
+    /// [`Error::BindParameterCount`] if the query has a different number of bind parameters
+    /// than the values bound.
+    ///
+    /// ```rust,ignore
+    /// let ids = vec![1, 2, 3];
+    ///
+    /// sqlx::query("DELETE FROM users WHERE id IN ($1, $2, $3)")
+    ///     .bind_iter(ids)
+    /// ```
+    pub fn bind_iter<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: 'q + Send + Encode<'q, DB> + Type<DB>,
+    {
+        if let Some(arguments) = &mut self.arguments {
+            let values = values.into_iter();
+            arguments.reserve(values.size_hint().0, 0);
+
+            for value in values {
+                arguments.add(value);
+            }
+        }
+
+        self
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'q> Query<'q, crate::postgres::Postgres, crate::postgres::PgArguments> {
+    /// Bind a value for use with this SQL query, declaring the parameter with the given type
+    /// instead of the type of the value.
+    ///
+    /// The value must be encoded in the binary format of the declared type, e.g. an `i32` can be
+    /// bound as an `OID`. Strings are sent in the text format and parsed by the server as if
+    /// they were cast to the declared type, which allows binding a `String` as e.g. an `ltree`
+    /// without a wrapper type.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("SELECT * FROM nodes WHERE path <@ $1")
+    ///     .bind_with_type("top.science", PgTypeInfo::with_name("ltree"))
+    /// ```
+    pub fn bind_with_type<T>(mut self, value: T, type_info: crate::postgres::PgTypeInfo) -> Self
+    where
+        T: 'q + Send + Encode<'q, crate::postgres::Postgres> + Type<crate::postgres::Postgres>,
+    {
+        if let Some(arguments) = &mut self.arguments {
+            arguments.add_with_type(value, type_info);
+        }
+
+        self
+    }
+
+    /// Bind a `NULL` of the given type for use with this SQL query.
+    ///
+    /// Binding `None::<T>` declares the parameter with the type of `T`, which may not be the
+    /// type the query expects when `T` is compatible with several types, or is unknown to SQLx.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("UPDATE nodes SET path = $1 WHERE id = $2")
+    ///     .bind_null_with(PgTypeInfo::with_name("ltree"))
+    ///     .bind(id)
+    /// ```
+    pub fn bind_null_with(mut self, type_info: crate::postgres::PgTypeInfo) -> Self {
+        if let Some(arguments) = &mut self.arguments {
+            arguments.add_null(type_info);
+        }
+
+        self
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<'q> Query<'q, crate::mysql::MySql, crate::mysql::MySqlArguments> {
+    /// Attach a query attribute to this SQL query, which the server makes available with
+    /// `mysql_query_attribute_string()`, e.g. to trace the origin of queries in the logs.
+    ///
+    /// Query attributes are supported since MySQL 8.0.23, with the `query_attributes` component
+    /// installed; executing a query with attributes on an older server (or MariaDB) fails.
+    ///
+    /// ```rust,ignore
+    /// sqlx::query("SELECT * FROM users WHERE id = ?")
+    ///     .bind(id)
+    ///     .attribute("trace_id", &trace_id)
+    /// ```
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        if let Some(arguments) = &mut self.arguments {
+            arguments.add_attribute(key, value);
+        }
+
+        self
+    }
 }
 
 impl<'q, DB, A> Query<'q, DB, A>
@@ -251,6 +354,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.arguments.is_some()
     }
+
+    #[inline]
+    fn deferred_check(&self) -> Option<DeferredCheck<DB>> {
+        self.inner.deferred_check
+    }
 }
 
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
@@ -406,6 +514,7 @@ where
         arguments: Some(Default::default()),
         statement: Either::Right(statement),
         persistent: true,
+        deferred_check: None,
     }
 }
 
@@ -423,6 +532,7 @@ where
         arguments: Some(arguments),
         statement: Either::Right(statement),
         persistent: true,
+        deferred_check: None,
     }
 }
 
@@ -436,6 +546,7 @@ where
         arguments: Some(Default::default()),
         statement: Either::Left(sql),
         persistent: true,
+        deferred_check: None,
     }
 }
 
@@ -450,5 +561,30 @@ where
         arguments: Some(arguments),
         statement: Either::Left(sql),
         persistent: true,
+        deferred_check: None,
     }
 }
+
+/// Make a SQL query.
+///
+/// Unlike [`query`], this is not deprecated when the `checked-queries` feature is enabled, for the
+/// SQL which cannot be checked at compile time, e.g. because it is built at run time.
+#[inline]
+pub fn query_dynamic<DB>(sql: &str) -> Query<'_, DB, <DB as HasArguments<'_>>::Arguments>
+where
+    DB: Database,
+{
+    query(sql)
+}
+
+/// Make a SQL query, with the given arguments.
+///
+/// Unlike [`query_with`], this is not deprecated when the `checked-queries` feature is enabled.
+#[inline]
+pub fn query_dynamic_with<'q, DB, A>(sql: &'q str, arguments: A) -> Query<'q, DB, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+{
+    query_with(sql, arguments)
+}
@@ -11,6 +11,7 @@ use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::from_row::FromRow;
 use crate::query::{query, query_statement, query_statement_with, query_with, Query};
+use crate::query_macros::DeferredCheck;
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters, mapped to a concrete type using [`FromRow`].
@@ -45,6 +46,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn deferred_check(&self) -> Option<DeferredCheck<DB>> {
+        self.inner.deferred_check()
+    }
 }
 
 impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments> {
@@ -55,6 +61,51 @@ impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind each value of an iterator as its own bind parameter.
+    ///
+    /// See [`Query::bind_iter`](Query::bind_iter).
+    pub fn bind_iter<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: 'q + Send + Encode<'q, DB> + Type<DB>,
+    {
+        self.inner = self.inner.bind_iter(values);
+        self
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'q, O> QueryAs<'q, crate::postgres::Postgres, O, crate::postgres::PgArguments> {
+    /// Bind a value for use with this SQL query, declaring the parameter with the given type.
+    ///
+    /// See [`Query::bind_with_type`](Query::bind_with_type).
+    pub fn bind_with_type<T>(mut self, value: T, type_info: crate::postgres::PgTypeInfo) -> Self
+    where
+        T: 'q + Send + Encode<'q, crate::postgres::Postgres> + Type<crate::postgres::Postgres>,
+    {
+        self.inner = self.inner.bind_with_type(value, type_info);
+        self
+    }
+
+    /// Bind a `NULL` of the given type for use with this SQL query.
+    ///
+    /// See [`Query::bind_null_with`](Query::bind_null_with).
+    pub fn bind_null_with(mut self, type_info: crate::postgres::PgTypeInfo) -> Self {
+        self.inner = self.inner.bind_null_with(type_info);
+        self
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<'q, O> QueryAs<'q, crate::mysql::MySql, O, crate::mysql::MySqlArguments> {
+    /// Attach a query attribute to this SQL query.
+    ///
+    /// See [`Query::attribute`](Query::attribute).
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.inner = self.inner.attribute(key, value);
+        self
+    }
 }
 
 // FIXME: This is very close, nearly 1:1 with `Map`
@@ -180,6 +231,35 @@ where
     }
 }
 
+/// Make a SQL query that is mapped to a concrete type
+/// using [`FromRow`].
+///
+/// Unlike [`query_as`], this is not deprecated when the `checked-queries` feature is enabled.
+#[inline]
+pub fn query_as_dynamic<'q, DB, O>(
+    sql: &'q str,
+) -> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments>
+where
+    DB: Database,
+    O: for<'r> FromRow<'r, DB::Row>,
+{
+    query_as(sql)
+}
+
+/// Make a SQL query, with the given arguments, that is mapped to a concrete type
+/// using [`FromRow`].
+///
+/// Unlike [`query_as_with`], this is not deprecated when the `checked-queries` feature is enabled.
+#[inline]
+pub fn query_as_dynamic_with<'q, DB, O, A>(sql: &'q str, arguments: A) -> QueryAs<'q, DB, O, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+    O: for<'r> FromRow<'r, DB::Row>,
+{
+    query_as_with(sql, arguments)
+}
+
 // Make a SQL query from a statement, that is mapped to a concrete type.
 pub(crate) fn query_statement_as<'q, DB, O>(
     statement: &'q <DB as HasStatement<'q>>::Statement,
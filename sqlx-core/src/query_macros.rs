@@ -0,0 +1,407 @@
+//! Support code for the expansion of the query macros.
+//!
+//! The macros call into these generic functions instead of expanding the equivalent code at
+//! every invocation. Identical instantiations are shared between invocations, which reduces the
+//! amount of code to compile in crates with many queries.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use either::Either;
+
+use crate::arguments::{Arguments, IntoArguments};
+use crate::column::Column;
+use crate::database::{Database, HasArguments};
+use crate::describe::Describe;
+use crate::encode::Encode;
+use crate::error::{mismatched_types, BoxDynError, Error};
+use crate::executor::Executor;
+use crate::from_row::FromRow;
+use crate::query::query_with;
+use crate::query_as::QueryAs;
+use crate::query_scalar::QueryScalar;
+use crate::type_info::TypeInfo;
+use crate::types::Type;
+
+/// Converts the row mapping closure of a query macro to a function pointer.
+///
+/// A closure has a unique type for every invocation, which would have the whole fetching
+/// machinery of [`Map`](crate::query::Map) compiled again for each of them. As a function pointer,
+/// it is only compiled once for every output type.
+#[inline]
+pub fn map_row<DB: Database, O>(
+    f: fn(DB::Row) -> Result<O, Error>,
+) -> fn(DB::Row) -> Result<O, Error> {
+    f
+}
+
+/// The check of a query macro expanded while the database was unreachable
+/// (`SQLX_DATABASE_UNREACHABLE=runtime`), deferred to the first execution of the query.
+///
+/// Before the query is executed for the first time, it is described by the database and its
+/// parameters and columns are checked against the invocation of the macro.
+pub struct DeferredCheck<DB: Database> {
+    // whether the invocation was checked; a static for every invocation
+    checked: &'static AtomicBool,
+    arguments: usize,
+    // checks the type of the column of `query_scalar!()`
+    scalar: Option<fn(&DB::TypeInfo) -> Result<(), BoxDynError>>,
+}
+
+impl<DB: Database> Clone for DeferredCheck<DB> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<DB: Database> Copy for DeferredCheck<DB> {}
+
+impl<DB: Database> DeferredCheck<DB> {
+    /// Describes the query and checks it, unless it was checked before.
+    pub(crate) async fn run<'c, E>(self, executor: E, sql: &str) -> Result<(), Error>
+    where
+        E: Executor<'c, Database = DB>,
+    {
+        if self.checked.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        let describe = executor.describe(sql).await?;
+
+        self.check(&describe)?;
+        self.checked.store(true, Ordering::Release);
+
+        Ok(())
+    }
+
+    fn check(&self, describe: &Describe<DB>) -> Result<(), Error> {
+        let expected = match describe.parameters() {
+            Some(Either::Left(parameters)) => Some(parameters.len()),
+            Some(Either::Right(count)) => Some(count),
+            None => None,
+        };
+
+        match expected {
+            Some(expected) if expected != self.arguments => {
+                return Err(Error::BindParameterCount {
+                    expected,
+                    actual: self.arguments,
+                });
+            }
+
+            _ => {}
+        }
+
+        if let Some(check_type) = self.scalar {
+            let columns = describe.columns();
+
+            if columns.len() != 1 {
+                return Err(Error::Decode(
+                    format!(
+                        "`query_scalar!()` expects the query to return a single column, \
+                         it returns {}",
+                        columns.len()
+                    )
+                    .into(),
+                ));
+            }
+
+            let ty = columns[0].type_info();
+
+            if !ty.is_null() {
+                check_type(ty).map_err(|source| Error::ColumnDecode {
+                    index: format!("{:?}", 0),
+                    source,
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_type<DB: Database, T: Type<DB>>(ty: &DB::TypeInfo) -> Result<(), BoxDynError> {
+    if T::compatible(ty) {
+        Ok(())
+    } else {
+        Err(mismatched_types::<DB, T>(ty))
+    }
+}
+
+/// Makes the query of `query_as!()`, checked before it is first executed.
+pub fn query_as_deferred<'q, DB, O, A>(
+    sql: &'q str,
+    arguments: A,
+    checked: &'static AtomicBool,
+    arguments_count: usize,
+) -> QueryAs<'q, DB, O, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+    O: for<'r> FromRow<'r, DB::Row>,
+{
+    let mut inner = query_with(sql, arguments);
+
+    inner.deferred_check = Some(DeferredCheck {
+        checked,
+        arguments: arguments_count,
+        scalar: None,
+    });
+
+    QueryAs {
+        inner,
+        output: Default::default(),
+    }
+}
+
+/// Makes the query of `query_scalar!()`, checked before it is first executed.
+pub fn query_scalar_deferred<'q, DB, O, A>(
+    sql: &'q str,
+    arguments: A,
+    checked: &'static AtomicBool,
+    arguments_count: usize,
+) -> QueryScalar<'q, DB, O, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+    O: Type<DB>,
+    (O,): for<'r> FromRow<'r, DB::Row>,
+{
+    let mut inner = query_with(sql, arguments);
+
+    inner.deferred_check = Some(DeferredCheck {
+        checked,
+        arguments: arguments_count,
+        scalar: Some(check_type::<DB, O>),
+    });
+
+    QueryScalar {
+        inner: QueryAs {
+            inner,
+            output: Default::default(),
+        },
+    }
+}
+
+/// Binds the arguments of a query macro, given as a tuple of references.
+#[inline]
+pub fn bind_args<'q, DB, A>(args: A) -> <DB as HasArguments<'q>>::Arguments
+where
+    DB: Database,
+    A: BindArgs<'q, DB>,
+{
+    args.bind_args()
+}
+
+pub trait BindArgs<'q, DB: HasArguments<'q>> {
+    fn bind_args(self) -> <DB as HasArguments<'q>>::Arguments;
+}
+
+// implement BindArgs for tuples of types that implement Encode
+// up to tuples of 16 values; the macros expand the binding in place for more arguments
+
+macro_rules! impl_bind_args_for_tuple {
+    ($( ($idx:tt) -> $T:ident );+;) => {
+        impl<'q, DB, $($T,)+> BindArgs<'q, DB> for ($($T,)+)
+        where
+            DB: Database,
+            $($T: 'q + Send + Encode<'q, DB> + Type<DB>,)+
+        {
+            fn bind_args(self) -> <DB as HasArguments<'q>>::Arguments {
+                let mut args = <DB as HasArguments<'q>>::Arguments::default();
+
+                args.reserve(
+                    [$($idx,)+].len(),
+                    0 $(+ self.$idx.size_hint())+,
+                );
+
+                $(args.add(self.$idx);)+
+
+                args
+            }
+        }
+    };
+}
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+);
+
+impl_bind_args_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+    (15) -> T16;
+);
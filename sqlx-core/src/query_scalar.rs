@@ -11,13 +11,14 @@ use crate::from_row::FromRow;
 use crate::query_as::{
     query_as, query_as_with, query_statement_as, query_statement_as_with, QueryAs,
 };
+use crate::query_macros::DeferredCheck;
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters, mapped to a concrete type using [`FromRow`] on `(O,)`.
 /// Returned from [`query_scalar`].
 #[must_use = "query must be executed to affect database"]
 pub struct QueryScalar<'q, DB: Database, O, A> {
-    inner: QueryAs<'q, DB, (O,), A>,
+    pub(crate) inner: QueryAs<'q, DB, (O,), A>,
 }
 
 impl<'q, DB: Database, O: Send, A: Send> Execute<'q, DB> for QueryScalar<'q, DB, O, A>
@@ -42,6 +43,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn deferred_check(&self) -> Option<DeferredCheck<DB>> {
+        self.inner.deferred_check()
+    }
 }
 
 impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Arguments> {
@@ -52,6 +58,51 @@ impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Argum
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind each value of an iterator as its own bind parameter.
+    ///
+    /// See [`Query::bind_iter`](crate::query::Query::bind_iter).
+    pub fn bind_iter<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: 'q + Send + Encode<'q, DB> + Type<DB>,
+    {
+        self.inner = self.inner.bind_iter(values);
+        self
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<'q, O> QueryScalar<'q, crate::postgres::Postgres, O, crate::postgres::PgArguments> {
+    /// Bind a value for use with this SQL query, declaring the parameter with the given type.
+    ///
+    /// See [`Query::bind_with_type`](crate::query::Query::bind_with_type).
+    pub fn bind_with_type<T>(mut self, value: T, type_info: crate::postgres::PgTypeInfo) -> Self
+    where
+        T: 'q + Send + Encode<'q, crate::postgres::Postgres> + Type<crate::postgres::Postgres>,
+    {
+        self.inner = self.inner.bind_with_type(value, type_info);
+        self
+    }
+
+    /// Bind a `NULL` of the given type for use with this SQL query.
+    ///
+    /// See [`Query::bind_null_with`](crate::query::Query::bind_null_with).
+    pub fn bind_null_with(mut self, type_info: crate::postgres::PgTypeInfo) -> Self {
+        self.inner = self.inner.bind_null_with(type_info);
+        self
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<'q, O> QueryScalar<'q, crate::mysql::MySql, O, crate::mysql::MySqlArguments> {
+    /// Attach a query attribute to this SQL query.
+    ///
+    /// See [`Query::attribute`](crate::query::Query::attribute).
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.inner = self.inner.attribute(key, value);
+        self
+    }
 }
 
 // FIXME: This is very close, nearly 1:1 with `Map`
@@ -169,6 +220,39 @@ where
     }
 }
 
+/// Make a SQL query that is mapped to a single concrete type
+/// using [`FromRow`].
+///
+/// Unlike [`query_scalar`], this is not deprecated when the `checked-queries` feature is enabled.
+#[inline]
+pub fn query_scalar_dynamic<'q, DB, O>(
+    sql: &'q str,
+) -> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Arguments>
+where
+    DB: Database,
+    (O,): for<'r> FromRow<'r, DB::Row>,
+{
+    query_scalar(sql)
+}
+
+/// Make a SQL query, with the given arguments, that is mapped to a single concrete type
+/// using [`FromRow`].
+///
+/// Unlike [`query_scalar_with`], this is not deprecated when the `checked-queries` feature is
+/// enabled.
+#[inline]
+pub fn query_scalar_dynamic_with<'q, DB, O, A>(
+    sql: &'q str,
+    arguments: A,
+) -> QueryScalar<'q, DB, O, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+    (O,): for<'r> FromRow<'r, DB::Row>,
+{
+    query_scalar_with(sql, arguments)
+}
+
 // Make a SQL query from a statement, that is mapped to a concrete value.
 pub(crate) fn query_statement_scalar<'q, DB, O>(
     statement: &'q <DB as HasStatement<'q>>::Statement,
@@ -3,6 +3,7 @@ use crate::database::{Database, HasValueRef};
 use crate::decode::Decode;
 use crate::error::{mismatched_types, Error};
 use crate::type_info::TypeInfo;
+use crate::types::dynamic::{decode_dynamic, DynamicValue};
 use crate::types::Type;
 use crate::value::ValueRef;
 
@@ -101,11 +102,13 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     /// # Errors
     ///
     ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnAmbiguous`] if more than one column has the given name, in strict mode.
     ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
     ///  * [`ColumnDecode`] if the value could not be decoded into the requested type.
     ///
     /// [`ColumnDecode`]: Error::ColumnDecode
     /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnAmbiguous`]: Error::ColumnAmbiguous
     /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
     ///
     fn try_get<'r, T, I>(&'r self, index: I) -> Result<T, Error>
@@ -141,11 +144,13 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
     /// # Errors
     ///
     ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnAmbiguous`] if more than one column has the given name, in strict mode.
     ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
     ///  * [`ColumnDecode`] if the value could not be decoded into the requested type.
     ///
     /// [`ColumnDecode`]: Error::ColumnDecode
     /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnAmbiguous`]: Error::ColumnAmbiguous
     /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
     ///
     #[inline]
@@ -162,14 +167,54 @@ pub trait Row: private_row::Sealed + Unpin + Send + Sync + 'static {
         })
     }
 
+    /// Index into the database row and decode a single value with the function registered at
+    /// runtime for its SQL type, returning `None` if it is `NULL`.
+    ///
+    /// See [`types::dynamic`](crate::types::dynamic).
+    ///
+    /// # Errors
+    ///
+    ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnAmbiguous`] if more than one column has the given name, in strict mode.
+    ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
+    ///  * [`ColumnDecode`] if no function is registered for the SQL type of the value, or if the
+    ///    value could not be decoded.
+    ///
+    /// [`ColumnDecode`]: Error::ColumnDecode
+    /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnAmbiguous`]: Error::ColumnAmbiguous
+    /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
+    ///
+    fn try_get_dynamic<I>(&self, index: I) -> Result<Option<DynamicValue>, Error>
+    where
+        I: ColumnIndex<Self>,
+    {
+        let value = self.try_get_raw(&index)?;
+
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        let ty = value.type_info().into_owned();
+
+        decode_dynamic::<Self::Database>(value, &ty)
+            .map(Some)
+            .map_err(|source| Error::ColumnDecode {
+                index: format!("{:?}", index),
+                source,
+            })
+    }
+
     /// Index into the database row and decode a single value.
     ///
     /// # Errors
     ///
     ///  * [`ColumnNotFound`] if the column by the given name was not found.
+    ///  * [`ColumnAmbiguous`] if more than one column has the given name, in strict mode.
     ///  * [`ColumnIndexOutOfBounds`] if the `usize` index was greater than the number of columns in the row.
     ///
     /// [`ColumnNotFound`]: Error::ColumnNotFound
+    /// [`ColumnAmbiguous`]: Error::ColumnAmbiguous
     /// [`ColumnIndexOutOfBounds`]: Error::ColumnIndexOutOfBounds
     ///
     fn try_get_raw<I>(
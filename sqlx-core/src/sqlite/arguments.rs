@@ -31,6 +31,12 @@ impl<'q> SqliteArguments<'q> {
             self.values.push(SqliteArgumentValue::Null);
         }
     }
+
+    /// Adds the arguments of `other` to the end of these arguments, e.g. for the bind
+    /// parameters of a condition built separately from the rest of the query.
+    pub fn append(&mut self, other: SqliteArguments<'q>) {
+        self.values.extend(other.values);
+    }
 }
 
 impl<'q> Arguments<'q> for SqliteArguments<'q> {
@@ -8,6 +8,17 @@ pub struct SqliteColumn {
     pub(crate) name: UStr,
     pub(crate) ordinal: usize,
     pub(crate) type_info: SqliteTypeInfo,
+
+    #[cfg_attr(feature = "offline", serde(skip))]
+    pub(crate) table: Option<UStr>,
+}
+
+impl SqliteColumn {
+    /// Gets the name of the table of the column, if the column is a column of a table and not
+    /// an expression.
+    pub fn table_name(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
 }
 
 impl crate::column::private_column::Sealed for SqliteColumn {}
@@ -23,6 +34,10 @@ impl Column for SqliteColumn {
         &*self.name
     }
 
+    fn name_shared(&self) -> UStr {
+        self.name.clone()
+    }
+
     fn type_info(&self) -> &SqliteTypeInfo {
         &self.type_info
     }
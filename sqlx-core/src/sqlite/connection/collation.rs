@@ -8,6 +8,7 @@ use libsqlite3_sys::{sqlite3_create_collation_v2, SQLITE_OK, SQLITE_UTF8};
 
 use crate::error::Error;
 use crate::sqlite::connection::handle::ConnectionHandle;
+use crate::sqlite::statement::catch_callback_panic;
 use crate::sqlite::SqliteError;
 
 unsafe extern "C" fn free_boxed_value<T>(p: *mut c_void) {
@@ -42,7 +43,7 @@ where
             let c_slice = slice::from_raw_parts(arg5 as *const u8, arg4 as usize);
             from_utf8_unchecked(c_slice)
         };
-        let t = (*boxed_f)(s1, s2);
+        let t = catch_callback_panic(Ordering::Equal, || (*boxed_f)(s1, s2));
 
         match t {
             Ordering::Less => -1,
@@ -1,5 +1,6 @@
 use crate::describe::Describe;
 use crate::error::Error;
+use crate::ext::ustr::UStr;
 use crate::sqlite::connection::explain::explain;
 use crate::sqlite::statement::VirtualStatement;
 use crate::sqlite::type_info::DataType;
@@ -88,6 +89,7 @@ pub(super) fn describe<'c: 'e, 'q: 'e, 'e>(
                     name: name.into(),
                     type_info,
                     ordinal: col,
+                    table: stmt.column_table_name(col).map(UStr::new),
                 });
             }
         }
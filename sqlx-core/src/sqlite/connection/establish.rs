@@ -114,6 +114,9 @@ pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<SqliteCo
         statements: StatementCache::new(options.statement_cache_capacity),
         statement: None,
         transaction_depth: 0,
+        write_lock: options.write_lock.clone(),
+        write_guard: None,
+        strict_column_names: options.strict_column_names,
         log_settings: options.log_settings.clone(),
     })
 }
@@ -4,6 +4,7 @@ use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::logger::QueryLogger;
 use crate::sqlite::connection::describe::describe;
+use crate::sqlite::connection::{ConnectionHandle, WriteLock, WriteLockGuard};
 use crate::sqlite::statement::{StatementHandle, VirtualStatement};
 use crate::sqlite::{
     Sqlite, SqliteArguments, SqliteConnection, SqliteQueryResult, SqliteRow, SqliteStatement,
@@ -59,6 +60,33 @@ fn bind(
     Ok(n)
 }
 
+// The turn of the connection to write to the database, if writes are serialized. It is taken
+// before the first statement writing to the database, and given back at the end of the execution
+// unless the connection is left inside of a transaction.
+struct WriteTurn<'a> {
+    conn: &'a mut ConnectionHandle,
+    lock: Option<&'a Arc<WriteLock>>,
+    guard: &'a mut Option<WriteLockGuard>,
+}
+
+impl WriteTurn<'_> {
+    async fn wait_before_step(&mut self, statement: &StatementHandle) {
+        if let Some(lock) = self.lock {
+            if self.guard.is_none() && !statement.read_only() {
+                *self.guard = Some(lock.acquire().await);
+            }
+        }
+    }
+}
+
+impl Drop for WriteTurn<'_> {
+    fn drop(&mut self) {
+        if self.guard.is_some() && self.conn.in_autocommit() {
+            *self.guard = None;
+        }
+    }
+}
+
 impl<'c> Executor<'c> for &'c mut SqliteConnection {
     type Database = Sqlite;
 
@@ -74,23 +102,37 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         let mut logger = QueryLogger::new(sql, self.log_settings.clone());
         let arguments = query.take_arguments();
         let persistent = query.persistent() && arguments.is_some();
+        let deferred_check = query.deferred_check();
 
         Box::pin(try_stream! {
+            if let Some(check) = deferred_check {
+                check.run(&mut *self, sql).await?;
+            }
+
             let SqliteConnection {
                 handle: ref mut conn,
                 ref mut statements,
                 ref mut statement,
                 ref mut worker,
+                ref write_lock,
+                ref mut write_guard,
+                strict_column_names,
                 ..
             } = self;
 
+            let mut turn = WriteTurn {
+                conn,
+                lock: write_lock.as_ref(),
+                guard: write_guard,
+            };
+
             // prepare statement object (or checkout from cache)
             let stmt = prepare(statements, statement, sql, persistent)?;
 
             // keep track of how many arguments we have bound
             let mut num_arguments = 0;
 
-            while let Some((stmt, columns, column_names, last_row_values)) = stmt.prepare(conn)? {
+            while let Some((stmt, columns, column_names, last_row_values)) = stmt.prepare(turn.conn)? {
                 // bind values to the statement
                 num_arguments += bind(stmt, &arguments, num_arguments)?;
 
@@ -99,6 +141,8 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                     // and send them to the still-live row object
                     SqliteRow::inflate_if_needed(stmt, &*columns, last_row_values.take());
 
+                    turn.wait_before_step(stmt).await;
+
                     // invoke [sqlite3_step] on the dedicated worker thread
                     // this will move us forward one row or finish the statement
                     let s = worker.step(*stmt).await?;
@@ -106,7 +150,7 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                     match s {
                         Either::Left(changes) => {
                             let last_insert_rowid = unsafe {
-                                sqlite3_last_insert_rowid(conn.as_ptr())
+                                sqlite3_last_insert_rowid(turn.conn.as_ptr())
                             };
 
                             let done = SqliteQueryResult {
@@ -123,7 +167,8 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                             let (row, weak_values_ref) = SqliteRow::current(
                                 *stmt,
                                 columns,
-                                column_names
+                                column_names,
+                                *strict_column_names
                             );
 
                             let v = Either::Right(row);
@@ -153,16 +198,30 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
         let mut logger = QueryLogger::new(sql, self.log_settings.clone());
         let arguments = query.take_arguments();
         let persistent = query.persistent() && arguments.is_some();
+        let deferred_check = query.deferred_check();
 
         Box::pin(async move {
+            if let Some(check) = deferred_check {
+                check.run(&mut *self, sql).await?;
+            }
+
             let SqliteConnection {
                 handle: ref mut conn,
                 ref mut statements,
                 ref mut statement,
                 ref mut worker,
+                ref write_lock,
+                ref mut write_guard,
+                strict_column_names,
                 ..
             } = self;
 
+            let mut turn = WriteTurn {
+                conn,
+                lock: write_lock.as_ref(),
+                guard: write_guard,
+            };
+
             // prepare statement object (or checkout from cache)
             let virtual_stmt = prepare(statements, statement, sql, persistent)?;
 
@@ -170,7 +229,7 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
             let mut num_arguments = 0;
 
             while let Some((stmt, columns, column_names, last_row_values)) =
-                virtual_stmt.prepare(conn)?
+                virtual_stmt.prepare(turn.conn)?
             {
                 // bind values to the statement
                 num_arguments += bind(stmt, &arguments, num_arguments)?;
@@ -179,6 +238,8 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
                 // and send them to the still-live row object
                 SqliteRow::inflate_if_needed(stmt, &*columns, last_row_values.take());
 
+                turn.wait_before_step(stmt).await;
+
                 // invoke [sqlite3_step] on the dedicated worker thread
                 // this will move us forward one row or finish the statement
                 match worker.step(*stmt).await? {
@@ -186,7 +247,7 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
 
                     Either::Right(()) => {
                         let (row, weak_values_ref) =
-                            SqliteRow::current(*stmt, columns, column_names);
+                            SqliteRow::current(*stmt, columns, column_names, *strict_column_names);
 
                         *last_row_values = Some(weak_values_ref);
 
@@ -1,6 +1,6 @@
 use std::ptr::NonNull;
 
-use libsqlite3_sys::{sqlite3, sqlite3_close, SQLITE_OK};
+use libsqlite3_sys::{sqlite3, sqlite3_close, sqlite3_get_autocommit, SQLITE_OK};
 
 use crate::sqlite::SqliteError;
 
@@ -30,6 +30,12 @@ impl ConnectionHandle {
     pub(crate) fn as_ptr(&self) -> *mut sqlite3 {
         self.0.as_ptr()
     }
+
+    /// Returns `false` if the connection is inside of a transaction.
+    pub(crate) fn in_autocommit(&self) -> bool {
+        // https://www.sqlite.org/c3ref/get_autocommit.html
+        unsafe { sqlite3_get_autocommit(self.0.as_ptr()) != 0 }
+    }
 }
 
 impl Drop for ConnectionHandle {
@@ -2,13 +2,16 @@ use crate::common::StatementCache;
 use crate::connection::{Connection, LogSettings};
 use crate::error::Error;
 use crate::sqlite::statement::{StatementWorker, VirtualStatement};
-use crate::sqlite::{Sqlite, SqliteConnectOptions};
+use crate::sqlite::{
+    Sqlite, SqliteConnectOptions, SqliteTransactionBehavior, SqliteTransactionManager,
+};
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
 use futures_util::future;
 use libsqlite3_sys::sqlite3;
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
 
 mod collation;
 mod describe;
@@ -16,8 +19,11 @@ pub(crate) mod establish;
 mod executor;
 mod explain;
 mod handle;
+mod pragma;
+mod write_lock;
 
 pub(crate) use handle::ConnectionHandle;
+pub(crate) use write_lock::{WriteLock, WriteLockGuard};
 
 /// A connection to a [Sqlite] database.
 pub struct SqliteConnection {
@@ -33,6 +39,13 @@ pub struct SqliteConnection {
     // most recent non-persistent statement
     pub(crate) statement: Option<VirtualStatement>,
 
+    // lock serializing the writes of the connections sharing the same options, if enabled
+    pub(crate) write_lock: Option<Arc<WriteLock>>,
+    pub(crate) write_guard: Option<WriteLockGuard>,
+
+    // whether accessing a column of a row by name fails if more than one column has that name
+    pub(crate) strict_column_names: bool,
+
     log_settings: LogSettings,
 }
 
@@ -49,6 +62,21 @@ impl SqliteConnection {
     ) -> Result<(), Error> {
         collation::create_collation(&self.handle, name, compare)
     }
+
+    /// Begins a new transaction with the given behavior, e.g. `BEGIN IMMEDIATE` for a
+    /// transaction which writes to the database.
+    ///
+    /// Within a transaction, a savepoint is established instead, and `behavior` is ignored.
+    pub fn begin_with(
+        &mut self,
+        behavior: SqliteTransactionBehavior,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Sqlite>, Error>> {
+        Box::pin(async move {
+            SqliteTransactionManager::begin_with(self, behavior).await?;
+
+            Ok(Transaction::begun(self))
+        })
+    }
 }
 
 impl Debug for SqliteConnection {
@@ -0,0 +1,99 @@
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::query_scalar::query_scalar;
+use crate::sqlite::{SqliteConnection, SqliteJournalMode};
+
+// https://www.sqlite.org/pragma.html
+
+impl SqliteConnection {
+    /// Returns `true` if [foreign key constraints](https://www.sqlite.org/pragma.html#pragma_foreign_keys)
+    /// are enforced.
+    pub async fn foreign_keys(&mut self) -> Result<bool, Error> {
+        query_scalar("PRAGMA foreign_keys").fetch_one(self).await
+    }
+
+    /// Enables or disables the enforcement of foreign key constraints.
+    ///
+    /// This has no effect inside of a transaction.
+    pub async fn set_foreign_keys(&mut self, on: bool) -> Result<(), Error> {
+        let sql = format!("PRAGMA foreign_keys = {}", if on { "ON" } else { "OFF" });
+
+        self.execute(&*sql).await?;
+
+        Ok(())
+    }
+
+    /// Returns the [journal mode](https://www.sqlite.org/pragma.html#pragma_journal_mode)
+    /// of the main database.
+    pub async fn journal_mode(&mut self) -> Result<SqliteJournalMode, Error> {
+        let mode: String = query_scalar("PRAGMA journal_mode").fetch_one(self).await?;
+
+        mode.parse()
+    }
+
+    /// Changes the journal mode of the main database and returns the new mode.
+    ///
+    /// The mode is left unchanged if it cannot be changed, e.g. an in-memory database always
+    /// uses the `MEMORY` mode, so the returned mode may differ from the requested one.
+    pub async fn set_journal_mode(
+        &mut self,
+        mode: SqliteJournalMode,
+    ) -> Result<SqliteJournalMode, Error> {
+        let sql = format!("PRAGMA journal_mode = {}", mode.as_str());
+        let mode: String = query_scalar(&sql).fetch_one(self).await?;
+
+        mode.parse()
+    }
+
+    /// Returns the [size of a page](https://www.sqlite.org/pragma.html#pragma_page_size)
+    /// of the main database, in bytes.
+    pub async fn page_size(&mut self) -> Result<u32, Error> {
+        query_scalar("PRAGMA page_size").fetch_one(self).await
+    }
+
+    /// Sets the size of a page of the main database, in bytes: a power of two between 512
+    /// and 65536.
+    ///
+    /// This only takes effect when the database is created, or on the next `VACUUM` (except
+    /// in WAL mode).
+    pub async fn set_page_size(&mut self, size: u32) -> Result<(), Error> {
+        self.execute(&*format!("PRAGMA page_size = {}", size))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the [user version](https://www.sqlite.org/pragma.html#pragma_user_version)
+    /// of the main database.
+    ///
+    /// This integer is stored in the database header and is not used by SQLite, so applications
+    /// can use it to track the version of their schema. It is `0` for a new database.
+    pub async fn user_version(&mut self) -> Result<i32, Error> {
+        query_scalar("PRAGMA user_version").fetch_one(self).await
+    }
+
+    /// Sets the user version of the main database.
+    pub async fn set_user_version(&mut self, version: i32) -> Result<(), Error> {
+        self.execute(&*format!("PRAGMA user_version = {}", version))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Runs an [integrity check](https://www.sqlite.org/pragma.html#pragma_integrity_check)
+    /// of the main database and returns the problems found, if any.
+    ///
+    /// The check stops after 100 problems.
+    pub async fn integrity_check(&mut self) -> Result<Vec<String>, Error> {
+        let mut problems: Vec<String> = query_scalar("PRAGMA integrity_check")
+            .fetch_all(self)
+            .await?;
+
+        // a single row `ok` is returned when no problem is found
+        if problems.len() == 1 && problems[0] == "ok" {
+            problems.clear();
+        }
+
+        Ok(problems)
+    }
+}
@@ -0,0 +1,111 @@
+use futures_channel::oneshot;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+// A fair, asynchronous lock shared by the connections opened with the same
+// [SqliteConnectOptions], which is held by the connection currently writing to the database.
+
+// It is held across calls (until the connection leaves its transaction), so the guard owns
+// a reference to the lock instead of borrowing it.
+
+pub(crate) struct WriteLock {
+    state: Mutex<WriteLockState>,
+}
+
+struct WriteLockState {
+    locked: bool,
+
+    // the connections waiting for the lock, in order of arrival
+    waiters: VecDeque<oneshot::Sender<()>>,
+}
+
+pub(crate) struct WriteLockGuard {
+    lock: Arc<WriteLock>,
+}
+
+// a connection waiting for the lock; if it stops waiting after the lock was handed to it,
+// the lock is passed on to the next connection
+struct Waiting {
+    lock: Arc<WriteLock>,
+    rx: oneshot::Receiver<()>,
+}
+
+impl WriteLock {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(WriteLockState {
+                locked: false,
+                waiters: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub(crate) async fn acquire(self: &Arc<Self>) -> WriteLockGuard {
+        let rx = {
+            let mut state = self.state.lock();
+
+            if !state.locked {
+                state.locked = true;
+
+                return WriteLockGuard {
+                    lock: Arc::clone(self),
+                };
+            }
+
+            let (tx, rx) = oneshot::channel();
+            state.waiters.push_back(tx);
+
+            rx
+        };
+
+        let mut waiting = Waiting {
+            lock: Arc::clone(self),
+            rx,
+        };
+
+        // the sender is only dropped after sending, as the lock is alive
+        let _ = (&mut waiting.rx).await;
+
+        WriteLockGuard {
+            lock: Arc::clone(self),
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock();
+
+        // hand the lock over to the first connection still waiting for it
+        while let Some(waiter) = state.waiters.pop_front() {
+            if waiter.send(()).is_ok() {
+                return;
+            }
+        }
+
+        state.locked = false;
+    }
+}
+
+impl Debug for WriteLock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteLock").finish()
+    }
+}
+
+impl Drop for WriteLockGuard {
+    fn drop(&mut self) {
+        self.lock.release();
+    }
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        self.rx.close();
+
+        if let Ok(Some(())) = self.rx.try_recv() {
+            // the lock was handed over but not taken
+            self.lock.release();
+        }
+    }
+}
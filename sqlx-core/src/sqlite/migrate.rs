@@ -3,7 +3,8 @@ use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
 use crate::migrate::Migration;
-use crate::migrate::{Migrate, MigrateDatabase};
+use crate::migrate::{execute_statements, split_statements};
+use crate::migrate::{Migrate, MigrateDatabase, MigrationProgress};
 use crate::query::query;
 use crate::query_as::query_as;
 use crate::query_scalar::query_scalar;
@@ -119,35 +120,67 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn apply<'e: 'm, 'm>(
+    fn apply_with_progress<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
+        progress: &'m mut (dyn FnMut(MigrationProgress<'_>) + Send),
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
+            let statements = split_statements(&migration.sql, false);
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            let res = if migration.no_tx() {
+                // each statement is committed on its own
+                let results = self.execute_many(&*migration.sql);
 
-            tx.commit().await?;
+                execute_statements(results, migration, &statements, progress).await
+            } else {
+                let mut tx = self.begin().await?;
+
+                let results = tx.execute_many(&*migration.sql);
+
+                execute_statements(results, migration, &statements, progress).await?;
+
+                tx.commit().await?;
+
+                Ok(())
+            };
 
             let elapsed = start.elapsed();
 
+            // a migration run in a transaction is only recorded once it is applied, while
+            // a failed migration run without one leaves the database dirty
+            self.record(migration, res.is_ok(), elapsed).await?;
+
+            res?;
+
+            Ok(elapsed)
+        })
+    }
+
+    fn record<'e: 'm, 'm>(
+        &'e mut self,
+        migration: &'m Migration,
+        success: bool,
+        elapsed: Duration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
             // language=SQL
             let _ = query(
                 r#"
     INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
-    VALUES ( ?1, ?2, TRUE, ?3, ?4 )
+    VALUES ( ?1, ?2, ?3, ?4, ?5 )
                 "#,
             )
             .bind(migration.version)
             .bind(&*migration.description)
+            .bind(success)
             .bind(&*migration.checksum)
             .bind(elapsed.as_nanos() as i64)
             .execute(self)
             .await?;
 
-            Ok(elapsed)
+            Ok(())
         })
     }
 
@@ -156,12 +189,17 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
             let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+            if migration.no_tx() {
+                let _ = self.execute(&*migration.sql).await?;
+            } else {
+                let mut tx = self.begin().await?;
 
-            tx.commit().await?;
+                let _ = tx.execute(&*migration.sql).await?;
+
+                tx.commit().await?;
+            }
 
             let elapsed = start.elapsed();
 
@@ -31,7 +31,7 @@ pub use options::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous};
 pub use query_result::SqliteQueryResult;
 pub use row::SqliteRow;
 pub use statement::SqliteStatement;
-pub use transaction::SqliteTransactionManager;
+pub use transaction::{SqliteTransactionBehavior, SqliteTransactionManager};
 pub use type_info::SqliteTypeInfo;
 pub use value::{SqliteValue, SqliteValueRef};
 
@@ -6,8 +6,9 @@ mod parse;
 mod synchronous;
 
 use crate::connection::LogSettings;
+use crate::sqlite::connection::WriteLock;
 pub use journal_mode::SqliteJournalMode;
-use std::{borrow::Cow, time::Duration};
+use std::{borrow::Cow, sync::Arc, time::Duration};
 pub use synchronous::SqliteSynchronous;
 
 /// Options and flags which can be used to configure a SQLite connection.
@@ -56,6 +57,8 @@ pub struct SqliteConnectOptions {
     pub(crate) busy_timeout: Duration,
     pub(crate) log_settings: LogSettings,
     pub(crate) synchronous: SqliteSynchronous,
+    pub(crate) write_lock: Option<Arc<WriteLock>>,
+    pub(crate) strict_column_names: bool,
 }
 
 impl Default for SqliteConnectOptions {
@@ -78,6 +81,8 @@ impl SqliteConnectOptions {
             busy_timeout: Duration::from_secs(5),
             log_settings: Default::default(),
             synchronous: SqliteSynchronous::Full,
+            write_lock: None,
+            strict_column_names: false,
         }
     }
 
@@ -140,6 +145,19 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets whether accessing a column of a row by name fails with
+    /// [`ColumnAmbiguous`](crate::error::Error::ColumnAmbiguous) if more than one column has
+    /// that name, e.g. the `id` columns of `SELECT a.*, b.* FROM a JOIN b ...`.
+    ///
+    /// Otherwise, the first column of that name is used. The columns of the same name can be
+    /// told apart by the name of their table, with a `(table, name)` index.
+    ///
+    /// By default, this is disabled.
+    pub fn strict_column_names(mut self, strict: bool) -> Self {
+        self.strict_column_names = strict;
+        self
+    }
+
     /// Sets the [synchronous](https://www.sqlite.org/pragma.html#pragma_synchronous) setting for the database connection.
     ///
     /// The default synchronous settings is FULL. However, if durability is not a concern,
@@ -148,4 +166,32 @@ impl SqliteConnectOptions {
         self.synchronous = synchronous;
         self
     }
+
+    /// Serializes the writes of the connections opened with these options (and their clones,
+    /// e.g. in a pool), so that the database has a single writer at a time and many readers.
+    ///
+    /// SQLite only allows one writer at a time and the others wait for the busy timeout,
+    /// polling the database, and fail with `SQLITE_BUSY` when it expires. With this option,
+    /// a connection about to write to the database instead waits in a queue until the previous
+    /// writer commits, then holds its turn until it leaves its transaction. Reads are not queued
+    /// and, in WAL mode, proceed in parallel with the writer.
+    ///
+    /// A transaction joins the queue on its first write, so it is best started with a write:
+    /// in WAL mode, a deferred transaction which reads, then writes after another connection
+    /// committed, fails with `SQLITE_BUSY` as its snapshot is stale. A transaction begun with
+    /// [`SqliteTransactionBehavior::Immediate`](crate::sqlite::SqliteTransactionBehavior) joins
+    /// the queue when it begins. Other statements which lock the database without writing,
+    /// e.g. a raw `BEGIN IMMEDIATE`, are not queued and may make the writer in the queue wait
+    /// for the busy timeout.
+    ///
+    /// By default, writes are not serialized.
+    pub fn serialized_writes(mut self, serialized: bool) -> Self {
+        self.write_lock = if serialized {
+            Some(Arc::new(WriteLock::new()))
+        } else {
+            None
+        };
+
+        self
+    }
 }
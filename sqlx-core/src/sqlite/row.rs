@@ -7,7 +7,7 @@ use std::sync::{Arc, Weak};
 
 use crate::HashMap;
 
-use crate::column::ColumnIndex;
+use crate::column::{index_by_name, index_by_table_and_name, ColumnIndex};
 use crate::error::Error;
 use crate::ext::ustr::UStr;
 use crate::row::Row;
@@ -30,6 +30,7 @@ pub struct SqliteRow {
 
     pub(crate) columns: Arc<Vec<SqliteColumn>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
+    pub(crate) strict_column_names: bool,
 }
 
 impl crate::row::private_row::Sealed for SqliteRow {}
@@ -51,6 +52,7 @@ impl SqliteRow {
         statement: StatementHandle,
         columns: &Arc<Vec<SqliteColumn>>,
         column_names: &Arc<HashMap<UStr, usize>>,
+        strict_column_names: bool,
     ) -> (Self, Weak<AtomicPtr<SqliteValue>>) {
         let values = Arc::new(AtomicPtr::new(null_mut()));
         let weak_values = Arc::downgrade(&values);
@@ -62,6 +64,7 @@ impl SqliteRow {
             num_values: size,
             columns: Arc::clone(columns),
             column_names: Arc::clone(column_names),
+            strict_column_names,
         };
 
         (row, weak_values)
@@ -149,10 +152,23 @@ impl Drop for SqliteRow {
 
 impl ColumnIndex<SqliteRow> for &'_ str {
     fn index(&self, row: &SqliteRow) -> Result<usize, Error> {
-        row.column_names
-            .get(*self)
-            .ok_or_else(|| Error::ColumnNotFound((*self).into()))
-            .map(|v| *v)
+        index_by_name(
+            &row.columns,
+            &row.column_names,
+            self,
+            row.strict_column_names,
+        )
+    }
+}
+
+impl ColumnIndex<SqliteRow> for (&'_ str, &'_ str) {
+    fn index(&self, row: &SqliteRow) -> Result<usize, Error> {
+        index_by_table_and_name(
+            &row.columns,
+            SqliteColumn::table_name,
+            *self,
+            row.strict_column_names,
+        )
     }
 }
 
@@ -83,6 +83,19 @@ impl StatementHandle {
         }
     }
 
+    pub(crate) fn column_table_name(&self, index: usize) -> Option<&str> {
+        // https://sqlite.org/c3ref/column_database_name.html
+        unsafe {
+            let name = sqlite3_column_table_name(self.0.as_ptr(), index as c_int);
+
+            if name.is_null() {
+                None
+            } else {
+                Some(from_utf8_unchecked(CStr::from_ptr(name).to_bytes()))
+            }
+        }
+    }
+
     pub(crate) fn column_type_info(&self, index: usize) -> SqliteTypeInfo {
         SqliteTypeInfo(DataType::from_code(self.column_type(index)))
     }
@@ -14,7 +14,7 @@ mod worker;
 
 pub(crate) use handle::StatementHandle;
 pub(crate) use r#virtual::VirtualStatement;
-pub(crate) use worker::StatementWorker;
+pub(crate) use worker::{catch_callback_panic, StatementWorker};
 
 #[derive(Debug, Clone)]
 #[allow(clippy::rc_buffer)]
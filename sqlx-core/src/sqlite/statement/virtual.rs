@@ -154,9 +154,11 @@ impl VirtualStatement {
                         ordinal: i,
                         name: name.clone(),
                         type_info,
+                        table: statement.column_table_name(i).map(UStr::new),
                     });
 
-                    column_names.insert(name, i);
+                    // the first of the columns with the same name is found by name
+                    column_names.entry(name).or_insert(i);
                 }
 
                 self.handles.push(statement);
@@ -3,7 +3,10 @@ use crate::sqlite::statement::StatementHandle;
 use crossbeam_channel::{unbounded, Sender};
 use either::Either;
 use futures_channel::oneshot;
-use libsqlite3_sys::{sqlite3_step, SQLITE_DONE, SQLITE_ROW};
+use libsqlite3_sys::{sqlite3_reset, sqlite3_step, SQLITE_DONE, SQLITE_ROW};
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::thread;
 
 // Each SQLite connection has a dedicated thread.
@@ -12,6 +15,12 @@ use std::thread;
 //       OS resource usage. Low priority because a high concurrent load for SQLite3 is very
 //       unlikely.
 
+thread_local! {
+    // a panic caught inside of a callback invoked by SQLite during [sqlite3_step]; a panic
+    // must not unwind through SQLite so it is stashed here and reported once the step returns
+    static CALLBACK_PANIC: RefCell<Option<String>> = RefCell::new(None);
+}
+
 pub(crate) struct StatementWorker {
     tx: Sender<StatementWorkerCommand>,
 }
@@ -31,13 +40,31 @@ impl StatementWorker {
             for cmd in rx {
                 match cmd {
                     StatementWorkerCommand::Step { statement, tx } => {
-                        let status = unsafe { sqlite3_step(statement.0.as_ptr()) };
+                        CALLBACK_PANIC.with(|p| p.borrow_mut().take());
+
+                        let resp = catch_unwind(AssertUnwindSafe(|| step(&statement)))
+                            .map_err(|payload| panic_message(&*payload))
+                            .and_then(|resp| {
+                                match CALLBACK_PANIC.with(|p| p.borrow_mut().take()) {
+                                    Some(message) => Err(message),
+                                    None => Ok(resp),
+                                }
+                            });
+
+                        let resp = resp.unwrap_or_else(|message| {
+                            // abort the current execution of the statement so it does not
+                            // continue from an inconsistent state; other statements and the
+                            // connection itself are unaffected
+                            unsafe { sqlite3_reset(statement.0.as_ptr()) };
+
+                            log::error!(
+                                "panicked while executing statement: {}; the statement \
+                                 was aborted but the connection can still be used",
+                                message
+                            );
 
-                        let resp = match status {
-                            SQLITE_ROW => Ok(Either::Right(())),
-                            SQLITE_DONE => Ok(Either::Left(statement.changes())),
-                            _ => Err(statement.last_error().into()),
-                        };
+                            Err(Error::WorkerCrashed)
+                        });
 
                         let _ = tx.send(resp);
                     }
@@ -56,8 +83,54 @@ impl StatementWorker {
 
         self.tx
             .send(StatementWorkerCommand::Step { statement, tx })
-            .map_err(|_| Error::WorkerCrashed)?;
+            .map_err(|_| worker_exited())?;
+
+        rx.await.map_err(|_| worker_exited())?
+    }
+}
+
+fn step(statement: &StatementHandle) -> Result<Either<u64, ()>, Error> {
+    let status = unsafe { sqlite3_step(statement.0.as_ptr()) };
+
+    match status {
+        SQLITE_ROW => Ok(Either::Right(())),
+        SQLITE_DONE => Ok(Either::Left(statement.changes())),
+        _ => Err(statement.last_error().into()),
+    }
+}
+
+fn worker_exited() -> Error {
+    log::error!(
+        "the worker thread has exited; this connection can no longer be used and should be closed"
+    );
+
+    Error::WorkerCrashed
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_owned()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Runs a user callback invoked by SQLite, catching any panic so it does not unwind through
+/// SQLite; the panic is reported as [`Error::WorkerCrashed`] once the statement step returns.
+pub(crate) fn catch_callback_panic<R>(default: R, f: impl FnOnce() -> R) -> R {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+
+        Err(payload) => {
+            CALLBACK_PANIC.with(|p| {
+                // keep the first panic, subsequent ones are likely a consequence of it
+                p.borrow_mut()
+                    .get_or_insert_with(|| panic_message(&*payload));
+            });
 
-        rx.await.map_err(|_| Error::WorkerCrashed)?
+            default
+        }
     }
 }
@@ -1,3 +1,4 @@
+use std::fmt::{self, Display, Formatter};
 use std::ptr;
 
 use futures_core::future::BoxFuture;
@@ -5,15 +6,111 @@ use libsqlite3_sys::{sqlite3_exec, SQLITE_OK};
 
 use crate::error::Error;
 use crate::executor::Executor;
-use crate::sqlite::{Sqlite, SqliteConnection, SqliteError};
+use crate::pool::MaybePoolConnection;
+use crate::sqlite::{Sqlite, SqliteConnection, SqliteError, SqlitePool};
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
-    TransactionManager,
+    savepoint_ansi_sql, SavepointCommand, Transaction, TransactionManager,
 };
 
+/// The locks taken by a transaction when it begins, selected with
+/// [`SqliteConnection::begin_with`].
+///
+/// A savepoint, i.e. a nested transaction, does not change the locks of the transaction
+/// it is established within.
+///
+/// See <https://www.sqlite.org/lang_transaction.html>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteTransactionBehavior {
+    /// No lock is taken until the database is first read or written.
+    ///
+    /// This is the default behavior of [`begin`](crate::connection::Connection::begin).
+    Deferred,
+
+    /// A write to the database begins immediately, without waiting for the first write
+    /// statement.
+    ///
+    /// This avoids the `SQLITE_BUSY` errors of transactions which read, then write while
+    /// another connection is writing, as the transaction cannot be upgraded from a read to
+    /// a write.
+    Immediate,
+
+    /// Like `Immediate`, but in other journaling modes than WAL, other connections also cannot
+    /// read from the database while the transaction is in progress.
+    Exclusive,
+}
+
+impl Default for SqliteTransactionBehavior {
+    fn default() -> Self {
+        SqliteTransactionBehavior::Deferred
+    }
+}
+
+impl Display for SqliteTransactionBehavior {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SqliteTransactionBehavior::Deferred => "DEFERRED",
+            SqliteTransactionBehavior::Immediate => "IMMEDIATE",
+            SqliteTransactionBehavior::Exclusive => "EXCLUSIVE",
+        })
+    }
+}
+
+impl SqlitePool {
+    /// Retrieves a new connection and immediately begins a new transaction with the given
+    /// behavior.
+    ///
+    /// See [`SqliteConnection::begin_with`].
+    pub async fn begin_with(
+        &self,
+        behavior: SqliteTransactionBehavior,
+    ) -> Result<Transaction<'static, Sqlite>, Error> {
+        let mut conn = self.acquire().await?;
+
+        SqliteTransactionManager::begin_with(&mut conn, behavior).await?;
+
+        Ok(Transaction::begun(MaybePoolConnection::PoolConnection(
+            conn,
+        )))
+    }
+}
+
 /// Implementation of [`TransactionManager`] for SQLite.
 pub struct SqliteTransactionManager;
 
+impl SqliteTransactionManager {
+    pub(crate) async fn begin_with(
+        conn: &mut SqliteConnection,
+        behavior: SqliteTransactionBehavior,
+    ) -> Result<(), Error> {
+        let depth = conn.transaction_depth;
+
+        if depth > 0 {
+            return Self::begin(conn).await;
+        }
+
+        if behavior != SqliteTransactionBehavior::Deferred && conn.write_guard.is_none() {
+            // the transaction writes from its start, so it takes the turn to write, if writes
+            // are serialized, before it locks the database
+            if let Some(lock) = &conn.write_lock {
+                conn.write_guard = Some(lock.acquire().await);
+            }
+        }
+
+        if let Err(error) = conn.execute(&*format!("BEGIN {}", behavior)).await {
+            if conn.handle.in_autocommit() {
+                conn.write_guard = None;
+            }
+
+            return Err(error);
+        }
+
+        conn.transaction_depth = depth + 1;
+
+        Ok(())
+    }
+}
+
 impl TransactionManager for SqliteTransactionManager {
     type Database = Sqlite;
 
@@ -58,31 +155,59 @@ impl TransactionManager for SqliteTransactionManager {
         let depth = conn.transaction_depth;
 
         if depth > 0 {
-            let query = rollback_ansi_transaction_sql(depth);
-            let mut z_query = String::with_capacity(query.len() + 1);
-            z_query.push_str(&query);
-            z_query.push('\0');
-
-            unsafe {
-                // NOTE: this is a direct execution as a ROLLBACK is unlikely to block
-                //       for any amount of time
-                let status = sqlite3_exec(
-                    conn.handle.as_ptr(),
-                    z_query.as_ptr() as _,
-                    None,
-                    ptr::null_mut(),
-                    ptr::null_mut(),
-                );
-
-                if status != SQLITE_OK {
-                    panic!(
-                        "error occurred while dropping a transaction: {}",
-                        SqliteError::new(conn.handle.as_ptr())
-                    );
-                }
-            }
+            execute_rollback(conn, &rollback_ansi_transaction_sql(depth));
 
             conn.transaction_depth = depth - 1;
+
+            // give back the turn to write, if any, as the connection may now sit in a pool
+            if conn.handle.in_autocommit() {
+                conn.write_guard = None;
+            }
+        }
+    }
+
+    fn execute_savepoint<'c>(
+        conn: &'c mut SqliteConnection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        Box::pin(async move {
+            conn.execute(&*savepoint_ansi_sql(command, name, '"'))
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn start_rollback_to_savepoint(conn: &mut SqliteConnection, name: &str) {
+        execute_rollback(
+            conn,
+            &savepoint_ansi_sql(SavepointCommand::RollbackTo, name, '"'),
+        );
+    }
+}
+
+fn execute_rollback(conn: &mut SqliteConnection, query: &str) {
+    let mut z_query = String::with_capacity(query.len() + 1);
+    z_query.push_str(query);
+    z_query.push('\0');
+
+    unsafe {
+        // NOTE: this is a direct execution as a ROLLBACK is unlikely to block
+        //       for any amount of time
+        let status = sqlite3_exec(
+            conn.handle.as_ptr(),
+            z_query.as_ptr() as _,
+            None,
+            ptr::null_mut(),
+            ptr::null_mut(),
+        );
+
+        if status != SQLITE_OK {
+            panic!(
+                "error occurred while dropping a transaction: {}",
+                SqliteError::new(conn.handle.as_ptr())
+            );
         }
     }
 }
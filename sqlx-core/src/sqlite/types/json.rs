@@ -37,8 +37,6 @@ where
     fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
         let string_value = <&str as Decode<Sqlite>>::decode(value)?;
 
-        serde_json::from_str(&string_value)
-            .map(Json)
-            .map_err(Into::into)
+        Json::decode_from_slice(string_value.as_bytes())
     }
 }
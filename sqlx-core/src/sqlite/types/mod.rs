@@ -15,7 +15,7 @@
 //! | `u64`                                 | BIGINT, INT8                                         |
 //! | `f32`                                 | REAL                                                 |
 //! | `f64`                                 | REAL                                                 |
-//! | `&str`, [`String`]                    | TEXT                                                 |
+//! | `&str`, [`String`], `Cow<str>`        | TEXT                                                 |
 //! | `&[u8]`, `Vec<u8>`                    | BLOB                                                 |
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
@@ -1,12 +1,16 @@
 use std::borrow::Cow;
+use std::cmp;
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Deref, DerefMut};
 
 use futures_core::future::BoxFuture;
 
+use crate::arguments::IntoArguments;
 use crate::database::Database;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::pool::MaybePoolConnection;
+use crate::query::query_with;
 
 /// Generic management of database transactions.
 ///
@@ -32,6 +36,48 @@ pub trait TransactionManager {
 
     /// Starts to abort the active transaction or restore from the most recent snapshot.
     fn start_rollback(conn: &mut <Self::Database as Database>::Connection);
+
+    /// Establishes, releases or restores from a named savepoint of the active transaction.
+    ///
+    /// Drivers which do not override this method do not support named savepoints: the
+    /// statement is not executed and an error is returned instead.
+    fn execute_savepoint<'c>(
+        _conn: &'c mut <Self::Database as Database>::Connection,
+        command: SavepointCommand,
+        name: &'c str,
+    ) -> BoxFuture<'c, Result<(), Error>> {
+        let sql = savepoint_ansi_sql(command, name, '"');
+
+        Box::pin(async move {
+            Err(Error::Configuration(
+                format!("named savepoints are not supported by this driver: {}", sql).into(),
+            ))
+        })
+    }
+
+    /// Starts to restore from a named savepoint of the active transaction.
+    ///
+    /// Without an override of [`execute_savepoint`](Self::execute_savepoint), no named savepoint
+    /// can be established, so there is nothing to restore from.
+    fn start_rollback_to_savepoint(
+        _conn: &mut <Self::Database as Database>::Connection,
+        _name: &str,
+    ) {
+    }
+}
+
+/// A statement on a named savepoint, executed by [`TransactionManager::execute_savepoint`].
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SavepointCommand {
+    /// `SAVEPOINT`
+    Establish,
+
+    /// `RELEASE SAVEPOINT`
+    Release,
+
+    /// `ROLLBACK TO SAVEPOINT`
+    RollbackTo,
 }
 
 /// An in-progress database transaction or savepoint.
@@ -46,6 +92,9 @@ pub trait TransactionManager {
 /// executed after it was established to be rolled back, restoring the transaction state to
 /// what it was at the time of the savepoint.
 ///
+/// Savepoints are established by calling [`Connection::begin`] on a transaction, or by name with
+/// [`savepoint`](Self::savepoint).
+///
 /// [`Connection::begin`]: crate::connection::Connection::begin()
 /// [`Pool::begin`]: crate::pool::Pool::begin()
 /// [`commit`]: Self::commit()
@@ -55,7 +104,10 @@ where
     DB: Database,
 {
     connection: MaybePoolConnection<'c, DB>,
-    open: bool,
+    pub(crate) open: bool,
+
+    // the name of the savepoint, if established with `savepoint`
+    pub(crate) savepoint: Option<String>,
 }
 
 impl<'c, DB> Transaction<'c, DB>
@@ -70,16 +122,35 @@ where
         Box::pin(async move {
             DB::TransactionManager::begin(&mut conn).await?;
 
-            Ok(Self {
-                connection: conn,
-                open: true,
-            })
+            Ok(Self::begun(conn))
         })
     }
 
+    // wraps a connection on which a transaction or savepoint was just begun, when it was begun
+    // otherwise than with `TransactionManager::begin`
+    pub(crate) fn begun(conn: impl Into<MaybePoolConnection<'c, DB>>) -> Self {
+        Self {
+            connection: conn.into(),
+            open: true,
+            savepoint: None,
+        }
+    }
+
     /// Commits this transaction or savepoint.
     pub async fn commit(mut self) -> Result<(), Error> {
-        DB::TransactionManager::commit(&mut self.connection).await?;
+        match &self.savepoint {
+            Some(name) => {
+                DB::TransactionManager::execute_savepoint(
+                    &mut self.connection,
+                    SavepointCommand::Release,
+                    name,
+                )
+                .await?
+            }
+
+            None => DB::TransactionManager::commit(&mut self.connection).await?,
+        }
+
         self.open = false;
 
         Ok(())
@@ -87,11 +158,159 @@ where
 
     /// Aborts this transaction or savepoint.
     pub async fn rollback(mut self) -> Result<(), Error> {
-        DB::TransactionManager::rollback(&mut self.connection).await?;
+        match &self.savepoint {
+            Some(name) => {
+                DB::TransactionManager::execute_savepoint(
+                    &mut self.connection,
+                    SavepointCommand::RollbackTo,
+                    name,
+                )
+                .await?
+            }
+
+            None => DB::TransactionManager::rollback(&mut self.connection).await?,
+        }
+
         self.open = false;
 
         Ok(())
     }
+
+    /// Establishes a savepoint with the given name, returned as a nested transaction.
+    ///
+    /// Committing the savepoint releases it, and keeps its changes in this transaction. Rolling
+    /// it back, or dropping it, restores this transaction to the state it had when the savepoint
+    /// was established.
+    ///
+    /// A savepoint may be established with the name of another savepoint, which it hides until
+    /// it is released. Savepoints established by [`Connection::begin`] are named
+    /// `_sqlx_savepoint_<depth>`.
+    ///
+    /// ```rust,ignore
+    /// let mut tx = conn.begin().await?;
+    ///
+    /// for item in items {
+    ///     let mut savepoint = tx.savepoint("item").await?;
+    ///
+    ///     match insert(&mut savepoint, item).await {
+    ///         Ok(_) => savepoint.commit().await?,
+    ///         // the other items are still inserted
+    ///         Err(_) => savepoint.rollback().await?,
+    ///     }
+    /// }
+    ///
+    /// tx.commit().await?;
+    /// ```
+    ///
+    /// [`Connection::begin`]: crate::connection::Connection::begin()
+    pub async fn savepoint(&mut self, name: &str) -> Result<Transaction<'_, DB>, Error> {
+        DB::TransactionManager::execute_savepoint(
+            &mut self.connection,
+            SavepointCommand::Establish,
+            name,
+        )
+        .await?;
+
+        Ok(Transaction {
+            connection: MaybePoolConnection::Connection(&mut *self.connection),
+            open: true,
+            savepoint: Some(name.to_owned()),
+        })
+    }
+
+    /// Restores this transaction to the state it had when the named savepoint was established.
+    ///
+    /// The savepoint, established within this transaction, stays established, e.g. to try
+    /// again the statements which followed it.
+    pub async fn rollback_to(&mut self, name: &str) -> Result<(), Error> {
+        DB::TransactionManager::execute_savepoint(
+            &mut self.connection,
+            SavepointCommand::RollbackTo,
+            name,
+        )
+        .await
+    }
+
+    /// Releases the named savepoint, established within this transaction, and keeps its
+    /// changes.
+    pub async fn release(&mut self, name: &str) -> Result<(), Error> {
+        DB::TransactionManager::execute_savepoint(
+            &mut self.connection,
+            SavepointCommand::Release,
+            name,
+        )
+        .await
+    }
+
+    /// Executes a bulk statement for all of the `rows`, split into as many statements as
+    /// needed to stay within the limits of the database, in this transaction.
+    ///
+    /// `build` returns the SQL and the arguments of the statement for a chunk of the rows. The
+    /// statement is first built for all of the rows; when it exceeds a limit (see
+    /// [`Error::LimitExceeded`]), it is built again for as many rows as fit, assuming that every
+    /// row takes the same share of the limit. As the limits are checked before anything is
+    /// sent, this leaves the transaction usable.
+    ///
+    /// Returns the combined result of the statements. A single row which exceeds a limit, or
+    /// any other error, fails the whole operation; the transaction should then be rolled back.
+    ///
+    /// ```rust,ignore
+    /// let mut tx = pool.begin().await?;
+    ///
+    /// tx.execute_chunked(&users, |users| {
+    ///     let mut sql = String::from("INSERT INTO users (id, name) VALUES ");
+    ///     let mut arguments = PgArguments::default();
+    ///
+    ///     for (i, user) in users.iter().enumerate() {
+    ///         if i > 0 {
+    ///             sql.push_str(", ");
+    ///         }
+    ///
+    ///         sql.push_str(&format!("(${}, ${})", 2 * i + 1, 2 * i + 2));
+    ///         arguments.add(user.id);
+    ///         arguments.add(&user.name);
+    ///     }
+    ///
+    ///     (sql, arguments)
+    /// })
+    /// .await?;
+    ///
+    /// tx.commit().await?;
+    /// ```
+    pub async fn execute_chunked<'a, T, A, F>(
+        &mut self,
+        rows: &'a [T],
+        mut build: F,
+    ) -> Result<DB::QueryResult, Error>
+    where
+        F: FnMut(&'a [T]) -> (String, A),
+        A: for<'q> IntoArguments<'q, DB> + Send,
+        for<'e> &'e mut DB::Connection: Executor<'e, Database = DB>,
+    {
+        let mut result = DB::QueryResult::default();
+        let mut rows = rows;
+        let mut len = rows.len();
+
+        while !rows.is_empty() {
+            let chunk = &rows[..cmp::min(len, rows.len())];
+            let (sql, arguments) = build(chunk);
+
+            match query_with(&sql, arguments).execute(&mut **self).await {
+                Ok(done) => {
+                    result.extend(Some(done));
+                    rows = &rows[chunk.len()..];
+                }
+
+                Err(Error::LimitExceeded { max, actual, .. }) if chunk.len() > 1 => {
+                    len = cmp::min(cmp::max(chunk.len() * max / actual, 1), chunk.len() - 1);
+                }
+
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 // NOTE: required due to lack of lazy normalization
@@ -209,7 +428,13 @@ where
             // operation that will happen on the next asynchronous invocation of the underlying
             // connection (including if the connection is returned to a pool)
 
-            DB::TransactionManager::start_rollback(&mut self.connection);
+            match &self.savepoint {
+                Some(name) => {
+                    DB::TransactionManager::start_rollback_to_savepoint(&mut self.connection, name)
+                }
+
+                None => DB::TransactionManager::start_rollback(&mut self.connection),
+            }
         }
     }
 }
@@ -243,3 +468,15 @@ pub(crate) fn rollback_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
         ))
     }
 }
+
+pub(crate) fn savepoint_ansi_sql(command: SavepointCommand, name: &str, quote: char) -> String {
+    let command = match command {
+        SavepointCommand::Establish => "SAVEPOINT",
+        SavepointCommand::Release => "RELEASE SAVEPOINT",
+        SavepointCommand::RollbackTo => "ROLLBACK TO SAVEPOINT",
+    };
+
+    let escaped = name.replace(quote, &quote.to_string().repeat(2));
+
+    format!("{} {}{}{}", command, quote, escaped, quote)
+}
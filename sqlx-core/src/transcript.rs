@@ -0,0 +1,402 @@
+//! Recorded exchanges with a database server, replayed to test a driver without a server.
+//!
+//! A [`Transcript`] is attached to the connect options of a network-based driver
+//! (e.g., [`PgConnectOptions::transcript`]). A transcript built with [`Transcript::new`] is
+//! *replayed*: no socket is opened, the bytes the driver sends are compared with the bytes
+//! the transcript expects, and the driver receives the bytes recorded from the server. This
+//! makes regression tests of the protocol (authentication flows, recovery from errors)
+//! deterministic.
+//!
+//! A transcript built with [`Transcript::recorder`] instead *records* the exchange of a real
+//! connection, to be replayed later, possibly after editing it.
+//!
+//! ```rust,ignore
+//! let recorder = Transcript::recorder();
+//!
+//! let mut conn = PgConnection::connect_with(&options.clone().transcript(&recorder)).await?;
+//! conn.execute("SELECT 1").await?;
+//! drop(conn);
+//!
+//! // the same exchange, without the server
+//! let transcript = Transcript::from_steps(recorder.steps());
+//!
+//! let mut conn = PgConnection::connect_with(&options.transcript(&transcript)).await?;
+//! conn.execute("SELECT 1").await?;
+//!
+//! transcript.assert_finished();
+//! ```
+//!
+//! When the driver sends something else than what the transcript expects, or tries to read
+//! while it is expected to send, the connection fails with [`InvalidData`] and the transcript
+//! keeps the mismatch for [`Transcript::assert_finished`]. Once all the steps are replayed,
+//! the server appears to have closed the connection.
+//!
+//! This module is only available with the `transcripts` feature and is not intended for
+//! production use.
+//!
+//! [`PgConnectOptions::transcript`]: crate::postgres::PgConnectOptions::transcript
+//! [`InvalidData`]: io::ErrorKind::InvalidData
+
+use std::cmp;
+use std::fmt::{self, Debug, Formatter};
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+use futures_core::ready;
+use sqlx_rt::{AsyncRead, AsyncWrite};
+
+use crate::net::{PollReadBuf, PollReadOut, Socket};
+
+/// A step of a [`Transcript`], from the point of view of the driver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranscriptStep {
+    /// Bytes sent by the driver to the server.
+    Send(Vec<u8>),
+
+    /// Bytes received by the driver from the server.
+    Receive(Vec<u8>),
+}
+
+/// A handle to a transcript, replayed or recorded by the connections opened with it.
+///
+/// The handle is cheap to clone; all clones share the same steps.
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    steps: Vec<TranscriptStep>,
+    recording: bool,
+
+    // the step being replayed, and the number of its bytes already exchanged
+    step: usize,
+    offset: usize,
+
+    // the first difference between the exchange and the transcript
+    mismatch: Option<String>,
+}
+
+impl Transcript {
+    /// Creates an empty transcript to be replayed, with steps added by [`send`](Self::send)
+    /// and [`receive`](Self::receive).
+    pub fn new() -> Self {
+        Self::from_steps(Vec::new())
+    }
+
+    /// Creates a transcript of the given steps, to be replayed.
+    pub fn from_steps(steps: Vec<TranscriptStep>) -> Self {
+        Self::with_state(State {
+            steps,
+            ..State::default()
+        })
+    }
+
+    /// Creates a transcript which records the exchange of the connections opened with it.
+    pub fn recorder() -> Self {
+        Self::with_state(State {
+            recording: true,
+            ..State::default()
+        })
+    }
+
+    fn with_state(state: State) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Expects the driver to send these bytes.
+    pub fn send(self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.lock().steps.push(TranscriptStep::Send(bytes.into()));
+        self
+    }
+
+    /// Has the server send these bytes to the driver.
+    pub fn receive(self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.lock()
+            .steps
+            .push(TranscriptStep::Receive(bytes.into()));
+        self
+    }
+
+    /// Returns the steps of the transcript, e.g. those recorded.
+    pub fn steps(&self) -> Vec<TranscriptStep> {
+        self.lock().steps.clone()
+    }
+
+    /// Panics if the exchange differed from the transcript or if some of its steps were not
+    /// replayed.
+    pub fn assert_finished(&self) {
+        let state = self.lock();
+
+        if let Some(mismatch) = &state.mismatch {
+            panic!("the exchange differs from the transcript: {}", mismatch);
+        }
+
+        if !state.recording && state.step < state.steps.len() {
+            panic!(
+                "the transcript was not finished; the next step is {:?}",
+                state.steps[state.step]
+            );
+        }
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.lock().recording
+    }
+
+    /// Returns a socket replaying the transcript.
+    pub(crate) fn replay(&self) -> Socket {
+        Socket::Transcript(Box::new(TranscriptSocket {
+            inner: None,
+            transcript: self.clone(),
+        }))
+    }
+
+    /// Returns a socket recording the exchange through `socket` in the transcript.
+    pub(crate) fn record(&self, socket: Socket) -> Socket {
+        Socket::Transcript(Box::new(TranscriptSocket {
+            inner: Some(socket),
+            transcript: self.clone(),
+        }))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // a panic while holding the lock can not leave the state in an inconsistent state
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl State {
+    fn fail(&mut self, mismatch: String) -> io::Error {
+        let mismatch = self.mismatch.get_or_insert(mismatch);
+
+        io::Error::new(io::ErrorKind::InvalidData, mismatch.clone())
+    }
+
+    fn check(&mut self) -> io::Result<()> {
+        match &self.mismatch {
+            Some(mismatch) => Err(io::Error::new(io::ErrorKind::InvalidData, mismatch.clone())),
+
+            None => Ok(()),
+        }
+    }
+
+    fn advance(&mut self, n: usize, len: usize) {
+        self.offset += n;
+
+        if self.offset == len {
+            self.step += 1;
+            self.offset = 0;
+        }
+    }
+
+    // copies the next bytes received from the server into `buf`, returning their number;
+    // `0` once the transcript is finished
+    fn replay_read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.check()?;
+
+        loop {
+            match self.steps.get(self.step) {
+                None => return Ok(0),
+
+                Some(TranscriptStep::Receive(bytes)) if bytes.is_empty() => self.advance(0, 0),
+
+                Some(TranscriptStep::Receive(bytes)) => {
+                    let len = bytes.len();
+                    let n = cmp::min(buf.len(), len - self.offset);
+
+                    buf[..n].copy_from_slice(&bytes[self.offset..self.offset + n]);
+                    self.advance(n, len);
+
+                    return Ok(n);
+                }
+
+                Some(TranscriptStep::Send(bytes)) => {
+                    let mismatch = format!(
+                        "step #{}: the driver reads while it is expected to send {:?}",
+                        self.step,
+                        &bytes[self.offset..]
+                    );
+
+                    return Err(self.fail(mismatch));
+                }
+            }
+        }
+    }
+
+    // compares the bytes sent by the driver with those expected
+    fn replay_write(&mut self, mut buf: &[u8]) -> io::Result<()> {
+        self.check()?;
+
+        while !buf.is_empty() {
+            let mismatch = match self.steps.get(self.step) {
+                Some(TranscriptStep::Send(bytes)) => {
+                    let len = bytes.len();
+                    let n = cmp::min(buf.len(), len - self.offset);
+                    let expected = &bytes[self.offset..self.offset + n];
+
+                    if expected == &buf[..n] {
+                        self.advance(n, len);
+                        buf = &buf[n..];
+
+                        continue;
+                    }
+
+                    format!(
+                        "step #{}: the driver sends {:?} instead of {:?}",
+                        self.step,
+                        &buf[..n],
+                        expected
+                    )
+                }
+
+                Some(TranscriptStep::Receive(_)) => format!(
+                    "step #{}: the driver sends {:?} while it is expected to receive",
+                    self.step, buf
+                ),
+
+                None => format!("the driver sends {:?} after the end of the transcript", buf),
+            };
+
+            return Err(self.fail(mismatch));
+        }
+
+        Ok(())
+    }
+
+    // appends the bytes to the last step if it is in the same direction
+    fn record(&mut self, step: TranscriptStep) {
+        match (self.steps.last_mut(), step) {
+            (Some(TranscriptStep::Send(last)), TranscriptStep::Send(bytes))
+            | (Some(TranscriptStep::Receive(last)), TranscriptStep::Receive(bytes)) => {
+                last.extend_from_slice(&bytes)
+            }
+
+            (_, step) => self.steps.push(step),
+        }
+    }
+}
+
+pub(crate) struct TranscriptSocket {
+    // the socket to the server when recording
+    pub(crate) inner: Option<Socket>,
+    transcript: Transcript,
+}
+
+impl Debug for TranscriptSocket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TranscriptSocket")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl AsyncRead for TranscriptSocket {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut PollReadBuf<'_>,
+    ) -> Poll<io::Result<PollReadOut>> {
+        let this = &mut *self;
+
+        let inner = match &mut this.inner {
+            Some(inner) => inner,
+
+            None => {
+                let mut state = this.transcript.lock();
+
+                #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+                {
+                    let n = state.replay_read(buf.initialize_unfilled())?;
+                    buf.advance(n);
+
+                    return Poll::Ready(Ok(()));
+                }
+
+                #[cfg(feature = "_rt-async-std")]
+                {
+                    return Poll::Ready(state.replay_read(buf));
+                }
+            }
+        };
+
+        #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+        let filled = buf.filled().len();
+
+        let out = ready!(Pin::new(inner).poll_read(cx, buf))?;
+
+        #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+        let received = &buf.filled()[filled..];
+
+        #[cfg(feature = "_rt-async-std")]
+        let received = &buf[..out];
+
+        this.transcript
+            .lock()
+            .record(TranscriptStep::Receive(received.to_vec()));
+
+        Poll::Ready(Ok(out))
+    }
+}
+
+impl AsyncWrite for TranscriptSocket {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+
+        let inner = match &mut this.inner {
+            Some(inner) => inner,
+
+            None => {
+                this.transcript.lock().replay_write(buf)?;
+
+                return Poll::Ready(Ok(buf.len()));
+            }
+        };
+
+        let n = ready!(Pin::new(inner).poll_write(cx, buf))?;
+
+        this.transcript
+            .lock()
+            .record(TranscriptStep::Send(buf[..n].to_vec()));
+
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.inner {
+            Some(inner) => Pin::new(inner).poll_flush(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    #[cfg(any(feature = "_rt-actix", feature = "_rt-tokio"))]
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.inner {
+            Some(inner) => Pin::new(inner).poll_shutdown(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+
+    #[cfg(feature = "_rt-async-std")]
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.inner {
+            Some(inner) => Pin::new(inner).poll_close(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
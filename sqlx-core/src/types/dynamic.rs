@@ -0,0 +1,105 @@
+//! Decoding of the SQL types registered at runtime, into boxed values.
+//!
+//! An application can register a decode function for a SQL type by its name, e.g. the type of
+//! an extension, so that plugins can support new types without recompiling the application.
+//! The values of the columns of that type are then decoded with
+//! [`Row::try_get_dynamic`](crate::row::Row::try_get_dynamic).
+//!
+//! ```rust,ignore
+//! use sqlx::types::dynamic::{self, DynamicValue};
+//!
+//! #[derive(sqlx::Type)]
+//! #[sqlx(type_name = "status", rename_all = "lowercase")]
+//! enum Status { New, Open, Closed }
+//!
+//! dynamic::register_type::<Postgres, Status>("status");
+//!
+//! let value: Option<DynamicValue> = row.try_get_dynamic("status")?;
+//! let status = value.unwrap().downcast::<Status>();
+//! ```
+
+use std::any::{Any, TypeId};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::database::{Database, HasValueRef};
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::type_info::TypeInfo;
+use crate::HashMap;
+
+/// A value decoded by a function registered at runtime, to be downcast to its type.
+pub type DynamicValue = Box<dyn Any + Send + Sync>;
+
+/// A function which decodes a value into a [`DynamicValue`].
+pub type DecodeDynamic<DB> =
+    for<'r> fn(<DB as HasValueRef<'r>>::ValueRef) -> Result<DynamicValue, BoxDynError>;
+
+// the decode functions, by the type of the database and the lowercase name of the SQL type; each
+// function is a `DecodeDynamic<DB>` of its database
+static REGISTRY: Lazy<RwLock<HashMap<(TypeId, String), Box<dyn Any + Send + Sync>>>> =
+    Lazy::new(Default::default);
+
+/// Registers `T` as the type of the values of the SQL type of the given name, as returned by
+/// [`TypeInfo::name`], ignoring case.
+///
+/// It replaces the function registered before for that SQL type, if any.
+pub fn register_type<DB, T>(type_name: &str)
+where
+    DB: Database,
+    T: for<'r> Decode<'r, DB> + Send + Sync + 'static,
+{
+    register_decode_fn::<DB>(type_name, decode_boxed::<DB, T>);
+}
+
+/// Registers a function which decodes the values of the SQL type of the given name, as returned
+/// by [`TypeInfo::name`], ignoring case.
+///
+/// It replaces the function registered before for that SQL type, if any.
+pub fn register_decode_fn<DB: Database>(type_name: &str, decode: DecodeDynamic<DB>) {
+    REGISTRY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key::<DB>(type_name), Box::new(decode));
+}
+
+/// Removes the function registered for the SQL type of the given name, returning `true` if
+/// there was one.
+pub fn unregister_type<DB: Database>(type_name: &str) -> bool {
+    REGISTRY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&key::<DB>(type_name))
+        .is_some()
+}
+
+// decodes the value with the function registered for its type
+pub(crate) fn decode_dynamic<'r, DB: Database>(
+    value: <DB as HasValueRef<'r>>::ValueRef,
+    ty: &DB::TypeInfo,
+) -> Result<DynamicValue, BoxDynError> {
+    let decode = REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&key::<DB>(ty.name()))
+        .and_then(|decode| decode.downcast_ref::<DecodeDynamic<DB>>())
+        .copied()
+        .ok_or_else(|| format!("no type is registered for SQL type `{}`", ty.name()))?;
+
+    decode(value)
+}
+
+fn decode_boxed<DB, T>(
+    value: <DB as HasValueRef<'_>>::ValueRef,
+) -> Result<DynamicValue, BoxDynError>
+where
+    DB: Database,
+    T: for<'r> Decode<'r, DB> + Send + Sync + 'static,
+{
+    Ok(Box::new(T::decode(value)?))
+}
+
+fn key<DB: Database>(type_name: &str) -> (TypeId, String) {
+    (TypeId::of::<DB>(), type_name.to_ascii_lowercase())
+}
@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+use crate::error::BoxDynError;
+
+// parses a float from its text format, where the special values are spelled differently by each
+// database, e.g. `Infinity` and `NaN` by Postgres, or `inf` and `nan` by MySQL
+pub(crate) fn parse_float<T>(s: &str) -> Result<T, BoxDynError>
+where
+    T: FromStr + From<f32>,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let special =
+        if unsigned.eq_ignore_ascii_case("infinity") || unsigned.eq_ignore_ascii_case("inf") {
+            f32::INFINITY
+        } else if unsigned.eq_ignore_ascii_case("nan") {
+            f32::NAN
+        } else {
+            return Ok(s.parse()?);
+        };
+
+    Ok(T::from(if negative { -special } else { special }))
+}
+
+#[test]
+fn test_parse_float() {
+    assert_eq!(parse_float::<f64>("1.5").unwrap(), 1.5);
+    assert_eq!(parse_float::<f64>("-2e-3").unwrap(), -2e-3);
+
+    assert_eq!(parse_float::<f64>("Infinity").unwrap(), f64::INFINITY);
+    assert_eq!(parse_float::<f64>("-Infinity").unwrap(), f64::NEG_INFINITY);
+    assert_eq!(parse_float::<f32>("+inf").unwrap(), f32::INFINITY);
+    assert_eq!(parse_float::<f32>("-INF").unwrap(), f32::NEG_INFINITY);
+    assert!(parse_float::<f64>("NaN").unwrap().is_nan());
+    assert!(parse_float::<f32>("nan").unwrap().is_nan());
+
+    assert!(parse_float::<f64>("infinite").is_err());
+    assert!(parse_float::<f64>("").is_err());
+}
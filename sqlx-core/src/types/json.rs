@@ -42,6 +42,24 @@ impl<T> AsMut<T> for Json<T> {
     }
 }
 
+impl<'r, T: 'r> Json<T>
+where
+    T: Deserialize<'r>,
+{
+    /// Deserializes JSON text, with `simd-json` if the `json-simd` feature is enabled.
+    pub(crate) fn decode_from_slice(buf: &'r [u8]) -> Result<Self, BoxDynError> {
+        #[cfg(feature = "json-simd")]
+        {
+            // serde_json only deserializes the types which borrow from the text
+            if let Some(value) = super::json_simd::from_slice(buf)? {
+                return Ok(Json(value));
+            }
+        }
+
+        serde_json::from_slice(buf).map(Json).map_err(Into::into)
+    }
+}
+
 impl<DB> Type<DB> for JsonValue
 where
     Json<Self>: Type<DB>,
@@ -120,8 +138,18 @@ where
     }
 }
 
-// We don't have to implement Encode for JsonRawValue because that's covered by the default
-// implementation for Encode
+// `RawValue` is unsized so the blanket implementation of Encode for references doesn't cover it;
+// the JSON text is written as-is, without being parsed again
+impl<'q, DB> Encode<'q, DB> for &'_ JsonRawValue
+where
+    for<'a> Json<&'a JsonRawValue>: Encode<'q, DB>,
+    DB: Database,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        <Json<&JsonRawValue> as Encode<'q, DB>>::encode(Json(*self), buf)
+    }
+}
+
 impl<'r, DB> Decode<'r, DB> for &'r JsonRawValue
 where
     Self: Type<DB>,
@@ -132,3 +160,38 @@ where
         <Json<Self> as Decode<DB>>::decode(value).map(|item| item.0)
     }
 }
+
+impl<DB> Type<DB> for Box<JsonRawValue>
+where
+    JsonRawValue: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        <JsonRawValue as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <JsonRawValue as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for Box<JsonRawValue>
+where
+    for<'a> &'a JsonRawValue: Encode<'q, DB>,
+    DB: Database,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        <&JsonRawValue as Encode<'q, DB>>::encode(&**self, buf)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for Box<JsonRawValue>
+where
+    Self: Type<DB>,
+    Json<Self>: Decode<'r, DB>,
+    DB: Database,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        <Json<Self> as Decode<DB>>::decode(value).map(|item| item.0)
+    }
+}
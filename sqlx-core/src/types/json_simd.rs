@@ -0,0 +1,451 @@
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use serde::Deserialize;
+
+// the name of the private newtype through which serde_json deserializes a `RawValue`
+const RAW_VALUE_TOKEN: &str = "$serde_json::private::RawValue";
+
+// simd-json parses a mutable copy of the JSON text, which does not live as long as the text, so
+// the strings it lends to `T` are passed on as transient strings. A type which needs to borrow
+// from the text (e.g. `&str` or `&RawValue`) cannot be deserialized from those: this is recorded
+// and reported as `Ok(None)`, to deserialize it from the text with serde_json instead.
+
+/// Deserializes JSON text with simd-json; returns `None` if `T` may need to borrow from the text.
+pub(crate) fn from_slice<'de, T>(buf: &[u8]) -> Result<Option<T>, simd_json::Error>
+where
+    T: Deserialize<'de>,
+{
+    let mut copy = buf.to_vec();
+    let mut deserializer = simd_json::Deserializer::from_slice(&mut copy)?;
+    let borrows = Cell::new(false);
+
+    match T::deserialize(Transient::new(&mut deserializer, &borrows)) {
+        Ok(value) => Ok(Some(value)),
+        Err(_) if borrows.get() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+// Wraps the deserializer, visitors, seeds and accesses of the copy (`'a`) to serve them to those
+// of `T` (`'de`), recording in `borrows` whether a failure may come from a type which borrows.
+struct Transient<'a, 'de, 'b, T> {
+    inner: T,
+    borrows: &'b Cell<bool>,
+    lifetimes: PhantomData<(&'a (), &'de ())>,
+}
+
+impl<'a, 'de, 'b, T> Transient<'a, 'de, 'b, T> {
+    fn new(inner: T, borrows: &'b Cell<bool>) -> Self {
+        Self {
+            inner,
+            borrows,
+            lifetimes: PhantomData,
+        }
+    }
+
+    fn wrap<U>(&self, inner: U) -> Transient<'a, 'de, 'b, U> {
+        Transient::new(inner, self.borrows)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let visitor = self.wrap(visitor);
+
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de, 'b, D> Deserializer<'de> for Transient<'a, 'de, 'b, D>
+where
+    D: Deserializer<'a>,
+{
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        // a `RawValue` is the JSON text itself, which simd-json does not keep
+        if name == RAW_VALUE_TOKEN {
+            self.borrows.set(true);
+
+            return Err(de::Error::custom("simd-json does not support `RawValue`"));
+        }
+
+        let visitor = self.wrap(visitor);
+
+        self.inner.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de, 'b, V> Visitor<'a> for Transient<'a, 'de, 'b, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i64(i64);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u64(u64);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_str(&str);
+        visit_string(String);
+        visit_bytes(&[u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'a str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // the failure may be due to `T` wanting to borrow it; serde_json decides
+        let borrows = self.borrows;
+        let result = self.inner.visit_str(v);
+
+        if result.is_err() {
+            borrows.set(true);
+        }
+
+        result
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'a [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // the failure may be due to `T` wanting to borrow it; serde_json decides
+        let borrows = self.borrows;
+        let result = self.inner.visit_bytes(v);
+
+        if result.is_err() {
+            borrows.set(true);
+        }
+
+        result
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let deserializer = self.wrap(deserializer);
+
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let deserializer = self.wrap(deserializer);
+
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'a>,
+    {
+        let seq = self.wrap(seq);
+
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'a>,
+    {
+        let map = self.wrap(map);
+
+        self.inner.visit_map(map)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'a>,
+    {
+        let data = self.wrap(data);
+
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'a, 'de, 'b, S> DeserializeSeed<'a> for Transient<'a, 'de, 'b, S>
+where
+    S: DeserializeSeed<'de>,
+{
+    type Value = S::Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'a>,
+    {
+        let deserializer = self.wrap(deserializer);
+
+        self.inner.deserialize(deserializer)
+    }
+}
+
+impl<'a, 'de, 'b, A> SeqAccess<'de> for Transient<'a, 'de, 'b, A>
+where
+    A: SeqAccess<'a>,
+{
+    type Error = A::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, 'de, 'b, A> MapAccess<'de> for Transient<'a, 'de, 'b, A>
+where
+    A: MapAccess<'a>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, 'de, 'b, A> EnumAccess<'de> for Transient<'a, 'de, 'b, A>
+where
+    A: EnumAccess<'a>,
+{
+    type Error = A::Error;
+    type Variant = Transient<'a, 'de, 'b, A::Variant>;
+
+    fn variant_seed<S>(self, seed: S) -> Result<(S::Value, Self::Variant), Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+        let borrows = self.borrows;
+
+        self.inner
+            .variant_seed(seed)
+            .map(|(value, variant)| (value, Transient::new(variant, borrows)))
+    }
+}
+
+impl<'a, 'de, 'b, A> VariantAccess<'de> for Transient<'a, 'de, 'b, A>
+where
+    A: VariantAccess<'a>,
+{
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<S>(self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let seed = self.wrap(seed);
+
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = self.wrap(visitor);
+
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let visitor = self.wrap(visitor);
+
+        self.inner.struct_variant(fields, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_slice;
+    use serde::Deserialize;
+    use serde_json::value::RawValue;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Item {
+        name: String,
+        tags: Vec<String>,
+        count: Option<u32>,
+        kind: Kind,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Kind {
+        Unit,
+        Pair(u8, u8),
+    }
+
+    #[test]
+    fn it_deserializes_owned_types() {
+        let item: Option<Item> = from_slice(
+            br#"{"name": "a\"b", "tags": ["x", "y"], "count": null, "kind": {"Pair": [1, 2]}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            item,
+            Some(Item {
+                name: "a\"b".to_owned(),
+                tags: vec!["x".to_owned(), "y".to_owned()],
+                count: None,
+                kind: Kind::Pair(1, 2),
+            })
+        );
+
+        let kind: Option<Kind> = from_slice(br#""Unit""#).unwrap();
+
+        assert_eq!(kind, Some(Kind::Unit));
+    }
+
+    #[test]
+    fn it_defers_types_borrowing_from_the_text() {
+        assert_eq!(from_slice::<&str>(br#""foo""#).unwrap(), None);
+        assert!(from_slice::<&RawValue>(br#"{"foo": 1}"#).unwrap().is_none());
+        assert!(from_slice::<Box<RawValue>>(br#"[1]"#).unwrap().is_none());
+    }
+
+    #[test]
+    fn it_reports_the_errors_of_simd_json() {
+        assert!(from_slice::<Vec<u32>>(b"[1, 2").is_err());
+        assert!(from_slice::<Vec<String>>(b"[1, 2]").is_err());
+        assert!(from_slice::<Item>(br#"{"name": "a", "tags": []}"#).is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use std::ops::{Deref, DerefMut};
+
+/// Opt-in wrapper for decoding a value into a Rust type that cannot represent it exactly.
+///
+/// The canonical example is decoding a Postgres `NUMERIC` into an `f64` for analytics
+/// queries where exactness is not needed:
+///
+/// ```rust,ignore
+/// let avg: Lossy<f64> = sqlx::query_scalar("SELECT avg(price) FROM items")
+///     .fetch_one(&mut conn)
+///     .await?;
+/// ```
+///
+/// Without the wrapper, `f64` is only compatible with `DOUBLE PRECISION` and decoding a
+/// `NUMERIC` is a type mismatch error.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct Lossy<T>(pub T);
+
+impl<T> Lossy<T> {
+    /// Unwraps the decoded value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Lossy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Lossy<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<T> for Lossy<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsMut<T> for Lossy<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
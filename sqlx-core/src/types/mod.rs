@@ -17,6 +17,23 @@
 //! To represent nullable SQL types, `Option<T>` is supported where `T` implements `Type`.
 //! An `Option<T>` represents a potentially `NULL` value from SQL.
 //!
+//! [`SentinelAsNone<T>`] also decodes the values which stand for `NULL` in legacy schemas,
+//! such as an empty string, as `None`.
+//!
+//! # Non-zero integers
+//!
+//! The [`NonZero*`](std::num) integer types are supported wherever the corresponding primitive
+//! integer is; e.g., `NonZeroI64` maps to the same SQL type as `i64`. Decoding a zero into one
+//! of them is an error.
+//!
+//! They can be passed to `query!()` where the primitive integer is expected, and decoded from
+//! its columns with a type override, e.g. `SELECT id AS "id: NonZeroI64"`.
+//!
+//! # Shared strings and bytes
+//!
+//! `Box<str>`, `Arc<str>` and `Rc<str>` can be decoded wherever `String` can, and `Arc<[u8]>`
+//! wherever `Vec<u8>` can, e.g. to share the decoded values without converting them.
+//!
 
 use crate::database::Database;
 
@@ -24,6 +41,8 @@ use crate::database::Database;
 #[cfg_attr(docsrs, doc(cfg(feature = "bstr")))]
 pub mod bstr;
 
+pub mod dynamic;
+
 #[cfg(feature = "git2")]
 #[cfg_attr(docsrs, doc(cfg(feature = "git2")))]
 pub mod git2;
@@ -32,6 +51,20 @@ pub mod git2;
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 mod json;
 
+#[cfg(feature = "json-simd")]
+mod json_simd;
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+mod float;
+mod lossy;
+mod non_zero;
+mod sensitive;
+mod sentinel;
+mod shared;
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+pub(crate) use float::parse_float;
+
 #[cfg(feature = "uuid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
 #[doc(no_inline)]
@@ -58,6 +91,15 @@ pub mod time {
     pub use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 }
 
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+pub mod jiff {
+    #[doc(no_inline)]
+    pub use jiff::civil::{Date, Time};
+    #[doc(no_inline)]
+    pub use jiff::{Span, Timestamp};
+}
+
 #[cfg(feature = "bigdecimal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
 #[doc(no_inline)]
@@ -75,9 +117,20 @@ pub mod ipnetwork {
     pub use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 }
 
+#[cfg(feature = "ipnet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ipnet")))]
+pub mod ipnet {
+    #[doc(no_inline)]
+    pub use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+}
+
 #[cfg(feature = "json")]
 pub use json::Json;
 
+pub use lossy::Lossy;
+pub use sensitive::{Sensitive, SensitiveValue};
+pub use sentinel::{Sentinel, SentinelAsNone};
+
 /// Indicates that a SQL type is supported for a database.
 ///
 /// ## Compile-time verification
@@ -140,6 +193,19 @@ pub use json::Json;
 /// enum Color { Red, Green, Blue }
 /// ```
 ///
+/// ##### Attributes
+///
+/// * `#[sqlx(other)]` on a unit variant of an enumeration matched by variant name: decode any
+///   unknown name to this variant instead of failing, e.g. so that clients keep working when
+///   values are added to the type in the database. The variant is still encoded by its name.
+///
+/// ### Arrays
+///
+/// For PostgreSQL, `#[sqlx(pg_array)]` on an enumeration matched by variant name or on a record
+/// also implements [`PgHasArrayType`](crate::postgres::types::PgHasArrayType) so that `&[T]` and
+/// `Vec<T>` can be used as well. The implicit array type (the type name prefixed by an
+/// underscore, e.g. `_color`, or `inventory._color` for `inventory.color`) is resolved by name.
+///
 /// ### Records
 ///
 /// User-defined composite types are supported through deriving a `struct`.
@@ -156,6 +222,13 @@ pub use json::Json;
 /// }
 /// ```
 ///
+/// ##### Attributes
+///
+/// * `#[sqlx(text_io)]` on struct definition: bind the record in the text format instead of
+///   binary. Required when a field is of a type without binary send/receive functions (common
+///   with extension types), which should then be given as a `String` holding its text
+///   representation.
+///
 pub trait Type<DB: Database> {
     /// Returns the canonical SQL type for this Rust type.
     ///
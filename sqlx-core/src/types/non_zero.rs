@@ -0,0 +1,69 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
+use crate::database::{Database, HasArguments, HasValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+// the `NonZero*` integers are supported wherever their primitive integer is, the only
+// difference being that decoding a zero is an error
+
+macro_rules! impl_non_zero {
+    ($($ty:ident: $primitive:ty),* $(,)?) => {$(
+        impl<DB: Database> Type<DB> for $ty
+        where
+            $primitive: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$primitive as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$primitive as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $ty
+        where
+            $primitive: Encode<'q, DB>,
+        {
+            fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+                <$primitive as Encode<'q, DB>>::encode(self.get(), buf)
+            }
+
+            fn produces(&self) -> Option<DB::TypeInfo> {
+                <$primitive as Encode<'q, DB>>::produces(&self.get())
+            }
+
+            fn size_hint(&self) -> usize {
+                <$primitive as Encode<'q, DB>>::size_hint(&self.get())
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $ty
+        where
+            $primitive: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+                let value = <$primitive as Decode<'r, DB>>::decode(value)?;
+
+                $ty::new(value)
+                    .ok_or_else(|| concat!("unexpected zero for ", stringify!($ty)).into())
+            }
+        }
+    )*};
+}
+
+impl_non_zero!(
+    NonZeroI8: i8,
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroU8: u8,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64,
+);
@@ -0,0 +1,139 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::database::{Database, HasArguments, HasValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+/// Wrapper for secrets, such as passwords or API keys, which should not end up in logs.
+///
+/// A `Sensitive<T>` is bound and decoded as a `T`, but is printed as `[REDACTED]` by both
+/// `Debug` and `Display`, so that it does not leak through the `Debug` output of a struct or
+/// an error. The value is only accessed explicitly, with [`expose`](Sensitive::expose).
+///
+/// ```rust,ignore
+/// #[derive(Debug, sqlx::FromRow)]
+/// struct Account {
+///     name: String,
+///     api_key: Sensitive<String>,
+/// }
+///
+/// let account: Account = sqlx::query_as("SELECT name, api_key FROM accounts WHERE id = $1")
+///     .bind(id)
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// // Account { name: "alice", api_key: Sensitive(..) }
+/// println!("{:?}", account);
+///
+/// client.authenticate(account.api_key.expose());
+/// ```
+///
+/// With the `zeroize` feature, the value must implement [`Zeroize`] and its memory is zeroed
+/// when it is dropped. Copies made along the way, e.g. in the buffers of the connection, are not.
+///
+/// [`Zeroize`]: https://docs.rs/zeroize/1/zeroize/trait.Zeroize.html
+pub struct Sensitive<T: SensitiveValue>(T);
+
+/// The values which can be wrapped in a [`Sensitive`]: any type, or with the `zeroize` feature,
+/// the types implementing `Zeroize`.
+#[cfg(feature = "zeroize")]
+pub trait SensitiveValue: zeroize::Zeroize {}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> SensitiveValue for T {}
+
+/// The values which can be wrapped in a [`Sensitive`]: any type, or with the `zeroize` feature,
+/// the types implementing `Zeroize`.
+#[cfg(not(feature = "zeroize"))]
+pub trait SensitiveValue {}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> SensitiveValue for T {}
+
+impl<T: SensitiveValue> Sensitive<T> {
+    pub fn new(value: T) -> Self {
+        Sensitive(value)
+    }
+
+    /// Returns the secret value.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the secret value, mutably.
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: SensitiveValue> From<T> for Sensitive<T> {
+    fn from(value: T) -> Self {
+        Sensitive(value)
+    }
+}
+
+impl<T: SensitiveValue + Clone> Clone for Sensitive<T> {
+    fn clone(&self) -> Self {
+        Sensitive(self.0.clone())
+    }
+}
+
+impl<T: SensitiveValue> Debug for Sensitive<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Sensitive(..)")
+    }
+}
+
+impl<T: SensitiveValue> Display for Sensitive<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<T: SensitiveValue> Drop for Sensitive<T> {
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        self.0.zeroize();
+    }
+}
+
+impl<T, DB: Database> Type<DB> for Sensitive<T>
+where
+    T: SensitiveValue + Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'q, T, DB: Database> Encode<'q, DB> for Sensitive<T>
+where
+    T: SensitiveValue + Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn produces(&self) -> Option<DB::TypeInfo> {
+        self.0.produces()
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<'r, T, DB: Database> Decode<'r, DB> for Sensitive<T>
+where
+    T: SensitiveValue + Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        T::decode(value).map(Sensitive)
+    }
+}
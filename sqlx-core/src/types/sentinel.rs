@@ -0,0 +1,103 @@
+use crate::database::{Database, HasValueRef};
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::types::Type;
+use crate::value::ValueRef;
+
+/// Opt-in wrapper for decoding a value which stands for "no value" in a legacy schema as `None`.
+///
+/// Columns of legacy schemas are often `NOT NULL` and use a sentinel value instead, e.g. an
+/// empty string or `-1`; decoding them into a `SentinelAsNone<T>` gives `None` for the
+/// sentinel, as well as for a `NULL`:
+///
+/// ```rust,ignore
+/// let (nickname, parent_id): (SentinelAsNone<String>, SentinelAsNone<i32>) =
+///     sqlx::query_as("SELECT nickname, parent_id FROM users WHERE id = ?")
+///         .bind(id)
+///         .fetch_one(&mut conn)
+///         .await?;
+///
+/// let nickname: Option<String> = nickname.into_inner();
+/// ```
+///
+/// The zero dates of MySQL, such as `0000-00-00`, are already decoded as a `NULL` by default;
+/// they only need an `Option<T>`. See `MySqlConnectOptions::zero_date_mode`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SentinelAsNone<T>(pub Option<T>);
+
+impl<T> SentinelAsNone<T> {
+    /// Unwraps the decoded value.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> From<SentinelAsNone<T>> for Option<T> {
+    fn from(value: SentinelAsNone<T>) -> Self {
+        value.0
+    }
+}
+
+/// A type with a value which is used as a sentinel for "no value", decoded as `None` by
+/// [`SentinelAsNone`].
+///
+/// It is implemented for the strings, with the empty string, and for the signed integers,
+/// with `-1`.
+pub trait Sentinel {
+    /// Returns `true` if `self` is the sentinel value.
+    fn is_sentinel(&self) -> bool;
+}
+
+impl Sentinel for String {
+    fn is_sentinel(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl Sentinel for &'_ str {
+    fn is_sentinel(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+macro_rules! impl_sentinel_for_int {
+    ($($ty:ty),*) => {$(
+        impl Sentinel for $ty {
+            fn is_sentinel(&self) -> bool {
+                *self == -1
+            }
+        }
+    )*};
+}
+
+impl_sentinel_for_int!(i8, i16, i32, i64);
+
+impl<DB: Database, T> Type<DB> for SentinelAsNone<T>
+where
+    T: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'r, DB: Database, T> Decode<'r, DB> for SentinelAsNone<T>
+where
+    T: Decode<'r, DB> + Sentinel,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        if value.is_null() {
+            return Ok(SentinelAsNone(None));
+        }
+
+        let value = T::decode(value)?;
+
+        Ok(SentinelAsNone(
+            Some(value).filter(|value| !value.is_sentinel()),
+        ))
+    }
+}
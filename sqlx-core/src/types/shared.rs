@@ -0,0 +1,43 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::database::{Database, HasValueRef};
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+// immutable strings and byte strings can be decoded directly into the smart pointers used to
+// share them, wherever their owned counterpart is supported
+
+macro_rules! impl_shared {
+    ($($ty:ty: $owned:ty),* $(,)?) => {$(
+        impl<DB: Database> Type<DB> for $ty
+        where
+            $owned: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <$owned as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <$owned as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $ty
+        where
+            $owned: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+                <$owned as Decode<'r, DB>>::decode(value).map(<$ty>::from)
+            }
+        }
+    )*};
+}
+
+impl_shared!(
+    Box<str>: String,
+    Arc<str>: String,
+    Rc<str>: String,
+    Arc<[u8]>: Vec<u8>,
+);
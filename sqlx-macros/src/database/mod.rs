@@ -27,6 +27,18 @@ pub trait DatabaseExt: Database {
     fn return_type_for_id(id: &Self::TypeInfo) -> Option<&'static str>;
 
     fn get_feature_gate(info: &Self::TypeInfo) -> Option<&'static str>;
+
+    /// Returns the Rust type of a column whose SQL type is conventionally used for another type,
+    /// e.g. `bool` for `TINYINT(1)` in MySQL, which takes precedence over `return_type_for_id`.
+    fn coerced_type_for_id(_info: &Self::TypeInfo) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns `true` if a column of this pseudo-type is decoded as text, with a warning as its
+    /// values could be of any type.
+    fn is_text_pseudo_type(_info: &Self::TypeInfo) -> bool {
+        false
+    }
 }
 
 macro_rules! impl_database_ext {
@@ -36,6 +48,8 @@ macro_rules! impl_database_ext {
         },
         ParamChecking::$param_checking:ident,
         feature-types: $ty_info:ident => $get_gate:expr,
+        $(coercions: $coerce_info:ident => $coerce:expr,)?
+        $(pseudo-types: $pseudo_info:ident => $pseudo:expr,)?
         row = $row:path,
         name = $db_name:literal
     ) => {
@@ -76,6 +90,18 @@ macro_rules! impl_database_ext {
             fn get_feature_gate($ty_info: &Self::TypeInfo) -> Option<&'static str> {
                 $get_gate
             }
+
+            $(
+                fn coerced_type_for_id($coerce_info: &Self::TypeInfo) -> Option<&'static str> {
+                    $coerce
+                }
+            )?
+
+            $(
+                fn is_text_pseudo_type($pseudo_info: &Self::TypeInfo) -> bool {
+                    $pseudo
+                }
+            )?
         }
     }
 }
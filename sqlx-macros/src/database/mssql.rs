@@ -10,9 +10,15 @@ impl_database_ext! {
         f32,
         f64,
         String,
+
+        #[cfg(feature = "chrono")]
+        sqlx::types::chrono::NaiveTime,
+
+        #[cfg(feature = "chrono")]
+        sqlx::types::chrono::NaiveDateTime,
     },
     ParamChecking::Weak,
-    feature-types: _info => None,
+    feature-types: info => info.__type_feature_gate(),
     row = sqlx::mssql::MssqlRow,
     name = "MSSQL"
 }
@@ -55,6 +55,7 @@ impl_database_ext! {
     },
     ParamChecking::Weak,
     feature-types: info => info.__type_feature_gate(),
+    coercions: info => if info.__is_bool_like() { Some("bool") } else { None },
     row = sqlx::mysql::MySqlRow,
     name = "MySQL"
 }
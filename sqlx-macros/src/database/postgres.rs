@@ -16,6 +16,10 @@ impl_database_ext! {
 
         sqlx::postgres::types::PgInterval,
 
+        sqlx::postgres::types::PgTid,
+
+        sqlx::postgres::types::PgJsonPath,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -49,6 +53,15 @@ impl_database_ext! {
         #[cfg(feature = "time")]
         sqlx::postgres::types::PgTimeTz<sqlx::types::time::Time, sqlx::types::time::UtcOffset>,
 
+        #[cfg(feature = "jiff")]
+        sqlx::types::jiff::Time,
+
+        #[cfg(feature = "jiff")]
+        sqlx::types::jiff::Date,
+
+        #[cfg(feature = "jiff")]
+        sqlx::types::jiff::Timestamp,
+
         #[cfg(feature = "bigdecimal")]
         sqlx::types::BigDecimal,
 
@@ -58,6 +71,9 @@ impl_database_ext! {
         #[cfg(feature = "ipnetwork")]
         sqlx::types::ipnetwork::IpNetwork,
 
+        #[cfg(feature = "ipnet")]
+        sqlx::types::ipnet::IpNet,
+
         #[cfg(feature = "json")]
         serde_json::Value,
 
@@ -77,6 +93,10 @@ impl_database_ext! {
         Vec<f32> | &[f32],
         Vec<f64> | &[f64],
 
+        Vec<sqlx::postgres::types::PgTid> | &[sqlx::postgres::types::PgTid],
+
+        Vec<sqlx::postgres::types::PgJsonPath> | &[sqlx::postgres::types::PgJsonPath],
+
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
 
@@ -92,6 +112,9 @@ impl_database_ext! {
         #[cfg(feature = "chrono")]
         Vec<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>> | &[sqlx::types::chrono::DateTime<_>],
 
+        #[cfg(feature = "chrono")]
+        Vec<sqlx::postgres::types::PgTimeTz<sqlx::types::chrono::NaiveTime, sqlx::types::chrono::FixedOffset>> | &[sqlx::postgres::types::PgTimeTz<sqlx::types::chrono::NaiveTime, sqlx::types::chrono::FixedOffset>],
+
         #[cfg(feature = "time")]
         Vec<sqlx::types::time::Time> | &[sqlx::types::time::Time],
 
@@ -104,12 +127,27 @@ impl_database_ext! {
         #[cfg(feature = "time")]
         Vec<sqlx::types::time::OffsetDateTime> | &[sqlx::types::time::OffsetDateTime],
 
+        #[cfg(feature = "time")]
+        Vec<sqlx::postgres::types::PgTimeTz<sqlx::types::time::Time, sqlx::types::time::UtcOffset>> | &[sqlx::postgres::types::PgTimeTz<sqlx::types::time::Time, sqlx::types::time::UtcOffset>],
+
+        #[cfg(feature = "jiff")]
+        Vec<sqlx::types::jiff::Time> | &[sqlx::types::jiff::Time],
+
+        #[cfg(feature = "jiff")]
+        Vec<sqlx::types::jiff::Date> | &[sqlx::types::jiff::Date],
+
+        #[cfg(feature = "jiff")]
+        Vec<sqlx::types::jiff::Timestamp> | &[sqlx::types::jiff::Timestamp],
+
         #[cfg(feature = "bigdecimal")]
         Vec<sqlx::types::BigDecimal> | &[sqlx::types::BigDecimal],
 
         #[cfg(feature = "ipnetwork")]
         Vec<sqlx::types::ipnetwork::IpNetwork> | &[sqlx::types::ipnetwork::IpNetwork],
 
+        #[cfg(feature = "ipnet")]
+        Vec<sqlx::types::ipnet::IpNet> | &[sqlx::types::ipnet::IpNet],
+
         #[cfg(feature = "json")]
         Vec<serde_json::Value> | &[serde_json::Value],
 
@@ -179,6 +217,7 @@ impl_database_ext! {
     },
     ParamChecking::Strong,
     feature-types: info => info.__type_feature_gate(),
+    pseudo-types: info => info.__is_text_pseudo_type(),
     row = sqlx::postgres::PgRow,
     name = "PostgreSQL"
 }
@@ -3,7 +3,7 @@ use quote::{quote, quote_spanned};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Attribute, DeriveInput, Field, Lit, Meta, MetaNameValue, NestedMeta, Variant};
+use syn::{Attribute, DeriveInput, Field, Fields, Lit, Meta, MetaNameValue, NestedMeta, Variant};
 
 macro_rules! assert_attribute {
     ($e:expr, $err:expr, $input:expr) => {
@@ -65,11 +65,14 @@ pub struct SqlxContainerAttributes {
     pub type_name: Option<TypeName>,
     pub rename_all: Option<RenameAll>,
     pub repr: Option<Ident>,
+    pub text_io: bool,
+    pub pg_array: bool,
 }
 
 pub struct SqlxChildAttributes {
     pub rename: Option<String>,
     pub default: bool,
+    pub other: bool,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
@@ -77,6 +80,8 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     let mut repr = None;
     let mut type_name = None;
     let mut rename_all = None;
+    let mut text_io = None;
+    let mut pg_array = None;
 
     for attr in input
         .iter()
@@ -94,6 +99,14 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 try_set!(transparent, true, value)
                             }
 
+                            Meta::Path(p) if p.is_ident("text_io") => {
+                                try_set!(text_io, true, value)
+                            }
+
+                            Meta::Path(p) if p.is_ident("pg_array") => {
+                                try_set!(pg_array, true, value)
+                            }
+
                             Meta::NameValue(MetaNameValue {
                                 path,
                                 lit: Lit::Str(val),
@@ -171,12 +184,15 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
         repr,
         type_name,
         rename_all,
+        text_io: text_io.unwrap_or(false),
+        pg_array: pg_array.unwrap_or(false),
     })
 }
 
 pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttributes> {
     let mut rename = None;
     let mut default = false;
+    let mut other = false;
 
     for attr in input.iter().filter(|a| a.path.is_ident("sqlx")) {
         let meta = attr
@@ -193,6 +209,7 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                             ..
                         }) if path.is_ident("rename") => try_set!(rename, val.value(), value),
                         Meta::Path(path) if path.is_ident("default") => default = true,
+                        Meta::Path(path) if path.is_ident("other") => other = true,
                         u => fail!(u, "unexpected attribute"),
                     },
                     u => fail!(u, "unexpected attribute"),
@@ -201,7 +218,11 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         }
     }
 
-    Ok(SqlxChildAttributes { rename, default })
+    Ok(SqlxChildAttributes {
+        rename,
+        default,
+        other,
+    })
 }
 
 pub fn check_transparent_attributes(
@@ -218,6 +239,14 @@ pub fn check_transparent_attributes(
 
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
+    assert_attribute!(!attributes.text_io, "unexpected #[sqlx(text_io)]", input);
+
+    assert_attribute!(
+        !attributes.pg_array,
+        "unexpected #[sqlx(pg_array)]",
+        input
+    );
+
     let ch_attributes = parse_child_attributes(&field.attrs)?;
 
     assert_attribute!(
@@ -226,6 +255,8 @@ pub fn check_transparent_attributes(
         field
     );
 
+    assert_attribute!(!ch_attributes.other, "unexpected #[sqlx(other)]", field);
+
     Ok(attributes)
 }
 
@@ -238,6 +269,8 @@ pub fn check_enum_attributes(input: &DeriveInput) -> syn::Result<SqlxContainerAt
         input
     );
 
+    assert_attribute!(!attributes.text_io, "unexpected #[sqlx(text_io)]", input);
+
     Ok(attributes)
 }
 
@@ -249,6 +282,12 @@ pub fn check_weak_enum_attributes(
 
     assert_attribute!(attributes.repr.is_some(), "expected #[repr(..)]", input);
 
+    assert_attribute!(
+        !attributes.pg_array,
+        "unexpected #[sqlx(pg_array)]",
+        input
+    );
+
     assert_attribute!(
         attributes.rename_all.is_none(),
         "unexpected #[sqlx(c = ..)]",
@@ -263,6 +302,8 @@ pub fn check_weak_enum_attributes(
             "unexpected #[sqlx(rename = ..)]",
             variant
         );
+
+        assert_attribute!(!attributes.other, "unexpected #[sqlx(other)]", variant);
     }
 
     Ok(attributes)
@@ -270,12 +311,26 @@ pub fn check_weak_enum_attributes(
 
 pub fn check_strong_enum_attributes(
     input: &DeriveInput,
-    _variants: &Punctuated<Variant, Comma>,
+    variants: &Punctuated<Variant, Comma>,
 ) -> syn::Result<SqlxContainerAttributes> {
     let attributes = check_enum_attributes(input)?;
 
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
+    let mut other = None;
+
+    for variant in variants {
+        if parse_child_attributes(&variant.attrs)?.other {
+            assert_attribute!(
+                matches!(variant.fields, Fields::Unit),
+                "expected a unit variant for #[sqlx(other)]",
+                variant
+            );
+
+            try_set!(other, (), variant);
+        }
+    }
+
     Ok(attributes)
 }
 
@@ -307,6 +362,8 @@ pub fn check_struct_attributes<'a>(
             "unexpected #[sqlx(rename = ..)]",
             field
         );
+
+        assert_attribute!(!attributes.other, "unexpected #[sqlx(other)]", field);
     }
 
     Ok(attributes)
@@ -163,11 +163,24 @@ fn expand_derive_decode_strong_enum(
         }
     });
 
+    // unknown values decode to the `#[sqlx(other)]` variant, if any
+    let fallback = variants
+        .iter()
+        .find(|v| parse_child_attributes(&v.attrs).unwrap().other)
+        .map(|v| &v.ident);
+
+    let fallback_arm: Arm = match fallback {
+        Some(id) => parse_quote!(_ => ::std::result::Result::Ok(#ident :: #id),),
+        None => parse_quote!(
+            _ => Err(format!("invalid value {:?} for enum {}", value, #ident_s).into()),
+        ),
+    };
+
     let values = quote! {
         match value {
             #(#value_arms)*
 
-            _ => Err(format!("invalid value {:?} for enum {}", value, #ident_s).into())
+            #fallback_arm
         }
     };
 
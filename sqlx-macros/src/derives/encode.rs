@@ -197,7 +197,7 @@ fn expand_derive_encode_struct(
     input: &DeriveInput,
     fields: &Punctuated<Field, Comma>,
 ) -> syn::Result<TokenStream> {
-    check_struct_attributes(input, &fields)?;
+    let attributes = check_struct_attributes(input, &fields)?;
 
     let mut tts = TokenStream::new();
 
@@ -241,6 +241,12 @@ fn expand_derive_encode_struct(
             )
         });
 
+        let new_encoder = if attributes.text_io {
+            quote!(new_text)
+        } else {
+            quote!(new)
+        };
+
         tts.extend(quote!(
             #[automatically_derived]
             impl #impl_generics ::sqlx::encode::Encode<'_, ::sqlx::Postgres> for #ident #ty_generics
@@ -250,7 +256,7 @@ fn expand_derive_encode_struct(
                     &self,
                     buf: &mut ::sqlx::postgres::PgArgumentBuffer,
                 ) -> ::sqlx::encode::IsNull {
-                    let mut encoder = ::sqlx::postgres::types::PgRecordEncoder::new(buf);
+                    let mut encoder = ::sqlx::postgres::types::PgRecordEncoder::#new_encoder(buf);
 
                     #(#writes)*
 
@@ -159,6 +159,10 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
             }
         ));
+
+        if attributes.pg_array {
+            tts.extend(expand_pg_array_type(ident, attributes.type_name.as_ref()));
+        }
     }
 
     if cfg!(feature = "sqlite") {
@@ -199,11 +203,38 @@ fn expand_derive_has_sql_type_struct(
                 }
             }
         ));
+
+        if attributes.pg_array {
+            tts.extend(expand_pg_array_type(ident, attributes.type_name.as_ref()));
+        }
     }
 
     Ok(tts)
 }
 
+// Postgres implicitly creates an array type for every enum and composite type, named after the
+// element type with a leading underscore (in the same schema); this lets `&[T]` and `Vec<T>` be
+// bound and decoded
+fn expand_pg_array_type(ident: &Ident, explicit_name: Option<&TypeName>) -> TokenStream {
+    let name = explicit_name
+        .map(|tn| tn.val.clone())
+        .unwrap_or_else(|| ident.to_string());
+
+    let array_name = match name.rfind('.') {
+        Some(dot) => format!("{}._{}", &name[..dot], &name[dot + 1..]),
+        None => format!("_{}", name),
+    };
+
+    quote!(
+        #[automatically_derived]
+        impl ::sqlx::postgres::types::PgHasArrayType for #ident {
+            fn array_type_info() -> ::sqlx::postgres::PgTypeInfo {
+                ::sqlx::postgres::PgTypeInfo::with_name(#array_name)
+            }
+        }
+    )
+}
+
 fn type_name(ident: &Ident, explicit_name: Option<&TypeName>) -> TokenStream {
     explicit_name.map(|tn| tn.get()).unwrap_or_else(|| {
         let s = ident.to_string();
@@ -1,38 +1,48 @@
 use crate::database::DatabaseExt;
+use crate::query::config::Config;
 use crate::query::QueryMacroInput;
 use either::Either;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use sqlx_core::describe::Describe;
 use syn::spanned::Spanned;
 use syn::{Expr, ExprCast, ExprGroup, ExprType, Type};
 
+// the largest tuple `sqlx::query_macros::bind_args()` is implemented for
+const MAX_SHARED_ARGS: usize = 16;
+
 /// Returns a tokenstream which typechecks the arguments passed to the macro
 /// and binds them to `DB::Arguments` with the ident `query_args`.
 pub fn quote_args<DB: DatabaseExt>(
     input: &QueryMacroInput,
     info: &Describe<DB>,
+    config: &Config,
 ) -> crate::Result<TokenStream> {
-    let db_path = DB::db_path();
-
-    if input.arg_exprs.is_empty() {
-        return Ok(quote! {
-            let query_args = <#db_path as ::sqlx::database::HasArguments>::Arguments::default();
-        });
-    }
+    let arg_names = arg_names(input);
 
-    let arg_names = (0..input.arg_exprs.len())
-        .map(|i| format_ident!("arg{}", i))
-        .collect::<Vec<_>>();
-
-    let arg_name = &arg_names;
-    let arg_expr = input.arg_exprs.iter().cloned().map(strip_wildcard);
+    let args_check = match info.parameters() {
+        None | Some(Either::Right(_)) if input.checked && config.strict_params() => {
+            // the database does not tell the types of the parameters (MySQL, MSSQL, SQLite), so
+            // they have to be given by the caller
+            for (i, expr) in input.arg_exprs.iter().enumerate() {
+                match get_type_override(expr) {
+                    Some(Type::Infer(_)) | None => {
+                        let message = format!(
+                            "the type of param #{} cannot be checked against the database; \
+                             `macros.strict-params` requires a cast to its type, e.g. `id as i64`",
+                            i + 1,
+                        );
+
+                        return Err(syn::Error::new_spanned(expr, message).into());
+                    }
+
+                    Some(_) => {}
+                }
+            }
 
-    let arg_bindings = quote! {
-        #(let #arg_name = &(#arg_expr);)*
-    };
+            TokenStream::new()
+        }
 
-    let args_check = match info.parameters() {
         None | Some(Either::Right(_)) => {
             // all we can do is check arity which we did
             TokenStream::new()
@@ -53,7 +63,9 @@ pub fn quote_args<DB: DatabaseExt>(
                         // cast or type ascription will fail to compile if the type does not match
                         // and we strip casts to wildcard
                         Some(_) => return Ok(quote!()),
-                        None => {
+                        None => if let Some(param_ty) = config.type_override(param_ty) {
+                            param_ty
+                        } else {
                             DB::param_type_for_id(&param_ty)
                                 .ok_or_else(|| {
                                     if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(&param_ty) {
@@ -98,20 +110,66 @@ pub fn quote_args<DB: DatabaseExt>(
         }
     };
 
+    Ok(quote_bind_args::<DB>(input, args_check))
+}
+
+/// Returns a tokenstream which binds the arguments passed to the macro to `DB::Arguments` with
+/// the ident `query_args`, without checking their types.
+pub fn quote_args_unchecked<DB: DatabaseExt>(input: &QueryMacroInput) -> TokenStream {
+    quote_bind_args::<DB>(input, TokenStream::new())
+}
+
+fn arg_names(input: &QueryMacroInput) -> Vec<Ident> {
+    (0..input.arg_exprs.len())
+        .map(|i| format_ident!("arg{}", i))
+        .collect()
+}
+
+fn quote_bind_args<DB: DatabaseExt>(
+    input: &QueryMacroInput,
+    args_check: TokenStream,
+) -> TokenStream {
+    let db_path = DB::db_path();
+
+    if input.arg_exprs.is_empty() {
+        return quote! {
+            let query_args = <#db_path as ::sqlx::database::HasArguments>::Arguments::default();
+        };
+    }
+
+    let arg_names = arg_names(input);
+    let arg_name = &arg_names;
+    let arg_expr = input.arg_exprs.iter().cloned().map(strip_wildcard);
+
+    let arg_bindings = quote! {
+        #(let #arg_name = &(#arg_expr);)*
+    };
+
     let args_count = input.arg_exprs.len();
 
-    Ok(quote! {
+    // bind through a shared generic function where one exists for this number of arguments
+    let bind_args = if args_count <= MAX_SHARED_ARGS {
+        quote! {
+            let query_args = ::sqlx::query_macros::bind_args::<#db_path, _>((#(#arg_name,)*));
+        }
+    } else {
+        quote! {
+            let mut query_args = <#db_path as ::sqlx::database::HasArguments>::Arguments::default();
+            query_args.reserve(
+                #args_count,
+                0 #(+ ::sqlx::encode::Encode::<#db_path>::size_hint(#arg_name))*
+            );
+            #(query_args.add(#arg_name);)*
+        }
+    };
+
+    quote! {
         #arg_bindings
 
         #args_check
 
-        let mut query_args = <#db_path as ::sqlx::database::HasArguments>::Arguments::default();
-        query_args.reserve(
-            #args_count,
-            0 #(+ ::sqlx::encode::Encode::<#db_path>::size_hint(#arg_name))*
-        );
-        #(query_args.add(#arg_name);)*
-    })
+        #bind_args
+    }
 }
 
 fn get_type_override(expr: &Expr) -> Option<&Type> {
@@ -0,0 +1,186 @@
+//! Caches the data of described queries on disk, in the target directory, so a clean build does
+//! not have to describe every query against the database again.
+//!
+//! Entries are keyed by the hash of `DATABASE_URL` and of the query and record the version of
+//! the database schema they were described against. The schema version is queried once for
+//! every crate being compiled; an entry recorded against another version is described again.
+//!
+//! The cache is disabled by setting `SQLX_DESCRIBE_CACHE=false`.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+use sqlx_core::database::Database;
+use sqlx_core::describe::Describe;
+use sqlx_core::error::Error;
+use sqlx_core::executor::Executor;
+use sqlx_rt::block_on;
+
+use crate::database::DatabaseExt;
+use crate::query::data::offline::hash_string;
+use crate::query::data::QueryData;
+use crate::query::pool;
+
+// the schema version of every database described against, keyed by URL; the schema is assumed
+// not to change while a crate is being compiled
+static SCHEMA_VERSIONS: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(Default::default);
+
+pub trait SchemaVersion: DatabaseExt {
+    /// Fetches a value that changes whenever the schema of the database changes.
+    fn schema_version(conn: &mut Self::Connection) -> BoxFuture<'_, Result<String, Error>>;
+}
+
+#[cfg(feature = "postgres")]
+impl SchemaVersion for sqlx_core::postgres::Postgres {
+    fn schema_version(
+        conn: &mut sqlx_core::postgres::PgConnection,
+    ) -> BoxFuture<'_, Result<String, Error>> {
+        // any DDL statement writes to the catalogs and so changes the transaction ID (`xmin`)
+        // of the rows it touches; statistics are updated in place and don't
+        Box::pin(
+            sqlx_core::query_scalar::query_scalar(
+                "SELECT md5(string_agg(xmin::text, ',' ORDER BY xmin::text)) FROM (
+                    SELECT xmin FROM pg_catalog.pg_class
+                    UNION ALL SELECT xmin FROM pg_catalog.pg_attribute
+                    UNION ALL SELECT xmin FROM pg_catalog.pg_type
+                    UNION ALL SELECT xmin FROM pg_catalog.pg_proc
+                    UNION ALL SELECT xmin FROM pg_catalog.pg_constraint
+                ) catalog",
+            )
+            .fetch_one(conn),
+        )
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl SchemaVersion for sqlx_core::mysql::MySql {
+    fn schema_version(
+        conn: &mut sqlx_core::mysql::MySqlConnection,
+    ) -> BoxFuture<'_, Result<String, Error>> {
+        Box::pin(
+            sqlx_core::query_scalar::query_scalar(
+                "SELECT CAST(CONCAT(COUNT(*), ':', COALESCE(SUM(CRC32(CONCAT_WS(',',
+                    TABLE_NAME, ORDINAL_POSITION, COLUMN_NAME, COLUMN_TYPE, IS_NULLABLE,
+                    COLUMN_KEY, EXTRA))), 0)) AS CHAR)
+                FROM information_schema.COLUMNS
+                WHERE TABLE_SCHEMA = DATABASE()",
+            )
+            .fetch_one(conn),
+        )
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl SchemaVersion for sqlx_core::sqlite::Sqlite {
+    fn schema_version(
+        conn: &mut sqlx_core::sqlite::SqliteConnection,
+    ) -> BoxFuture<'_, Result<String, Error>> {
+        Box::pin(
+            sqlx_core::query_scalar::query_scalar(
+                "SELECT CAST(schema_version AS TEXT) FROM pragma_schema_version",
+            )
+            .fetch_one(conn),
+        )
+    }
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(bound(
+    serialize = "Describe<DB>: serde::Serialize",
+    deserialize = "Describe<DB>: serde::de::DeserializeOwned"
+))]
+struct CacheEntry<DB: Database> {
+    schema_version: String,
+    data: QueryData<DB>,
+}
+
+/// Describes `query` against the database at `db_url`, or returns the data cached by an earlier
+/// build if the schema did not change since.
+pub fn describe<DB>(db_url: &str, query: &str) -> crate::Result<QueryData<DB>>
+where
+    DB: SchemaVersion,
+    Describe<DB>: serde::Serialize + serde::de::DeserializeOwned,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    let enabled = dotenv::var("SQLX_DESCRIBE_CACHE")
+        .map(|s| !(s.eq_ignore_ascii_case("false") || s == "0"))
+        .unwrap_or(true);
+
+    block_on(async {
+        if !enabled {
+            let mut conn = pool::acquire::<DB>(db_url).await?;
+
+            return QueryData::from_db(&mut *conn, query).await;
+        }
+
+        let mut conn = None;
+
+        let cached_version = SCHEMA_VERSIONS.lock().unwrap().get(db_url).cloned();
+
+        let schema_version = match cached_version {
+            Some(version) => version,
+
+            None => {
+                let conn = conn.get_or_insert(pool::acquire::<DB>(db_url).await?);
+                let version = DB::schema_version(&mut *conn).await?;
+
+                SCHEMA_VERSIONS
+                    .lock()
+                    .unwrap()
+                    .insert(db_url.to_owned(), version.clone());
+
+                version
+            }
+        };
+
+        let mut path =
+            PathBuf::from(env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target/".into()));
+
+        path.push("sqlx");
+        path.push("describe");
+
+        fs::create_dir_all(&path)?;
+
+        path.push(format!(
+            "{}.json",
+            hash_string(&format!("{}\0{}", db_url, query))
+        ));
+
+        // a missing or unreadable entry is simply described again
+        let entry = File::open(&path).ok().and_then(|file| {
+            serde_json::from_reader::<_, CacheEntry<DB>>(BufReader::new(file)).ok()
+        });
+
+        if let Some(entry) = entry {
+            if entry.schema_version == schema_version && entry.data.query == query {
+                return Ok(entry.data);
+            }
+        }
+
+        let mut conn = match conn {
+            Some(conn) => conn,
+            None => pool::acquire::<DB>(db_url).await?,
+        };
+
+        let entry = CacheEntry {
+            schema_version,
+            data: QueryData::from_db(&mut *conn, query).await?,
+        };
+
+        serde_json::to_writer(
+            BufWriter::new(
+                File::create(&path)
+                    .map_err(|e| format!("failed to open path {}: {}", path.display(), e))?,
+            ),
+            &entry,
+        )?;
+
+        Ok(entry.data)
+    })
+}
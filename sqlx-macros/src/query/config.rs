@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use sqlx_core::type_info::TypeInfo;
+use syn::Type;
+
+/// Project-level configuration for the query macros, read from `sqlx.toml` in the root of the
+/// crate being compiled.
+///
+/// ```toml
+/// [macros]
+/// strict-params = true
+/// bool-coercions = false
+///
+/// [macros.type-overrides]
+/// timestamptz = "time::OffsetDateTime"
+/// numeric = "rust_decimal::Decimal"
+/// email = "crate::Email"
+/// ```
+pub struct Config {
+    // the path of the file the configuration was read from, if any
+    path: Option<PathBuf>,
+
+    // keyed by the lowercased name of the SQL type
+    type_overrides: HashMap<String, Type>,
+
+    // require a type annotation on the parameters whose type is not known from the database
+    strict_params: bool,
+
+    // infer `bool` for the columns of the types used for booleans, e.g. `TINYINT(1)` in MySQL
+    bool_coercions: bool,
+}
+
+impl Config {
+    pub fn from_manifest_dir(manifest_dir: &str) -> crate::Result<Self> {
+        let path = Path::new(manifest_dir).join("sqlx.toml");
+
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+        let value: toml::Value = contents
+            .parse()
+            .map_err(|e| format!("failed to parse {}: {}", path.display(), e))?;
+
+        let mut type_overrides = HashMap::new();
+
+        let strict_params = get_bool(&value, "strict-params", &path)?.unwrap_or(false);
+        let bool_coercions = get_bool(&value, "bool-coercions", &path)?.unwrap_or(true);
+
+        if let Some(table) = value.get("macros").and_then(|it| it.get("type-overrides")) {
+            let table = table.as_table().ok_or_else(|| {
+                format!(
+                    "expected `macros.type-overrides` in {} to be a table",
+                    path.display()
+                )
+            })?;
+
+            for (sql_type, rust_type) in table {
+                let rust_type = rust_type
+                    .as_str()
+                    .and_then(|it| syn::parse_str::<Type>(it).ok())
+                    .ok_or_else(|| {
+                        format!(
+                            "type override for `{}` in {} is not a valid Rust type",
+                            sql_type,
+                            path.display()
+                        )
+                    })?;
+
+                type_overrides.insert(sql_type.to_lowercase(), rust_type);
+            }
+        }
+
+        Ok(Config {
+            path: Some(path),
+            type_overrides,
+            strict_params,
+            bool_coercions,
+        })
+    }
+
+    /// Returns the Rust type configured for the given SQL type, if any.
+    ///
+    /// An override for a type also applies to arrays of that type, unless the array type has
+    /// its own override.
+    pub fn type_override(&self, type_info: &impl TypeInfo) -> Option<TokenStream> {
+        let name = type_info.name().to_lowercase();
+
+        if let Some(ty) = self.type_overrides.get(&name) {
+            return Some(quote!(#ty));
+        }
+
+        let elem = self.type_overrides.get(name.strip_suffix("[]")?)?;
+
+        Some(quote!(::std::vec::Vec<#elem>))
+    }
+
+    /// Returns `true` if the parameters whose type is not known from the database must be
+    /// annotated with their type, instead of accepting any type.
+    pub fn strict_params(&self) -> bool {
+        self.strict_params
+    }
+
+    /// Returns `true` if `bool` is inferred for the columns of the SQL types which are used for
+    /// booleans, such as `TINYINT(1)` and `BIT(1)` in MySQL, instead of an integer type.
+    pub fn bool_coercions(&self) -> bool {
+        self.bool_coercions
+    }
+
+    /// Returns tokens which make the crate be recompiled when the configuration file changes.
+    pub fn quote_dependency(&self) -> TokenStream {
+        match &self.path {
+            Some(path) => {
+                let path = path.to_string_lossy();
+
+                quote! {
+                    const _: &[u8] = ::std::include_bytes!(#path);
+                }
+            }
+
+            None => TokenStream::new(),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            path: None,
+            type_overrides: HashMap::new(),
+            strict_params: false,
+            bool_coercions: true,
+        }
+    }
+}
+
+fn get_bool(value: &toml::Value, key: &str, path: &Path) -> crate::Result<Option<bool>> {
+    match value.get("macros").and_then(|it| it.get(key)) {
+        Some(value) => {
+            let value = value.as_bool().ok_or_else(|| {
+                format!(
+                    "expected `macros.{}` in {} to be a boolean",
+                    key,
+                    path.display()
+                )
+            })?;
+
+            Ok(Some(value))
+        }
+
+        None => Ok(None),
+    }
+}
@@ -1,18 +1,26 @@
+use std::cmp;
 use std::fs;
+use std::ops::Range;
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Literal, Span, TokenTree};
+use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Expr, LitBool, LitStr, Token};
 use syn::{ExprArray, Type};
 
 /// Macro input shared by `query!()` and `query_file!()`
+#[derive(Clone)]
 pub struct QueryMacroInput {
     pub(super) src: String,
 
     #[cfg_attr(not(feature = "offline"), allow(dead_code))]
     pub(super) src_span: Span,
 
+    // the string literals the query was given as, if not read from a file
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    src_lits: Vec<LitStr>,
+
     pub(super) record_type: RecordType,
 
     pub(super) arg_exprs: Vec<Expr>,
@@ -21,10 +29,11 @@ pub struct QueryMacroInput {
 }
 
 enum QuerySrc {
-    String(String),
+    String(Vec<LitStr>),
     File(String),
 }
 
+#[derive(Clone)]
 pub enum RecordType {
     Given(Type),
     Scalar,
@@ -51,11 +60,10 @@ impl Parse for QueryMacroInput {
 
             if key == "source" {
                 let span = input.span();
-                let query_str = Punctuated::<LitStr, Token![+]>::parse_separated_nonempty(input)?
-                    .iter()
-                    .map(LitStr::value)
+                let lits = Punctuated::<LitStr, Token![+]>::parse_separated_nonempty(input)?
+                    .into_iter()
                     .collect();
-                query_src = Some((QuerySrc::String(query_str), span));
+                query_src = Some((QuerySrc::String(lits), span));
             } else if key == "source_file" {
                 let lit_str = input.parse::<LitStr>()?;
                 query_src = Some((QuerySrc::File(lit_str.value()), lit_str.span()));
@@ -94,9 +102,15 @@ impl Parse for QueryMacroInput {
 
         let arg_exprs = args.unwrap_or_default();
 
+        let src_lits = match &src {
+            QuerySrc::String(lits) => lits.clone(),
+            QuerySrc::File(_) => Vec::new(),
+        };
+
         Ok(QueryMacroInput {
             src: src.resolve(src_span)?,
             src_span,
+            src_lits,
             record_type,
             arg_exprs,
             checked,
@@ -104,11 +118,154 @@ impl Parse for QueryMacroInput {
     }
 }
 
+impl QueryMacroInput {
+    /// Returns the span of the SQL token starting at the given (0-based) character offset into
+    /// the query, to point at it in an error.
+    ///
+    /// `Literal::subspan` only returns a span within a string literal on nightly; otherwise,
+    /// `None` is returned and [`span_at`](Self::span_at) can be used instead.
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub(super) fn token_span_at(&self, offset: usize) -> Option<Span> {
+        let (lit, offset) = self.lit_at(offset)?;
+        let value = lit.value();
+
+        // the token runs up to the next whitespace
+        let token_len = cmp::max(
+            value
+                .chars()
+                .skip(offset)
+                .take_while(|ch| !ch.is_whitespace())
+                .count(),
+            1,
+        );
+
+        let range = literal_range(lit, offset..(offset + token_len))?;
+
+        literal_token(lit)?.subspan(range)
+    }
+
+    /// Returns the span of the string literal containing the given (0-based) character offset
+    /// into the query, or of the query if it was read from a file.
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub(super) fn span_at(&self, offset: usize) -> Span {
+        match self.lit_at(offset) {
+            Some((lit, _)) => lit.span(),
+            None => self.src_span,
+        }
+    }
+
+    /// Returns the (1-based) line and column of the given (0-based) character offset into the
+    /// query.
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub(super) fn line_column_at(&self, offset: usize) -> (usize, usize) {
+        let before = self.src.chars().take(offset);
+
+        before.fold((1, 1), |(line, column), ch| {
+            if ch == '\n' {
+                (line + 1, 1)
+            } else {
+                (line, column + 1)
+            }
+        })
+    }
+
+    // Returns the string literal containing the given character offset into the query, and
+    // the offset into its value
+    fn lit_at(&self, mut offset: usize) -> Option<(&LitStr, usize)> {
+        for lit in &self.src_lits {
+            let len = lit.value().chars().count();
+
+            if offset < len {
+                return Some((lit, offset));
+            }
+
+            offset -= len;
+        }
+
+        None
+    }
+}
+
+// Returns the literal token of a string literal; `LitStr::token` requires a newer `syn`
+fn literal_token(lit: &LitStr) -> Option<Literal> {
+    match lit.to_token_stream().into_iter().next()? {
+        TokenTree::Literal(literal) => Some(literal),
+        _ => None,
+    }
+}
+
+/// Maps a range of characters of the value of a string literal to the range of bytes of
+/// its source, accounting for escapes and raw strings.
+fn literal_range(lit: &LitStr, chars: Range<usize>) -> Option<Range<usize>> {
+    let source = literal_token(lit)?.to_string();
+    let offsets = char_offsets(&source)?;
+
+    Some(*offsets.get(chars.start)?..*offsets.get(chars.end)?)
+}
+
+// Returns the offset in the source of a string literal of every character of its value,
+// followed by the offset of the closing quote
+fn char_offsets(source: &str) -> Option<Vec<usize>> {
+    let mut offsets = Vec::with_capacity(source.len());
+
+    if let Some(raw) = source.strip_prefix('r') {
+        // r#"..."#
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let start = 1 + hashes + 1;
+        let end = source.rfind('"')?;
+
+        offsets.extend(
+            source
+                .get(start..end)?
+                .char_indices()
+                .map(|(i, _)| start + i),
+        );
+        offsets.push(end);
+
+        return Some(offsets);
+    }
+
+    let end = source.rfind('"')?;
+    let mut chars = source.get(1..end)?.char_indices().peekable();
+
+    while let Some((i, ch)) = chars.next() {
+        if ch == '\\' {
+            match chars.next()?.1 {
+                // `\x7F`
+                'x' => {
+                    chars.next()?;
+                    chars.next()?;
+                }
+
+                // `\u{1F600}`
+                'u' => while chars.next()?.1 != '}' {},
+
+                // a line continuation, which skips the following whitespace
+                ch if ch.is_whitespace() => {
+                    while chars.peek().map_or(false, |(_, ch)| ch.is_whitespace()) {
+                        chars.next();
+                    }
+
+                    continue;
+                }
+
+                _ => {}
+            }
+        }
+
+        offsets.push(1 + i);
+    }
+
+    offsets.push(end);
+
+    Some(offsets)
+}
+
 impl QuerySrc {
     /// If the query source is a file, read it to a string. Otherwise return the query string.
     fn resolve(self, source_span: Span) -> syn::Result<String> {
         match self {
-            QuerySrc::String(string) => Ok(string),
+            QuerySrc::String(lits) => Ok(lits.iter().map(LitStr::value).collect()),
             QuerySrc::File(file) => read_file_src(&file, source_span),
         }
     }
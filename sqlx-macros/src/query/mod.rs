@@ -1,7 +1,9 @@
+use std::collections::HashSet;
 use std::env;
 use std::path::Path;
 #[cfg(feature = "offline")]
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 use proc_macro2::TokenStream;
 use syn::Type;
@@ -9,20 +11,24 @@ use url::Url;
 
 pub use input::QueryMacroInput;
 use quote::{format_ident, quote};
-use sqlx_core::connection::Connection;
 use sqlx_core::database::Database;
 use sqlx_core::{column::Column, describe::Describe, type_info::TypeInfo};
 use sqlx_rt::block_on;
 
 use crate::database::DatabaseExt;
+use crate::query::config::Config;
 use crate::query::data::QueryData;
 use crate::query::input::RecordType;
 use either::Either;
 
 mod args;
+#[cfg(feature = "offline")]
+mod cache;
+mod config;
 mod data;
 mod input;
 mod output;
+mod pool;
 
 // If we are in a workspace, lookup `workspace_root` since `CARGO_MANIFEST_DIR` won't
 // reflect the workspace dir: https://github.com/rust-lang/cargo/issues/3946
@@ -71,18 +77,24 @@ pub fn expand_input(input: QueryMacroInput) -> crate::Result<TokenStream> {
             .unwrap_or(false),
         dotenv::var("DATABASE_URL"),
     ) {
-        (false, Ok(db_url)) => expand_from_db(input, &db_url),
+        (false, Ok(db_url)) => {
+            // the other invocations do not wait for a database found to be unreachable
+            if UNREACHABLE_URLS.lock().unwrap().contains(&db_url) {
+                return expand_without_db(input, &manifest_dir, &db_url);
+            }
+
+            match expand_from_db(input.clone(), &db_url) {
+                Err(e) if is_unreachable(&e) => {
+                    expand_unreachable(input, &manifest_dir, &db_url, e)
+                }
+                res => res,
+            }
+        }
 
         #[cfg(feature = "offline")]
         _ => {
-            let data_file_path = Path::new(&manifest_dir).join("sqlx-data.json");
-
-            let workspace_data_file_path = CRATE_ROOT.join("sqlx-data.json");
-
-            if data_file_path.exists() {
+            if let Some(data_file_path) = find_data_file(&manifest_dir) {
                 expand_from_file(input, data_file_path)
-            } else if workspace_data_file_path.exists() {
-                expand_from_file(input, workspace_data_file_path)
             } else {
                 Err(
                     "`DATABASE_URL` must be set, or `cargo sqlx prepare` must have been run \
@@ -102,6 +114,196 @@ pub fn expand_input(input: QueryMacroInput) -> crate::Result<TokenStream> {
     }
 }
 
+#[cfg(feature = "offline")]
+fn find_data_file(manifest_dir: &str) -> Option<PathBuf> {
+    let data_file_path = Path::new(manifest_dir).join("sqlx-data.json");
+
+    let workspace_data_file_path = CRATE_ROOT.join("sqlx-data.json");
+
+    if data_file_path.exists() {
+        Some(data_file_path)
+    } else if workspace_data_file_path.exists() {
+        Some(workspace_data_file_path)
+    } else {
+        None
+    }
+}
+
+/// What the macros do when the database cannot be reached, set with `SQLX_DATABASE_UNREACHABLE`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum UnreachablePolicy {
+    /// Fail to compile (the default).
+    Error,
+
+    /// Expand the macros without checking the queries, which are described by the database and
+    /// checked before they are first executed.
+    Runtime,
+
+    /// Expand the macros without checking the queries at all, with a warning for every
+    /// invocation.
+    Unchecked,
+}
+
+impl UnreachablePolicy {
+    fn from_env() -> crate::Result<Self> {
+        match dotenv::var("SQLX_DATABASE_UNREACHABLE") {
+            Err(_) => Ok(UnreachablePolicy::Error),
+
+            Ok(policy) => match &*policy.to_ascii_lowercase() {
+                "error" => Ok(UnreachablePolicy::Error),
+                "runtime" => Ok(UnreachablePolicy::Runtime),
+                "unchecked" => Ok(UnreachablePolicy::Unchecked),
+
+                _ => Err(format!(
+                    "`SQLX_DATABASE_UNREACHABLE` must be `error`, `runtime` or `unchecked`, \
+                     got {:?}",
+                    policy
+                )
+                .into()),
+            },
+        }
+    }
+}
+
+// the database URLs which could not be connected to, with a policy other than `Error`
+static UNREACHABLE_URLS: once_cell::sync::Lazy<Mutex<HashSet<String>>> =
+    once_cell::sync::Lazy::new(Default::default);
+
+// errors connecting to the database, as opposed to errors describing the query
+fn is_unreachable(e: &crate::Error) -> bool {
+    use sqlx_core::error::Error;
+
+    matches!(
+        e.downcast_ref::<Error>(),
+        Some(Error::Io(_)) | Some(Error::Tls(_)) | Some(Error::PoolTimedOut)
+    )
+}
+
+fn expand_unreachable(
+    input: QueryMacroInput,
+    manifest_dir: &str,
+    db_url: &str,
+    error: crate::Error,
+) -> crate::Result<TokenStream> {
+    if UnreachablePolicy::from_env()? == UnreachablePolicy::Error {
+        return Err(error);
+    }
+
+    UNREACHABLE_URLS.lock().unwrap().insert(db_url.to_owned());
+
+    expand_without_db(input, manifest_dir, db_url)
+}
+
+#[allow(unused_variables)]
+fn expand_without_db(
+    input: QueryMacroInput,
+    manifest_dir: &str,
+    db_url: &str,
+) -> crate::Result<TokenStream> {
+    // an offline snapshot is still checked
+    #[cfg(feature = "offline")]
+    if let Some(data_file_path) = find_data_file(manifest_dir) {
+        return expand_from_file(input, data_file_path);
+    }
+
+    let policy = UnreachablePolicy::from_env()?;
+
+    match Url::parse(db_url)?.scheme() {
+        #[cfg(feature = "postgres")]
+        "postgres" | "postgresql" => {
+            expand_unchecked::<sqlx_core::postgres::Postgres>(input, policy)
+        }
+
+        #[cfg(feature = "mssql")]
+        "mssql" | "sqlserver" => expand_unchecked::<sqlx_core::mssql::Mssql>(input, policy),
+
+        #[cfg(feature = "mysql")]
+        "mysql" | "mariadb" => expand_unchecked::<sqlx_core::mysql::MySql>(input, policy),
+
+        #[cfg(feature = "sqlite")]
+        "sqlite" => expand_unchecked::<sqlx_core::sqlite::Sqlite>(input, policy),
+
+        scheme => Err(format!("unknown database URL scheme {:?}", scheme).into()),
+    }
+}
+
+/// Expands the macro without a description of the query: `query_as!()` maps the rows with
+/// `FromRow` and the type of `query_scalar!()` is inferred.
+///
+/// With `UnreachablePolicy::Runtime`, the query is described and checked before it is first
+/// executed; with `UnreachablePolicy::Unchecked`, it is not checked but emits a warning.
+fn expand_unchecked<DB: DatabaseExt>(
+    input: QueryMacroInput,
+    policy: UnreachablePolicy,
+) -> crate::Result<TokenStream> {
+    let args_tokens = args::quote_args_unchecked::<DB>(&input);
+
+    let db_path = DB::db_path();
+    let sql = &input.src;
+    let args_count = input.arg_exprs.len();
+
+    let output =
+        match (&input.record_type, policy) {
+            // the fields of the record are the columns of the query
+            (RecordType::Generated, _) => return Err(
+                "`query!()` cannot be expanded while the database is unreachable, as its record \
+                 type is generated from the columns of the query; use `query_as!()` with a \
+                 type implementing `FromRow`, or run `cargo sqlx prepare` to check it offline"
+                    .into(),
+            ),
+
+            (RecordType::Given(out_ty), UnreachablePolicy::Runtime) => quote! {
+                ::sqlx::query_macros::query_as_deferred::<#db_path, #out_ty, _>(
+                    #sql,
+                    query_args,
+                    &CHECKED,
+                    #args_count,
+                )
+            },
+            (RecordType::Given(out_ty), _) => quote! {
+                ::sqlx::query_as_dynamic_with::<#db_path, #out_ty, _>(#sql, query_args)
+            },
+
+            (RecordType::Scalar, UnreachablePolicy::Runtime) => quote! {
+                ::sqlx::query_macros::query_scalar_deferred::<#db_path, _, _>(
+                    #sql,
+                    query_args,
+                    &CHECKED,
+                    #args_count,
+                )
+            },
+            (RecordType::Scalar, _) => quote! {
+                ::sqlx::query_scalar_dynamic_with::<#db_path, _, _>(#sql, query_args)
+            },
+        };
+
+    let prelude = match policy {
+        // whether this invocation was checked, for the whole process
+        UnreachablePolicy::Runtime => quote! {
+            static CHECKED: ::std::sync::atomic::AtomicBool =
+                ::std::sync::atomic::AtomicBool::new(false);
+        },
+
+        // the function is deprecated so that its use emits a warning
+        _ => quote!(::sqlx::ty_match::unchecked_query();),
+    };
+
+    Ok(quote! {
+        {
+            #[allow(clippy::all)]
+            {
+                use ::sqlx::Arguments as _;
+
+                #prelude
+
+                #args_tokens
+
+                #output
+            }
+        }
+    })
+}
+
 #[allow(unused_variables)]
 fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenStream> {
     // FIXME: Introduce [sqlx::any::AnyConnection] and [sqlx::any::AnyDatabase] to support
@@ -111,10 +313,8 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
     match db_url.scheme() {
         #[cfg(feature = "postgres")]
         "postgres" | "postgresql" => {
-            let data = block_on(async {
-                let mut conn = sqlx_core::postgres::PgConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.src).await
-            })?;
+            let data = describe::<sqlx_core::postgres::Postgres>(db_url.as_str(), &input.src)
+                .map_err(|e| locate_pg_error(&input, e))?;
 
             expand_with_data(input, data, false)
         },
@@ -125,8 +325,8 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
         #[cfg(feature = "mssql")]
         "mssql" | "sqlserver" => {
             let data = block_on(async {
-                let mut conn = sqlx_core::mssql::MssqlConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.src).await
+                let mut conn = pool::acquire::<sqlx_core::mssql::Mssql>(db_url.as_str()).await?;
+                QueryData::from_db(&mut *conn, &input.src).await
             })?;
 
             expand_with_data(input, data, false)
@@ -137,10 +337,7 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
 
         #[cfg(feature = "mysql")]
         "mysql" | "mariadb" => {
-            let data = block_on(async {
-                let mut conn = sqlx_core::mysql::MySqlConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.src).await
-            })?;
+            let data = describe::<sqlx_core::mysql::MySql>(db_url.as_str(), &input.src)?;
 
             expand_with_data(input, data, false)
         },
@@ -150,10 +347,7 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
 
         #[cfg(feature = "sqlite")]
         "sqlite" => {
-            let data = block_on(async {
-                let mut conn = sqlx_core::sqlite::SqliteConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.src).await
-            })?;
+            let data = describe::<sqlx_core::sqlite::Sqlite>(db_url.as_str(), &input.src)?;
 
             expand_with_data(input, data, false)
         },
@@ -165,6 +359,55 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
     }
 }
 
+/// Points the error at the SQL token it was reported for, if any, instead of the whole macro
+/// invocation.
+#[cfg(feature = "postgres")]
+fn locate_pg_error(input: &QueryMacroInput, e: crate::Error) -> crate::Error {
+    use sqlx_core::postgres::{PgDatabaseError, PgErrorPosition};
+
+    let position = e
+        .downcast_ref::<sqlx_core::error::Error>()
+        .and_then(sqlx_core::error::Error::as_database_error)
+        .and_then(|db| db.try_downcast_ref::<PgDatabaseError>())
+        .and_then(PgDatabaseError::position);
+
+    let offset = match position {
+        // the position is 1-based
+        Some(PgErrorPosition::Original(position)) if position > 0 => position - 1,
+        _ => return e,
+    };
+
+    match input.token_span_at(offset) {
+        Some(span) => syn::Error::new(span, e).into(),
+
+        // a span within a string literal is only available on nightly; point at the literal
+        // and tell where the token is in the query instead
+        None => {
+            let (line, column) = input.line_column_at(offset);
+
+            syn::Error::new(
+                input.span_at(offset),
+                format!("{} (at line {}, column {} of the query)", e, line, column),
+            )
+            .into()
+        }
+    }
+}
+
+#[cfg(feature = "offline")]
+use cache::describe;
+
+#[cfg(not(feature = "offline"))]
+fn describe<DB: DatabaseExt>(db_url: &str, query: &str) -> crate::Result<QueryData<DB>>
+where
+    for<'c> &'c mut DB::Connection: sqlx_core::executor::Executor<'c, Database = DB>,
+{
+    block_on(async {
+        let mut conn = pool::acquire::<DB>(db_url).await?;
+        QueryData::from_db(&mut *conn, query).await
+    })
+}
+
 #[cfg(feature = "offline")]
 pub fn expand_from_file(input: QueryMacroInput, file: PathBuf) -> crate::Result<TokenStream> {
     use data::offline::DynQueryData;
@@ -239,7 +482,12 @@ where
         }
     }
 
-    let args_tokens = args::quote_args(&input, &data.describe)?;
+    let manifest_dir =
+        env::var("CARGO_MANIFEST_DIR").map_err(|_| "`CARGO_MANIFEST_DIR` must be set")?;
+
+    let config = Config::from_manifest_dir(&manifest_dir)?;
+
+    let args_tokens = args::quote_args(&input, &data.describe, &config)?;
 
     let query_args = format_ident!("query_args");
 
@@ -253,12 +501,12 @@ where
         let sql = &input.src;
 
         quote! {
-            ::sqlx::query_with::<#db_path, _>(#sql, #query_args)
+            ::sqlx::query_dynamic_with::<#db_path, _>(#sql, #query_args)
         }
     } else {
         match input.record_type {
             RecordType::Generated => {
-                let columns = output::columns_to_rust::<DB>(&data.describe)?;
+                let columns = output::columns_to_rust::<DB>(&data.describe, &config)?;
 
                 let record_name: Type = syn::parse_str("Record").unwrap();
 
@@ -295,22 +543,26 @@ where
                 record_tokens
             }
             RecordType::Given(ref out_ty) => {
-                let columns = output::columns_to_rust::<DB>(&data.describe)?;
+                let columns = output::columns_to_rust::<DB>(&data.describe, &config)?;
 
                 output::quote_query_as::<DB>(&input, out_ty, &query_args, &columns)
             }
             RecordType::Scalar => {
-                output::quote_query_scalar::<DB>(&input, &query_args, &data.describe)?
+                output::quote_query_scalar::<DB>(&input, &query_args, &data.describe, &config)?
             }
         }
     };
 
+    let config_dependency = config.quote_dependency();
+
     let ret_tokens = quote! {
         {
             #[allow(clippy::all)]
             {
                 use ::sqlx::Arguments as _;
 
+                #config_dependency
+
                 #args_tokens
 
                 #output
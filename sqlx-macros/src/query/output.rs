@@ -1,5 +1,6 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
 use syn::Type;
 
 use sqlx_core::column::Column;
@@ -7,6 +8,7 @@ use sqlx_core::describe::Describe;
 
 use crate::database::DatabaseExt;
 
+use crate::query::config::Config;
 use crate::query::QueryMacroInput;
 use std::fmt::{self, Display, Formatter};
 use syn::parse::{Parse, ParseStream};
@@ -74,13 +76,55 @@ impl Display for DisplayColumn<'_> {
     }
 }
 
-pub fn columns_to_rust<DB: DatabaseExt>(describe: &Describe<DB>) -> crate::Result<Vec<RustColumn>> {
-    (0..describe.columns().len())
-        .map(|i| column_to_rust(describe, i))
-        .collect::<crate::Result<Vec<_>>>()
+pub fn columns_to_rust<DB: DatabaseExt>(
+    describe: &Describe<DB>,
+    config: &Config,
+) -> crate::Result<Vec<RustColumn>> {
+    let columns = (0..describe.columns().len())
+        .map(|i| column_to_rust(describe, config, i))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    // e.g. the `id` columns of `SELECT a.*, b.* FROM a JOIN b ...`, which would be fields of the
+    // same name
+    let mut duplicates = Vec::new();
+
+    for (i, column) in columns.iter().enumerate() {
+        if columns[..i].iter().any(|other| other.ident == column.ident) {
+            continue;
+        }
+
+        let ordinals: Vec<String> = columns
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.ident == column.ident)
+            .map(|(j, _)| j.to_string())
+            .collect();
+
+        if ordinals.len() > 1 {
+            duplicates.push(format!(
+                "`{}` (columns {})",
+                column.ident.unraw(),
+                ordinals.join(", ")
+            ));
+        }
+    }
+
+    if !duplicates.is_empty() {
+        return Err(format!(
+            "more than one column has the same name: {}; rename them with `AS` in the query",
+            duplicates.join(", ")
+        )
+        .into());
+    }
+
+    Ok(columns)
 }
 
-fn column_to_rust<DB: DatabaseExt>(describe: &Describe<DB>, i: usize) -> crate::Result<RustColumn> {
+fn column_to_rust<DB: DatabaseExt>(
+    describe: &Describe<DB>,
+    config: &Config,
+    i: usize,
+) -> crate::Result<RustColumn> {
     let column = &describe.columns()[i];
 
     // add raw prefix to all identifiers
@@ -104,7 +148,7 @@ fn column_to_rust<DB: DatabaseExt>(describe: &Describe<DB>, i: usize) -> crate::
         (ColumnTypeOverride::Wildcard, true) => ColumnType::OptWildcard,
 
         (ColumnTypeOverride::None, _) => {
-            let type_ = get_column_type::<DB>(i, column);
+            let type_ = get_column_type::<DB>(config, i, column);
             if !nullable {
                 ColumnType::Exact(type_)
             } else {
@@ -160,13 +204,15 @@ pub fn quote_query_as<DB: DatabaseExt>(
     let sql = &input.src;
 
     quote! {
-        ::sqlx::query_with::<#db_path, _>(#sql, #bind_args).try_map(|row: #row_path| {
-            use ::sqlx::Row as _;
+        ::sqlx::query_dynamic_with::<#db_path, _>(#sql, #bind_args).try_map(
+            ::sqlx::query_macros::map_row::<#db_path, _>(|row: #row_path| {
+                use ::sqlx::Row as _;
 
-            #(#instantiations)*
+                #(#instantiations)*
 
-            Ok(#out_ty { #(#ident: #ident),* })
-        })
+                Ok(#out_ty { #(#ident: #ident),* })
+            })
+        )
     }
 }
 
@@ -174,6 +220,7 @@ pub fn quote_query_scalar<DB: DatabaseExt>(
     input: &QueryMacroInput,
     bind_args: &Ident,
     describe: &Describe<DB>,
+    config: &Config,
 ) -> crate::Result<TokenStream> {
     let columns = describe.columns();
 
@@ -186,10 +233,10 @@ pub fn quote_query_scalar<DB: DatabaseExt>(
     }
 
     // attempt to parse a column override, otherwise fall back to the inferred type of the column
-    let ty = if let Ok(rust_col) = column_to_rust(describe, 0) {
+    let ty = if let Ok(rust_col) = column_to_rust(describe, config, 0) {
         rust_col.type_.to_token_stream()
     } else if input.checked {
-        let ty = get_column_type::<DB>(0, &columns[0]);
+        let ty = get_column_type::<DB>(config, 0, &columns[0]);
         if describe.nullable(0).unwrap_or(true) {
             quote! { ::std::option::Option<#ty> }
         } else {
@@ -203,40 +250,57 @@ pub fn quote_query_scalar<DB: DatabaseExt>(
     let query = &input.src;
 
     Ok(quote! {
-        ::sqlx::query_scalar_with::<#db, #ty, _>(#query, #bind_args)
+        ::sqlx::query_scalar_dynamic_with::<#db, #ty, _>(#query, #bind_args)
     })
 }
 
-fn get_column_type<DB: DatabaseExt>(i: usize, column: &DB::Column) -> TokenStream {
+fn get_column_type<DB: DatabaseExt>(config: &Config, i: usize, column: &DB::Column) -> TokenStream {
     let type_info = &*column.type_info();
 
-    <DB as DatabaseExt>::return_type_for_id(&type_info).map_or_else(
-        || {
-            let message =
-                if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(&type_info) {
-                    format!(
-                        "optional feature `{feat}` required for type {ty} of {col}",
-                        ty = &type_info,
-                        feat = feature_gate,
-                        col = DisplayColumn {
-                            idx: i,
-                            name: &*column.name()
-                        }
-                    )
-                } else {
-                    format!(
-                        "unsupported type {ty} of {col}",
-                        ty = type_info,
-                        col = DisplayColumn {
-                            idx: i,
-                            name: &*column.name()
-                        }
-                    )
-                };
-            syn::Error::new(Span::call_site(), message).to_compile_error()
-        },
-        |t| t.parse().unwrap(),
-    )
+    if let Some(type_) = config.type_override(type_info) {
+        return type_;
+    }
+
+    // the alias of `String` is deprecated so that its use emits a warning
+    if <DB as DatabaseExt>::is_text_pseudo_type(&type_info) {
+        return quote!(::sqlx::ty_match::PseudoTypeText);
+    }
+
+    let coerced = if config.bool_coercions() {
+        <DB as DatabaseExt>::coerced_type_for_id(&type_info)
+    } else {
+        None
+    };
+
+    coerced
+        .or_else(|| <DB as DatabaseExt>::return_type_for_id(&type_info))
+        .map_or_else(
+            || {
+                let message =
+                    if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(&type_info) {
+                        format!(
+                            "optional feature `{feat}` required for type {ty} of {col}",
+                            ty = &type_info,
+                            feat = feature_gate,
+                            col = DisplayColumn {
+                                idx: i,
+                                name: &*column.name()
+                            }
+                        )
+                    } else {
+                        format!(
+                            "unsupported type {ty} of {col}",
+                            ty = type_info,
+                            col = DisplayColumn {
+                                idx: i,
+                                name: &*column.name()
+                            }
+                        )
+                    };
+                syn::Error::new(Span::call_site(), message).to_compile_error()
+            },
+            |t| t.parse().unwrap(),
+        )
 }
 
 impl ColumnDecl {
@@ -0,0 +1,98 @@
+//! Connections to the database used to describe queries.
+//!
+//! Every invocation of the query macros in a crate is expanded by the same compiler process, and
+//! several crates may be compiled in parallel. Instead of opening a connection for every
+//! invocation, the macros share a small pool per database URL, created lazily on first use.
+//!
+//! The size of each pool is set with `SQLX_MACROS_MAX_CONNECTIONS` (1 by default). Connecting is
+//! retried with an increasing delay while the server reports having too many connections.
+
+use std::any::Any;
+use std::cmp;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use sqlx_core::database::Database;
+use sqlx_core::error::Error;
+use sqlx_core::pool::{Pool, PoolConnection, PoolOptions};
+
+// keyed by URL; the value is a `Pool<DB>` for the database of the URL
+static POOLS: Lazy<Mutex<HashMap<String, Box<dyn Any + Send + Sync>>>> =
+    Lazy::new(Default::default);
+
+const MAX_CONNECTIONS: u32 = 1;
+
+const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(2);
+const RETRY_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn pool<DB: Database>(db_url: &str) -> crate::Result<Pool<DB>> {
+    let mut pools = POOLS.lock().unwrap();
+
+    if let Some(pool) = pools
+        .get(db_url)
+        .and_then(|it| it.downcast_ref::<Pool<DB>>())
+    {
+        return Ok(pool.clone());
+    }
+
+    let max_connections = match dotenv::var("SQLX_MACROS_MAX_CONNECTIONS") {
+        Ok(max) => max
+            .parse()
+            .ok()
+            .filter(|&max| max > 0)
+            .ok_or("`SQLX_MACROS_MAX_CONNECTIONS` must be a positive integer")?,
+
+        Err(_) => MAX_CONNECTIONS,
+    };
+
+    let pool = PoolOptions::<DB>::new()
+        .max_connections(max_connections)
+        .connect_lazy(db_url)?;
+
+    pools.insert(db_url.to_owned(), Box::new(pool.clone()));
+
+    Ok(pool)
+}
+
+/// Acquires a connection to the database at `db_url` from the pool shared by the macros.
+pub async fn acquire<DB: Database>(db_url: &str) -> crate::Result<PoolConnection<DB>> {
+    let pool = pool::<DB>(db_url)?;
+
+    let deadline = Instant::now() + RETRY_TIMEOUT;
+    let mut backoff = INITIAL_BACKOFF;
+
+    loop {
+        match pool.acquire().await {
+            Err(e) if is_too_many_connections(&e) && Instant::now() + backoff < deadline => {
+                sqlx_rt::sleep(backoff).await;
+                backoff = cmp::min(backoff * 2, MAX_BACKOFF);
+            }
+
+            res => return Ok(res?),
+        }
+    }
+}
+
+fn is_too_many_connections(error: &Error) -> bool {
+    let error = match error {
+        Error::Database(error) => error,
+        _ => return false,
+    };
+
+    // [postgres] too_many_connections
+    // [mysql] ER_CON_COUNT_ERROR
+    if matches!(error.code().as_deref(), Some("53300") | Some("08004")) {
+        return true;
+    }
+
+    #[cfg(feature = "mysql")]
+    if let Some(error) = error.try_downcast_ref::<sqlx_core::mysql::MySqlDatabaseError>() {
+        // the error can be sent before the protocol is negotiated, without an SQLSTATE
+        return error.number() == 1040;
+    }
+
+    false
+}
@@ -1,6 +1,6 @@
 use sqlx::pool::PoolOptions;
+use sqlx::testing::database_url;
 use sqlx::{Connection, Database, Pool};
-use std::env;
 
 pub fn setup_if_needed() {
     let _ = dotenv::dotenv();
@@ -15,7 +15,7 @@ where
 {
     setup_if_needed();
 
-    Ok(DB::Connection::connect(&env::var("DATABASE_URL")?).await?)
+    Ok(DB::Connection::connect(&database_url::<DB>()?).await?)
 }
 
 // Make a new pool
@@ -30,7 +30,7 @@ where
         .min_connections(0)
         .max_connections(5)
         .test_before_acquire(true)
-        .connect(&env::var("DATABASE_URL")?)
+        .connect(&database_url::<DB>()?)
         .await?;
 
     Ok(pool)
@@ -0,0 +1,121 @@
+//! The `query` family of functions, when the `checked-queries` feature is enabled.
+//!
+//! The functions are deprecated, so that every call to them is reported by a warning at compile
+//! time, or by an error with `#![deny(deprecated)]`: the SQL should go through the query macros,
+//! which check it at compile time. The SQL of the audited exceptions is made with the
+//! `*_dynamic` functions instead.
+//!
+//! Cargo features are unified across the dependency graph: once a crate enables
+//! `checked-queries`, the calls of every crate using `sqlx` are reported, though Cargo silences
+//! the warnings of the dependencies which are not local to the workspace. Nothing changes at
+//! run time.
+//!
+//! This only covers these functions: SQL passed directly as a `&str` to the [`Executor`]
+//! methods (e.g. `conn.execute("...")`), or to [`Executor::fetch_one_as`] and
+//! [`Executor::fetch_all_as`], is not reported.
+//!
+//! [`Executor`]: crate::Executor
+//! [`Executor::fetch_one_as`]: crate::Executor::fetch_one_as
+//! [`Executor::fetch_all_as`]: crate::Executor::fetch_all_as
+
+use sqlx_core::arguments::IntoArguments;
+use sqlx_core::database::{Database, HasArguments};
+use sqlx_core::from_row::FromRow;
+use sqlx_core::query::Query;
+use sqlx_core::query_as::QueryAs;
+use sqlx_core::query_scalar::QueryScalar;
+
+/// Make a SQL query.
+///
+/// With the `checked-queries` feature, this is deprecated; see [`query_dynamic`].
+///
+/// [`query_dynamic`]: crate::query_dynamic()
+#[deprecated = "the SQL is not checked at compile time: use the query macros, or `sqlx::query_dynamic` for an audited exception (the `checked-queries` feature is enabled)"]
+pub fn query<DB>(sql: &str) -> Query<'_, DB, <DB as HasArguments<'_>>::Arguments>
+where
+    DB: Database,
+{
+    sqlx_core::query::query(sql)
+}
+
+/// Make a SQL query, with the given arguments.
+///
+/// With the `checked-queries` feature, this is deprecated; see
+/// [`query_dynamic_with`].
+///
+/// [`query_dynamic_with`]: crate::query_dynamic_with()
+#[deprecated = "the SQL is not checked at compile time: use the query macros, or `sqlx::query_dynamic_with` for an audited exception (the `checked-queries` feature is enabled)"]
+pub fn query_with<'q, DB, A>(sql: &'q str, arguments: A) -> Query<'q, DB, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+{
+    sqlx_core::query::query_with(sql, arguments)
+}
+
+/// Make a SQL query that is mapped to a concrete type
+/// using [`FromRow`].
+///
+/// With the `checked-queries` feature, this is deprecated; see [`query_as_dynamic`].
+///
+/// [`query_as_dynamic`]: crate::query_as_dynamic()
+#[deprecated = "the SQL is not checked at compile time: use the query macros, or `sqlx::query_as_dynamic` for an audited exception (the `checked-queries` feature is enabled)"]
+pub fn query_as<'q, DB, O>(sql: &'q str) -> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments>
+where
+    DB: Database,
+    O: for<'r> FromRow<'r, DB::Row>,
+{
+    sqlx_core::query_as::query_as(sql)
+}
+
+/// Make a SQL query, with the given arguments, that is mapped to a concrete type
+/// using [`FromRow`].
+///
+/// With the `checked-queries` feature, this is deprecated; see
+/// [`query_as_dynamic_with`].
+///
+/// [`query_as_dynamic_with`]: crate::query_as_dynamic_with()
+#[deprecated = "the SQL is not checked at compile time: use the query macros, or `sqlx::query_as_dynamic_with` for an audited exception (the `checked-queries` feature is enabled)"]
+pub fn query_as_with<'q, DB, O, A>(sql: &'q str, arguments: A) -> QueryAs<'q, DB, O, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+    O: for<'r> FromRow<'r, DB::Row>,
+{
+    sqlx_core::query_as::query_as_with(sql, arguments)
+}
+
+/// Make a SQL query that is mapped to a single concrete type
+/// using [`FromRow`].
+///
+/// With the `checked-queries` feature, this is deprecated; see
+/// [`query_scalar_dynamic`].
+///
+/// [`query_scalar_dynamic`]: crate::query_scalar_dynamic()
+#[deprecated = "the SQL is not checked at compile time: use the query macros, or `sqlx::query_scalar_dynamic` for an audited exception (the `checked-queries` feature is enabled)"]
+pub fn query_scalar<'q, DB, O>(
+    sql: &'q str,
+) -> QueryScalar<'q, DB, O, <DB as HasArguments<'q>>::Arguments>
+where
+    DB: Database,
+    (O,): for<'r> FromRow<'r, DB::Row>,
+{
+    sqlx_core::query_scalar::query_scalar(sql)
+}
+
+/// Make a SQL query, with the given arguments, that is mapped to a single concrete type
+/// using [`FromRow`].
+///
+/// With the `checked-queries` feature, this is deprecated; see
+/// [`query_scalar_dynamic_with`].
+///
+/// [`query_scalar_dynamic_with`]: crate::query_scalar_dynamic_with()
+#[deprecated = "the SQL is not checked at compile time: use the query macros, or `sqlx::query_scalar_dynamic_with` for an audited exception (the `checked-queries` feature is enabled)"]
+pub fn query_scalar_with<'q, DB, O, A>(sql: &'q str, arguments: A) -> QueryScalar<'q, DB, O, A>
+where
+    DB: Database,
+    A: IntoArguments<'q, DB>,
+    (O,): for<'r> FromRow<'r, DB::Row>,
+{
+    sqlx_core::query_scalar::query_scalar_with(sql, arguments)
+}
@@ -11,22 +11,39 @@ compile_error!(
      and 'tls' is one of 'native-tls' and 'rustls'."
 );
 
+#[cfg(all(feature = "embedded-postgres", not(unix)))]
+compile_error!("the feature 'embedded-postgres' is only supported on Unix");
+
 pub use sqlx_core::acquire::Acquire;
 pub use sqlx_core::arguments::{Arguments, IntoArguments};
-pub use sqlx_core::column::Column;
 pub use sqlx_core::column::ColumnIndex;
+pub use sqlx_core::column::{Column, UStr};
 pub use sqlx_core::connection::{ConnectOptions, Connection};
 pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::describe::Describe;
 pub use sqlx_core::executor::{Execute, Executor};
 pub use sqlx_core::from_row::FromRow;
 pub use sqlx_core::pool::{self, Pool};
+pub use sqlx_core::query::{query_dynamic, query_dynamic_with};
+pub use sqlx_core::query_as::{query_as_dynamic, query_as_dynamic_with};
+pub use sqlx_core::query_scalar::{query_scalar_dynamic, query_scalar_dynamic_with};
+
+#[cfg(not(feature = "checked-queries"))]
 pub use sqlx_core::query::{query, query_with};
+#[cfg(not(feature = "checked-queries"))]
 pub use sqlx_core::query_as::{query_as, query_as_with};
+#[cfg(not(feature = "checked-queries"))]
 pub use sqlx_core::query_scalar::{query_scalar, query_scalar_with};
+
+#[cfg(feature = "checked-queries")]
+mod checked;
+
+#[cfg(feature = "checked-queries")]
+#[allow(deprecated)]
+pub use checked::{query, query_as, query_as_with, query_scalar, query_scalar_with, query_with};
 pub use sqlx_core::row::Row;
 pub use sqlx_core::statement::Statement;
-pub use sqlx_core::transaction::{Transaction, TransactionManager};
+pub use sqlx_core::transaction::{SavepointCommand, Transaction, TransactionManager};
 pub use sqlx_core::type_info::TypeInfo;
 pub use sqlx_core::types::Type;
 pub use sqlx_core::value::{Value, ValueRef};
@@ -37,6 +54,14 @@ pub use sqlx_core::error::{self, Error, Result};
 #[cfg(feature = "migrate")]
 pub use sqlx_core::migrate;
 
+#[cfg(feature = "fault-injection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fault-injection")))]
+pub use sqlx_core::fault;
+
+#[cfg(feature = "transcripts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "transcripts")))]
+pub use sqlx_core::transcript;
+
 #[cfg(all(
     any(
         feature = "mysql",
@@ -81,6 +106,13 @@ mod macros;
 #[doc(hidden)]
 pub mod ty_match;
 
+#[doc(hidden)]
+pub mod testing;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use sqlx_core::query_macros;
+
 /// Conversions between Rust and SQL types.
 ///
 /// To see how each SQL type maps to a Rust type, see the corresponding `types` module for each
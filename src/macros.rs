@@ -47,6 +47,20 @@
 ///     * Or, `sqlx-data.json` must exist at the workspace root. See [Offline Mode](#offline-mode)
 ///       below.
 ///
+///     * The invocations compiled by the same process share a pool of connections to the
+///       database, with a single connection by default. Set `SQLX_MACROS_MAX_CONNECTIONS` to
+///       allow more; connecting is retried for a while if the server has too many connections.
+///
+///     * If the database cannot be reached, e.g. in a CI environment without access to it, the
+///       macros fail to compile by default. Set `SQLX_DATABASE_UNREACHABLE` to `runtime` to
+///       fall back to `sqlx-data.json` if it exists, or else to defer the check of the queries:
+///       each query is described by the database before it is first executed, and fails with
+///       an error if its bind parameters, or the column of `query_scalar!()`, do not match the
+///       invocation. Set it to `unchecked` to not check the queries at all, with a warning for
+///       every invocation. In both modes, `query_as!()` requires the output type to implement
+///       `FromRow`, the output type of `query_scalar!()` is inferred, and `query!()` fails to
+///       compile, as its record type cannot be generated without the columns of the query.
+///
 /// * The query must be a string literal, or concatenation of string literals using `+` (useful
 /// for queries generated by macro), or else it cannot be introspected (and thus cannot be dynamic
 /// or the result of another macro).
@@ -272,6 +286,52 @@
 /// | `foo!: T` | Forced not-null | Overridden |
 /// | `foo?: T` | Forced nullable | Overridden |
 ///
+/// ## Type Overrides: Project Configuration
+/// Rather than overriding the same type in every query, a SQL type can be mapped to a Rust type
+/// for all the macro invocations of a crate with a `sqlx.toml` file next to its `Cargo.toml`:
+///
+/// ```toml
+/// [macros.type-overrides]
+/// # keys are SQL type names, matched case-insensitively
+/// timestamptz = "time::OffsetDateTime"
+/// numeric = "rust_decimal::Decimal"
+///
+/// # custom types must implement `sqlx::Type`, `Encode` and `Decode`
+/// status = "crate::Status"
+/// ```
+///
+/// The configured type is used for both output columns and bind parameters of that SQL type.
+/// Nullability is still inferred and the overrides above take precedence over the configuration.
+///
+/// An override also applies to arrays of built-in types (e.g. `timestamptz[]` becomes
+/// `Vec<time::OffsetDateTime>`). Arrays of custom types in Postgres are named after their element
+/// type prefixed with an underscore and have to be mapped separately, e.g.
+/// `_status = "Vec<crate::Status>"`.
+///
+/// Note that Postgres reports columns of a domain type as its base type, so domains can only be
+/// mapped for bind parameters.
+///
+/// ## Strict Parameter Checking
+/// Only Postgres reports the types of bind parameters; with MySQL, MSSQL and SQLite, any value
+/// which can be encoded for the database is accepted. Setting `strict-params` in `sqlx.toml`
+/// instead requires each argument to be cast to its type, so the type of every parameter is
+/// spelled out in the invocation:
+///
+/// ```toml
+/// [macros]
+/// strict-params = true
+/// ```
+///
+/// ```rust,ignore
+/// // error: the type of param #1 cannot be checked against the database
+/// sqlx::query!("SELECT * FROM users WHERE id = ?", id)
+///
+/// sqlx::query!("SELECT * FROM users WHERE id = ?", id as i64)
+/// ```
+///
+/// Parameters are checked against the types reported by Postgres in either case, and the
+/// `_unchecked` variants of the macros are not affected.
+///
 /// ## Offline Mode (requires the `offline` feature)
 /// The macros can be configured to not require a live database connection for compilation,
 /// but it requires a couple extra steps:
@@ -292,6 +352,12 @@
 ///
 /// See [the README for `sqlx-cli`](https://crates.io/crate/sqlx-cli) for more information.
 ///
+/// With the `offline` feature, queries described against a live database are also cached in
+/// `target/sqlx/describe/`, keyed by `DATABASE_URL` and the query. The cache is invalidated
+/// whenever the schema of the database changes, so a clean build only has to ask the database
+/// for its schema version instead of describing every query again. Set
+/// `SQLX_DESCRIBE_CACHE=false` to disable it.
+///
 /// ## See Also
 /// * [query_as!] if you want to use a struct you can name,
 /// * [query_file!] if you want to define the SQL query out-of-line,
@@ -0,0 +1,28 @@
+use sqlx_core::database::Database;
+use sqlx_core::error::Error;
+use std::env;
+
+/// Returns the URL of the database used by the tests of `DB`: `DATABASE_URL`, or without it and
+/// with the `embedded-postgres` feature, the URL of a temporary server shared by the process for
+/// the tests of Postgres.
+pub fn database_url<DB: Database>() -> Result<String, Error> {
+    match env::var("DATABASE_URL") {
+        Ok(url) => Ok(url),
+
+        #[cfg(all(unix, feature = "embedded-postgres"))]
+        Err(env::VarError::NotPresent) if is_postgres::<DB>() => {
+            Ok(sqlx_core::postgres::PgEmbedded::shared()?.url())
+        }
+
+        Err(error) => Err(Error::Configuration(
+            format!("`DATABASE_URL` must be set to run the tests: {}", error).into(),
+        )),
+    }
+}
+
+#[cfg(all(unix, feature = "embedded-postgres"))]
+fn is_postgres<DB: Database>() -> bool {
+    use std::any::TypeId;
+
+    TypeId::of::<DB>() == TypeId::of::<sqlx_core::postgres::Postgres>()
+}
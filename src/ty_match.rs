@@ -1,4 +1,7 @@
 use std::marker::PhantomData;
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
 
 // These types allow the `query!()` and friends to compare a given parameter's type to
 // an expected parameter type even if the former is behind a reference or in `Option`.
@@ -114,10 +117,56 @@ impl<T> MatchBorrowExt for MatchBorrow<Option<T>, Option<&'_ &'_ T>> {
     type Matched = Option<T>;
 }
 
+// the `NonZero*` integers are accepted where their primitive integer is expected
+macro_rules! impl_match_borrow_non_zero {
+    ($($ty:ident: $primitive:ty),* $(,)?) => {$(
+        impl MatchBorrowExt for MatchBorrow<$primitive, $ty> {
+            type Matched = $primitive;
+        }
+
+        impl MatchBorrowExt for MatchBorrow<$primitive, &'_ $ty> {
+            type Matched = $primitive;
+        }
+
+        impl MatchBorrowExt for MatchBorrow<Option<$primitive>, Option<$ty>> {
+            type Matched = Option<$primitive>;
+        }
+
+        impl MatchBorrowExt for MatchBorrow<Option<$primitive>, Option<&'_ $ty>> {
+            type Matched = Option<$primitive>;
+        }
+    )*};
+}
+
+impl_match_borrow_non_zero!(
+    NonZeroI8: i8,
+    NonZeroI16: i16,
+    NonZeroI32: i32,
+    NonZeroI64: i64,
+    NonZeroU8: u8,
+    NonZeroU16: u16,
+    NonZeroU32: u32,
+    NonZeroU64: u64,
+);
+
 impl<T, U> MatchBorrowExt for &'_ MatchBorrow<T, U> {
     type Matched = U;
 }
 
+// The type of the columns of a pseudo-type (e.g. `anyarray`), decoded as text.
+#[deprecated(
+    note = "a column of a pseudo-type (e.g. `anyarray`) is decoded as a `String`; \
+            cast it in the query, or override its type, to decode it as another type"
+)]
+pub type PseudoTypeText = String;
+
+// Called by the queries expanded without being checked, as the database was unreachable.
+#[deprecated(
+    note = "the query was not checked as the database was unreachable at compile time \
+            (`SQLX_DATABASE_UNREACHABLE=unchecked`)"
+)]
+pub fn unchecked_query() {}
+
 pub fn conjure_value<T>() -> T {
     panic!()
 }
@@ -160,5 +209,12 @@ fn test_match_borrow() {
 
         let (_, match_borrow) = MatchBorrow::new(0i64, &0i64);
         let _: i64 = match_borrow.match_borrow();
+
+        let (_, match_borrow) = MatchBorrow::new(0i64, &NonZeroI64::new(1).unwrap());
+        let _: i64 = match_borrow.match_borrow();
+
+        let id = NonZeroI32::new(1).unwrap();
+        let (_, match_borrow) = MatchBorrow::new(Some(0i32), &Some(&id));
+        let _: Option<i32> = match_borrow.match_borrow();
     }
 }
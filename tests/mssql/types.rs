@@ -41,3 +41,25 @@ test_type!(bool(
     "CAST(1 as BIT)" == true,
     "CAST(0 as BIT)" == false
 ));
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use super::*;
+    use sqlx::types::chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    test_type!(chrono_datetime<NaiveDateTime>(Mssql,
+        "CAST('2019-01-02 05:10:20' AS DATETIME)" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20),
+        "CAST('2019-01-02 05:10:20.003' AS DATETIME)"
+            == NaiveDate::from_ymd(2019, 1, 2).and_hms_nano(5, 10, 20, 3_333_333),
+        "CAST('1753-01-01 00:00:00' AS DATETIME)" == NaiveDate::from_ymd(1753, 1, 1).and_hms(0, 0, 0),
+    ));
+
+    test_type!(chrono_smalldatetime<NaiveDateTime>(Mssql,
+        "CAST('2019-01-02 05:10:00' AS SMALLDATETIME)" == NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 0),
+    ));
+
+    test_type!(chrono_time<NaiveTime>(Mssql,
+        "CAST('05:10:20.1151234' AS TIME)" == NaiveTime::from_hms_nano(5, 10, 20, 115_123_400),
+        "CAST('00:00:00' AS TIME)" == NaiveTime::from_hms(0, 0, 0),
+    ));
+}
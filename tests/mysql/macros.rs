@@ -146,6 +146,21 @@ async fn test_query_as_bool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_query_infers_bool_for_tinyint_1() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+    let mut conn = with_test_row(&mut conn).await?;
+
+    let record = sqlx::query!("select is_sent from tweet")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let is_sent: bool = record.is_sent;
+    assert!(is_sent);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_query_bytes() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -1,5 +1,7 @@
 use futures::TryStreamExt;
-use sqlx::mysql::{MySql, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow};
+use sqlx::mysql::{
+    MySql, MySqlConnectOptions, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow,
+};
 use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
@@ -387,3 +389,71 @@ async fn test_issue_622() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_sends_query_attributes() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let res =
+        sqlx::query_scalar::<_, Option<String>>("SELECT mysql_query_attribute_string('trace_id')")
+            .attribute("trace_id", "abc")
+            .fetch_one(&mut conn)
+            .await;
+
+    let trace_id = match res {
+        Ok(trace_id) => trace_id,
+
+        // query attributes require MySQL 8.0.23+ with the `query_attributes` component
+        Err(sqlx::Error::Configuration(_)) => return Ok(()),
+        Err(sqlx::Error::Database(err)) if err.code().as_deref() == Some("42000") => return Ok(()),
+
+        Err(err) => return Err(err.into()),
+    };
+
+    assert_eq!(trace_id.as_deref(), Some("abc"));
+
+    // the attributes only apply to their query
+    let trace_id: Option<String> =
+        sqlx::query_scalar("SELECT mysql_query_attribute_string('trace_id')")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(trace_id, None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_accesses_columns_of_the_same_name_by_table() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: MySqlConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = MySqlConnection::connect_with(&options.strict_column_names(true)).await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE join_parent (id INT, name TEXT);
+CREATE TEMPORARY TABLE join_child (id INT, parent_id INT);
+
+INSERT INTO join_parent VALUES (1, 'parent');
+INSERT INTO join_child VALUES (2, 1);
+        "#,
+    )
+    .await?;
+
+    let row =
+        sqlx::query("SELECT p.*, c.* FROM join_parent p JOIN join_child c ON p.id = c.parent_id")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(row.try_get::<i32, _>(("p", "id"))?, 1);
+    assert_eq!(row.try_get::<i32, _>(("c", "id"))?, 2);
+    assert_eq!(row.try_get::<&str, _>("name")?, "parent");
+
+    assert!(matches!(
+        row.try_get::<i32, _>("id"),
+        Err(sqlx::Error::ColumnAmbiguous(_))
+    ));
+
+    Ok(())
+}
@@ -4,5 +4,6 @@ CREATE TABLE tweet
     id         BIGINT PRIMARY KEY AUTO_INCREMENT,
     created_at TIMESTAMP NOT NULL DEFAULT NOW(),
     text       TEXT      NOT NULL,
-    owner_id   BIGINT
+    owner_id   BIGINT,
+    is_sent    BOOLEAN   NOT NULL DEFAULT TRUE
 );
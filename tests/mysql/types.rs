@@ -5,10 +5,16 @@ use std::str::FromStr;
 
 use sqlx::mysql::MySql;
 use sqlx::{Executor, Row};
-use sqlx_test::{new, test_type};
+use sqlx_test::{new, test_decode_type, test_type};
 
 test_type!(bool(MySql, "false" == false, "true" == true));
 
+test_decode_type!(sentinel_as_none_string<sqlx::types::SentinelAsNone<String>>(MySql,
+    "''" == sqlx::types::SentinelAsNone(None),
+    "NULL" == sqlx::types::SentinelAsNone(None),
+    "'sqlx'" == sqlx::types::SentinelAsNone(Some("sqlx".to_owned())),
+));
+
 test_type!(u8(MySql, "CAST(253 AS UNSIGNED)" == 253_u8));
 test_type!(i8(MySql, "5" == 5_i8, "0" == 0_i8));
 
@@ -123,6 +129,74 @@ mod chrono {
         assert_eq!(val, None);
         assert!(row.try_get::<NaiveDateTime, _>(0).is_err());
 
+        // text
+
+        let row = conn.fetch_one("SELECT DATE '0000-00-00'").await?;
+        let val: Option<NaiveDate> = row.get(0);
+
+        assert_eq!(val, None);
+
+        Ok(())
+    }
+
+    #[sqlx_macros::test]
+    async fn test_type_chrono_zero_date_mode() -> anyhow::Result<()> {
+        use sqlx::mysql::{MySqlConnectOptions, MySqlZeroDateMode};
+        use sqlx::ConnectOptions;
+
+        let options: MySqlConnectOptions = std::env::var("DATABASE_URL")?.parse()?;
+
+        // error
+
+        let mut conn = options
+            .clone()
+            .zero_date_mode(MySqlZeroDateMode::Error)
+            .connect()
+            .await?;
+
+        conn.execute("SET @@sql_mode := REPLACE(@@sql_mode, 'NO_ZERO_IN_DATE', '');")
+            .await?;
+
+        conn.execute("SET @@sql_mode := REPLACE(@@sql_mode, 'NO_ZERO_DATE', '');")
+            .await?;
+
+        let row = sqlx::query("SELECT DATE '0000-00-00'")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(row.try_get::<Option<NaiveDate>, _>(0).is_err());
+
+        // replace
+
+        let mut conn = options
+            .zero_date_mode(MySqlZeroDateMode::Replace {
+                year: 1970,
+                month: 1,
+                day: 1,
+            })
+            .connect()
+            .await?;
+
+        conn.execute("SET @@sql_mode := REPLACE(@@sql_mode, 'NO_ZERO_IN_DATE', '');")
+            .await?;
+
+        conn.execute("SET @@sql_mode := REPLACE(@@sql_mode, 'NO_ZERO_DATE', '');")
+            .await?;
+
+        let row = sqlx::query("SELECT DATE '0000-00-00', TIMESTAMP '0000-00-00 10:20:30'")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(row.get::<NaiveDate, _>(0), NaiveDate::from_ymd(1970, 1, 1));
+        assert_eq!(
+            row.get::<NaiveDateTime, _>(1),
+            NaiveDate::from_ymd(1970, 1, 1).and_hms(10, 20, 30)
+        );
+
+        let row = conn.fetch_one("SELECT DATE '2020-00-15'").await?;
+
+        assert_eq!(row.get::<NaiveDate, _>(0), NaiveDate::from_ymd(1970, 1, 1));
+
         Ok(())
     }
 }
@@ -307,5 +381,16 @@ CREATE TEMPORARY TABLE with_bits (
     assert_eq!(v1, 1);
     assert_eq!(vn, 510202);
 
+    // BIT(1) as bool
+    let v1: bool = sqlx::query_scalar("SELECT value_1 FROM with_bits")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(v1);
+
+    let v1: bool = row.try_get(0)?;
+
+    assert!(v1);
+
     Ok(())
 }
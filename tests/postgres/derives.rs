@@ -100,16 +100,56 @@ enum Mood {
     Sad,
 }
 
+// Defined in setup.sql, unlike `mood` this type is never dropped by a test
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "status", pg_array)]
+#[sqlx(rename_all = "lowercase")]
+enum Status {
+    New,
+    Open,
+    Closed,
+}
+
+// The same type, qualified by its schema; its array type is `public._status`
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "public.status", pg_array)]
+#[sqlx(rename_all = "lowercase")]
+enum PublicStatus {
+    New,
+    Open,
+    Closed,
+}
+
+// An older version of `status`, decoding the labels added since to `Unknown`
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "status")]
+#[sqlx(rename_all = "lowercase")]
+enum StatusV1 {
+    New,
+    Open,
+    #[sqlx(other)]
+    Unknown,
+}
+
 // Records must map to a custom type
 // Note that all types are types in Postgres
 #[derive(PartialEq, Debug, sqlx::Type)]
-#[sqlx(type_name = "inventory_item")]
+#[sqlx(type_name = "inventory_item", pg_array)]
 struct InventoryItem {
     name: String,
     supplier_id: Option<i32>,
     price: Option<i64>,
 }
 
+// Records may be sent in the text format, for types lacking binary send/receive functions
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(type_name = "inventory_item", text_io)]
+struct TextInventoryItem {
+    name: String,
+    supplier_id: Option<i32>,
+    price: Option<i64>,
+}
+
 // Custom range type
 #[derive(sqlx::Type, Debug, PartialEq)]
 #[sqlx(type_name = "float_range")]
@@ -360,6 +400,156 @@ SELECT $1 = ROW('fuzzy dice', 42, 199)::inventory_item, $1
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_enum_array_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let value = vec![Status::New, Status::Closed, Status::Open];
+
+    let rec: (bool, Vec<Status>) = sqlx::query_as(
+        "
+SELECT $1 = '{new,closed,open}'::status[], $1
+        ",
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_schema_qualified_enum_array_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let value = vec![PublicStatus::Open, PublicStatus::New];
+
+    let rec: (bool, Vec<PublicStatus>) = sqlx::query_as(
+        "
+SELECT $1 = '{open,new}'::public.status[], $1
+        ",
+    )
+    .bind(&value)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_enum_other_variant() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let statuses: Vec<StatusV1> =
+        sqlx::query_scalar("SELECT unnest('{new,open,closed}'::status[])")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        statuses,
+        vec![StatusV1::New, StatusV1::Open, StatusV1::Unknown]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_record_array_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let value = vec![
+        InventoryItem {
+            name: "fuzzy dice".to_owned(),
+            supplier_id: Some(42),
+            price: Some(199),
+        },
+        InventoryItem {
+            name: "dice".to_owned(),
+            supplier_id: None,
+            price: None,
+        },
+    ];
+
+    let rec: (bool, Vec<InventoryItem>) = sqlx::query_as(
+        "
+SELECT $1 = ARRAY[ROW('fuzzy dice', 42, 199), ROW('dice', NULL, NULL)]::inventory_item[], $1
+        ",
+    )
+    .bind(&value[..])
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_text_record_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let value = TextInventoryItem {
+        name: r#"fuzzy "dice", \ (large)"#.to_owned(),
+        supplier_id: None,
+        price: Some(-199),
+    };
+
+    let rec: (bool, TextInventoryItem) = sqlx::query_as(
+        r#"
+SELECT $1 = ROW('fuzzy "dice", \ (large)', NULL, -199)::inventory_item AND $2 = 5, $1
+        "#,
+    )
+    .bind(&value)
+    .bind(5_i32)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, value);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_text_record_type_with_unsupported_field() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // arrays have no text rendering in a text-format record
+    #[derive(sqlx::Type)]
+    #[sqlx(type_name = "inventory_item", text_io)]
+    struct ArrayInventoryItem {
+        name: String,
+        supplier_id: Vec<i32>,
+        price: Option<i64>,
+    }
+
+    let value = ArrayInventoryItem {
+        name: "fuzzy dice".to_owned(),
+        supplier_id: vec![42],
+        price: None,
+    };
+
+    let res = sqlx::query("SELECT $1::inventory_item")
+        .bind(&value)
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Encode(_))));
+
+    // the query was not sent, the connection is still usable
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[sqlx_macros::test]
 async fn test_from_row() -> anyhow::Result<()> {
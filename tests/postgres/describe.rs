@@ -93,3 +93,50 @@ async fn it_describes_composite() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_describes_many_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // an already prepared statement is described from the cache
+    conn.describe("SELECT 1::int8 + 10").await?;
+
+    let d = conn
+        .describe_all(&[
+            "SELECT * FROM tweet",
+            "SELECT 1::int8 + 10",
+            "DELETE FROM tweet WHERE id = $1",
+            "SELECT * FROM tweet",
+        ])
+        .await?;
+
+    assert_eq!(d.len(), 4);
+
+    assert_eq!(d[0].columns()[3].name(), "owner_id");
+    assert_eq!(d[0].nullable(0), Some(false));
+    assert_eq!(d[0].nullable(3), Some(true));
+
+    assert_eq!(d[1].columns()[0].type_info().name(), "INT8");
+    assert_eq!(d[1].nullable(0), None);
+
+    assert!(d[2].columns().is_empty());
+    assert_eq!(d[2].parameters().unwrap().left().unwrap()[0].name(), "INT8");
+
+    assert_eq!(d[3].columns().len(), 4);
+
+    // the connection is usable after an invalid statement
+    let err = conn
+        .describe_all(&["SELECT 1", "SELECT * FROM not_a_table", "SELECT 2"])
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.into_database_error().unwrap().code().as_deref(),
+        Some("42P01")
+    );
+
+    let d = conn.describe_all(&["SELECT 1", "SELECT 2::int2"]).await?;
+    assert_eq!(d[1].columns()[0].type_info().name(), "INT2");
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use sqlx::postgres::{PgEmbedded, Postgres};
+use sqlx::Connection;
+use sqlx_test::{new, pool, setup_if_needed};
+use std::env;
+
+// the only test of this binary, as the others could read the environment while it is modified
+#[sqlx_macros::test]
+async fn it_connects_to_the_embedded_server_without_database_url() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    env::remove_var("DATABASE_URL");
+
+    let mut conn = new::<Postgres>().await?;
+
+    let data_directory: String = sqlx::query_scalar("SHOW data_directory")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(data_directory.contains("sqlx-embedded-postgres-"));
+
+    conn.close().await?;
+
+    // the pool connects to the same server
+    let pool = pool::<Postgres>().await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT $1::int4 + 1")
+        .bind(41_i32)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    let server = PgEmbedded::shared()?;
+    assert_eq!(sqlx::testing::database_url::<Postgres>()?, server.url());
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use sqlx::fault::{FaultInjector, FaultPoint};
+use sqlx::postgres::{PgConnectOptions, PgConnection, PgListener, PgListenerEvent, PgPoolOptions};
+use sqlx::{ConnectOptions, Connection, Executor};
+use sqlx_test::setup_if_needed;
+use std::env;
+use std::io;
+use std::time::Duration;
+
+fn options(faults: &FaultInjector) -> anyhow::Result<PgConnectOptions> {
+    setup_if_needed();
+
+    Ok(env::var("DATABASE_URL")?
+        .parse::<PgConnectOptions>()?
+        .fault_injector(faults))
+}
+
+fn is_io_error(err: &sqlx::Error, kind: io::ErrorKind) -> bool {
+    matches!(err, sqlx::Error::Io(err) if err.kind() == kind)
+}
+
+#[sqlx_macros::test]
+async fn it_refuses_connections() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+    let options = options(&faults)?;
+
+    faults.refuse_connections(1);
+
+    let err = options.connect().await.unwrap_err();
+    assert!(is_io_error(&err, io::ErrorKind::ConnectionRefused));
+
+    // only the requested number of attempts is refused
+    let mut conn: PgConnection = options.connect().await?;
+    conn.ping().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_kills_connections_at_a_point_in_the_protocol() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+    let mut conn: PgConnection = options(&faults)?.connect().await?;
+
+    // the query is sent but the connection dies before the response is received
+    faults.kill_after(FaultPoint::Read, 0);
+
+    let err = conn.execute("SELECT 1").await.unwrap_err();
+    assert!(is_io_error(&err, io::ErrorKind::ConnectionReset));
+
+    // the connection stays dead
+    assert!(conn.ping().await.is_err());
+
+    // the fault only applies once
+    let mut conn: PgConnection = options(&faults)?.connect().await?;
+    conn.ping().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_kills_open_connections() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+    let options = options(&faults)?;
+
+    let mut a: PgConnection = options.connect().await?;
+    let mut b: PgConnection = options.connect().await?;
+
+    faults.kill_connections();
+
+    let mut c: PgConnection = options.connect().await?;
+
+    assert!(a.ping().await.is_err());
+    assert!(b.ping().await.is_err());
+    c.ping().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_delays_reads() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+    let mut conn: PgConnection = options(&faults)?.connect().await?;
+
+    faults.delay_reads(Some(Duration::from_secs(5)));
+
+    let res = sqlx_rt::timeout(Duration::from_millis(100), conn.execute("SELECT 1")).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_drops_writes() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+    let mut conn: PgConnection = options(&faults)?.connect().await?;
+
+    faults.drop_writes(true);
+
+    // the server never receives the query, so no response ever comes back
+    let res = sqlx_rt::timeout(Duration::from_millis(100), conn.execute("SELECT 1")).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_recovers_from_killed_connections_in_a_pool() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_with(options(&faults)?)
+        .await?;
+
+    pool.execute("SELECT 1").await?;
+
+    faults.kill_connections();
+
+    // the dead connection is detected when it is checked out and replaced
+    pool.execute("SELECT 1").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reconnects_listeners() -> anyhow::Result<()> {
+    let faults = FaultInjector::new();
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_with(options(&faults)?)
+        .await?;
+
+    let mut listener = PgListener::connect_with(&pool).await?;
+    listener.listen_all(vec!["fault_a", "fault_b"]).await?;
+
+    // not affected by the faults
+    let mut notifier: PgConnection = options(&FaultInjector::new())?.connect().await?;
+
+    faults.kill_connections();
+    faults.refuse_connections(2);
+
+    notifier.execute("NOTIFY fault_a, 'missed'").await?;
+
+    assert!(matches!(
+        listener.recv_event().await?,
+        PgListenerEvent::Reconnected
+    ));
+
+    // both channels are subscribed again
+    notifier.execute("NOTIFY fault_b, 'received'").await?;
+
+    match listener.recv_event().await? {
+        PgListenerEvent::Notification(notification) => {
+            assert_eq!(notification.channel(), "fault_b");
+            assert_eq!(notification.payload(), "received");
+        }
+
+        event => panic!("unexpected event: {:?}", event),
+    }
+
+    // `recv` reconnects transparently
+    faults.kill_connections();
+
+    let notify = async {
+        sqlx_rt::sleep(Duration::from_millis(200)).await;
+        notifier.execute("NOTIFY fault_a, 'again'").await
+    };
+
+    let (notification, notified) = futures::join!(listener.recv(), notify);
+    notified?;
+
+    assert_eq!(notification?.payload(), "again");
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use sqlx::{Connection, PgConnection, Postgres, Transaction};
+use sqlx::postgres::types::PgTid;
+use sqlx::{Connection, Executor, PgConnection, Postgres, Transaction};
 use sqlx_test::new;
 
 use futures::TryStreamExt;
@@ -370,6 +371,37 @@ async fn test_array_from_slice() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_ctid() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    sqlx::query!("INSERT INTO tweet (text) VALUES ('ctid 1'), ('ctid 2'), ('ctid 3')")
+        .execute(&mut tx)
+        .await?;
+
+    let ctids: Vec<PgTid> =
+        sqlx::query!("SELECT ctid FROM tweet WHERE text LIKE 'ctid %' ORDER BY ctid LIMIT 2")
+            .fetch_all(&mut tx)
+            .await?
+            .into_iter()
+            .map(|row| row.ctid)
+            .collect();
+
+    assert_eq!(ctids.len(), 2);
+    assert!(ctids[0] < ctids[1]);
+
+    let deleted = sqlx::query!("DELETE FROM tweet WHERE ctid = ANY($1)", &ctids[..])
+        .execute(&mut tx)
+        .await?;
+
+    assert_eq!(deleted.rows_affected(), 2);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn fetch_is_usable_issue_224() -> anyhow::Result<()> {
     // ensures that the stream returned by `query::Map::fetch()` is usable with `TryStreamExt`
@@ -417,6 +449,38 @@ async fn test_column_override_nullable() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_nullable_data_modifying_cte() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    // `text` is `NOT NULL` in `tweet` while `owner_id` is not
+    let record = sqlx::query!(
+        "WITH inserted AS (INSERT INTO tweet(text) VALUES ('#sqlx') RETURNING id, text, owner_id) \
+         SELECT id, text, owner_id FROM inserted"
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    let _: i64 = record.id;
+    assert_eq!(record.text, "#sqlx");
+    assert_eq!(record.owner_id, None);
+
+    // the inner half of an outer join is still nullable
+    let record = sqlx::query!(
+        "WITH deleted AS (DELETE FROM tweet RETURNING text) \
+         SELECT deleted.text FROM (VALUES (1)) foo(id) LEFT JOIN deleted ON false"
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    assert_eq!(record.text, None);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 async fn with_test_row<'a>(
     conn: &'a mut PgConnection,
 ) -> anyhow::Result<Transaction<'a, Postgres>> {
@@ -463,6 +527,45 @@ async fn test_column_override_wildcard() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_non_zero_ids() -> anyhow::Result<()> {
+    use std::num::NonZeroI64;
+
+    struct Tweet {
+        id: NonZeroI64,
+        owner_id: Option<NonZeroI64>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+    let mut conn = with_test_row(&mut conn).await?;
+
+    let id = NonZeroI64::new(1).unwrap();
+
+    let tweet = sqlx::query_as!(
+        Tweet,
+        r#"select id as "id: _", owner_id as "owner_id: _" from tweet where id = $1"#,
+        id
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(tweet.id, id);
+    assert_eq!(tweet.owner_id, Some(id));
+
+    let owner_id: Option<NonZeroI64> = tweet.owner_id;
+
+    let count = sqlx::query_scalar!(
+        r#"select count(*) as "count!" from tweet where owner_id = $1"#,
+        owner_id
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_column_override_wildcard_not_null() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -595,3 +698,114 @@ async fn test_bind_arg_override_wildcard() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+// mapped to the `configured_status` type by `sqlx.toml`
+#[derive(sqlx::Type, Debug, PartialEq)]
+#[sqlx(type_name = "configured_status", rename_all = "lowercase", pg_array)]
+enum ConfiguredStatus {
+    New,
+    Open,
+    Closed,
+}
+
+#[sqlx_macros::test]
+async fn test_configured_type_override() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let record = sqlx::query!(
+        r#"select $1::configured_status as "status!", array['new', 'closed']::configured_status[] as "statuses!""#,
+        ConfiguredStatus::Open
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(record.status, ConfiguredStatus::Open);
+    assert_eq!(
+        record.statuses,
+        vec![ConfiguredStatus::New, ConfiguredStatus::Closed]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pseudo_type_column() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE pseudo_type_test AS SELECT generate_series(1, 3) AS n;
+ANALYZE pseudo_type_test;
+        "#,
+    )
+    .await?;
+
+    // `histogram_bounds` is an `anyarray`, decoded as a `String` with a warning
+    #[allow(deprecated)]
+    let bounds = sqlx::query_scalar!(
+        "SELECT histogram_bounds FROM pg_stats WHERE tablename = $1 AND attname = $2",
+        "pseudo_type_test",
+        "n"
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(bounds.as_deref(), Some("{1,2,3}"));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_deferred_check() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // the expansion of `query_scalar!()` while the database is unreachable, in `runtime` mode
+    static CHECKED: AtomicBool = AtomicBool::new(false);
+    static MISMATCHED: AtomicBool = AtomicBool::new(false);
+
+    let mut conn = new::<Postgres>().await?;
+
+    let value: i32 = sqlx::query_macros::query_scalar_deferred(
+        "SELECT $1::int4",
+        sqlx::query_macros::bind_args::<Postgres, _>((&1_i32,)),
+        &CHECKED,
+        1,
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(value, 1);
+    assert!(CHECKED.load(Ordering::Acquire));
+
+    // the column is checked even if there is no row to decode
+    let res: Result<Option<String>, _> = sqlx::query_macros::query_scalar_deferred(
+        "SELECT $1::int4 WHERE false",
+        sqlx::query_macros::bind_args::<Postgres, _>((&1_i32,)),
+        &MISMATCHED,
+        1,
+    )
+    .fetch_optional(&mut conn)
+    .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+    assert!(!MISMATCHED.load(Ordering::Acquire));
+
+    let res = sqlx::query_macros::query_scalar_deferred::<Postgres, i32, _>(
+        "SELECT $1::int4, $2::int4",
+        sqlx::query_macros::bind_args::<Postgres, _>((&1_i32,)),
+        &MISMATCHED,
+        1,
+    )
+    .fetch_one(&mut conn)
+    .await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::BindParameterCount {
+            expected: 2,
+            actual: 1
+        })
+    ));
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use futures::TryStreamExt;
 use sqlx::postgres::{
-    PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgSeverity,
+    PgAdvisoryLock, PgConnectOptions, PgConnection, PgDatabaseError, PgDialect, PgErrorPosition,
+    PgIsolationLevel, PgNotice, PgReplicationConnection, PgReplicationEvent, PgSeverity, PgSslMode,
+    PgTargetSessionAttrs, PgTransactionOptions, PgTransactionStatus,
 };
-use sqlx::postgres::{PgPoolOptions, PgRow, Postgres};
-use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
+use sqlx::postgres::{PgPipeline, PgPoolOptions, PgRow, Postgres};
+use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo, UStr};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
@@ -59,6 +63,102 @@ async fn it_maths() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_fetches_as_with_the_executor() -> anyhow::Result<()> {
+    use sqlx::postgres::PgArguments;
+    use sqlx::Arguments;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut args = PgArguments::default();
+    args.add(5_i32);
+
+    let (value, name): (i32, String) = conn
+        .fetch_one_as("select 1 + $1::int, 'five'", args)
+        .await?;
+
+    assert_eq!(value, 6);
+    assert_eq!(name, "five");
+
+    let mut args = PgArguments::default();
+    args.add(3_i32);
+
+    let values: Vec<(i32,)> = conn
+        .fetch_all_as("select * from generate_series(1, $1)", args)
+        .await?;
+
+    assert_eq!(values, vec![(1,), (2,), (3,)]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reuses_and_appends_arguments() -> anyhow::Result<()> {
+    use sqlx::postgres::PgArguments;
+    use sqlx::Arguments;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut args = PgArguments::default();
+    args.add(5_i32);
+    args.add(Option::<String>::None);
+
+    let mut filter = PgArguments::default();
+    filter.add("text");
+    filter.add(vec![1_i64, 2]);
+
+    args.append(filter);
+
+    let sql = "select $1 + 1, $2::text, $3, $4::int8[]";
+
+    for _ in 0..2 {
+        let (value, null, text, array): (i32, Option<String>, String, Vec<i64>) =
+            sqlx::query_as_with(sql, args.clone())
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(value, 6);
+        assert_eq!(null, None);
+        assert_eq!(text, "text");
+        assert_eq!(array, vec![1, 2]);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_the_values_of_an_iterator() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let values: Vec<i32> = sqlx::query_scalar(
+        "SELECT * FROM generate_series(1, 10) AS value WHERE value >= $1 AND value IN ($2, $3, $4)",
+    )
+    .bind(3_i32)
+    .bind_iter(vec![2_i32, 4, 6])
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(values, vec![4, 6]);
+
+    let res = sqlx::query("SELECT $1::int4 IN ($2, $3)")
+        .bind_iter(&[1_i32, 2])
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::BindParameterCount {
+            expected: 3,
+            actual: 2
+        })
+    ));
+
+    // the connection is still usable
+    conn.ping().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_inspect_errors() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -116,6 +216,10 @@ async fn it_can_inspect_constraint_errors() -> anyhow::Result<()> {
     assert_eq!(err.position(), None);
     assert_eq!(err.routine(), Some("ExecConstraints"));
     assert_eq!(err.constraint(), Some("products_price_check"));
+    assert_eq!(err.schema(), Some("public"));
+    assert_eq!(err.table(), Some("products"));
+    assert!(err.is_check_violation());
+    assert!(!err.is_unique_violation());
 
     Ok(())
 }
@@ -306,6 +410,60 @@ async fn it_can_fail_and_recover_with_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_executes_batches() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let results: Vec<_> = conn
+        .execute_batch(
+            "CREATE TEMPORARY TABLE batch (id INT); \
+             INSERT INTO batch VALUES (1), (2); \
+             SELECT id::text FROM batch ORDER BY id; \
+             SELECT 'a;b'",
+        )
+        .try_collect()
+        .await?;
+
+    let summary: Vec<_> = results
+        .iter()
+        .map(|(statement, result)| {
+            let result = result.as_ref().either(
+                |done| format!("done {}", done.rows_affected()),
+                |row| format!("row {}", row.get::<String, _>(0)),
+            );
+
+            (*statement, result)
+        })
+        .collect();
+
+    assert_eq!(
+        summary,
+        [
+            (0, "done 0".to_owned()),
+            (1, "done 2".to_owned()),
+            (2, "row 1".to_owned()),
+            (2, "row 2".to_owned()),
+            (2, "done 2".to_owned()),
+            (3, "row a;b".to_owned()),
+            (3, "done 1".to_owned()),
+        ]
+    );
+
+    // a failed statement rolls back the statements before it
+    let res: Result<Vec<_>, _> = conn
+        .execute_batch("INSERT INTO batch VALUES (3); SELECT 1 / 0")
+        .try_collect()
+        .await;
+    assert!(res.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM batch")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_query_scalar() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -468,6 +626,60 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_work_with_named_savepoints() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn.begin().await?;
+
+    tx.execute("CREATE TEMPORARY TABLE named_savepoints (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    // a failed statement is rolled back to the savepoint, which is then kept
+    let mut savepoint = tx.savepoint("attempt").await?;
+
+    savepoint
+        .execute("INSERT INTO named_savepoints (id) VALUES (1)")
+        .await?;
+
+    assert!(savepoint
+        .execute("INSERT INTO named_savepoints (id) VALUES (1)")
+        .await
+        .is_err());
+
+    savepoint.rollback_to("attempt").await?;
+
+    savepoint
+        .execute("INSERT INTO named_savepoints (id) VALUES (2)")
+        .await?;
+
+    savepoint.commit().await?;
+
+    // the savepoint is rolled back when it is dropped
+    {
+        let mut savepoint = tx.savepoint("dropped").await?;
+
+        savepoint
+            .execute("INSERT INTO named_savepoints (id) VALUES (3)")
+            .await?;
+    }
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM named_savepoints ORDER BY id")
+        .fetch_all(&mut tx)
+        .await?;
+
+    assert_eq!(ids, vec![2]);
+
+    // a savepoint established by name, and released by name
+    tx.execute(r#"SAVEPOINT "manual""#).await?;
+    tx.release("manual").await?;
+    assert!(tx.rollback_to("manual").await.is_err());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -705,6 +917,53 @@ async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_pins_and_uncaches_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.set_statement_cache_capacity(1).await?;
+    conn.pin_statement("SELECT 1::int4").await?;
+
+    // the pinned statement is not evicted by the other statements
+    for i in 0..3 {
+        let val: i32 = sqlx::query_scalar(&*format!("SELECT {}::int4", i + 2))
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(i + 2, val);
+
+        let val: i32 = sqlx::query_scalar("SELECT 1::int4")
+            .fetch_one(&mut conn)
+            .await?;
+        assert_eq!(1, val);
+    }
+
+    let stats = conn.statement_cache_stats();
+    assert_eq!(2, stats.len);
+    assert_eq!(1, stats.pinned);
+    assert_eq!(1, stats.capacity);
+    assert_eq!(3, stats.hits);
+    assert_eq!(4, stats.misses);
+
+    assert!(conn.uncache_statement("SELECT 4::int4").await?);
+    assert!(!conn.uncache_statement("SELECT 4::int4").await?);
+    assert_eq!(1, conn.statement_cache_stats().len);
+
+    assert!(conn.unpin_statement("SELECT 1::int4").await?);
+    assert!(!conn.unpin_statement("SELECT 1::int4").await?);
+    assert_eq!(0, conn.statement_cache_stats().pinned);
+
+    conn.pin_statement("SELECT 1::int4").await?;
+    conn.clear_cached_statements().await?;
+    assert_eq!(0, conn.statement_cache_stats().len);
+
+    let val: i32 = sqlx::query_scalar("SELECT 1::int4")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(1, val);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_sets_application_name() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
@@ -725,6 +984,247 @@ async fn it_sets_application_name() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let version_num: String = sqlx::query_scalar("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(conn.server_version_num(), Some(version_num.parse()?));
+
+    // floats are sent with full precision whatever the version
+    let value: String = sqlx::query_scalar("SELECT 0.1::float8::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, "0.1");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rejects_servers_older_than_the_minimum_version() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    let mut conn = PgConnection::connect_with(&options.clone().min_server_version(90000)).await?;
+    conn.ping().await?;
+
+    let res = PgConnection::connect_with(&options.min_server_version(990000)).await;
+
+    assert!(matches!(res, Err(sqlx::Error::Configuration(_))));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_applies_tcp_timeouts() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    let mut conn = PgConnection::connect_with(
+        &options
+            .clone()
+            .tcp_keepalive(Duration::from_secs(60))
+            .tcp_user_timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5)),
+    )
+    .await?;
+
+    conn.ping().await?;
+
+    // the listener completes the TCP handshake but never answers the startup message
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    let res = PgConnection::connect_with(
+        &options
+            .host("127.0.0.1")
+            .port(port)
+            .ssl_mode(PgSslMode::Disable)
+            .connect_timeout(Duration::from_millis(200)),
+    )
+    .await;
+
+    assert!(
+        matches!(res, Err(sqlx::Error::Io(ref err)) if err.kind() == std::io::ErrorKind::TimedOut)
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_skips_statement_names_taken_by_the_user() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let mut conn = PgConnection::connect_with(&options.statement_name_prefix("App_s_")).await?;
+
+    // the names SQLx would give to its first statements
+    conn.execute(r#"PREPARE "App_s_1" AS SELECT 'user'; PREPARE "App_s_2" AS SELECT 'user'"#)
+        .await?;
+
+    let value: String = sqlx::query_scalar("SELECT $1::text")
+        .bind("sqlx")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, "sqlx");
+
+    // the nullability is still inferred from the statements prepared with the prefix
+    let sql = "SELECT t2.text FROM tweet t1 LEFT JOIN tweet t2 ON false";
+    assert_eq!(conn.describe(sql).await?.nullable(0), Some(true));
+
+    assert_eq!(
+        conn.user_prepared_statements().await?,
+        vec!["App_s_1", "App_s_2"]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_skips_statement_names_taken_by_the_user_in_a_transaction() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let mut conn = PgConnection::connect_with(&options.statement_name_prefix("App_s_")).await?;
+
+    conn.execute(r#"PREPARE "App_s_1" AS SELECT 'user'; PREPARE "App_s_3" AS SELECT 'user'"#)
+        .await?;
+
+    // outside of a transaction block, the first name is found taken and the statement is
+    // prepared again with the next name
+    let value: i32 = sqlx::query_scalar("SELECT $1::int4")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    // in a transaction block, where a name which is taken would abort the transaction, the
+    // names which are taken are skipped
+    conn.execute("BEGIN").await?;
+
+    for i in 0..3_i32 {
+        let value: i32 = sqlx::query_scalar(&format!("SELECT $1::int4 + {}", i))
+            .bind(1_i32)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, 1 + i);
+    }
+
+    let describes = conn
+        .describe_all(&["SELECT 1::int4", "SELECT 2::int8"])
+        .await?;
+
+    assert_eq!(describes.len(), 2);
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Transaction);
+
+    conn.execute("COMMIT").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_statements_prepared_by_the_user() -> anyhow::Result<()> {
+    use sqlx::postgres::PgArguments;
+    use sqlx::Arguments;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("PREPARE add_one (int4) AS SELECT $1 + 1 AS value")
+        .await?;
+
+    let mut arguments = PgArguments::default();
+    arguments.add(41_i32);
+
+    let results: Vec<_> = conn
+        .execute_prepared("add_one", arguments)
+        .try_collect()
+        .await?;
+
+    assert_eq!(results.len(), 2);
+
+    let row = results[0].as_ref().right().unwrap();
+    assert_eq!(row.try_get::<i32, _>("value")?, 42);
+
+    let result = results[1].as_ref().left().unwrap();
+    assert_eq!(result.rows_affected(), 1);
+
+    // an unknown statement fails, and the connection is still usable
+    let err = conn
+        .execute_prepared("not_prepared", PgArguments::default())
+        .try_next()
+        .await
+        .err()
+        .and_then(sqlx::Error::into_database_error)
+        .unwrap();
+
+    assert_eq!(err.code().as_deref(), Some("26000"));
+
+    conn.ping().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_detects_and_forces_the_dialect() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    // the nullability of a column of the inner side of an outer join is inferred from EXPLAIN
+    let sql = "SELECT t2.text FROM tweet t1 LEFT JOIN tweet t2 ON false";
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+    assert_eq!(conn.dialect(), PgDialect::Postgres);
+    assert_eq!(conn.describe(sql).await?.nullable(0), Some(true));
+
+    // only the catalog is used in the CockroachDB dialect
+    let mut conn = PgConnection::connect_with(&options.dialect(PgDialect::CockroachDb)).await?;
+    assert_eq!(conn.dialect(), PgDialect::CockroachDb);
+    assert_eq!(conn.describe(sql).await?.nullable(0), Some(false));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_connects_to_the_first_host_matching_target_session_attrs() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    // the test server is a primary; nothing listens on the port of the fallback host
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let options = options.fallback_host("127.0.0.1", 1);
+
+    for attrs in &[
+        PgTargetSessionAttrs::Any,
+        PgTargetSessionAttrs::ReadWrite,
+        PgTargetSessionAttrs::Primary,
+        PgTargetSessionAttrs::PreferStandby,
+    ] {
+        let mut conn =
+            PgConnection::connect_with(&options.clone().target_session_attrs(*attrs)).await?;
+        conn.ping().await?;
+    }
+
+    for attrs in &[
+        PgTargetSessionAttrs::ReadOnly,
+        PgTargetSessionAttrs::Standby,
+    ] {
+        let res = PgConnection::connect_with(&options.clone().target_session_attrs(*attrs)).await;
+
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()> {
     new::<Postgres>().await?.execute("SET NAMES 'UTF8'").await?;
@@ -887,3 +1387,1755 @@ from (values (null)) vals(val)
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_fails_to_bind_out_of_range_unsigned_integers() -> anyhow::Result<()> {
+    use sqlx::postgres::types::{PgU32, PgU64};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query("SELECT $1::int8")
+        .bind(PgU64(u64::MAX))
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Encode(_))));
+
+    let res = sqlx::query("SELECT $1::int4")
+        .bind(PgU32(i32::MAX as u32 + 1))
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Encode(_))));
+
+    // the connection is still usable afterwards
+    let value: PgU64 = sqlx::query_scalar("SELECT $1::int8")
+        .bind(PgU64(i64::MAX as u64))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, PgU64(i64::MAX as u64));
+
+    let res = sqlx::query_scalar::<_, PgU32>("SELECT -1::int4")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_to_decode_out_of_range_128_bit_integers() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for query in [
+        "SELECT 170141183460469231731687303715884105728::numeric",
+        "SELECT 1.5::numeric",
+        "SELECT 'NaN'::numeric",
+    ]
+    .iter()
+    {
+        let res = sqlx::query_scalar::<_, i128>(query)
+            .fetch_one(&mut conn)
+            .await;
+
+        assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+        let res = conn.fetch_one(*query).await?.try_get::<i128, _>(0);
+
+        assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+    }
+
+    let res = sqlx::query_scalar::<_, u128>("SELECT -1::numeric")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_to_bind_too_many_arguments() -> anyhow::Result<()> {
+    use sqlx::error::Limit;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut query = sqlx::query("SELECT $1::int4");
+
+    for i in 0..=u16::MAX as i32 {
+        query = query.bind(i);
+    }
+
+    let res = query.execute(&mut conn).await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::LimitExceeded {
+            limit: Limit::BindParameters,
+            max: 65535,
+            actual: 65536,
+        })
+    ));
+
+    // nothing was sent, so the connection is still usable
+    let value: i32 = sqlx::query_scalar("SELECT $1::int4")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_splits_a_bulk_statement_exceeding_the_bind_parameters_limit() -> anyhow::Result<()> {
+    use sqlx::postgres::PgArguments;
+    use sqlx::Arguments;
+
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    tx.execute("CREATE TEMPORARY TABLE bulk (a INT4, b INT4, c INT4)")
+        .await?;
+
+    // 90 000 arguments, more than can be bound to a single statement
+    let rows: Vec<i32> = (0..30_000).collect();
+    let mut statements = 0;
+
+    let done = tx
+        .execute_chunked(&rows, |rows| {
+            statements += 1;
+
+            let mut sql = String::from("INSERT INTO bulk (a, b, c) VALUES ");
+            let mut arguments = PgArguments::default();
+
+            for (i, &row) in rows.iter().enumerate() {
+                if i > 0 {
+                    sql.push_str(", ");
+                }
+
+                sql.push_str(&format!("(${}, ${}, ${})", 3 * i + 1, 3 * i + 2, 3 * i + 3));
+
+                arguments.add(row);
+                arguments.add(row * 2);
+                arguments.add(row * 3);
+            }
+
+            (sql, arguments)
+        })
+        .await?;
+
+    // built for all of the rows, then for the 21 845 rows which fit and the rest
+    assert_eq!(statements, 3);
+    assert_eq!(done.rows_affected(), 30_000);
+
+    let (count, sum): (i64, i64) = sqlx::query_as("SELECT COUNT(*), SUM(c)::int8 FROM bulk")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(count, 30_000);
+    assert_eq!(sum, 3 * (0..30_000_i64).sum::<i64>());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_dry_run_a_statement() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let count = "SELECT COUNT(*) FROM tweet";
+    let before: i64 = sqlx::query_scalar(count).fetch_one(&mut conn).await?;
+
+    let describe = conn
+        .dry_run("INSERT INTO tweet ( text, owner_id ) VALUES ( $1, $2 ) RETURNING id")
+        .await?;
+
+    let params = describe.parameters().unwrap().left().unwrap();
+
+    assert_eq!(params.len(), 2);
+    assert_eq!(params[0].name(), "TEXT");
+    assert_eq!(params[1].name(), "INT8");
+
+    assert_eq!(describe.columns().len(), 1);
+    assert_eq!(describe.column(0).name(), "id");
+
+    // nothing was inserted
+    let after: i64 = sqlx::query_scalar(count).fetch_one(&mut conn).await?;
+    assert_eq!(after, before);
+
+    // errors are still surfaced, and the connection remains usable afterwards
+    assert!(conn.dry_run("DELETE FROM not_a_table").await.is_err());
+
+    let after: i64 = sqlx::query_scalar(count).fetch_one(&mut conn).await?;
+    assert_eq!(after, before);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_to_decode_zero_into_non_zero_integers() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query_scalar::<_, std::num::NonZeroI32>("SELECT 0::int4")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_stream_bytea() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgByteaReader;
+    use sqlx_rt::AsyncReadExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let expected: Vec<u8> = (0..1_000_000_u32).map(|i| i as u8).collect();
+
+    // prepared queries use the binary format
+    let row = sqlx::query("SELECT $1::bytea")
+        .bind(&expected)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let mut reader: PgByteaReader = row.try_get(0)?;
+    assert_eq!(reader.len(), expected.len());
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).await?;
+
+    assert_eq!(data, expected);
+    assert_eq!(reader.remaining(), 0);
+
+    // simple queries use the text format
+    let row = conn.fetch_one("SELECT '\\x00ff10'::bytea").await?;
+    let mut reader: PgByteaReader = row.try_get(0)?;
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).await?;
+
+    assert_eq!(data, [0x00, 0xff, 0x10]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_and_writes_large_objects() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgLargeObject, PgLargeObjectMode};
+    use sqlx_rt::{AsyncReadExt, AsyncWriteExt};
+    use std::io::SeekFrom;
+
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    let oid = PgLargeObject::create(&mut tx).await?;
+    let expected: Vec<u8> = (0..3_000_000_u32).map(|i| i as u8).collect();
+
+    let mut object = PgLargeObject::open(&mut tx, oid, PgLargeObjectMode::Write).await?;
+    object.write_all(&expected).await?;
+    object.flush().await?;
+    assert_eq!(object.tell().await?, expected.len() as u64);
+    object.close().await?;
+
+    let mut object = PgLargeObject::open(&mut tx, oid, PgLargeObjectMode::ReadWrite).await?;
+
+    let mut data = Vec::new();
+    object.read_to_end(&mut data).await?;
+    assert!(data == expected);
+
+    // the bytes read ahead are not counted in the position
+    object.seek(SeekFrom::Start(10)).await?;
+
+    let mut buf = [0; 4];
+    object.read_exact(&mut buf).await?;
+    assert_eq!(buf, [10, 11, 12, 13]);
+    assert_eq!(object.tell().await?, 14);
+
+    object.write_chunk(&[0xff; 2]).await?;
+    assert_eq!(object.seek(SeekFrom::Current(-3)).await?, 13);
+    assert_eq!(object.read_chunk(4).await?, [13, 0xff, 0xff, 16]);
+
+    object.truncate(100).await?;
+    assert_eq!(object.seek(SeekFrom::End(0)).await?, 100);
+    assert!(object.read_chunk(10).await?.is_empty());
+
+    object.close().await?;
+
+    PgLargeObject::unlink(&mut tx, oid).await?;
+    assert!(PgLargeObject::open(&mut tx, oid, PgLargeObjectMode::Read)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_types_registered_at_runtime() -> anyhow::Result<()> {
+    use sqlx::types::dynamic::{self, DynamicValue};
+
+    #[derive(Debug, PartialEq, sqlx::Type)]
+    #[sqlx(type_name = "status", rename_all = "lowercase")]
+    enum Status {
+        New,
+        Open,
+        Closed,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let row = sqlx::query("SELECT 'open'::status, NULL::status, 1::int8")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.try_get_dynamic(0).is_err());
+
+    dynamic::register_type::<Postgres, Status>("status");
+    dynamic::register_decode_fn::<Postgres>("INT8", |value| {
+        let value: i64 = sqlx::decode::Decode::<Postgres>::decode(value)?;
+        Ok(Box::new(value.to_string()))
+    });
+
+    let value: Option<DynamicValue> = row.try_get_dynamic(0)?;
+    assert_eq!(value.unwrap().downcast_ref::<Status>(), Some(&Status::Open));
+
+    assert!(row.try_get_dynamic(1)?.is_none());
+
+    let value: Option<DynamicValue> = row.try_get_dynamic(2)?;
+    assert_eq!(value.unwrap().downcast_ref::<String>().unwrap(), "1");
+
+    assert!(dynamic::unregister_type::<Postgres>("int8"));
+    assert!(row.try_get_dynamic(2).is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_text_without_copying() -> anyhow::Result<()> {
+    use std::borrow::Cow;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // prepared queries use the binary format
+    let row = sqlx::query("SELECT $1::text")
+        .bind("hello")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let text: Cow<'_, str> = row.try_get(0)?;
+    assert!(matches!(text, Cow::Borrowed("hello")));
+
+    // simple queries use the text format
+    let row = conn.fetch_one("SELECT 'wor''ld'::varchar").await?;
+
+    let text: Cow<'_, str> = row.try_get(0)?;
+    assert!(matches!(text, Cow::Borrowed("wor'ld")));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_acquires_the_connection_opened_by_connect_lazy() -> anyhow::Result<()> {
+    // the only connection is opened in the background, while `acquire` waits for it
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect_timeout(Duration::from_secs(5))
+        .connect_lazy(&env::var("DATABASE_URL")?)?;
+
+    // let the background task start connecting
+    sqlx_rt::sleep(Duration::from_millis(1)).await;
+
+    for _ in 0..2 {
+        let mut conn = pool.acquire().await?;
+        conn.ping().await?;
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_tracks_the_transaction_status() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Idle);
+
+    conn.execute("BEGIN").await?;
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Transaction);
+
+    assert!(conn.execute("SELECT 1 / 0").await.is_err());
+
+    // the status is reported after the error, when the connection is next used
+    conn.ping().await?;
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Error);
+
+    conn.execute("ROLLBACK").await?;
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Idle);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_discards_connections_released_in_a_transaction() -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+    let pid: i32 = conn.fetch_one("SELECT pg_backend_pid()").await?.get(0);
+
+    conn.execute("BEGIN").await?;
+    drop(conn);
+
+    let mut conn = pool.acquire().await?;
+    let new_pid: i32 = conn.fetch_one("SELECT pg_backend_pid()").await?.get(0);
+
+    assert_ne!(pid, new_pid);
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Idle);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_emits_pool_events() -> anyhow::Result<()> {
+    use futures::StreamExt;
+    use sqlx::pool::{CloseReason, PoolEvent};
+
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .after_release(|_| false)
+        .connect_lazy(&env::var("DATABASE_URL")?)?;
+
+    let mut events = pool.subscribe_events();
+
+    let conn = pool.acquire().await?;
+    drop(conn);
+
+    assert!(matches!(
+        events.next().await,
+        Some(PoolEvent::ConnectionOpened { .. })
+    ));
+
+    assert!(matches!(
+        events.next().await,
+        Some(PoolEvent::ConnectionClosed {
+            reason: CloseReason::Rejected
+        })
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "decimal")]
+#[sqlx_macros::test]
+async fn it_converts_money_with_the_scale_of_the_connection() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgMoney;
+    use sqlx::types::Decimal;
+    use std::str::FromStr;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // the C locale has no monetary format; Postgres then uses 2 fractional digits
+    conn.execute("SET lc_monetary = 'C'").await?;
+
+    let scale = conn.money_scale().await?;
+    assert_eq!(scale, 2);
+
+    let money: PgMoney = sqlx::query_scalar("SELECT 12.34::money")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(money.to_decimal(scale), Decimal::from_str("12.34")?);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_anyarray_values() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgAnyArray;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE any_array_test AS SELECT generate_series(1, 1000) AS n;
+ANALYZE any_array_test;
+        "#,
+    )
+    .await?;
+
+    let query = "SELECT histogram_bounds FROM pg_stats \
+                 WHERE tablename = 'any_array_test' AND attname = 'n'";
+
+    // binary, with the type of the elements
+    let bounds: PgAnyArray = sqlx::query_scalar(query).fetch_one(&mut conn).await?;
+
+    assert_eq!(bounds.element_type_info().name(), "INT4");
+
+    let values = bounds.try_decode::<i32>()?;
+
+    assert_eq!(values.first(), Some(&1));
+    assert_eq!(values.last(), Some(&1000));
+    assert!(bounds.try_decode::<String>().is_err());
+
+    // or as text
+    let text: String = sqlx::query_scalar(query).fetch_one(&mut conn).await?;
+
+    assert!(text.starts_with("{1,"));
+    assert!(text.ends_with(",1000}"));
+
+    // text, without
+    let row = conn.fetch_one(query).await?;
+    let bounds: PgAnyArray = row.try_get(0)?;
+
+    assert_eq!(bounds.element_type_info().name(), "UNKNOWN");
+    assert_eq!(bounds.try_decode::<i32>()?, values);
+    assert_eq!(row.try_get::<String, _>(0)?, text);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_uses_registered_type_codecs() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgCodecValue;
+    use std::convert::TryInto;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // a codec for the points of the `cube` extension: a header with the number of dimensions
+    // and a flag, followed by the coordinates
+    let cube = conn.register_type_codec(
+        "cube",
+        |point: &Vec<f64>, buf| {
+            buf.extend_from_slice(&(point.len() as u32 | 0x8000_0000).to_be_bytes());
+
+            for coordinate in point {
+                buf.extend_from_slice(&coordinate.to_be_bytes());
+            }
+
+            Ok(())
+        },
+        |bytes| {
+            Ok(bytes[4..]
+                .chunks_exact(8)
+                .map(|chunk| f64::from_be_bytes(chunk.try_into().unwrap()))
+                .collect())
+        },
+    );
+
+    let point: PgCodecValue<Vec<f64>> = sqlx::query_scalar("SELECT '(1, 2.5)'::cube")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(*point, vec![1.0, 2.5]);
+
+    let (dim, point): (i32, PgCodecValue<Vec<f64>>) = sqlx::query_as("SELECT cube_dim($1), $1")
+        .bind(cube.value(vec![3.0, 4.0, 5.0]))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(dim, 3);
+    assert_eq!(point.into_inner(), vec![3.0, 4.0, 5.0]);
+
+    // a codec producing another type does not apply
+    let res = sqlx::query_scalar::<_, PgCodecValue<String>>("SELECT '(1)'::cube")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_computes_checksums_of_result_sets() -> anyhow::Result<()> {
+    use sqlx::postgres::PgChecksum;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let query = "SELECT n, n::text AS label, NULLIF(n % 2, 0) AS odd \
+                 FROM generate_series(1, 100) n ORDER BY n";
+
+    let a = PgChecksum::of(&mut conn, sqlx::query(query)).await?;
+    let b = PgChecksum::of(&mut conn, sqlx::query(query)).await?;
+
+    assert_eq!(a.rows, 100);
+    assert_eq!(a, b);
+
+    // the column names are not hashed
+    let renamed = query.replace("AS label", "AS name");
+    let c = PgChecksum::of(&mut conn, sqlx::query(&renamed)).await?;
+
+    assert_eq!(a, c);
+
+    // but the types are
+    let widened = query.replace("SELECT n,", "SELECT n::int8,");
+    let d = PgChecksum::of(&mut conn, sqlx::query(&widened)).await?;
+
+    assert_ne!(a, d);
+
+    // as well as the order of the rows
+    let reversed = query.replace("ORDER BY n", "ORDER BY n DESC");
+    let e = PgChecksum::of(&mut conn, sqlx::query(&reversed)).await?;
+
+    assert_ne!(a.digest, e.digest);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_aclitem_arrays() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgAclItem;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE aclitem_test (id INT);
+GRANT SELECT ON aclitem_test TO PUBLIC;
+        "#,
+    )
+    .await?;
+
+    let owner: String = sqlx::query_scalar("SELECT current_user::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // the other columns are still sent in the binary format
+    let (name, acl): (String, Vec<PgAclItem>) = sqlx::query_as(
+        "SELECT relname::text, relacl FROM pg_class WHERE oid = 'aclitem_test'::regclass",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(name, "aclitem_test");
+    assert_eq!(acl.len(), 2);
+
+    assert_eq!(acl[0].grantee.as_deref(), Some(&*owner));
+    assert!(acl[0].has_privilege('r') && acl[0].has_privilege('D'));
+
+    assert_eq!(acl[1].grantee, None);
+    assert_eq!(acl[1].grantor, owner);
+    assert_eq!(acl[1].privileges.len(), 1);
+    assert_eq!(acl[1].privileges[0].name(), Some("SELECT"));
+
+    let row = conn
+        .fetch_one("SELECT relacl FROM pg_class WHERE oid = 'aclitem_test'::regclass")
+        .await?;
+
+    assert_eq!(row.try_get::<Vec<PgAclItem>, _>(0)?, acl);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_values_with_a_declared_type() -> anyhow::Result<()> {
+    use sqlx::postgres::PgTypeInfo;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // strings are parsed by the server with the input function of the type
+    let (ty, host): (String, String) = sqlx::query_as("SELECT pg_typeof($1)::text, host($1)")
+        .bind_with_type("192.168.0.1/24", PgTypeInfo::with_name("inet"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(ty, "inet");
+    assert_eq!(host, "192.168.0.1");
+
+    // other values keep their binary format
+    let name: String = sqlx::query_scalar("SELECT $1::regtype::text")
+        .bind_with_type(23_i32, PgTypeInfo::with_name("oid"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, "integer");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_encrypts_sensitive_values() -> anyhow::Result<()> {
+    use sqlx::error::BoxDynError;
+    use sqlx::postgres::types::{PgFieldCipher, PgSensitive};
+
+    // not a real cipher: a version byte followed by the bytes of the value, inverted
+    struct Invert;
+
+    impl PgFieldCipher for Invert {
+        fn encrypt(plaintext: &[u8]) -> Result<Vec<u8>, BoxDynError> {
+            Ok(std::iter::once(1)
+                .chain(plaintext.iter().map(|b| !b))
+                .collect())
+        }
+
+        fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>, BoxDynError> {
+            match ciphertext.split_first() {
+                Some((1, bytes)) => Ok(bytes.iter().map(|b| !b).collect()),
+                _ => Err("unknown version".into()),
+            }
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE customers (name TEXT, ssn BYTEA, balance BYTEA)")
+        .await?;
+
+    sqlx::query("INSERT INTO customers (name, ssn, balance) VALUES ($1, $2, $3)")
+        .bind("alice")
+        .bind(PgSensitive::<_, Invert>::new("078-05-1120"))
+        .bind(PgSensitive::<_, Invert>::new(None::<i64>))
+        .execute(&mut conn)
+        .await?;
+
+    let (ssn, raw, balance): (
+        PgSensitive<String, Invert>,
+        Vec<u8>,
+        Option<PgSensitive<i64, Invert>>,
+    ) = sqlx::query_as("SELECT ssn, ssn, balance FROM customers WHERE name = 'alice'")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(*ssn, "078-05-1120");
+    assert_eq!(format!("{:?}", ssn), "PgSensitive(..)");
+    assert_eq!(raw[0], 1);
+    assert_eq!(raw[1], !b'0');
+    assert!(balance.is_none());
+
+    // decoding fails if the value cannot be decrypted
+    let res: Result<PgSensitive<String, Invert>, _> = sqlx::query_scalar("SELECT '\\x02'::bytea")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_redacts_sensitive_values() -> anyhow::Result<()> {
+    use sqlx::types::Sensitive;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let (key, len): (Sensitive<String>, i32) = sqlx::query_as("SELECT $1, length($1)")
+        .bind(Sensitive::new("hunter2".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(key.expose(), "hunter2");
+    assert_eq!(len, 7);
+
+    assert_eq!(format!("{:?}", key), "Sensitive(..)");
+    assert_eq!(key.to_string(), "[REDACTED]");
+
+    let key: Option<Sensitive<String>> = sqlx::query_scalar("SELECT NULL::text")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(key.is_none());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_nulls_with_a_declared_type() -> anyhow::Result<()> {
+    use sqlx::postgres::PgTypeInfo;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let (ty, is_null): (String, bool) = sqlx::query_as("SELECT pg_typeof($1)::text, $1 IS NULL")
+        .bind_null_with(PgTypeInfo::with_name("inet"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(ty, "inet");
+    assert!(is_null);
+
+    // `None::<i32>` would be declared as an INT4, which has no `||` operator with `TEXT[]`
+    let tags: Vec<String> = sqlx::query_scalar("SELECT $1 || ARRAY['a']")
+        .bind_null_with(PgTypeInfo::with_name("_text"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(tags, ["a"]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_arrays_of_records() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE pairs (a INT4, b TEXT); \
+         INSERT INTO pairs VALUES (1, 'x'), (2, NULL), (3, 'y,\"z')",
+    )
+    .await?;
+
+    let expected = vec![
+        (1, Some("x".to_owned())),
+        (2, None),
+        (3, Some("y,\"z".to_owned())),
+    ];
+
+    // anonymous records
+    let query = "SELECT array_agg(ROW(a, b) ORDER BY a) FROM pairs";
+
+    let pairs: Vec<(i32, Option<String>)> = sqlx::query_scalar(query).fetch_one(&mut conn).await?;
+    assert_eq!(pairs, expected);
+
+    // rows of a table, whose type is looked up in the catalog
+    let query = "SELECT array_agg(p ORDER BY a) FROM pairs p";
+
+    let pairs: Vec<(i32, Option<String>)> = sqlx::query_scalar(query).fetch_one(&mut conn).await?;
+    assert_eq!(pairs, expected);
+
+    // in the text format
+    let row = conn.fetch_one(query).await?;
+    let pairs: Vec<(i32, Option<String>)> = row.try_get(0)?;
+    assert_eq!(pairs, expected);
+
+    // nested records
+    let nested: Vec<(i32, (Option<String>, i32))> =
+        sqlx::query_scalar("SELECT array_agg(ROW(a, ROW(b, a)) ORDER BY a) FROM pairs")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(nested[1], (2, (None, 2)));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_converts_values_to_json() -> anyhow::Result<()> {
+    use serde_json::{json, Map, Value};
+
+    fn row_to_json(row: &PgRow) -> anyhow::Result<Value> {
+        let mut object = Map::new();
+
+        for column in row.columns() {
+            let value = row.try_get_raw(column.ordinal())?;
+            object.insert(
+                column.name().to_owned(),
+                value.to_json().map_err(anyhow::Error::msg)?,
+            );
+        }
+
+        Ok(Value::Object(object))
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let sql = r#"
+SELECT 1::int4 AS int, 'a'::text AS text, NULL::int4 AS null, 2.5::float8 AS float,
+       'NaN'::float8 AS nan, 1.10::numeric AS numeric, '{"a": [1]}'::jsonb AS jsonb,
+       'open'::status AS status, '\xdead'::bytea AS bytea, '2021-01-31'::date AS date,
+       '{a,NULL}'::text[] AS texts, ('pen', 7, 300)::inventory_item AS item
+    "#;
+
+    let expected = json!({
+        "int": 1,
+        "text": "a",
+        "null": null,
+        "float": 2.5,
+        "nan": "NaN",
+        "numeric": "1.10",
+        "jsonb": { "a": [1] },
+        "status": "open",
+        "bytea": "\\xdead",
+        "date": "2021-01-31",
+        "texts": ["a", null],
+        "item": { "name": "pen", "supplier_id": 7, "price": 300 },
+    });
+
+    // a prepared query is received in the binary format
+    let row = sqlx::query(sql).fetch_one(&mut conn).await?;
+    assert_eq!(row_to_json(&row)?, expected);
+
+    // and a query without arguments in the text format, where custom types are only
+    // described once they were seen by a prepared query
+    let row = conn.fetch_one(sql).await?;
+    assert_eq!(row_to_json(&row)?, expected);
+
+    let row = sqlx::query(
+        "SELECT '{{1,2},{3,NULL}}'::int4[] AS matrix, ROW(1, true) AS record, \
+         '2021-01-31 12:34:56.5+02'::timestamptz AS timestamp",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(
+        row_to_json(&row)?,
+        json!({
+            "matrix": [[1, 2], [3, null]],
+            "record": { "f1": 1, "f2": true },
+            "timestamp": "2021-01-31T10:34:56.5+00:00",
+        })
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_copies_in_and_out() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE copies (id INT4 PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let mut copy = conn
+        .copy_in_raw("COPY copies (id, name) FROM STDIN WITH (FORMAT CSV)")
+        .await?;
+
+    // chunks do not need to be aligned to rows
+    copy.send("1,alice\n2,").await?;
+    copy.send(b"bob\n3,\n".to_vec()).await?;
+
+    assert_eq!(copy.finish().await?, 3);
+
+    // an aborted copy does not copy any row
+    let mut copy = conn.copy_in_raw("COPY copies FROM STDIN").await?;
+    copy.send("4\tcarol\n").await?;
+    copy.abort("changed my mind").await?;
+
+    // invalid data is reported when the copy is finished
+    let mut copy = conn.copy_in_raw("COPY copies FROM STDIN").await?;
+    copy.send("not a number\tdave\n").await?;
+    assert!(copy.finish().await.is_err());
+
+    let chunks: Vec<_> = conn
+        .copy_out_raw("COPY (SELECT * FROM copies ORDER BY id) TO STDOUT WITH (FORMAT CSV)")
+        .await?
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks.concat(), b"1,alice\n2,bob\n3,\n");
+
+    // a statement which does not start a copy is rejected
+    assert!(conn.copy_out_raw("SELECT 1").await.is_err());
+    assert!(conn.copy_out_raw("COPY copies FROM STDIN").await.is_err());
+
+    // and the connection is still usable afterwards
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM copies")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_shares_column_names() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let row = conn.fetch_one("SELECT 1 AS id, 'a' AS name").await?;
+
+    let names: Vec<UStr> = row.columns().iter().map(|c| c.name_shared()).collect();
+    assert_eq!(names, ["id", "name"]);
+
+    // the names outlive the row, and are converted without copying
+    drop(row);
+
+    let name: Arc<str> = names[1].clone().into();
+    assert_eq!(&*name, "name");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_migrations_with_progress() -> anyhow::Result<()> {
+    use sqlx::migrate::{Migrate, Migration, MigrationType, Migrator};
+    use std::borrow::Cow;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // the migrations table is created in the schema of the connection
+    conn.execute(
+        "DROP SCHEMA IF EXISTS migrate_progress CASCADE; CREATE SCHEMA migrate_progress; \
+         SET search_path TO migrate_progress",
+    )
+    .await?;
+
+    let migrator = Migrator {
+        migrations: Cow::Owned(vec![
+            Migration::new(
+                1,
+                "items".into(),
+                MigrationType::Simple,
+                "CREATE TABLE items (id INT PRIMARY KEY, name TEXT);\n\
+                 -- a comment\n\
+                 INSERT INTO items VALUES (1, 'a;b');"
+                    .into(),
+            ),
+            Migration::new(
+                2,
+                "index items".into(),
+                MigrationType::Simple,
+                "-- sqlx:no-transaction\n\
+                 CREATE INDEX CONCURRENTLY items_name ON items (name);\n\
+                 CREATE INDEX CONCURRENTLY items_id_name ON items (id, name);"
+                    .into(),
+            ),
+        ]),
+    };
+
+    let mut statements = Vec::new();
+
+    migrator
+        .run_with_progress(&mut conn, |progress| {
+            statements.push((
+                progress.migration.version,
+                progress.statement,
+                progress.sql_preview().to_owned(),
+            ));
+        })
+        .await?;
+
+    assert_eq!(
+        statements,
+        [
+            (
+                1,
+                0,
+                "CREATE TABLE items (id INT PRIMARY KEY, name TEXT)".to_owned()
+            ),
+            (1, 1, "INSERT INTO items VALUES (1, 'a;b')".to_owned()),
+            (
+                2,
+                0,
+                "CREATE INDEX CONCURRENTLY items_name ON items (name)".to_owned()
+            ),
+            (
+                2,
+                1,
+                "CREATE INDEX CONCURRENTLY items_id_name ON items (id, name)".to_owned()
+            ),
+        ]
+    );
+
+    let indexes: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM pg_indexes WHERE schemaname = 'migrate_progress'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    // with the primary keys of `items` and `_sqlx_migrations`
+    assert_eq!(indexes, 4);
+
+    // a failed migration without a transaction leaves the database dirty
+    let migrator = Migrator {
+        migrations: Cow::Owned(vec![Migration::new(
+            3,
+            "fails".into(),
+            MigrationType::Simple,
+            "-- sqlx:no-transaction\nCREATE INDEX CONCURRENTLY items_missing ON items (missing);"
+                .into(),
+        )]),
+    };
+
+    assert!(migrator.run(&mut conn).await.is_err());
+    assert!(matches!(
+        migrator.run(&mut conn).await,
+        Err(sqlx::migrate::MigrateError::Dirty(3))
+    ));
+
+    // once the database is fixed by hand, the migration can be recorded as applied
+    conn.execute("DELETE FROM _sqlx_migrations WHERE version = 3")
+        .await?;
+    conn.record(&migrator.migrations[0], true, Duration::from_secs(0))
+        .await?;
+
+    migrator.run(&mut conn).await?;
+    assert_eq!(conn.version().await?, Some((3, false)));
+
+    conn.execute("DROP SCHEMA migrate_progress CASCADE").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_copies_in_binary_rows() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgArguments, PgCopyRow};
+    use sqlx::Arguments;
+
+    #[derive(sqlx::Type, Debug, PartialEq)]
+    #[sqlx(type_name = "status", rename_all = "lowercase", pg_array)]
+    enum Status {
+        New,
+        Open,
+    }
+
+    struct Item {
+        id: i32,
+        name: &'static str,
+        statuses: Option<Vec<Status>>,
+    }
+
+    impl PgCopyRow<'_> for Item {
+        fn encode_fields(self, fields: &mut PgArguments) {
+            fields.add(self.id);
+            fields.add(self.name);
+            fields.add(self.statuses);
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE binary_copies (id INT4, name TEXT, statuses status[])")
+        .await?;
+
+    // the OID of `status` is resolved before the copy starts
+    let mut encoder = conn.copy_in_binary("COPY binary_copies FROM STDIN WITH (FORMAT BINARY)");
+
+    encoder
+        .write_row((1_i32, "a", Some(vec![Status::New, Status::Open])))
+        .await?;
+
+    encoder
+        .write_row(Item {
+            id: 2,
+            name: "b",
+            statuses: None,
+        })
+        .await?;
+
+    assert_eq!(encoder.finish().await?, 2);
+
+    let rows: Vec<(i32, String, Option<Vec<Status>>)> =
+        sqlx::query_as("SELECT id, name, statuses FROM binary_copies ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        rows,
+        [
+            (1, "a".to_owned(), Some(vec![Status::New, Status::Open])),
+            (2, "b".to_owned(), None),
+        ]
+    );
+
+    // the values are not converted, so an INT8 cannot be copied to an INT4 column
+    let mut encoder =
+        conn.copy_in_binary("COPY binary_copies (id) FROM STDIN WITH (FORMAT BINARY)");
+    encoder.write_row((3_i64,)).await?;
+    assert!(encoder.finish().await.is_err());
+
+    // an empty copy
+    let encoder = conn.copy_in_binary("COPY binary_copies (id) FROM STDIN WITH (FORMAT BINARY)");
+    assert_eq!(encoder.finish().await?, 0);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM binary_copies")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_pipelines() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipelined (id INT4 PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let mut pipeline = PgPipeline::new();
+
+    pipeline
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(1_i32)
+                .bind("a"),
+        )
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(2_i32)
+                .bind("b"),
+        )
+        .push("SELECT id, name FROM pipelined ORDER BY id");
+
+    assert_eq!(pipeline.len(), 3);
+
+    let results = pipeline.run(&mut conn).await?;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].rows_affected(), 1);
+    assert!(results[0].rows().is_empty());
+
+    let names: Vec<String> = results[2]
+        .rows()
+        .iter()
+        .map(|row| row.get("name"))
+        .collect();
+    assert_eq!(names, ["a", "b"]);
+
+    // the queries after a failed one are skipped, and none of them is committed
+    let mut pipeline = PgPipeline::new();
+
+    pipeline
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(3_i32)
+                .bind("c"),
+        )
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(1_i32)
+                .bind("d"),
+        )
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(4_i32)
+                .bind("e"),
+        );
+
+    let err = pipeline.run(&mut conn).await.unwrap_err();
+    let err = err.into_database_error().unwrap();
+    assert_eq!(err.code().as_deref(), Some("23505"));
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pipelined")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_pipelines_larger_than_the_statement_cache() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.statement_cache_capacity(1)).await?;
+
+    let mut pipeline = PgPipeline::new();
+
+    for i in 0..3_i32 {
+        pipeline.push(
+            sqlx::query(
+                [
+                    "SELECT $1::int4",
+                    "SELECT $1::int4 + 0",
+                    "SELECT 0 + $1::int4",
+                ][i as usize],
+            )
+            .bind(i),
+        );
+    }
+
+    let results = pipeline.run(&mut conn).await?;
+
+    for (i, result) in results.iter().enumerate() {
+        assert_eq!(result.rows()[0].get::<i32, _>(0), i as i32);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_rows_in_chunks() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.fetch_size(10)).await?;
+
+    let sum: i64 = sqlx::query_scalar::<_, i32>("SELECT generate_series(1, $1)")
+        .bind(25_i32)
+        .fetch(&mut conn)
+        .try_fold(0, |sum, i| async move { Ok(sum + i as i64) })
+        .await?;
+
+    assert_eq!(sum, 325);
+
+    // a stream dropped early leaves the rest of the rows unfetched
+    {
+        let mut s = sqlx::query_scalar::<_, i32>("SELECT generate_series(1, $1)")
+            .bind(1_000_000_i32)
+            .fetch(&mut conn);
+
+        assert_eq!(s.try_next().await?, Some(1));
+        assert_eq!(s.try_next().await?, Some(2));
+    }
+
+    let value: i32 = sqlx::query_scalar("SELECT $1::int4")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 5);
+
+    // an error in a later chunk
+    let res: Result<Vec<i32>, _> = sqlx::query_scalar("SELECT 100 / (20 - generate_series(1, $1))")
+        .bind(30_i32)
+        .fetch_all(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let rows_affected = sqlx::query("SELECT generate_series(1, $1)")
+        .bind(15_i32)
+        .execute(&mut conn)
+        .await?
+        .rows_affected();
+
+    assert_eq!(rows_affected, 15);
+    assert_eq!(conn.transaction_status(), PgTransactionStatus::Idle);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_extra_float_digits() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+    let digits: String = sqlx::query_scalar("SHOW extra_float_digits")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // the server default is kept where it is precise, on PostgreSQL 12+
+    let version: i32 = sqlx::query_scalar("SELECT current_setting('server_version_num')::int")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(digits, if version >= 120000 { "1" } else { "3" });
+
+    let mut conn = PgConnection::connect_with(&options.extra_float_digits(0)).await?;
+    let digits: String = sqlx::query_scalar("SHOW extra_float_digits")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(digits, "0");
+
+    // the special values are decoded from the text format
+    let (nan, inf): (f64, f32) = sqlx::query_as("SELECT 'NaN'::float8, '-Infinity'::float4")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(nan.is_nan());
+    assert_eq!(inf, f32::NEG_INFINITY);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_cancels_queries_with_a_cancel_token() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let token = conn.cancel_token();
+
+    let cancel = async {
+        sqlx_rt::sleep(Duration::from_millis(200)).await;
+        token.cancel().await
+    };
+
+    let (res, canceled) = futures::join!(conn.execute("SELECT pg_sleep(10)"), cancel);
+    canceled?;
+
+    let err = res.unwrap_err();
+    assert_eq!(
+        err.as_database_error().and_then(|e| e.code()).as_deref(),
+        Some("57014")
+    );
+
+    // the connection is still usable
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_calls_the_notice_handler() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    static NOTICES: AtomicUsize = AtomicUsize::new(0);
+
+    fn handle_notice(notice: PgNotice) {
+        if notice.message() == "sqlx notice" {
+            assert!(matches!(notice.severity(), PgSeverity::Warning));
+            assert_eq!(notice.code(), "01000");
+            assert_eq!(notice.hint(), Some("a hint"));
+
+            NOTICES.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.notice_handler(handle_notice)).await?;
+
+    conn.execute("DO $$ BEGIN RAISE WARNING 'sqlx notice' USING HINT = 'a hint'; END $$")
+        .await?;
+
+    assert_eq!(NOTICES.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "protocol-hooks")]
+#[sqlx_macros::test]
+async fn it_calls_the_protocol_hook() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgMessageDirection, PgProtocolMessage};
+
+    setup_if_needed();
+
+    static UNTYPED: AtomicUsize = AtomicUsize::new(0);
+    static QUERIES: AtomicUsize = AtomicUsize::new(0);
+    static READY: AtomicUsize = AtomicUsize::new(0);
+
+    fn hook(message: &PgProtocolMessage) {
+        match (message.direction(), message.tag()) {
+            (PgMessageDirection::Frontend, None) => {
+                UNTYPED.fetch_add(1, Ordering::SeqCst);
+            }
+
+            // `Q`, the length and `SELECT 'sqlx hook'` with a nul terminator
+            (PgMessageDirection::Frontend, Some(b'Q')) => {
+                assert_eq!(message.size(), 1 + 4 + 19);
+                QUERIES.fetch_add(1, Ordering::SeqCst);
+            }
+
+            (PgMessageDirection::Backend, Some(b'Z')) => {
+                READY.fetch_add(1, Ordering::SeqCst);
+            }
+
+            _ => {}
+        }
+    }
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    let mut conn = PgConnection::connect_with(&options.protocol_hook(hook)).await?;
+
+    // the startup message, at least
+    assert!(UNTYPED.load(Ordering::SeqCst) >= 1);
+
+    let ready = READY.load(Ordering::SeqCst);
+
+    conn.execute("SELECT 'sqlx hook'").await?;
+
+    assert_eq!(QUERIES.load(Ordering::SeqCst), 1);
+    assert_eq!(READY.load(Ordering::SeqCst), ready + 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_streams_logical_replication_changes() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // logical replication requires `wal_level = logical`
+    let wal_level: String = sqlx::query_scalar("SHOW wal_level")
+        .fetch_one(&mut conn)
+        .await?;
+
+    if wal_level != "logical" {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+CREATE TABLE IF NOT EXISTS _sqlx_replication_test (id INT PRIMARY KEY, name TEXT NOT NULL);
+DROP PUBLICATION IF EXISTS _sqlx_replication_test;
+CREATE PUBLICATION _sqlx_replication_test FOR TABLE _sqlx_replication_test;
+        "#,
+    )
+    .await?;
+
+    let mut repl = PgReplicationConnection::connect(&env::var("DATABASE_URL")?).await?;
+    let start = repl
+        .create_replication_slot("_sqlx_replication_test", true)
+        .await?;
+
+    conn.execute(
+        r#"
+INSERT INTO _sqlx_replication_test (id, name) VALUES (1, 'alice');
+UPDATE _sqlx_replication_test SET name = 'bob' WHERE id = 1;
+DELETE FROM _sqlx_replication_test WHERE id = 1;
+        "#,
+    )
+    .await?;
+
+    let mut stream = repl
+        .start_replication("_sqlx_replication_test", &["_sqlx_replication_test"], start)
+        .await?;
+
+    let mut changes = Vec::new();
+
+    while changes.len() < 3 {
+        match stream.recv().await?.expect("end of the replication stream") {
+            PgReplicationEvent::Insert { relation, new } => {
+                assert_eq!(relation.name(), "_sqlx_replication_test");
+                assert!(relation.is_key(0));
+
+                changes.push(format!(
+                    "insert {} {}",
+                    new.try_get::<i32, _>("id")?,
+                    new.try_get::<&str, _>("name")?
+                ));
+            }
+
+            PgReplicationEvent::Update { old, new, .. } => {
+                assert!(old.is_none());
+
+                changes.push(format!("update {}", new.try_get::<&str, _>("name")?));
+            }
+
+            PgReplicationEvent::Delete { old, .. } => {
+                changes.push(format!("delete {}", old.try_get::<i32, _>("id")?));
+            }
+
+            PgReplicationEvent::Commit { end_lsn, .. } => {
+                stream.send_status_update(end_lsn).await?;
+            }
+
+            _ => {}
+        }
+    }
+
+    assert_eq!(changes, ["insert 1 alice", "update bob", "delete 1"]);
+
+    stream.stop().await?;
+    repl.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_accesses_columns_of_the_same_name_by_table() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    for strict in &[false, true] {
+        let mut conn =
+            PgConnection::connect_with(&options.clone().strict_column_names(*strict)).await?;
+
+        conn.execute(
+            r#"
+CREATE TEMPORARY TABLE join_parent (id INT4, name TEXT);
+CREATE TEMPORARY TABLE join_child (id INT4, parent_id INT4);
+
+INSERT INTO join_parent VALUES (1, 'parent');
+INSERT INTO join_child VALUES (2, 1);
+            "#,
+        )
+        .await?;
+
+        let row = sqlx::query(
+            "SELECT join_parent.*, join_child.* FROM join_parent JOIN join_child ON join_parent.id = parent_id WHERE join_child.id = $1",
+        )
+        .bind(2_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+        assert_eq!(row.columns()[0].table_name(), Some("join_parent"));
+        assert_eq!(row.columns()[2].table_name(), Some("join_child"));
+
+        assert_eq!(row.try_get::<i32, _>(("join_parent", "id"))?, 1);
+        assert_eq!(row.try_get::<i32, _>(("join_child", "id"))?, 2);
+        assert_eq!(row.try_get::<&str, _>("name")?, "parent");
+
+        assert!(matches!(
+            row.try_get::<i32, _>(("join_other", "id")),
+            Err(sqlx::Error::ColumnNotFound(_))
+        ));
+
+        if *strict {
+            assert!(matches!(
+                row.try_get::<i32, _>("id"),
+                Err(sqlx::Error::ColumnAmbiguous(_))
+            ));
+        } else {
+            // the first column of the name
+            assert_eq!(row.try_get::<i32, _>("id")?, 1);
+        }
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_acquires_and_releases_advisory_locks() -> anyhow::Result<()> {
+    let mut conn1 = new::<Postgres>().await?;
+    let mut conn2 = new::<Postgres>().await?;
+
+    let mut lock = PgAdvisoryLock::acquire(&mut conn1, 0x5eed_i64).await?;
+
+    // the connection can be used while it holds the lock
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut *lock).await?;
+    assert_eq!(value, 1);
+
+    assert!(PgAdvisoryLock::try_acquire(&mut conn2, 0x5eed_i64)
+        .await?
+        .is_none());
+
+    // a pair of keys is a different lock
+    PgAdvisoryLock::try_acquire(&mut conn2, (0, 0x5eed))
+        .await?
+        .expect("the lock of a pair of keys is free")
+        .release()
+        .await?;
+
+    // released with the next use of the connection
+    drop(lock);
+    conn1.ping().await?;
+
+    let lock = PgAdvisoryLock::try_acquire(&mut conn2, 0x5eed_i64).await?;
+    assert!(lock.is_some());
+
+    lock.unwrap().release().await?;
+
+    let held: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM pg_locks WHERE locktype = 'advisory' AND pid = pg_backend_pid()",
+    )
+    .fetch_one(&mut conn2)
+    .await?;
+    assert_eq!(held, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_run_time_parameters_on_startup() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let options = options
+        .options(&[("search_path", "app, public"), ("work_mem", "5MB")])
+        .statement_timeout(Duration::from_millis(1500));
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+
+    let settings: Vec<String> = sqlx::query_scalar(
+        "SELECT current_setting(name) FROM unnest(ARRAY['search_path', 'work_mem', 'statement_timeout']) AS name",
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(settings, vec!["app, public", "5MB", "1500ms"]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn
+        .begin_with(
+            PgTransactionOptions::new()
+                .isolation(PgIsolationLevel::Serializable)
+                .read_only(true)
+                .deferrable(true),
+        )
+        .await?;
+
+    let (isolation, read_only, deferrable): (String, String, String) = sqlx::query_as(
+        "SELECT current_setting('transaction_isolation'), current_setting('transaction_read_only'), current_setting('transaction_deferrable')",
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    assert_eq!(isolation, "serializable");
+    assert_eq!(read_only, "on");
+    assert_eq!(deferrable, "on");
+
+    let error = tx
+        .execute("CREATE TEMPORARY TABLE read_only_transaction (id INT)")
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.into_database_error().unwrap().code().as_deref(),
+        Some("25006")
+    );
+
+    tx.rollback().await?;
+
+    // the options only apply to the transaction
+    let mut tx = conn.begin_with(PgTransactionOptions::new()).await?;
+
+    let read_only: String = sqlx::query_scalar("SELECT current_setting('transaction_read_only')")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(read_only, "off");
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_prepares_transactions_for_two_phase_commit() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_two_phase_commit (id INT PRIMARY KEY)")
+        .await?;
+    conn.execute("DELETE FROM _sqlx_two_phase_commit").await?;
+
+    // a nested transaction cannot be prepared
+    let mut tx = conn.begin().await?;
+    let nested = tx.begin().await?;
+
+    assert!(matches!(
+        nested.prepare_transaction("sqlx_nested").await,
+        Err(sqlx::Error::Configuration(_))
+    ));
+
+    tx.rollback().await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO _sqlx_two_phase_commit (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+    tx.prepare_transaction("sqlx_commit").await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO _sqlx_two_phase_commit (id) VALUES (2)")
+        .execute(&mut tx)
+        .await?;
+    tx.prepare_transaction("sqlx_rollback").await?;
+
+    // the prepared transactions are not visible until they are committed
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_two_phase_commit")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    // they can be finished from another connection
+    let mut other = new::<Postgres>().await?;
+
+    other.commit_prepared("sqlx_commit").await?;
+    other.rollback_prepared("sqlx_rollback").await?;
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM _sqlx_two_phase_commit")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![1]);
+
+    let prepared: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM pg_prepared_xacts WHERE gid IN ('sqlx_commit', 'sqlx_rollback')",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(prepared, 0);
+
+    conn.execute("DROP TABLE _sqlx_two_phase_commit").await?;
+
+    Ok(())
+}
@@ -1,6 +1,12 @@
+-- https://www.postgresql.org/docs/current/cube.html
+CREATE EXTENSION IF NOT EXISTS cube;
+
 -- https://www.postgresql.org/docs/current/sql-createtype.html
 CREATE TYPE status AS ENUM ('new', 'open', 'closed');
 
+-- only mapped to a Rust type by `sqlx.toml`, so the mapping does not apply to other tests
+CREATE TYPE configured_status AS ENUM ('new', 'open', 'closed');
+
 -- https://www.postgresql.org/docs/current/rowtypes.html#ROWTYPES-DECLARING
 CREATE TYPE inventory_item AS
 (
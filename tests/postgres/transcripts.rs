@@ -0,0 +1,107 @@
+use sqlx::postgres::{PgConnectOptions, PgConnection, PgSslMode};
+use sqlx::transcript::{Transcript, TranscriptStep};
+use sqlx::{Connection, Executor, Row};
+use sqlx_test::setup_if_needed;
+use std::env;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+
+fn options() -> anyhow::Result<PgConnectOptions> {
+    setup_if_needed();
+
+    // TLS would make the exchange different on every connection
+    Ok(env::var("DATABASE_URL")?
+        .parse::<PgConnectOptions>()?
+        .ssl_mode(PgSslMode::Disable))
+}
+
+// records the exchange of a connection running `SELECT 1::int4`
+async fn record_select_1() -> anyhow::Result<Vec<TranscriptStep>> {
+    let recorder = Transcript::recorder();
+
+    let mut conn = PgConnection::connect_with(&options()?.transcript(&recorder)).await?;
+    let row = conn.fetch_one("SELECT 1::int4").await?;
+    assert_eq!(row.try_get::<i32, _>(0)?, 1);
+
+    conn.close().await?;
+    recorder.assert_finished();
+
+    Ok(recorder.steps())
+}
+
+// an `ErrorResponse` for a division by zero, followed by a `ReadyForQuery`
+fn division_by_zero() -> Vec<u8> {
+    let fields = b"SERROR\0VERROR\0C22012\0Mdivision by zero\0\0";
+
+    let mut bytes = vec![b'E'];
+    bytes.extend_from_slice(&(fields.len() as u32 + 4).to_be_bytes());
+    bytes.extend_from_slice(fields);
+    bytes.extend_from_slice(b"Z\0\0\0\x05I");
+
+    bytes
+}
+
+#[sqlx_macros::test]
+async fn it_replays_a_recorded_transcript() -> anyhow::Result<()> {
+    let steps = record_select_1().await?;
+    assert!(matches!(steps.first(), Some(TranscriptStep::Send(_))));
+
+    // the server is not involved anymore
+    let transcript = Transcript::from_steps(steps);
+
+    let mut conn = PgConnection::connect_with(&options()?.transcript(&transcript)).await?;
+    let row = conn.fetch_one("SELECT 1::int4").await?;
+    assert_eq!(row.try_get::<i32, _>(0)?, 1);
+
+    conn.close().await?;
+    transcript.assert_finished();
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_recovers_from_a_replayed_error() -> anyhow::Result<()> {
+    let mut steps = record_select_1().await?;
+
+    // the steps end with the query, its response, and the termination of the connection
+    let terminate = steps.pop().unwrap();
+    let response = steps.pop().unwrap();
+    let query = steps.pop().unwrap();
+
+    steps.push(query.clone());
+    steps.push(TranscriptStep::Receive(division_by_zero()));
+    steps.push(query);
+    steps.push(response);
+    steps.push(terminate);
+
+    let transcript = Transcript::from_steps(steps);
+    let mut conn = PgConnection::connect_with(&options()?.transcript(&transcript)).await?;
+
+    let err = conn.execute("SELECT 1::int4").await.unwrap_err();
+    let err = err.into_database_error().unwrap();
+    assert_eq!(err.code().as_deref(), Some("22012"));
+    assert_eq!(err.message(), "division by zero");
+
+    // the connection is still usable
+    let row = conn.fetch_one("SELECT 1::int4").await?;
+    assert_eq!(row.try_get::<i32, _>(0)?, 1);
+
+    conn.close().await?;
+    transcript.assert_finished();
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_on_a_mismatch_with_the_transcript() -> anyhow::Result<()> {
+    let transcript = Transcript::from_steps(record_select_1().await?);
+    let mut conn = PgConnection::connect_with(&options()?.transcript(&transcript)).await?;
+
+    let err = conn.execute("SELECT 2::int4").await.unwrap_err();
+    assert!(matches!(err, sqlx::Error::Io(ref err) if err.kind() == io::ErrorKind::InvalidData));
+
+    let finished = panic::catch_unwind(AssertUnwindSafe(|| transcript.assert_finished()));
+    assert!(finished.is_err());
+
+    Ok(())
+}
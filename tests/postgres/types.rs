@@ -4,7 +4,7 @@ use std::ops::Bound;
 #[cfg(feature = "decimal")]
 use std::str::FromStr;
 
-use sqlx::postgres::types::{PgInterval, PgMoney, PgRange};
+use sqlx::postgres::types::{PgCube, PgInterval, PgJsonPath, PgMoney, PgRange, PgSnapshot, PgTid};
 use sqlx::postgres::Postgres;
 use sqlx_test::{test_decode_type, test_prepared_type, test_type};
 
@@ -65,12 +65,51 @@ test_type!(string_vec<Vec<String>>(Postgres,
         == vec!["Hello, World", "", "Goodbye"]
 ));
 
+test_type!(bpchar<sqlx::postgres::types::PgBpchar>(Postgres,
+    "'abc'::char(6)" == sqlx::postgres::types::PgBpchar("abc".into()),
+    "' a b'::char(6)" == sqlx::postgres::types::PgBpchar(" a b".into()),
+    "''::char(2)" == sqlx::postgres::types::PgBpchar("".into()),
+));
+
+test_decode_type!(bpchar_vec<Vec<sqlx::postgres::types::PgBpchar>>(Postgres,
+    "array['ab', 'c']::char(3)[]"
+        == vec![sqlx::postgres::types::PgBpchar("ab".into()), sqlx::postgres::types::PgBpchar("c".into())],
+));
+
+test_decode_type!(string_opt_vec<Vec<Option<String>>>(Postgres,
+    "array['a,b', NULL, 'NULL', ' padded ', 'with \"quotes\"', 'back\\slash', '{}']::text[]"
+        == vec![
+            Some("a,b".to_owned()),
+            None,
+            Some("NULL".to_owned()),
+            Some(" padded ".to_owned()),
+            Some("with \"quotes\"".to_owned()),
+            Some("back\\slash".to_owned()),
+            Some("{}".to_owned()),
+        ],
+));
+
+test_decode_type!(string_vec_tuple<(Vec<String>, String)>(Postgres,
+    "row(array['ü', 'x y'], 'ß')"
+        == (vec!["ü".to_owned(), "x y".to_owned()], "ß".to_owned())
+));
+
 test_type!(i8(
     Postgres,
     "0::\"char\"" == 0_i8,
     "120::\"char\"" == 120_i8,
 ));
 
+test_decode_type!(char(
+    Postgres,
+    "'r'::\"char\"" == 'r',
+    "0::\"char\"" == '\0',
+));
+
+test_decode_type!(char_vec<Vec<char>>(Postgres,
+    "array['r', 'v', 'm']::\"char\"[]" == vec!['r', 'v', 'm'],
+));
+
 test_type!(u32(Postgres, "325235::oid" == 325235_u32,));
 
 test_type!(i16(
@@ -94,11 +133,68 @@ test_type!(i32_vec<Vec<i32>>(Postgres,
 
 test_type!(i64(Postgres, "9358295312::bigint" == 9358295312_i64));
 
-test_type!(f32(Postgres, "9419.122::real" == 9419.122_f32));
+test_type!(i128(
+    Postgres,
+    "0::numeric" == 0_i128,
+    "-1::numeric" == -1_i128,
+    "100000000::numeric" == 100000000_i128,
+    "170141183460469231731687303715884105727::numeric" == i128::MAX,
+    "-170141183460469231731687303715884105728::numeric" == i128::MIN,
+));
+
+test_type!(u128(
+    Postgres,
+    "12345678901234567890::numeric" == 12345678901234567890_u128,
+    "340282366920938463463374607431768211455::numeric" == u128::MAX,
+));
+
+test_decode_type!(i128_with_scale<i128>(Postgres,
+    "42.000::numeric" == 42_i128,
+));
+
+test_type!(non_zero_i16<std::num::NonZeroI16>(Postgres,
+    "-2144::smallint" == std::num::NonZeroI16::new(-2144).unwrap(),
+));
+
+test_type!(non_zero_i64<std::num::NonZeroI64>(Postgres,
+    "9358295312::bigint" == std::num::NonZeroI64::new(9358295312).unwrap(),
+));
+
+test_decode_type!(box_str<Box<str>>(Postgres,
+    "'hello'::text" == Box::<str>::from("hello"),
+));
+
+test_decode_type!(arc_str<std::sync::Arc<str>>(Postgres,
+    "'hello'::varchar" == std::sync::Arc::<str>::from("hello"),
+));
+
+test_decode_type!(rc_str<std::rc::Rc<str>>(Postgres,
+    "''::text" == std::rc::Rc::<str>::from(""),
+));
+
+test_decode_type!(arc_bytes<std::sync::Arc<[u8]>>(Postgres,
+    "E'\\\\xDEADBEEF'::bytea" == std::sync::Arc::<[u8]>::from(&[0xDE_u8, 0xAD, 0xBE, 0xEF][..]),
+));
+
+test_type!(pg_u32<sqlx::postgres::types::PgU32>(Postgres,
+    "0::int4" == sqlx::postgres::types::PgU32(0),
+    "2147483647::int4" == sqlx::postgres::types::PgU32(2147483647),
+));
+
+test_type!(pg_u64<sqlx::postgres::types::PgU64>(Postgres,
+    "9358295312::int8" == sqlx::postgres::types::PgU64(9358295312),
+    "9223372036854775807::int8" == sqlx::postgres::types::PgU64(9223372036854775807),
+));
+
+test_type!(f32(Postgres,
+    "9419.122::real" == 9419.122_f32,
+    "'Infinity'::real" == f32::INFINITY,
+));
 
 test_type!(f64(
     Postgres,
-    "939399419.1225182::double precision" == 939399419.1225182_f64
+    "939399419.1225182::double precision" == 939399419.1225182_f64,
+    "'-Infinity'::double precision" == f64::NEG_INFINITY,
 ));
 
 test_type!(f64_vec<Vec<f64>>(Postgres,
@@ -167,6 +263,39 @@ test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
             .unwrap(),
 ));
 
+test_type!(ipaddr<std::net::IpAddr>(Postgres,
+    "'127.0.0.1'::inet" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'::ffff:1.2.3.0'::inet" == "::ffff:1.2.3.0".parse::<std::net::IpAddr>().unwrap(),
+));
+
+test_type!(ipaddr_vec<Vec<std::net::IpAddr>>(Postgres,
+    "'{127.0.0.1,2001:4f8:3:ba::1}'::inet[]"
+        == vec![
+           "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+           "2001:4f8:3:ba::1".parse::<std::net::IpAddr>().unwrap()
+        ]
+));
+
+#[cfg(feature = "ipnet")]
+test_type!(ipnet<sqlx::types::ipnet::IpNet>(Postgres,
+    "'127.0.0.1'::inet"
+        == "127.0.0.1/32"
+            .parse::<sqlx::types::ipnet::IpNet>()
+            .unwrap(),
+    "'8.8.8.8/24'::inet"
+        == "8.8.8.8/24"
+            .parse::<sqlx::types::ipnet::IpNet>()
+            .unwrap(),
+    "'2001:4f8:3:ba::/64'::inet"
+        == "2001:4f8:3:ba::/64"
+            .parse::<sqlx::types::ipnet::IpNet>()
+            .unwrap(),
+    "'192.168'::cidr"
+        == "192.168.0.0/24"
+            .parse::<sqlx::types::ipnet::IpNet>()
+            .unwrap(),
+));
+
 #[cfg(feature = "bit-vec")]
 test_type!(bitvec<sqlx::types::BitVec>(
     Postgres,
@@ -207,14 +336,45 @@ mod chrono {
     use sqlx::types::chrono::{
         DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
     };
+    use sqlx::{Executor, Row};
+    use sqlx_test::new;
 
+    type PgDate = sqlx::postgres::types::PgDate<NaiveDate>;
     type PgTimeTz = sqlx::postgres::types::PgTimeTz<NaiveTime, FixedOffset>;
 
     test_type!(chrono_date<NaiveDate>(Postgres,
         "DATE '2001-01-05'" == NaiveDate::from_ymd(2001, 1, 5),
-        "DATE '2050-11-23'" == NaiveDate::from_ymd(2050, 11, 23)
+        "DATE '2050-11-23'" == NaiveDate::from_ymd(2050, 11, 23),
+        "DATE '0044-03-15 BC'" == NaiveDate::from_ymd(-43, 3, 15)
     ));
 
+    test_type!(chrono_pg_date<PgDate>(Postgres,
+        "DATE '2001-01-05'" == PgDate::Date(NaiveDate::from_ymd(2001, 1, 5)),
+        "DATE 'infinity'" == PgDate::Infinity,
+        "DATE '-infinity'" == PgDate::NegInfinity
+    ));
+
+    #[sqlx_macros::test]
+    async fn chrono_fails_to_decode_out_of_range_dates() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        for date in ["5874897-12-31", "infinity"].iter() {
+            let query = format!("SELECT DATE '{}'", date);
+
+            let res = sqlx::query_scalar::<_, NaiveDate>(&query)
+                .fetch_one(&mut conn)
+                .await;
+
+            assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+            let res = conn.fetch_one(&*query).await?.try_get::<NaiveDate, _>(0);
+
+            assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+        }
+
+        Ok(())
+    }
+
     test_type!(chrono_time<NaiveTime>(Postgres,
         "TIME '05:10:20.115100'" == NaiveTime::from_hms_micro(5, 10, 20, 115100)
     ));
@@ -255,7 +415,16 @@ mod chrono {
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(0) },
         "TIMETZ '05:10:20.115100+06:30'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(60 * 60 * 6 + 1800) },
         "TIMETZ '05:10:20.115100-05'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::west(60 * 60 * 5) },
-        "TIMETZ '05:10:20+02'" == PgTimeTz { time: NaiveTime::from_hms(5, 10, 20), offset: FixedOffset::east(60 * 60 * 2 )}
+        "TIMETZ '05:10:20+02'" == PgTimeTz { time: NaiveTime::from_hms(5, 10, 20), offset: FixedOffset::east(60 * 60 * 2 )},
+        "TIMETZ '05:10:20-05:30:15'" == PgTimeTz { time: NaiveTime::from_hms(5, 10, 20), offset: FixedOffset::west(60 * 60 * 5 + 1800 + 15) }
+    ));
+
+    test_type!(chrono_time_tz_vec<Vec<PgTimeTz>>(Postgres,
+        "array['05:10:20.115100+06:30', '23:00:00-08']::timetz[]"
+            == vec![
+                PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::east(60 * 60 * 6 + 1800) },
+                PgTimeTz { time: NaiveTime::from_hms(23, 0, 0), offset: FixedOffset::west(60 * 60 * 8) },
+            ]
     ));
 }
 
@@ -263,16 +432,48 @@ mod chrono {
 mod time_tests {
     use super::*;
     use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    use sqlx::{Executor, Row};
+    use sqlx_test::new;
     use time::{date, time};
 
+    type PgDate = sqlx::postgres::types::PgDate<Date>;
     type PgTimeTz = sqlx::postgres::types::PgTimeTz<Time, UtcOffset>;
 
     test_type!(time_date<Date>(
         Postgres,
         "DATE '2001-01-05'" == date!(2001 - 1 - 5),
-        "DATE '2050-11-23'" == date!(2050 - 11 - 23)
+        "DATE '2050-11-23'" == date!(2050 - 11 - 23),
+        "DATE '0044-03-15 BC'" == date!(-43 - 3 - 15)
+    ));
+
+    test_type!(time_pg_date<PgDate>(
+        Postgres,
+        "DATE '2001-01-05'" == PgDate::Date(date!(2001 - 1 - 5)),
+        "DATE 'infinity'" == PgDate::Infinity,
+        "DATE '-infinity'" == PgDate::NegInfinity
     ));
 
+    #[sqlx_macros::test]
+    async fn time_fails_to_decode_out_of_range_dates() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        for date in ["5874897-12-31", "infinity"].iter() {
+            let query = format!("SELECT DATE '{}'", date);
+
+            let res = sqlx::query_scalar::<_, Date>(&query)
+                .fetch_one(&mut conn)
+                .await;
+
+            assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+
+            let res = conn.fetch_one(&*query).await?.try_get::<Date, _>(0);
+
+            assert!(matches!(res, Err(sqlx::Error::ColumnDecode { .. })));
+        }
+
+        Ok(())
+    }
+
     test_type!(time_time<Time>(
         Postgres,
         "TIME '05:10:20.115100'" == time!(5:10:20.115100)
@@ -293,11 +494,68 @@ mod time_tests {
                 .assume_utc()
     ));
 
-    test_prepared_type!(time_time_tz<PgTimeTz>(Postgres,
+    test_type!(time_time_tz<PgTimeTz>(Postgres,
         "TIMETZ '05:10:20.115100+00'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::east_seconds(0) },
         "TIMETZ '05:10:20.115100+06:30'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::east_seconds(60 * 60 * 6 + 1800) },
         "TIMETZ '05:10:20.115100-05'" == PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::west_seconds(60 * 60 * 5) },
-        "TIMETZ '05:10:20+02'" == PgTimeTz { time: time!(5:10:20), offset: UtcOffset::east_seconds(60 * 60 * 2 )}
+        "TIMETZ '05:10:20+02'" == PgTimeTz { time: time!(5:10:20), offset: UtcOffset::east_seconds(60 * 60 * 2 )},
+        "TIMETZ '05:10:20-05:30:15'" == PgTimeTz { time: time!(5:10:20), offset: UtcOffset::west_seconds(60 * 60 * 5 + 1800 + 15) }
+    ));
+
+    test_type!(time_time_tz_vec<Vec<PgTimeTz>>(Postgres,
+        "array['05:10:20.115100+06:30', '23:00:00-08']::timetz[]"
+            == vec![
+                PgTimeTz { time: time!(5:10:20.115100), offset: UtcOffset::east_seconds(60 * 60 * 6 + 1800) },
+                PgTimeTz { time: time!(23:00:00), offset: UtcOffset::west_seconds(60 * 60 * 8) },
+            ]
+    ));
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_tests {
+    use super::*;
+    use sqlx::types::jiff::{Date, Span, Time, Timestamp};
+
+    type PgDate = sqlx::postgres::types::PgDate<Date>;
+
+    test_type!(jiff_date<Date>(
+        Postgres,
+        "DATE '2001-01-05'" == Date::constant(2001, 1, 5),
+        "DATE '2050-11-23'" == Date::constant(2050, 11, 23),
+        "DATE '0044-03-15 BC'" == Date::constant(-43, 3, 15)
+    ));
+
+    test_type!(jiff_pg_date<PgDate>(
+        Postgres,
+        "DATE '2001-01-05'" == PgDate::Date(Date::constant(2001, 1, 5)),
+        "DATE 'infinity'" == PgDate::Infinity,
+        "DATE '-infinity'" == PgDate::NegInfinity
+    ));
+
+    test_type!(jiff_time<Time>(
+        Postgres,
+        "TIME '05:10:20.115100'" == Time::constant(5, 10, 20, 115_100_000)
+    ));
+
+    test_type!(jiff_timestamp<Timestamp>(
+        Postgres,
+        "TIMESTAMPTZ '2019-01-02 05:10:20.115100+00'"
+            == "2019-01-02T05:10:20.1151Z".parse::<Timestamp>().unwrap(),
+        "TIMESTAMPTZ '1999-12-31 23:00:00-02'"
+            == "2000-01-01T01:00:00Z".parse::<Timestamp>().unwrap()
+    ));
+
+    test_prepared_type!(jiff_span<Span>(
+        Postgres,
+        "INTERVAL '1 year 2 months 3 days 01:15:10.5'"
+            == Span::new()
+                .months(14)
+                .days(3)
+                .hours(1)
+                .minutes(15)
+                .seconds(10)
+                .microseconds(500_000),
+        "INTERVAL '-2 days -00:00:01'" == Span::new().days(-2).seconds(-1)
     ));
 }
 
@@ -382,6 +640,27 @@ mod json {
 
         assert_eq!(value.get(), "{\"hello\": \"world\"}");
 
+        let value: Json<&JsonRawValue> = row.try_get(0)?;
+
+        assert_eq!(value.get(), "{\"hello\": \"world\"}");
+
+        // passed through without being parsed on our side
+        let raw = JsonRawValue::from_string("{\"foo\": [1, 2]}".to_owned())?;
+
+        let row: PgRow = conn
+            .fetch_one(
+                sqlx::query("SELECT $1::jsonb, $2::json")
+                    .bind(&*raw)
+                    .bind(&raw),
+            )
+            .await?;
+
+        let jsonb: &JsonRawValue = row.try_get(0)?;
+        let json: Box<JsonRawValue> = row.try_get(1)?;
+
+        assert_eq!(jsonb.get(), "{\"foo\": [1, 2]}");
+        assert_eq!(json.get(), raw.get());
+
         Ok(())
     }
 }
@@ -443,6 +722,32 @@ test_type!(int4range<PgRange<i32>>(Postgres,
     "'[1,2]'::int4range" == PgRange::from((INC1, EXC3)),
 ));
 
+#[sqlx_macros::test]
+async fn it_converts_int4range_to_range() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgInt4Range;
+    use std::convert::TryFrom;
+    use std::ops::Range;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    // discrete ranges are canonicalized by the server to `[start,end)`
+    let range: PgInt4Range = sqlx::query_scalar("SELECT '[1,2]'::int4range")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(Range::try_from(range).ok(), Some(1..3));
+
+    let range: PgInt4Range = sqlx::query_scalar("SELECT '[1,)'::int4range")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(Range::try_from(range).is_err());
+
+    assert!(Range::try_from(PgRange::from((INC1, Bound::Included(2)))).is_err());
+
+    Ok(())
+}
+
 test_prepared_type!(interval<PgInterval>(
     Postgres,
     "INTERVAL '1h'"
@@ -471,8 +776,121 @@ test_prepared_type!(interval<PgInterval>(
         },
 ));
 
+test_type!(cube<PgCube>(Postgres,
+    "'(1, 2)'::cube" == PgCube::Point(vec![1.0, 2.0]),
+    "'(1.5)'::cube" == PgCube::Point(vec![1.5]),
+    "'(1, -2),(3, 4)'::cube" == PgCube::Box(vec![1.0, -2.0], vec![3.0, 4.0]),
+    "cube(array[0.5, 1e20])" == PgCube::from(vec![0.5, 1e20]),
+));
+
+test_type!(cube_vec<Vec<PgCube>>(Postgres,
+    "array['(1, 2)', '(0),(1)']::cube[]"
+        == vec![PgCube::Point(vec![1.0, 2.0]), PgCube::Box(vec![0.0], vec![1.0])],
+));
+
+// snapshots have no equality operator
+test_type!(pg_snapshot<PgSnapshot>(
+    Postgres,
+    "SELECT ({0}::text is not distinct from $1::text)::int4, {0}, $2",
+    "'10:20:10,14,15'::pg_snapshot"
+        == PgSnapshot {
+            xmin: 10,
+            xmax: 20,
+            xip: vec![10, 14, 15]
+        },
+    "'12:12:'::pg_snapshot"
+        == PgSnapshot {
+            xmin: 12,
+            xmax: 12,
+            xip: vec![]
+        },
+));
+
+test_decode_type!(txid_snapshot<PgSnapshot>(
+    Postgres,
+    "'10:20:14'::txid_snapshot"
+        == PgSnapshot {
+            xmin: 10,
+            xmax: 20,
+            xip: vec![14]
+        },
+));
+
+test_type!(tid<PgTid>(
+    Postgres,
+    "'(0,1)'::tid" == PgTid { block: 0, offset: 1 },
+    "'(4294967295,65535)'::tid" == PgTid { block: u32::MAX, offset: u16::MAX },
+));
+
+test_type!(tid_vec<Vec<PgTid>>(
+    Postgres,
+    "array['(0,1)','(12,3)']::tid[]" == vec![PgTid { block: 0, offset: 1 }, PgTid { block: 12, offset: 3 }],
+));
+
+test_decode_type!(jsonpath<PgJsonPath>(
+    Postgres,
+    "'$.a[*] ? (@ > 1)'::jsonpath" == PgJsonPath::from("$.\"a\"[*]?(@ > 1)"),
+    "'strict $'::jsonpath" == PgJsonPath::from("strict $"),
+));
+
+test_decode_type!(jsonpath_vec<Vec<PgJsonPath>>(
+    Postgres,
+    "array['$.a', '$[0]']::jsonpath[]" == vec![PgJsonPath::from("$.\"a\""), PgJsonPath::from("$[0]")],
+));
+
+#[sqlx_macros::test]
+async fn it_binds_jsonpath() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    // the parameter is not cast in the query
+    let values: Vec<i32> = sqlx::query_scalar(
+        "SELECT value::int4 FROM jsonb_path_query('{\"a\": [1, 2, 3]}', $1) AS value",
+    )
+    .bind(PgJsonPath::from("$.a[*] ? (@ > 1)"))
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(values, [2, 3]);
+
+    let paths: Vec<PgJsonPath> = sqlx::query_scalar("SELECT $1::jsonpath[]")
+        .bind(vec![PgJsonPath::from("$.a"), PgJsonPath::from("lax $.b")])
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(
+        paths,
+        [PgJsonPath::from("$.\"a\""), PgJsonPath::from("$.\"b\"")]
+    );
+
+    Ok(())
+}
+
 test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)));
 
 test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
     "array[123.45,420.00,666.66]::money[]" == vec![PgMoney(12345), PgMoney(42000), PgMoney(66666)],
 ));
+
+test_decode_type!(lossy_f64<sqlx::types::Lossy<f64>>(Postgres,
+    "12345.6789::numeric" == sqlx::types::Lossy(12345.6789_f64),
+    "-0.0001::numeric" == sqlx::types::Lossy(-0.0001_f64),
+    "0::numeric" == sqlx::types::Lossy(0_f64),
+    "3.5::float8" == sqlx::types::Lossy(3.5_f64),
+    "1.25::float4" == sqlx::types::Lossy(1.25_f64),
+));
+
+test_decode_type!(lossy_f32<sqlx::types::Lossy<f32>>(Postgres,
+    "9419.122::numeric" == sqlx::types::Lossy(9419.122_f32),
+    "9419.122::real" == sqlx::types::Lossy(9419.122_f32),
+));
+
+test_decode_type!(sentinel_as_none_string<sqlx::types::SentinelAsNone<String>>(Postgres,
+    "''::text" == sqlx::types::SentinelAsNone(None),
+    "NULL::text" == sqlx::types::SentinelAsNone(None),
+    "'sqlx'::text" == sqlx::types::SentinelAsNone(Some("sqlx".to_owned())),
+));
+
+test_decode_type!(sentinel_as_none_i32<sqlx::types::SentinelAsNone<i32>>(Postgres,
+    "-1::int4" == sqlx::types::SentinelAsNone(None),
+    "0::int4" == sqlx::types::SentinelAsNone(Some(0)),
+));
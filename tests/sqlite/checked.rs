@@ -0,0 +1,51 @@
+use sqlx::Sqlite;
+use sqlx_test::new;
+
+#[sqlx_macros::test]
+async fn it_runs_checked_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let account = sqlx::query!("select id, name from accounts where id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(account.id, 1);
+
+    let name: String = sqlx::query_scalar!("select name from accounts where id = ?", 1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, account.name);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_dynamic_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let id: i64 = sqlx::query_scalar_dynamic("select id from accounts where name = ?")
+        .bind("Herp Derpinson")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 1);
+
+    Ok(())
+}
+
+// the calls are reported at compile time (see `tests/ui/checked`), but still run
+#[sqlx_macros::test]
+#[allow(deprecated)]
+async fn it_runs_unchecked_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let id: i64 = sqlx::query_scalar("select id from accounts where name = ?")
+        .bind("Herp Derpinson")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 1);
+
+    Ok(())
+}
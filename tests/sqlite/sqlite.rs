@@ -1,10 +1,13 @@
 use futures::TryStreamExt;
-use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::sqlite::{
+    SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteTransactionBehavior,
+};
 use sqlx::{
     query, sqlite::Sqlite, sqlite::SqliteRow, Column, Connection, Executor, Row, SqliteConnection,
     SqlitePool, Statement, TypeInfo,
 };
 use sqlx_test::new;
+use std::time::Duration;
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -504,3 +507,261 @@ async fn it_resets_prepared_statement_after_fetch_one() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_recovers_from_a_panic_in_a_collation() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.create_collation("panicking_collation", |_, _| panic!("collation failed"))?;
+
+    let _ = conn
+        .execute(
+            r#"
+CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL COLLATE panicking_collation)
+            "#,
+        )
+        .await?;
+
+    conn.execute("INSERT INTO users (name) VALUES ('a'), ('b')")
+        .await?;
+
+    let res = conn
+        .fetch_all("SELECT name FROM users ORDER BY name ASC")
+        .await;
+
+    match res {
+        Err(sqlx::Error::WorkerCrashed) => {}
+
+        other => panic!(
+            "expected a crashed worker, got {:?}",
+            other.map(|rows| rows.len())
+        ),
+    }
+
+    // the connection remains usable
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_serializes_concurrent_writes() -> anyhow::Result<()> {
+    let filename = std::env::temp_dir().join("sqlx-serialized-writes.db");
+    let _ = std::fs::remove_file(&filename);
+
+    // without serialized writes, the concurrent transactions would fail immediately
+    // with `SQLITE_BUSY`
+    let options = SqliteConnectOptions::new()
+        .filename(&filename)
+        .create_if_missing(true)
+        .busy_timeout(Duration::from_millis(0))
+        .serialized_writes(true);
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(4)
+        .connect_with(options)
+        .await?;
+
+    pool.execute("CREATE TABLE events (id INTEGER PRIMARY KEY, writer INTEGER NOT NULL)")
+        .await?;
+
+    let writers = (0..4).map(|writer: i64| {
+        let pool = pool.clone();
+
+        async move {
+            let mut tx = pool.begin().await?;
+
+            for _ in 0..2 {
+                sqlx::query("INSERT INTO events (writer) VALUES (?)")
+                    .bind(writer)
+                    .execute(&mut tx)
+                    .await?;
+
+                sqlx_rt::sleep(Duration::from_millis(10)).await;
+            }
+
+            tx.commit().await
+        }
+    });
+
+    futures::future::try_join_all(writers).await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 8);
+
+    pool.close().await;
+    let _ = std::fs::remove_file(&filename);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_and_writes_pragmas() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+
+    // the page size can only be changed before the database is written to
+    conn.set_page_size(8192).await?;
+    assert_eq!(conn.page_size().await?, 8192);
+
+    assert_eq!(conn.user_version().await?, 0);
+    conn.set_user_version(42).await?;
+    assert_eq!(conn.user_version().await?, 42);
+
+    assert!(conn.foreign_keys().await?);
+    conn.set_foreign_keys(false).await?;
+    assert!(!conn.foreign_keys().await?);
+
+    // an in-memory database cannot use WAL
+    let mode = conn.set_journal_mode(SqliteJournalMode::Wal).await?;
+    assert_eq!(mode, SqliteJournalMode::Memory);
+    assert_eq!(conn.journal_mode().await?, SqliteJournalMode::Memory);
+
+    assert!(conn.integrity_check().await?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_accesses_columns_of_the_same_name_by_table() -> anyhow::Result<()> {
+    let options: SqliteConnectOptions = "sqlite::memory:".parse()?;
+    let mut conn = SqliteConnection::connect_with(&options.strict_column_names(true)).await?;
+
+    conn.execute(
+        r#"
+CREATE TABLE join_parent (id INTEGER, name TEXT);
+CREATE TABLE join_child (id INTEGER, parent_id INTEGER);
+
+INSERT INTO join_parent VALUES (1, 'parent');
+INSERT INTO join_child VALUES (2, 1);
+        "#,
+    )
+    .await?;
+
+    let row = sqlx::query(
+        "SELECT join_parent.*, join_child.* FROM join_parent JOIN join_child ON join_parent.id = parent_id",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.try_get::<i64, _>(("join_parent", "id"))?, 1);
+    assert_eq!(row.try_get::<i64, _>(("join_child", "id"))?, 2);
+    assert_eq!(row.try_get::<&str, _>("name")?, "parent");
+
+    assert!(matches!(
+        row.try_get::<i64, _>("id"),
+        Err(sqlx::Error::ColumnAmbiguous(_))
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_immediate_transactions() -> anyhow::Result<()> {
+    let filename = std::env::temp_dir().join("sqlx-immediate-transactions.db");
+    let _ = std::fs::remove_file(&filename);
+
+    let options = SqliteConnectOptions::new()
+        .filename(&filename)
+        .create_if_missing(true)
+        .busy_timeout(Duration::from_millis(0));
+
+    let mut conn1 = SqliteConnection::connect_with(&options).await?;
+    let mut conn2 = SqliteConnection::connect_with(&options).await?;
+
+    conn1
+        .execute("CREATE TABLE immediate (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx1 = conn1
+        .begin_with(SqliteTransactionBehavior::Immediate)
+        .await?;
+
+    // a deferred transaction takes no lock when it begins
+    let tx2 = conn2
+        .begin_with(SqliteTransactionBehavior::Deferred)
+        .await?;
+    tx2.rollback().await?;
+
+    // but an immediate transaction cannot begin while another connection writes
+    assert!(conn2
+        .begin_with(SqliteTransactionBehavior::Immediate)
+        .await
+        .is_err());
+
+    // a savepoint within the transaction ignores the behavior
+    let mut savepoint = tx1.begin_with(SqliteTransactionBehavior::Exclusive).await?;
+    savepoint
+        .execute("INSERT INTO immediate (id) VALUES (1)")
+        .await?;
+    savepoint.commit().await?;
+
+    tx1.commit().await?;
+
+    let mut tx2 = conn2
+        .begin_with(SqliteTransactionBehavior::Immediate)
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM immediate")
+        .fetch_one(&mut tx2)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    tx2.commit().await?;
+
+    drop(conn1);
+    drop(conn2);
+    let _ = std::fs::remove_file(&filename);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_work_with_named_savepoints() -> anyhow::Result<()> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+
+    conn.execute("CREATE TABLE named_savepoints (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+
+    let mut savepoint = tx.savepoint("attempt").await?;
+
+    savepoint
+        .execute("INSERT INTO named_savepoints (id) VALUES (1)")
+        .await?;
+
+    savepoint.rollback_to("attempt").await?;
+
+    savepoint
+        .execute("INSERT INTO named_savepoints (id) VALUES (2)")
+        .await?;
+
+    savepoint.commit().await?;
+
+    // the savepoint is rolled back when it is dropped
+    {
+        let mut savepoint = tx.savepoint("dropped").await?;
+
+        savepoint
+            .execute("INSERT INTO named_savepoints (id) VALUES (3)")
+            .await?;
+    }
+
+    tx.commit().await?;
+
+    let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM named_savepoints ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(ids, vec![2]);
+
+    Ok(())
+}
@@ -13,6 +13,10 @@ test_type!(i32(Sqlite, "94101" == 94101_i32));
 
 test_type!(i64(Sqlite, "9358295312" == 9358295312_i64));
 
+test_type!(non_zero_i64<std::num::NonZeroI64>(Sqlite,
+    "9358295312" == std::num::NonZeroI64::new(9358295312).unwrap(),
+));
+
 // NOTE: This behavior can be surprising. Floating-point parameters are widening to double which can
 //       result in strange rounding.
 test_type!(f32(Sqlite, "3.1410000324249268" == 3.141f32 as f64 as f32));
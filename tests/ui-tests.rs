@@ -42,5 +42,9 @@ fn ui_tests() {
         t.compile_fail("tests/ui/sqlite/*.rs");
     }
 
+    if cfg!(feature = "checked-queries") {
+        t.compile_fail("tests/ui/checked/*.rs");
+    }
+
     t.compile_fail("tests/ui/*.rs");
 }
@@ -0,0 +1,9 @@
+#[allow(dead_code)]
+fn unchecked<DB: sqlx::Database>() {
+    let _ = sqlx::query::<DB>("select 1");
+    let _ = sqlx::query_dynamic::<DB>("select 1");
+}
+
+fn main() {
+    compile_error!("trybuild test needs to fail for stderr checking");
+}
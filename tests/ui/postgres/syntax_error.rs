@@ -0,0 +1,8 @@
+fn main() {
+    let _ = sqlx::query!("SELECT 1 FROMM users");
+
+    let _ = sqlx::query!(
+        "SELECT id \
+         FROM users WHERE \"id\" = = 1"
+    );
+}
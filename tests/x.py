@@ -141,6 +141,13 @@ for runtime in ["async-std", "tokio", "actix"]:
         tag=f"sqlite" if runtime == "async-std" else f"sqlite_{runtime}",
     )
 
+    run(
+        f"cargo test --no-default-features --features macros,offline,sqlite,checked-queries,runtime-{runtime}-native-tls --test sqlite-checked",
+        comment=f"test sqlite with checked queries",
+        service="sqlite",
+        tag=f"sqlite_checked" if runtime == "async-std" else f"sqlite_checked_{runtime}",
+    )
+
     #
     # postgres
     #